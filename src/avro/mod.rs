@@ -0,0 +1,3 @@
+pub mod named_type;
+pub mod protocol;
+pub mod translation;
@@ -0,0 +1,137 @@
+use std::path::{Path, PathBuf};
+
+use crate::domain::usecase::avro::registry::avro_registry::AvroRegistry;
+
+/// # AvroNamedType
+/// Record, error, enum or fixed type declared in an Avro file of a directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvroNamedType {
+    full_name: String,
+    file: PathBuf,
+    dependencies: Vec<String>,
+    recursive: bool,
+}
+
+/// # find_named_types
+/// Named types of the schemas (".avsc"), protocols (".avpr") and IDL files (".avdl") found
+/// in "avro_dir" and its subdirectories, every type after the ones it references except
+/// inside cycles. Duplicated full names and unknown references are an error.
+pub fn find_named_types(avro_dir: &Path) -> Result<Vec<AvroNamedType>, String> {
+    let registry = AvroRegistry::from_dir(avro_dir)?;
+
+    let mut result = Vec::new();
+    for item in registry.get_sorted_named_types() {
+        let full_name = item.get_full_name().unwrap_or_default();
+        let file = registry
+            .get_file(&full_name)
+            .map(Path::to_path_buf)
+            .unwrap_or_default();
+        let dependencies = registry
+            .get_dependencies(&full_name)
+            .iter()
+            .filter_map(|dependency| dependency.get_full_name())
+            .collect();
+        let recursive = registry.is_recursive(&full_name);
+        result.push(AvroNamedType::new(full_name, file, dependencies, recursive));
+    }
+
+    Ok(result)
+}
+
+impl AvroNamedType {
+    // Public methods
+    /// # get_full_name
+    /// Name with its namespace, i.e. "com.shop.Order".
+    pub fn get_full_name(&self) -> &str {
+        &self.full_name
+    }
+
+    pub fn get_file(&self) -> &Path {
+        &self.file
+    }
+
+    /// # get_dependencies
+    /// Full names of the named types it references or declares inline.
+    pub fn get_dependencies(&self) -> &Vec<String> {
+        &self.dependencies
+    }
+
+    /// # is_recursive
+    /// It returns true when the type references itself, directly or through other types.
+    pub fn is_recursive(&self) -> bool {
+        self.recursive
+    }
+}
+
+impl AvroNamedType {
+    // Crate or private methods
+    pub(crate) fn new(
+        full_name: String,
+        file: PathBuf,
+        dependencies: Vec<String>,
+        recursive: bool,
+    ) -> Self {
+        Self {
+            full_name,
+            file,
+            dependencies,
+            recursive,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::avro::named_type;
+
+    #[test]
+    fn find_named_types_dependencies_first() {
+        let named_types =
+            named_type::find_named_types(&get_registry_test_dir()).expect("Named types expected");
+
+        let found: Vec<(&str, bool)> = named_types
+            .iter()
+            .map(|named_type| (named_type.get_full_name(), named_type.is_recursive()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("com.customer.Address", false),
+                ("com.customer.Customer", false),
+                ("com.shop.Category", true),
+                ("com.shop.Money", false),
+                ("com.shop.Order", false),
+            ],
+            found
+        );
+        assert!(named_types[1]
+            .get_file()
+            .ends_with("customer/customer.avsc"));
+        assert_eq!(
+            &vec![
+                "com.customer.Customer".to_string(),
+                "com.shop.Money".to_string(),
+                "com.shop.Category".to_string()
+            ],
+            named_types[4].get_dependencies()
+        );
+    }
+
+    #[test]
+    fn find_named_types_missing_dir() {
+        assert!(named_type::find_named_types(&get_current_file_path()).is_err());
+    }
+
+    fn get_registry_test_dir() -> PathBuf {
+        let mut dir = get_current_file_path();
+        dir.pop();
+        dir.pop();
+        dir.push("domain/usecase/avro/registry/test/shop");
+        dir
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1,236 @@
+use std::path::Path;
+
+use crate::domain::usecase::avro::parser::avro_parser;
+use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
+use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+use crate::domain::usecase::avro::parser::dto::{avro_message, avro_protocol};
+
+/// # AvroProtocol
+/// Name and messages of an Avro protocol (".avpr" or ".avdl").
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvroProtocol {
+    name: String,
+    namespace: Option<String>,
+    doc: Option<String>,
+    messages: Vec<AvroMessage>,
+}
+
+/// # AvroMessage
+/// Protocol message, types are written as in the IDL (i.e. "com.shop.Order" or "array<string>").
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvroMessage {
+    name: String,
+    doc: Option<String>,
+    parameters: Vec<AvroParameter>,
+    response: String,
+    errors: Vec<String>,
+    one_way: bool,
+}
+
+/// # AvroParameter
+/// Request parameter of a protocol message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvroParameter {
+    name: String,
+    type_name: String,
+}
+
+/// # read_protocol
+/// Protocol declared in "protocol_file", a JSON protocol (".avpr") or an IDL file (".avdl").
+pub fn read_protocol(protocol_file: &Path) -> Result<AvroProtocol, String> {
+    let protocol = avro_parser::parse_protocol(protocol_file)?;
+    Ok(AvroProtocol::from(&protocol))
+}
+
+impl AvroProtocol {
+    // Public methods
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_namespace(&self) -> Option<&str> {
+        self.namespace.as_deref()
+    }
+
+    pub fn get_doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+
+    pub fn get_messages(&self) -> &Vec<AvroMessage> {
+        &self.messages
+    }
+}
+
+impl AvroProtocol {
+    // Crate or private methods
+    fn from(protocol: &avro_protocol::AvroProtocol) -> Self {
+        Self {
+            name: protocol.get_name().to_string(),
+            namespace: protocol.get_namespace(),
+            doc: protocol.get_doc(),
+            messages: protocol
+                .get_messages()
+                .iter()
+                .map(AvroMessage::from)
+                .collect(),
+        }
+    }
+}
+
+impl AvroMessage {
+    // Public methods
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+
+    pub fn get_parameters(&self) -> &Vec<AvroParameter> {
+        &self.parameters
+    }
+
+    pub fn get_response(&self) -> &str {
+        &self.response
+    }
+
+    /// # get_errors
+    /// Declared errors, without the implicit "string" error of every message.
+    pub fn get_errors(&self) -> &Vec<String> {
+        &self.errors
+    }
+
+    /// # is_one_way
+    /// It returns true when the message has no response nor errors.
+    pub fn is_one_way(&self) -> bool {
+        self.one_way
+    }
+}
+
+impl AvroMessage {
+    // Crate or private methods
+    fn from(message: &avro_message::AvroMessage) -> Self {
+        Self {
+            name: message.get_name().to_string(),
+            doc: message.get_doc(),
+            parameters: message
+                .get_request()
+                .iter()
+                .map(AvroParameter::from)
+                .collect(),
+            response: get_type_name(message.get_response()),
+            errors: message.get_errors().iter().map(get_type_name).collect(),
+            one_way: message.is_one_way(),
+        }
+    }
+}
+
+impl AvroParameter {
+    // Public methods
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_type_name(&self) -> &str {
+        &self.type_name
+    }
+}
+
+impl AvroParameter {
+    // Crate or private methods
+    fn from(parameter: &AvroItem) -> Self {
+        Self {
+            name: parameter.get_name().unwrap_or_default(),
+            type_name: get_type_name(parameter.get_item_type()),
+        }
+    }
+}
+
+fn get_type_name(item_type: &AvroItemType) -> String {
+    match item_type {
+        AvroItemType::Reference(full_name) => full_name.to_string(),
+        AvroItemType::Array(items) => format!("array<{}>", get_type_name(items)),
+        AvroItemType::Map(values) => format!("map<{}>", get_type_name(values)),
+        AvroItemType::Union(types) => {
+            let type_names: Vec<String> = types.iter().map(get_type_name).collect();
+            format!("union {{ {} }}", type_names.join(", "))
+        }
+        AvroItemType::Inline(item) => get_inline_type_name(item),
+        AvroItemType::Record => "record".to_string(),
+        AvroItemType::Error => "error".to_string(),
+        AvroItemType::Enum => "enum".to_string(),
+        AvroItemType::Fixed => "fixed".to_string(),
+        AvroItemType::Null => "null".to_string(),
+        AvroItemType::Boolean => "boolean".to_string(),
+        AvroItemType::Int => "int".to_string(),
+        AvroItemType::Long => "long".to_string(),
+        AvroItemType::Float => "float".to_string(),
+        AvroItemType::Double => "double".to_string(),
+        AvroItemType::Bytes => "bytes".to_string(),
+        AvroItemType::String => "string".to_string(),
+    }
+}
+
+/// Named types declared inline are written with their full name, logical types with theirs
+fn get_inline_type_name(item: &AvroItem) -> String {
+    if let Some(full_name) = item.get_full_name() {
+        return full_name;
+    }
+    if let Some(logical_type) = item.get_logical_type() {
+        return logical_type.to_string();
+    }
+    get_type_name(item.get_item_type())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::avro::protocol;
+
+    #[test]
+    fn read_protocol_messages() {
+        let protocol =
+            protocol::read_protocol(&get_protocol_test_file()).expect("Protocol expected");
+
+        assert_eq!("Greeter", protocol.get_name());
+        assert_eq!(Some("com.greeting"), protocol.get_namespace());
+        assert_eq!(Some("Greeting service"), protocol.get_doc());
+        let messages = protocol.get_messages();
+        assert_eq!(2, messages.len());
+        assert_eq!("hello", messages[0].get_name());
+        assert_eq!(Some("Say hello"), messages[0].get_doc());
+        assert_eq!("greeting", messages[0].get_parameters()[0].get_name());
+        assert_eq!(
+            "com.greeting.Greeting",
+            messages[0].get_parameters()[0].get_type_name()
+        );
+        assert_eq!("com.greeting.Greeting", messages[0].get_response());
+        assert_eq!(
+            &vec!["com.greeting.Curse".to_string()],
+            messages[0].get_errors()
+        );
+        assert!(!messages[0].is_one_way());
+        assert_eq!("notify", messages[1].get_name());
+        assert_eq!("null", messages[1].get_response());
+        assert!(messages[1].is_one_way());
+    }
+
+    #[test]
+    fn read_protocol_missing_file() {
+        assert!(protocol::read_protocol(&PathBuf::from("missing.avpr")).is_err());
+    }
+
+    fn get_protocol_test_file() -> PathBuf {
+        let mut file = get_current_file_path();
+        file.pop();
+        file.pop();
+        file.push("domain/usecase/avro/parser/test/protocol.avpr");
+        file
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
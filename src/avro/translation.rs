@@ -0,0 +1,76 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::domain::usecase::avro::translator::from_openapi::avro_from_openapi_translator;
+use crate::domain::usecase::openapi::parser::openapi_parser;
+
+/// # openapi_to_avro
+/// Write the Avro schemas of the component schemas of an OpenAPI document in "output_folder",
+/// one ".avsc" file per namespace. Schema names without dots get the given "namespace".
+/// The folder is created if needed. It returns the written files in reading order.
+pub fn openapi_to_avro(
+    openapi_file: &Path,
+    namespace: &str,
+    output_folder: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let document = openapi_parser::parse(openapi_file)?;
+    fs::create_dir_all(output_folder).map_err(|err| {
+        format!(
+            "Avro output folder could not be created ({}):\n{}\n",
+            err,
+            try_to_absolute_path(output_folder)
+        )
+    })?;
+
+    avro_from_openapi_translator::openapi_to_avsc(
+        document.get_components().get_schemas(),
+        namespace,
+        output_folder,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::avro::translation;
+    use crate::core::testing::test_assert::assert_same_file;
+
+    #[test]
+    fn openapi_to_avro_writes_one_file_per_namespace() {
+        let test_dir = get_translator_test_dir();
+        let output_folder =
+            std::env::temp_dir().join(format!("genco_openapi_to_avro_test_{}", std::process::id()));
+
+        let avsc_files =
+            translation::openapi_to_avro(&test_dir.join("shop.yaml"), "com.shop", &output_folder)
+                .expect("Avro schemas must be written");
+
+        assert_eq!(
+            vec![
+                output_folder.join("com.common.avsc"),
+                output_folder.join("com.shop.avsc")
+            ],
+            avsc_files
+        );
+        for avsc_file in &avsc_files {
+            let file_name = avsc_file.file_name().expect("File name");
+            assert_same_file(&test_dir.join("expected").join(file_name), avsc_file);
+        }
+        fs::remove_dir_all(&output_folder).expect("Output folder must be removed");
+    }
+
+    fn get_translator_test_dir() -> PathBuf {
+        let mut dir = get_current_file_path();
+        dir.pop();
+        dir.pop();
+        dir.push("domain/usecase/avro/translator/from_openapi/test");
+        dir
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -1,16 +1,27 @@
 use std::future::Future;
+use std::sync::OnceLock;
 
-pub(crate) fn wait<T>(async_call: impl Future<Output = T>) -> T {
-    let rt = tokio::runtime::Runtime::new().expect("Error trying to wait for asynchronous task");
+use tokio::runtime::Runtime;
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
 
-    rt.block_on(async_call)
+/// # get_runtime
+/// Tokio runtime shared by the whole crate, it is created the first time it is requested.
+pub(crate) fn get_runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| Runtime::new().expect("Error trying to create the asynchronous runtime"))
+}
+
+/// Block the current thread until the future completes.
+/// It must not be called from asynchronous code running in the shared runtime.
+pub(crate) fn wait<T>(async_call: impl Future<Output = T>) -> T {
+    get_runtime().block_on(async_call)
 }
 
 #[cfg(test)]
 mod tests {
     use std::{thread, time};
 
-    use crate::core::asynchronous::future_handler::wait;
+    use crate::core::asynchronous::future_handler::{get_runtime, wait};
 
     #[test]
     fn wait_fake_async() {
@@ -33,4 +44,21 @@ mod tests {
 
         assert_eq!(1, result);
     }
+
+    #[test]
+    fn wait_spawned_blocking_tasks() {
+        let handles: Vec<_> = (0..4)
+            .map(|index| get_runtime().spawn_blocking(move || index * 2))
+            .collect();
+
+        let result = wait(async {
+            let mut result = Vec::new();
+            for handle in handles {
+                result.push(handle.await.expect("Blocking task must finish"));
+            }
+            result
+        });
+
+        assert_eq!(vec![0, 2, 4, 6], result);
+    }
 }
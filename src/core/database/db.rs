@@ -1,4 +1,4 @@
-use rusqlite::Error;
+use rusqlite::{Error, Params, Transaction};
 
 use crate::core::database::db_setup;
 use crate::core::observability::logger;
@@ -7,13 +7,46 @@ pub(crate) fn execute_insert_3_param(
     query: &str,
     params: (&str, &str, &str),
 ) -> Result<usize, String> {
-    let conn = db_setup::get_db_connection();
+    let conn = db_setup::get_db_connection()?;
     match conn.execute(query, params) {
         Ok(n) => Ok(n),
         Err(err) => log_execute_error(err),
     }
 }
 
+pub(crate) fn execute<P: Params>(query: &str, params: P) -> Result<usize, String> {
+    let conn = db_setup::get_db_connection()?;
+    match conn.execute(query, params) {
+        Ok(n) => Ok(n),
+        Err(err) => log_execute_error(err),
+    }
+}
+
+/// # execute_in_transaction
+/// Run all the database operations in a single transaction, nothing is stored if any of them fails.
+pub(crate) fn execute_in_transaction<T, F>(operations: F) -> Result<T, String>
+where
+    F: FnOnce(&Transaction) -> Result<T, Error>,
+{
+    let mut conn = db_setup::get_db_connection()?;
+    let transaction = conn
+        .transaction()
+        .map_err(|err| format!("Error starting database transaction: {}", err))?;
+    let result = match operations(&transaction) {
+        Ok(result) => result,
+        Err(err) => {
+            let err_msg = format!("Error running transaction query: {}", err);
+            logger::log_warning(err_msg.as_str());
+            return Err(err_msg);
+        }
+    };
+    transaction
+        .commit()
+        .map_err(|err| format!("Error committing database transaction: {}", err))?;
+
+    Ok(result)
+}
+
 fn log_execute_error(err: Error) -> Result<usize, String> {
     let err_msg = format!("Error running execute query: {}", err);
     logger::log_warning(err_msg.as_str());
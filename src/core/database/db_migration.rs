@@ -0,0 +1,193 @@
+use rusqlite::{Connection, Error, TransactionBehavior};
+
+use crate::core::database::db_pool::DbConnection;
+
+/// Database schema change, migrations are embedded in the library and applied
+/// in ascending version order. Versions must never be modified once released.
+struct DbMigration {
+    version: i64,
+    name: &'static str,
+    sql: &'static str,
+}
+
+static MIGRATIONS: &[DbMigration] = &[
+    DbMigration {
+        version: 1,
+        name: "java_import_route",
+        sql: include_str!("migrations/0001_java_import_route.sql"),
+    },
+    DbMigration {
+        version: 2,
+        name: "java_scanned_file",
+        sql: include_str!("migrations/0002_java_scanned_file.sql"),
+    },
+    DbMigration {
+        version: 3,
+        name: "java_symbol_index",
+        sql: include_str!("migrations/0003_java_symbol_index.sql"),
+    },
+    DbMigration {
+        version: 4,
+        name: "java_usage_reference",
+        sql: include_str!("migrations/0004_java_usage_reference.sql"),
+    },
+];
+
+const CREATE_SCHEMA_VERSION_TABLE: &str = "CREATE TABLE IF NOT EXISTS schema_version (
+    version  INTEGER PRIMARY KEY,
+    name  TEXT NOT NULL,
+    applied_at  INTEGER NOT NULL
+);";
+
+/// # migrate
+/// Apply the pending migrations, each one in its own transaction.
+/// It fails if the database was created by a newer genco version.
+/// Processes sharing the database file can migrate it at the same time, the version is
+/// read again once the write lock is taken, so every migration is only applied once.
+pub(crate) fn migrate(conn: &mut DbConnection) -> Result<(), String> {
+    migrate_connection(conn)
+}
+
+fn migrate_connection(conn: &mut Connection) -> Result<(), String> {
+    conn.execute_batch(CREATE_SCHEMA_VERSION_TABLE)
+        .map_err(|err| {
+            format!(
+                "Database schema_version table could not be created: {}",
+                err
+            )
+        })?;
+
+    let current_version = get_current_version(conn)
+        .map_err(|err| format!("Database schema version could not be read: {}", err))?;
+    let latest_version = get_latest_version();
+    if current_version > latest_version {
+        return Err(format!(
+            "Database schema version {} is newer than the latest version supported by this genco release ({}). \
+            Downgrades are not supported, please upgrade genco or use another database location.",
+            current_version, latest_version
+        ));
+    }
+
+    for migration in MIGRATIONS
+        .iter()
+        .filter(|migration| migration.version > current_version)
+    {
+        apply(conn, migration).map_err(|err| {
+            format!(
+                "Database migration {} (\"{}\") failed: {}",
+                migration.version, migration.name, err
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Migrations already applied by another connection are skipped
+fn apply(conn: &mut Connection, migration: &DbMigration) -> Result<(), Error> {
+    let transaction = conn.transaction_with_behavior(TransactionBehavior::Immediate)?;
+    if get_current_version(&transaction)? >= migration.version {
+        return Ok(());
+    }
+    transaction.execute_batch(migration.sql)?;
+    transaction.execute(
+        "INSERT INTO schema_version (version, name, applied_at) VALUES (?1, ?2, strftime('%s', 'now'))",
+        (migration.version, migration.name),
+    )?;
+    transaction.commit()
+}
+
+fn get_current_version(conn: &Connection) -> Result<i64, Error> {
+    conn.query_row(
+        "SELECT COALESCE(MAX(version), 0) FROM schema_version",
+        [],
+        |row| row.get(0),
+    )
+}
+
+fn get_latest_version() -> i64 {
+    MIGRATIONS
+        .last()
+        .map(|migration| migration.version)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use rusqlite::Connection;
+
+    use crate::core::database::db_migration;
+    use crate::core::database::db_migration::MIGRATIONS;
+
+    #[test]
+    fn migrate_empty_database() {
+        let mut conn = Connection::open_in_memory().expect("In-memory database");
+
+        db_migration::migrate_connection(&mut conn).expect("Migrations must succeed");
+
+        let version = db_migration::get_current_version(&conn).expect("Version expected");
+        assert_eq!(db_migration::get_latest_version(), version);
+        let java_import_routes: i64 = conn
+            .query_row("SELECT COUNT(*) FROM java_import_route", [], |row| {
+                row.get(0)
+            })
+            .expect("java_import_route table must exist");
+        assert_eq!(0, java_import_routes);
+    }
+
+    #[test]
+    fn migrate_twice_applies_migrations_once() {
+        let mut conn = Connection::open_in_memory().expect("In-memory database");
+
+        db_migration::migrate_connection(&mut conn).expect("Migrations must succeed");
+        db_migration::migrate_connection(&mut conn).expect("Migrations must succeed");
+
+        let applied_migrations: i64 = conn
+            .query_row("SELECT COUNT(*) FROM schema_version", [], |row| row.get(0))
+            .expect("schema_version table must exist");
+        assert_eq!(MIGRATIONS.len() as i64, applied_migrations);
+    }
+
+    #[test]
+    fn apply_migration_applied_by_another_connection() {
+        let db_file =
+            std::env::temp_dir().join(format!("genco_db_migration_test_{}.db", std::process::id()));
+        let mut first_conn = Connection::open(&db_file).expect("Database file");
+        let mut second_conn = Connection::open(&db_file).expect("Database file");
+        db_migration::migrate_connection(&mut first_conn).expect("Migrations must succeed");
+
+        let result = db_migration::apply(&mut second_conn, &MIGRATIONS[0]);
+
+        let applied_migrations: i64 = second_conn
+            .query_row("SELECT COUNT(*) FROM schema_version", [], |row| row.get(0))
+            .expect("schema_version table must exist");
+        drop(first_conn);
+        drop(second_conn);
+        std::fs::remove_file(&db_file).expect("Database file must be removed");
+        assert!(result.is_ok());
+        assert_eq!(MIGRATIONS.len() as i64, applied_migrations);
+    }
+
+    #[test]
+    fn migrate_downgrade_fails() {
+        let mut conn = Connection::open_in_memory().expect("In-memory database");
+        db_migration::migrate_connection(&mut conn).expect("Migrations must succeed");
+        conn.execute(
+            "INSERT INTO schema_version (version, name, applied_at) VALUES (9999, 'future', 0)",
+            [],
+        )
+        .expect("Fake future migration must be inserted");
+
+        match db_migration::migrate_connection(&mut conn) {
+            Ok(_) => panic!("Downgrade must fail"),
+            Err(err) => assert!(err.contains("Database schema version 9999 is newer")),
+        }
+    }
+
+    #[test]
+    fn migration_versions_are_ascending() {
+        for (index, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!((index + 1) as i64, migration.version);
+        }
+    }
+}
@@ -0,0 +1,275 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::{Condvar, Mutex};
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+
+use rusqlite::Connection;
+
+use crate::core::database::db_setup::DatabaseLocation;
+
+const MAX_FILE_CONNECTIONS: usize = 8;
+const BUSY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Thread-safe pool of database connections.
+///
+/// In-memory databases only live as long as their connection, so the pool keeps
+/// a single connection shared by all the threads in that case. A thread requesting a
+/// connection while it holds all the connections of the pool gets an error, waiting
+/// for them would never end.
+pub(crate) struct DbPool {
+    location: DatabaseLocation,
+    max_connections: usize,
+    state: Mutex<DbPoolState>,
+    connection_released: Condvar,
+}
+
+struct DbPoolState {
+    idle_connections: Vec<Connection>,
+    open_connections: usize,
+    /// Thread that requested each borrowed connection
+    holders: Vec<ThreadId>,
+}
+
+/// Connection borrowed from a DbPool, it is returned to the pool when dropped
+pub(crate) struct DbConnection<'a> {
+    connection: Option<Connection>,
+    holder: ThreadId,
+    pool: &'a DbPool,
+}
+
+// Public crate methods
+impl DbPool {
+    pub(crate) fn new(location: DatabaseLocation) -> Result<Self, String> {
+        let max_connections = match location {
+            DatabaseLocation::File(_) => MAX_FILE_CONNECTIONS,
+            DatabaseLocation::InMemory => 1,
+        };
+        let pool = Self {
+            location,
+            max_connections,
+            state: Mutex::new(DbPoolState {
+                idle_connections: Vec::new(),
+                open_connections: 0,
+                holders: Vec::new(),
+            }),
+            connection_released: Condvar::new(),
+        };
+
+        let first_connection = pool.open_connection()?;
+        let mut state = pool.lock_state();
+        state.idle_connections.push(first_connection);
+        state.open_connections = 1;
+        drop(state);
+
+        Ok(pool)
+    }
+
+    pub(crate) fn get_location(&self) -> &DatabaseLocation {
+        &self.location
+    }
+
+    /// Wait until a connection is available, a new one is opened if the pool is not full
+    pub(crate) fn get_connection(&self) -> Result<DbConnection<'_>, String> {
+        let current_thread = thread::current().id();
+        let mut state = self.lock_state();
+        loop {
+            if let Some(connection) = state.idle_connections.pop() {
+                state.holders.push(current_thread);
+                return Ok(DbConnection::new(connection, current_thread, self));
+            }
+
+            if state.open_connections < self.max_connections {
+                state.open_connections += 1;
+                state.holders.push(current_thread);
+                drop(state);
+                return match self.open_connection() {
+                    Ok(connection) => Ok(DbConnection::new(connection, current_thread, self)),
+                    Err(err) => {
+                        let mut state = self.lock_state();
+                        self.remove_holder(&mut state, current_thread);
+                        state.open_connections -= 1;
+                        Err(err)
+                    }
+                };
+            }
+
+            if state.holders.iter().all(|holder| current_thread == *holder) {
+                return Err(format!(
+                    "Database connection requested by a thread holding all the {} connections of the pool",
+                    self.max_connections
+                ));
+            }
+
+            state = self
+                .connection_released
+                .wait(state)
+                .expect("Database pool lock must not be poisoned");
+        }
+    }
+}
+
+// Private methods
+impl DbPool {
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, DbPoolState> {
+        self.state
+            .lock()
+            .expect("Database pool lock must not be poisoned")
+    }
+
+    fn release(&self, connection: Connection, holder: ThreadId) {
+        let mut state = self.lock_state();
+        self.remove_holder(&mut state, holder);
+        state.idle_connections.push(connection);
+        drop(state);
+        self.connection_released.notify_one();
+    }
+
+    fn remove_holder(&self, state: &mut DbPoolState, holder: ThreadId) {
+        if let Some(index) = state.holders.iter().position(|current| holder == *current) {
+            state.holders.swap_remove(index);
+        }
+    }
+
+    fn open_connection(&self) -> Result<Connection, String> {
+        let connection = match &self.location {
+            DatabaseLocation::File(db_file) => {
+                let connection = Connection::open(db_file).map_err(|err| {
+                    format!(
+                        "Database could not be opened ({}):\n\"{}\"\n",
+                        err,
+                        db_file.to_string_lossy()
+                    )
+                })?;
+                connection
+                    .query_row("PRAGMA journal_mode=WAL", [], |_| Ok(()))
+                    .map_err(|err| format!("Database WAL mode could not be enabled: {}", err))?;
+                connection
+            }
+            DatabaseLocation::InMemory => Connection::open_in_memory()
+                .map_err(|err| format!("In-memory database could not be opened: {}", err))?,
+        };
+
+        connection
+            .busy_timeout(BUSY_TIMEOUT)
+            .map_err(|err| format!("Database busy timeout could not be set: {}", err))?;
+        Ok(connection)
+    }
+}
+
+impl<'a> DbConnection<'a> {
+    fn new(connection: Connection, holder: ThreadId, pool: &'a DbPool) -> Self {
+        Self {
+            connection: Some(connection),
+            holder,
+            pool,
+        }
+    }
+}
+
+impl Deref for DbConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.connection
+            .as_ref()
+            .expect("Database connection must exist until it is dropped")
+    }
+}
+
+impl DerefMut for DbConnection<'_> {
+    fn deref_mut(&mut self) -> &mut Connection {
+        self.connection
+            .as_mut()
+            .expect("Database connection must exist until it is dropped")
+    }
+}
+
+impl Drop for DbConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(connection) = self.connection.take() {
+            self.pool.release(connection, self.holder);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::database::db_pool::DbPool;
+    use crate::core::database::db_setup::DatabaseLocation;
+    use crate::core::file_system::file_edition::file_editor;
+
+    #[test]
+    fn in_memory_pool_shares_data() {
+        let pool = DbPool::new(DatabaseLocation::InMemory).expect("In-memory pool must open");
+
+        pool.get_connection()
+            .expect("Connection expected")
+            .execute_batch("CREATE TABLE shared (id INTEGER); INSERT INTO shared VALUES (1);")
+            .expect("Table must be created");
+        let count: i32 = pool
+            .get_connection()
+            .expect("Connection expected")
+            .query_row("SELECT COUNT(*) FROM shared", [], |row| row.get(0))
+            .expect("Table must be shared");
+
+        assert_eq!(1, count);
+    }
+
+    #[test]
+    fn in_memory_pool_nested_connection_error() {
+        let pool = DbPool::new(DatabaseLocation::InMemory).expect("In-memory pool must open");
+
+        let first_connection = pool.get_connection().expect("First connection expected");
+        let nested_result = pool.get_connection();
+        let is_nested_error = nested_result.is_err();
+        drop(nested_result);
+        drop(first_connection);
+
+        assert!(is_nested_error);
+        assert!(pool.get_connection().is_ok());
+    }
+
+    #[test]
+    fn in_memory_pool_waits_for_other_threads() {
+        let pool = DbPool::new(DatabaseLocation::InMemory).expect("In-memory pool must open");
+
+        let connection = pool.get_connection().expect("Connection expected");
+        std::thread::scope(|scope| {
+            let waiting_thread = scope.spawn(|| pool.get_connection().is_ok());
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            drop(connection);
+
+            assert!(waiting_thread.join().expect("Thread must end"));
+        });
+    }
+
+    #[test]
+    fn file_pool_several_connections_in_wal_mode() {
+        let db_file = get_test_db_file();
+        let pool = DbPool::new(DatabaseLocation::File(db_file.clone())).expect("Pool must open");
+
+        let first_connection = pool.get_connection().expect("First connection expected");
+        let second_connection = pool.get_connection().expect("Second connection expected");
+        let journal_mode: String = second_connection
+            .query_row("PRAGMA journal_mode", [], |row| row.get(0))
+            .expect("Journal mode expected");
+        drop(first_connection);
+        drop(second_connection);
+        drop(pool);
+
+        assert_eq!("wal", journal_mode);
+        for suffix in ["", "-wal", "-shm"] {
+            let file = PathBuf::from(format!("{}{}", db_file.to_string_lossy(), suffix));
+            file_editor::remove_file_if_exists(&file).expect("Database file must be removed");
+        }
+    }
+
+    fn get_test_db_file() -> PathBuf {
+        let dir = std::env::temp_dir().join("genco_db_pool_test");
+        fs::create_dir_all(&dir).expect("Test directory must be created");
+        dir.join(format!("pool_{}.db", std::process::id()))
+    }
+}
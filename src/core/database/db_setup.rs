@@ -1,71 +1,126 @@
-use std::fs;
 use std::path::PathBuf;
-use std::sync::Once;
+use std::sync::{Mutex, OnceLock};
 
-use rusqlite::Connection;
+use crate::core::database::db_migration;
+use crate::core::database::db_pool::{DbConnection, DbPool};
+use crate::core::observability::logger;
 
-static SYNC_OBJ: Once = Once::new();
+const DB_FOLDER_NAME: &str = "genco";
+const DB_FILE_NAME: &str = "genco.db";
 
-/// WARN: there is a bottleneck and a bug here.
-/// Current approach using "rusqlite::Connection" only handles individual connections
-/// i.e. the connection is created from scratch everytime.
-/// This is a problem in parallel scenarios (i.e. "cargo test"!!!) where several attempts
-/// to get a database connection from different threads would lead to a panic.
-///
-/// Solution: migrate slowly to a pooled connection (and avoid repositories to use connection directly)
-pub(crate) fn get_db_connection() -> Connection {
-    SYNC_OBJ.call_once(|| {
-        db_initial_migration();
-    });
+static DB_POOL: OnceLock<DbPool> = OnceLock::new();
+static DB_LOCATION: Mutex<Option<DatabaseLocation>> = Mutex::new(None);
 
-    get_db_connection_without_migration_attempt()
+/// Where genco stores its index database.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DatabaseLocation {
+    File(PathBuf),
+    /// The database is lost when the process ends
+    InMemory,
 }
 
-fn db_initial_migration() {
-    let conn = get_db_connection_without_migration_attempt();
-    let paths = fs::read_dir("./src/core/database/migrations")
-        .expect("Database migrations path must exist");
-
-    for dir_entry_result in paths {
-        let file_path = dir_entry_result.expect("Dir entry must exist").path();
-        if file_path.exists() && file_path.is_file() {
-            let filename = file_path
-                .file_name()
-                .expect("File name must exists")
-                .to_str()
-                .expect("File name str");
-            if filename.ends_with(".sql") {
-                let sql_query = fs::read_to_string(file_path).expect("Read to string");
-                conn.execute(sql_query.as_str(), ())
-                    .unwrap_or_else(|_| panic!("migration query: \"{}\"", sql_query));
-            }
+/// # set_database_location
+/// Configure the database location, it must be called before any other genco operation.
+/// By default the database is stored in the user cache directory
+/// (i.e. "~/.cache/genco/genco.db" in Linux).
+///
+/// ```
+/// use genco::DatabaseLocation;
+///
+/// let result = genco::set_database_location(DatabaseLocation::InMemory);
+/// ```
+pub fn set_database_location(location: DatabaseLocation) -> Result<(), String> {
+    if let Some(pool) = DB_POOL.get() {
+        if pool.get_location() == &location {
+            return Ok(());
         }
+        return Err(format!(
+            "Database location can not be changed to {:?}, it is already in use: {:?}",
+            location,
+            pool.get_location()
+        ));
     }
-    conn.close().expect("Database connection must close");
+
+    *DB_LOCATION
+        .lock()
+        .expect("Database location lock must not be poisoned") = Some(location);
+    Ok(())
 }
 
-fn get_db_connection_without_migration_attempt() -> Connection {
-    let base_db_folder = get_base_folder().join("database");
-    let db_file = base_db_folder.join("test.db");
+/// Connection from the database pool, it returns to the pool when dropped.
+/// Requesting a second connection while holding one is an error for in-memory
+/// databases, they only have a single connection.
+pub(crate) fn get_db_connection() -> Result<DbConnection<'static>, String> {
+    get_db_pool().get_connection()
+}
+
+fn get_db_pool() -> &'static DbPool {
+    DB_POOL.get_or_init(|| {
+        let location = get_configured_location();
+        let pool = DbPool::new(location).expect("Database must be opened");
+        db_initial_migration(&pool);
+        pool
+    })
+}
 
-    fs::create_dir_all(base_db_folder)
+fn get_configured_location() -> DatabaseLocation {
+    let configured_location = DB_LOCATION
+        .lock()
+        .expect("Database location lock must not be poisoned")
+        .clone();
+
+    configured_location.unwrap_or_else(get_default_location)
+}
+
+#[cfg(not(test))]
+fn get_default_location() -> DatabaseLocation {
+    let db_folder = get_default_base_folder().join(DB_FOLDER_NAME);
+    std::fs::create_dir_all(&db_folder)
         .expect("It was not possible to create the database directory");
 
-    Connection::open(db_file).expect("connection open issue")
+    DatabaseLocation::File(db_folder.join(DB_FILE_NAME))
+}
+
+#[cfg(test)]
+fn get_default_location() -> DatabaseLocation {
+    DatabaseLocation::InMemory
 }
 
-fn get_base_folder() -> PathBuf {
-    std::env::current_dir()
-        .expect("It was not possible to get base_dir for database setup")
-        .to_path_buf()
+fn get_default_base_folder() -> PathBuf {
+    dirs::cache_dir().unwrap_or_else(std::env::temp_dir)
+}
+
+fn db_initial_migration(pool: &DbPool) {
+    let mut conn = pool
+        .get_connection()
+        .expect("Database connection must be available for migrations");
+
+    if let Err(err) = db_migration::migrate(&mut conn) {
+        logger::log_unrecoverable_error(&err);
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::core::database::db_setup::get_db_connection;
+    use crate::core::database::db_setup;
+    use crate::core::database::db_setup::{get_db_connection, DatabaseLocation};
 
     #[test]
     fn setup_test() {
-        get_db_connection();
+        get_db_connection().expect("Database connection expected");
+    }
+
+    #[test]
+    fn default_test_location_is_in_memory() {
+        get_db_connection().expect("Database connection expected");
+
+        let result = db_setup::set_database_location(DatabaseLocation::InMemory);
+
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn default_base_folder_is_absolute() {
+        assert!(db_setup::get_default_base_folder().is_absolute());
     }
 }
@@ -0,0 +1,9 @@
+CREATE TABLE IF NOT EXISTS java_scanned_file (
+    id    INTEGER PRIMARY KEY AUTOINCREMENT,
+    base_package  TEXT NOT NULL,
+    file_path  TEXT NOT NULL UNIQUE ON CONFLICT REPLACE,
+    route  TEXT NOT NULL,
+    modified_time  INTEGER NOT NULL,
+    size  INTEGER NOT NULL,
+    content_hash  TEXT NOT NULL
+);
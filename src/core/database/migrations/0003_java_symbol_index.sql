@@ -0,0 +1,99 @@
+CREATE TABLE IF NOT EXISTS java_structure (
+    id    INTEGER PRIMARY KEY AUTOINCREMENT,
+    base_package  TEXT NOT NULL,
+    file_path  TEXT NOT NULL,
+    route  TEXT NOT NULL,
+    name  TEXT NOT NULL,
+    structure_type  TEXT NOT NULL,
+    start_byte  INTEGER NOT NULL,
+    end_byte  INTEGER NOT NULL,
+    line_number  INTEGER NOT NULL,
+    column_number  INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS java_structure_route_idx ON java_structure (base_package, route);
+CREATE INDEX IF NOT EXISTS java_structure_file_idx ON java_structure (file_path);
+
+CREATE TABLE IF NOT EXISTS java_field (
+    id    INTEGER PRIMARY KEY AUTOINCREMENT,
+    base_package  TEXT NOT NULL,
+    file_path  TEXT NOT NULL,
+    structure_route  TEXT NOT NULL,
+    name  TEXT NOT NULL,
+    data_type  TEXT NOT NULL,
+    data_type_route  TEXT,
+    start_byte  INTEGER NOT NULL,
+    end_byte  INTEGER NOT NULL,
+    line_number  INTEGER NOT NULL,
+    column_number  INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS java_field_structure_idx ON java_field (base_package, structure_route);
+CREATE INDEX IF NOT EXISTS java_field_file_idx ON java_field (file_path);
+
+CREATE TABLE IF NOT EXISTS java_method (
+    id    INTEGER PRIMARY KEY AUTOINCREMENT,
+    base_package  TEXT NOT NULL,
+    file_path  TEXT NOT NULL,
+    structure_route  TEXT NOT NULL,
+    name  TEXT NOT NULL,
+    return_type  TEXT NOT NULL,
+    return_type_route  TEXT,
+    parameter_types  TEXT NOT NULL,
+    start_byte  INTEGER NOT NULL,
+    end_byte  INTEGER NOT NULL,
+    line_number  INTEGER NOT NULL,
+    column_number  INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS java_method_structure_idx ON java_method (base_package, structure_route);
+CREATE INDEX IF NOT EXISTS java_method_file_idx ON java_method (file_path);
+
+CREATE TABLE IF NOT EXISTS java_annotation (
+    id    INTEGER PRIMARY KEY AUTOINCREMENT,
+    base_package  TEXT NOT NULL,
+    file_path  TEXT NOT NULL,
+    structure_route  TEXT NOT NULL,
+    member_name  TEXT,
+    name  TEXT NOT NULL,
+    route  TEXT,
+    start_byte  INTEGER NOT NULL,
+    end_byte  INTEGER NOT NULL,
+    line_number  INTEGER NOT NULL,
+    column_number  INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS java_annotation_route_idx ON java_annotation (base_package, route);
+CREATE INDEX IF NOT EXISTS java_annotation_file_idx ON java_annotation (file_path);
+
+CREATE TABLE IF NOT EXISTS java_supertype (
+    id    INTEGER PRIMARY KEY AUTOINCREMENT,
+    base_package  TEXT NOT NULL,
+    file_path  TEXT NOT NULL,
+    structure_route  TEXT NOT NULL,
+    relation  TEXT NOT NULL,
+    name  TEXT NOT NULL,
+    route  TEXT,
+    start_byte  INTEGER NOT NULL,
+    end_byte  INTEGER NOT NULL,
+    line_number  INTEGER NOT NULL,
+    column_number  INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS java_supertype_route_idx ON java_supertype (base_package, route);
+CREATE INDEX IF NOT EXISTS java_supertype_structure_idx ON java_supertype (base_package, structure_route);
+CREATE INDEX IF NOT EXISTS java_supertype_file_idx ON java_supertype (file_path);
+
+CREATE TABLE IF NOT EXISTS java_reference (
+    id    INTEGER PRIMARY KEY AUTOINCREMENT,
+    base_package  TEXT NOT NULL,
+    file_path  TEXT NOT NULL,
+    structure_route  TEXT NOT NULL,
+    kind  TEXT NOT NULL,
+    name  TEXT NOT NULL,
+    target_route  TEXT,
+    start_byte  INTEGER NOT NULL,
+    end_byte  INTEGER NOT NULL,
+    line_number  INTEGER NOT NULL,
+    column_number  INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS java_reference_target_idx ON java_reference (base_package, target_route, name);
+CREATE INDEX IF NOT EXISTS java_reference_file_idx ON java_reference (file_path);
+
+-- Force a full re-scan so files indexed before this migration get their symbols
+DELETE FROM java_scanned_file;
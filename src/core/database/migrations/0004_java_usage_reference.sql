@@ -0,0 +1,2 @@
+-- Import and field access references are only indexed by scans after this migration
+DELETE FROM java_scanned_file;
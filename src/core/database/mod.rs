@@ -1,3 +1,5 @@
-mod db;
-mod db_setup;
+pub(crate) mod db;
+mod db_migration;
+mod db_pool;
+pub(crate) mod db_setup;
 pub mod model;
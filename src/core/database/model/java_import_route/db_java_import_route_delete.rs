@@ -0,0 +1,46 @@
+use rusqlite::{Error, Transaction};
+
+use crate::core::database::db;
+
+const DELETE_QUERY: &str = "DELETE FROM java_import_route WHERE base_package = ?1 AND route = ?2";
+
+pub(crate) fn by_base_package_and_route(base_package: &str, route: &str) -> Result<usize, String> {
+    db::execute(DELETE_QUERY, (base_package, route))
+}
+
+pub(crate) fn by_base_package_and_route_in_transaction(
+    transaction: &Transaction,
+    base_package: &str,
+    route: &str,
+) -> Result<usize, Error> {
+    transaction
+        .prepare_cached(DELETE_QUERY)?
+        .execute((base_package, route))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::database::model::java_import_route::java_import_route_entity::JavaImportRouteCreate;
+    use crate::core::database::model::java_import_route::{
+        db_java_import_route_delete, db_java_import_route_save, db_java_import_route_search,
+    };
+
+    #[test]
+    fn delete_test() {
+        let last_type_id = "DeletedJavaImportRoute";
+        let base_package = "/home/test_user/delete_test";
+        let route = "org.test.DeletedJavaImportRoute";
+        let entity_to_create = JavaImportRouteCreate {
+            base_package: base_package.to_string(),
+            route: route.to_string(),
+            last_type_id: last_type_id.to_string(),
+        };
+        db_java_import_route_save::save(vec![entity_to_create]).expect("Save should work");
+
+        db_java_import_route_delete::by_base_package_and_route(base_package, route)
+            .expect("Delete should work");
+
+        let result = db_java_import_route_search::by_last_type_id(last_type_id);
+        assert!(result.is_empty());
+    }
+}
@@ -1,9 +1,22 @@
+use rusqlite::{Error, Transaction};
+
 use crate::core::database::db;
 use crate::core::database::model::java_import_route::java_import_route_entity::JavaImportRouteCreate;
 
+const INSERT_QUERY: &str =
+    "INSERT INTO java_import_route (base_package, route, last_type_id) VALUES (?1, ?2, ?3)";
+
 pub(crate) fn save(java_files: Vec<JavaImportRouteCreate>) -> Result<(), String> {
-    for java_file in java_files {
-        save_internal(java_file)?;
+    db::execute_in_transaction(|transaction| save_in_transaction(transaction, &java_files))
+}
+
+pub(crate) fn save_in_transaction(
+    transaction: &Transaction,
+    java_files: &[JavaImportRouteCreate],
+) -> Result<(), Error> {
+    let mut stmt = transaction.prepare_cached(INSERT_QUERY)?;
+    for entity in java_files {
+        stmt.execute((&entity.base_package, &entity.route, &entity.last_type_id))?;
     }
 
     Ok(())
@@ -11,7 +24,7 @@ pub(crate) fn save(java_files: Vec<JavaImportRouteCreate>) -> Result<(), String>
 
 fn save_internal(entity: JavaImportRouteCreate) -> Result<usize, String> {
     db::execute_insert_3_param(
-        "INSERT INTO java_import_route (base_package, route, last_type_id) VALUES (?1, ?2, ?3)",
+        INSERT_QUERY,
         (&entity.base_package, &entity.route, &entity.last_type_id),
     )
 }
@@ -7,7 +7,7 @@ use crate::core::file_system::path_helper::try_to_absolute_path;
 /// WARN: this method on itself is not strong enough to detect file moved from one folder to another.
 /// It does not have logic to validate returned JavaImportRouteEntity
 pub(crate) fn by_last_type_id(type_id: &str) -> Vec<JavaImportRouteEntity> {
-    let conn = db_setup::get_db_connection();
+    let conn = db_setup::get_db_connection().expect("Database connection must be available");
 
     let mut stmt = conn
         .prepare(
@@ -27,7 +27,7 @@ pub(crate) fn by_base_package_and_route(
     base_package: &Path,
     import_route: &str,
 ) -> Vec<JavaImportRouteEntity> {
-    let conn = db_setup::get_db_connection();
+    let conn = db_setup::get_db_connection().expect("Database connection must be available");
 
     let base_package_str = try_to_absolute_path(base_package);
     let mut stmt = conn
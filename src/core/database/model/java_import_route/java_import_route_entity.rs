@@ -93,7 +93,7 @@ impl JavaImportRouteCreate {
 /// - file_path: "/home/<user>/genco/src/java/dto/test/class/src/main/java/org/test/JavaClassFrom.java"
 ///
 /// Expected result: "org.test.JavaClassFrom"
-fn get_import_route(base_package_path: &str, file_path: &str) -> Option<String> {
+pub(crate) fn get_import_route(base_package_path: &str, file_path: &str) -> Option<String> {
     let start = base_package_path.as_bytes().len() + "/src/main/java/".as_bytes().len();
     let end = file_path.as_bytes().len() - ".java".as_bytes().len();
     if start >= end {
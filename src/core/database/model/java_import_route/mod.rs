@@ -1,3 +1,4 @@
+pub mod db_java_import_route_delete;
 pub mod db_java_import_route_save;
 pub mod db_java_import_route_search;
 pub mod java_import_route_entity;
@@ -0,0 +1,46 @@
+use rusqlite::{Error, Transaction};
+
+use crate::core::database::db;
+
+const DELETE_QUERY: &str = "DELETE FROM java_scanned_file WHERE file_path = ?1";
+
+pub(crate) fn by_file_path(file_path: &str) -> Result<usize, String> {
+    db::execute(DELETE_QUERY, [file_path])
+}
+
+pub(crate) fn by_file_path_in_transaction(
+    transaction: &Transaction,
+    file_path: &str,
+) -> Result<usize, Error> {
+    transaction
+        .prepare_cached(DELETE_QUERY)?
+        .execute([file_path])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::database::model::java_scanned_file::java_scanned_file_entity::JavaScannedFileCreate;
+    use crate::core::database::model::java_scanned_file::{
+        db_java_scanned_file_delete, db_java_scanned_file_save, db_java_scanned_file_search,
+    };
+
+    #[test]
+    fn delete_test() {
+        let base_package = "/home/test_user/java_scanned_file_delete_test";
+        let entity_to_create = JavaScannedFileCreate {
+            base_package: base_package.to_string(),
+            file_path: format!("{}/src/main/java/org/test/Deleted.java", base_package),
+            route: "org.test.Deleted".to_string(),
+            modified_time: 1,
+            size: 10,
+            content_hash: "0000000000000001".to_string(),
+        };
+        db_java_scanned_file_save::save(&entity_to_create).expect("Save should work");
+
+        db_java_scanned_file_delete::by_file_path(&entity_to_create.file_path)
+            .expect("Delete should work");
+
+        let result = db_java_scanned_file_search::by_base_package(base_package);
+        assert!(result.is_empty());
+    }
+}
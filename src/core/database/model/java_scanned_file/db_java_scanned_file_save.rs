@@ -0,0 +1,61 @@
+use rusqlite::{Error, Transaction};
+
+use crate::core::database::db;
+use crate::core::database::model::java_scanned_file::java_scanned_file_entity::JavaScannedFileCreate;
+
+const INSERT_QUERY: &str = "INSERT INTO java_scanned_file \
+    (base_package, file_path, route, modified_time, size, content_hash) \
+    VALUES (?1, ?2, ?3, ?4, ?5, ?6)";
+
+/// Previous rows with the same file_path are replaced
+pub(crate) fn save(entity: &JavaScannedFileCreate) -> Result<usize, String> {
+    db::execute_in_transaction(|transaction| save_in_transaction(transaction, entity))
+}
+
+pub(crate) fn save_in_transaction(
+    transaction: &Transaction,
+    entity: &JavaScannedFileCreate,
+) -> Result<usize, Error> {
+    transaction.prepare_cached(INSERT_QUERY)?.execute((
+        &entity.base_package,
+        &entity.file_path,
+        &entity.route,
+        entity.modified_time,
+        entity.size,
+        &entity.content_hash,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::database::model::java_scanned_file::java_scanned_file_entity::JavaScannedFileCreate;
+    use crate::core::database::model::java_scanned_file::{
+        db_java_scanned_file_save, db_java_scanned_file_search,
+    };
+
+    #[test]
+    fn save_test() {
+        let base_package = "/home/test_user/java_scanned_file_save_test";
+        let mut entity_to_create = JavaScannedFileCreate {
+            base_package: base_package.to_string(),
+            file_path: format!("{}/src/main/java/org/test/Saved.java", base_package),
+            route: "org.test.Saved".to_string(),
+            modified_time: 1,
+            size: 10,
+            content_hash: "0000000000000001".to_string(),
+        };
+        db_java_scanned_file_save::save(&entity_to_create).expect("Save should work");
+        entity_to_create.modified_time = 2;
+        db_java_scanned_file_save::save(&entity_to_create).expect("Save should work");
+
+        let result = db_java_scanned_file_search::by_base_package(base_package);
+
+        assert_eq!(1, result.len());
+        let result_item = result.first().expect("Scanned file expected");
+        assert_eq!(entity_to_create.file_path, result_item.get_file_path());
+        assert_eq!("org.test.Saved", result_item.get_route());
+        assert_eq!(2, result_item.get_modified_time());
+        assert_eq!(10, result_item.get_size());
+        assert_eq!("0000000000000001", result_item.get_content_hash());
+    }
+}
@@ -0,0 +1,21 @@
+use crate::core::database::db_setup;
+use crate::core::database::model::java_scanned_file::java_scanned_file_entity::JavaScannedFileEntity;
+
+pub(crate) fn by_base_package(base_package: &str) -> Vec<JavaScannedFileEntity> {
+    let conn = db_setup::get_db_connection().expect("Database connection must be available");
+
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, base_package, file_path, route, modified_time, size, content_hash \
+         FROM java_scanned_file \
+         WHERE base_package = ?1",
+        )
+        .expect("Database statement preparation failed (\"by_base_package\")");
+
+    stmt.query_map([base_package], |row| {
+        Ok(JavaScannedFileEntity::from_row(row))
+    })
+    .expect("Search JavaScannedFile by_base_package query failed")
+    .filter_map(|row| row.ok())
+    .collect()
+}
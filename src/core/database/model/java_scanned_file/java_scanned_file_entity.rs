@@ -0,0 +1,68 @@
+use rusqlite::Row;
+
+#[derive(Debug)]
+pub struct JavaScannedFileEntity {
+    id: i32,
+    base_package: String,
+    file_path: String,
+    route: String,
+    modified_time: i64,
+    size: i64,
+    content_hash: String,
+}
+
+impl JavaScannedFileEntity {
+    pub(crate) fn get_id(&self) -> i32 {
+        self.id
+    }
+    pub(crate) fn get_base_package(&self) -> String {
+        self.base_package.clone()
+    }
+    pub(crate) fn get_file_path(&self) -> String {
+        self.file_path.clone()
+    }
+    pub(crate) fn get_route(&self) -> String {
+        self.route.clone()
+    }
+    pub(crate) fn get_modified_time(&self) -> i64 {
+        self.modified_time
+    }
+    pub(crate) fn get_size(&self) -> i64 {
+        self.size
+    }
+    pub(crate) fn get_content_hash(&self) -> String {
+        self.content_hash.clone()
+    }
+}
+
+#[derive(Debug)]
+pub struct JavaScannedFileCreate {
+    pub(crate) base_package: String,
+    pub(crate) file_path: String,
+    pub(crate) route: String,
+    pub(crate) modified_time: i64,
+    pub(crate) size: i64,
+    pub(crate) content_hash: String,
+}
+
+impl JavaScannedFileEntity {
+    pub(crate) fn from_row(row: &Row) -> Self {
+        Self {
+            id: row.get(0).expect("JavaScannedFile field \"id\" missing"),
+            base_package: row
+                .get(1)
+                .expect("JavaScannedFile field \"base_package\" missing"),
+            file_path: row
+                .get(2)
+                .expect("JavaScannedFile field \"file_path\" missing"),
+            route: row.get(3).expect("JavaScannedFile field \"route\" missing"),
+            modified_time: row
+                .get(4)
+                .expect("JavaScannedFile field \"modified_time\" missing"),
+            size: row.get(5).expect("JavaScannedFile field \"size\" missing"),
+            content_hash: row
+                .get(6)
+                .expect("JavaScannedFile field \"content_hash\" missing"),
+        }
+    }
+}
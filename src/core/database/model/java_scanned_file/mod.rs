@@ -0,0 +1,4 @@
+pub mod db_java_scanned_file_delete;
+pub mod db_java_scanned_file_save;
+pub mod db_java_scanned_file_search;
+pub mod java_scanned_file_entity;
@@ -0,0 +1,24 @@
+use rusqlite::{Error, Transaction};
+
+const SYMBOL_TABLES: [&str; 6] = [
+    "java_structure",
+    "java_field",
+    "java_method",
+    "java_annotation",
+    "java_supertype",
+    "java_reference",
+];
+
+/// Delete all the symbols declared or referenced in the java file
+pub(crate) fn by_file_path_in_transaction(
+    transaction: &Transaction,
+    file_path: &str,
+) -> Result<(), Error> {
+    for table in SYMBOL_TABLES {
+        transaction
+            .prepare_cached(&format!("DELETE FROM {} WHERE file_path = ?1", table))?
+            .execute([file_path])?;
+    }
+
+    Ok(())
+}
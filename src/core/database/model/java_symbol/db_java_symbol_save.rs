@@ -0,0 +1,140 @@
+use rusqlite::{Error, Transaction};
+
+use crate::core::database::model::java_symbol::java_symbol_entity;
+use crate::core::database::model::java_symbol::java_symbol_entity::JavaFileSymbolsCreate;
+
+/// Symbols previously stored for the same file must be deleted first
+pub(crate) fn save_in_transaction(
+    transaction: &Transaction,
+    symbols: &JavaFileSymbolsCreate,
+) -> Result<(), Error> {
+    let base_package = &symbols.base_package;
+
+    let mut stmt = transaction.prepare_cached(
+        "INSERT INTO java_structure (base_package, route, name, structure_type, \
+        file_path, start_byte, end_byte, line_number, column_number) \
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+    )?;
+    for structure in &symbols.structures {
+        let location = &structure.location;
+        stmt.execute((
+            base_package,
+            &structure.route,
+            &structure.name,
+            &structure.structure_type,
+            &location.file_path,
+            location.start_byte,
+            location.end_byte,
+            location.line,
+            location.column,
+        ))?;
+    }
+
+    let mut stmt = transaction.prepare_cached(
+        "INSERT INTO java_field (base_package, structure_route, name, data_type, data_type_route, \
+        file_path, start_byte, end_byte, line_number, column_number) \
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+    )?;
+    for field in &symbols.fields {
+        let location = &field.location;
+        stmt.execute((
+            base_package,
+            &field.structure_route,
+            &field.name,
+            &field.data_type,
+            &field.data_type_route,
+            &location.file_path,
+            location.start_byte,
+            location.end_byte,
+            location.line,
+            location.column,
+        ))?;
+    }
+
+    let mut stmt = transaction.prepare_cached(
+        "INSERT INTO java_method (base_package, structure_route, name, return_type, return_type_route, \
+        parameter_types, file_path, start_byte, end_byte, line_number, column_number) \
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
+    )?;
+    for method in &symbols.methods {
+        let location = &method.location;
+        stmt.execute((
+            base_package,
+            &method.structure_route,
+            &method.name,
+            &method.return_type,
+            &method.return_type_route,
+            java_symbol_entity::parameter_types_to_db_str(&method.parameter_types),
+            &location.file_path,
+            location.start_byte,
+            location.end_byte,
+            location.line,
+            location.column,
+        ))?;
+    }
+
+    let mut stmt = transaction.prepare_cached(
+        "INSERT INTO java_annotation (base_package, structure_route, member_name, name, route, \
+        file_path, start_byte, end_byte, line_number, column_number) \
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+    )?;
+    for annotation in &symbols.annotations {
+        let location = &annotation.location;
+        stmt.execute((
+            base_package,
+            &annotation.structure_route,
+            &annotation.member_name,
+            &annotation.name,
+            &annotation.route,
+            &location.file_path,
+            location.start_byte,
+            location.end_byte,
+            location.line,
+            location.column,
+        ))?;
+    }
+
+    let mut stmt = transaction.prepare_cached(
+        "INSERT INTO java_supertype (base_package, structure_route, relation, name, route, \
+        file_path, start_byte, end_byte, line_number, column_number) \
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+    )?;
+    for supertype in &symbols.supertypes {
+        let location = &supertype.location;
+        stmt.execute((
+            base_package,
+            &supertype.structure_route,
+            supertype.relation.as_db_str(),
+            &supertype.name,
+            &supertype.route,
+            &location.file_path,
+            location.start_byte,
+            location.end_byte,
+            location.line,
+            location.column,
+        ))?;
+    }
+
+    let mut stmt = transaction.prepare_cached(
+        "INSERT INTO java_reference (base_package, structure_route, kind, name, target_route, \
+        file_path, start_byte, end_byte, line_number, column_number) \
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+    )?;
+    for reference in &symbols.references {
+        let location = &reference.location;
+        stmt.execute((
+            base_package,
+            &reference.structure_route,
+            reference.kind.as_db_str(),
+            &reference.name,
+            &reference.target_route,
+            &location.file_path,
+            location.start_byte,
+            location.end_byte,
+            location.line,
+            location.column,
+        ))?;
+    }
+
+    Ok(())
+}
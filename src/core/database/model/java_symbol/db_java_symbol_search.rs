@@ -0,0 +1,315 @@
+use std::path::Path;
+
+use rusqlite::{Params, Row};
+
+use crate::core::database::db_setup;
+use crate::core::database::model::java_symbol::java_symbol_entity::{
+    JavaAnnotationEntity, JavaFieldEntity, JavaMethodEntity, JavaReferenceEntity,
+    JavaReferenceKind, JavaStructureEntity, JavaSupertypeEntity,
+};
+use crate::core::file_system::path_helper::try_to_absolute_path;
+
+/// # implementors_of
+/// Structures directly extending or implementing the type with the given route
+/// (i.e. "org.test.JavaInterface").
+pub(crate) fn implementors_of(base_package: &Path, route: &str) -> Vec<JavaStructureEntity> {
+    let sql = format!(
+        "SELECT DISTINCT {} FROM java_structure \
+        WHERE base_package = ?1 AND route IN ( \
+            SELECT structure_route FROM java_supertype WHERE base_package = ?1 AND route = ?2 \
+        ) \
+        ORDER BY route",
+        JavaStructureEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), route),
+        JavaStructureEntity::from_row,
+    )
+}
+
+/// # method_calls
+/// Calls to the method declared in the structure with the given route.
+/// Calls whose target type could not be resolved while indexing are not included.
+pub(crate) fn method_calls(
+    base_package: &Path,
+    structure_route: &str,
+    method_name: &str,
+) -> Vec<JavaReferenceEntity> {
+    let sql = format!(
+        "SELECT {} FROM java_reference \
+        WHERE base_package = ?1 AND kind = ?2 AND target_route = ?3 AND name = ?4 \
+        ORDER BY file_path, start_byte",
+        JavaReferenceEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (
+            try_to_absolute_path(base_package),
+            JavaReferenceKind::MethodCall.as_db_str(),
+            structure_route,
+            method_name,
+        ),
+        JavaReferenceEntity::from_row,
+    )
+}
+
+/// # structures_with_annotation
+/// Structures annotated with the annotation route (i.e. "org.springframework.stereotype.Service").
+pub(crate) fn structures_with_annotation(
+    base_package: &Path,
+    annotation_route: &str,
+) -> Vec<JavaStructureEntity> {
+    let sql = format!(
+        "SELECT DISTINCT {} FROM java_structure \
+        WHERE base_package = ?1 AND route IN ( \
+            SELECT structure_route FROM java_annotation \
+            WHERE base_package = ?1 AND route = ?2 AND member_name IS NULL \
+        ) \
+        ORDER BY route",
+        JavaStructureEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), annotation_route),
+        JavaStructureEntity::from_row,
+    )
+}
+
+/// # references_to
+/// References of any kind targeting the type with the given route. When a name is given only
+/// the references to that member (or to the type itself for type references) are returned.
+pub(crate) fn references_to(
+    base_package: &Path,
+    target_route: &str,
+    name_opt: Option<&str>,
+) -> Vec<JavaReferenceEntity> {
+    let sql = format!(
+        "SELECT {} FROM java_reference \
+        WHERE base_package = ?1 AND target_route = ?2 AND (?3 IS NULL OR name = ?3) \
+        ORDER BY file_path, start_byte",
+        JavaReferenceEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), target_route, name_opt),
+        JavaReferenceEntity::from_row,
+    )
+}
+
+/// # annotations_by_route
+/// Usages of the annotation with the given route on structures and members.
+pub(crate) fn annotations_by_route(base_package: &Path, route: &str) -> Vec<JavaAnnotationEntity> {
+    let sql = format!(
+        "SELECT {} FROM java_annotation WHERE base_package = ?1 AND route = ?2 \
+        ORDER BY file_path, start_byte",
+        JavaAnnotationEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), route),
+        JavaAnnotationEntity::from_row,
+    )
+}
+
+pub(crate) fn structure_by_route(base_package: &Path, route: &str) -> Option<JavaStructureEntity> {
+    let sql = format!(
+        "SELECT {} FROM java_structure WHERE base_package = ?1 AND route = ?2",
+        JavaStructureEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), route),
+        JavaStructureEntity::from_row,
+    )
+    .into_iter()
+    .next()
+}
+
+pub(crate) fn fields_by_structure_route(
+    base_package: &Path,
+    structure_route: &str,
+) -> Vec<JavaFieldEntity> {
+    let sql = format!(
+        "SELECT {} FROM java_field WHERE base_package = ?1 AND structure_route = ?2 \
+        ORDER BY start_byte",
+        JavaFieldEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), structure_route),
+        JavaFieldEntity::from_row,
+    )
+}
+
+pub(crate) fn methods_by_structure_route(
+    base_package: &Path,
+    structure_route: &str,
+) -> Vec<JavaMethodEntity> {
+    let sql = format!(
+        "SELECT {} FROM java_method WHERE base_package = ?1 AND structure_route = ?2 \
+        ORDER BY start_byte",
+        JavaMethodEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), structure_route),
+        JavaMethodEntity::from_row,
+    )
+}
+
+pub(crate) fn annotations_by_structure_route(
+    base_package: &Path,
+    structure_route: &str,
+) -> Vec<JavaAnnotationEntity> {
+    let sql = format!(
+        "SELECT {} FROM java_annotation WHERE base_package = ?1 AND structure_route = ?2 \
+        ORDER BY start_byte",
+        JavaAnnotationEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), structure_route),
+        JavaAnnotationEntity::from_row,
+    )
+}
+
+pub(crate) fn supertypes_by_structure_route(
+    base_package: &Path,
+    structure_route: &str,
+) -> Vec<JavaSupertypeEntity> {
+    let sql = format!(
+        "SELECT {} FROM java_supertype WHERE base_package = ?1 AND structure_route = ?2 \
+        ORDER BY start_byte",
+        JavaSupertypeEntity::COLUMNS
+    );
+
+    query(
+        &sql,
+        (try_to_absolute_path(base_package), structure_route),
+        JavaSupertypeEntity::from_row,
+    )
+}
+
+fn query<T, P: Params>(sql: &str, params: P, from_row: fn(&Row) -> T) -> Vec<T> {
+    let conn = db_setup::get_db_connection().expect("Database connection must be available");
+
+    let mut stmt = conn
+        .prepare(sql)
+        .unwrap_or_else(|err| panic!("Database statement preparation failed ({}):\n{}", err, sql));
+
+    stmt.query_map(params, |row| Ok(from_row(row)))
+        .unwrap_or_else(|err| panic!("Search java symbols query failed ({}):\n{}", err, sql))
+        .filter_map(|row| row.ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::database::model::java_symbol::db_java_symbol_search;
+    use crate::core::database::model::java_symbol::java_symbol_entity::JavaReferenceKind;
+    use crate::java::scanner::package::java_dependency_scanner;
+
+    #[test]
+    fn symbol_queries_test() {
+        let base_package = get_symbol_project_dir();
+        java_dependency_scanner::recursive_scan_dir_unchecked(&base_package);
+
+        let implementors =
+            db_java_symbol_search::implementors_of(&base_package, "org.test.JavaGreeting");
+        let annotated =
+            db_java_symbol_search::structures_with_annotation(&base_package, "org.test.JavaMarker");
+        let service_calls = db_java_symbol_search::method_calls(
+            &base_package,
+            "org.test.JavaGreetingService",
+            "greet",
+        );
+        let interface_calls =
+            db_java_symbol_search::method_calls(&base_package, "org.test.JavaGreeting", "greet");
+        let nested = db_java_symbol_search::structure_by_route(
+            &base_package,
+            "org.test.JavaGreetingService.Tone",
+        );
+
+        assert_eq!(1, implementors.len());
+        assert_eq!("org.test.JavaGreetingService", implementors[0].route);
+        assert_eq!(1, annotated.len());
+        assert_eq!("JavaGreetingService", annotated[0].name);
+        assert_eq!(1, service_calls.len());
+        assert_eq!(
+            "org.test.JavaGreetingClient",
+            service_calls[0].structure_route
+        );
+        assert_eq!(8, service_calls[0].location.line);
+        assert!(service_calls[0]
+            .location
+            .file_path
+            .ends_with("JavaGreetingClient.java"));
+        assert_eq!(1, interface_calls.len());
+        assert_eq!(9, interface_calls[0].location.line);
+        assert_eq!(
+            Some("enum"),
+            nested.map(|structure| structure.structure_type).as_deref()
+        );
+    }
+
+    #[test]
+    fn references_to_test() {
+        let base_package = get_symbol_project_dir();
+        java_dependency_scanner::recursive_scan_dir_unchecked(&base_package);
+
+        let field_references = db_java_symbol_search::references_to(
+            &base_package,
+            "org.test.JavaGreetingClient",
+            Some("greeting"),
+        );
+        let objects_references =
+            db_java_symbol_search::references_to(&base_package, "java.util.Objects", None);
+        let marker_annotations =
+            db_java_symbol_search::annotations_by_route(&base_package, "org.test.JavaMarker");
+
+        let objects_kinds: Vec<JavaReferenceKind> = objects_references
+            .iter()
+            .map(|reference| reference.kind)
+            .collect();
+        assert_eq!(1, field_references.len());
+        assert_eq!(JavaReferenceKind::FieldAccess, field_references[0].kind);
+        assert_eq!(9, field_references[0].location.line);
+        assert_eq!(
+            vec![JavaReferenceKind::Import, JavaReferenceKind::MethodCall],
+            objects_kinds
+        );
+        assert_eq!("requireNonNull", objects_references[0].name);
+        assert_eq!(1, marker_annotations.len());
+        assert_eq!(
+            "org.test.JavaGreetingService",
+            marker_annotations[0].structure_route
+        );
+    }
+
+    fn get_symbol_project_dir() -> PathBuf {
+        let mut dir = get_current_file_path();
+        dir.pop();
+        dir.pop();
+        dir.pop();
+        dir.pop();
+        dir.pop();
+        dir.push("java/scanner/index/test/symbol_project");
+        dir
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1,323 @@
+use rusqlite::Row;
+
+/// Position of a symbol in its java file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JavaSymbolLocation {
+    pub(crate) file_path: String,
+    pub(crate) start_byte: usize,
+    pub(crate) end_byte: usize,
+    /// Starting at 1
+    pub(crate) line: usize,
+    /// Starting at 1
+    pub(crate) column: usize,
+}
+
+/// Class, interface, enum, record or annotation declaration.
+/// Nested structures use their parent route as prefix (i.e. "org.test.Parent.Child").
+#[derive(Debug, Clone)]
+pub struct JavaStructureEntity {
+    pub(crate) route: String,
+    pub(crate) name: String,
+    pub(crate) structure_type: String,
+    pub(crate) location: JavaSymbolLocation,
+}
+
+#[derive(Debug, Clone)]
+pub struct JavaFieldEntity {
+    pub(crate) structure_route: String,
+    pub(crate) name: String,
+    /// Data type as written in the file (i.e. "List<String>")
+    pub(crate) data_type: String,
+    pub(crate) data_type_route: Option<String>,
+    pub(crate) location: JavaSymbolLocation,
+}
+
+#[derive(Debug, Clone)]
+pub struct JavaMethodEntity {
+    pub(crate) structure_route: String,
+    pub(crate) name: String,
+    /// "void" for methods without return type and "" for constructors
+    pub(crate) return_type: String,
+    pub(crate) return_type_route: Option<String>,
+    pub(crate) parameter_types: Vec<String>,
+    pub(crate) location: JavaSymbolLocation,
+}
+
+/// Annotation usage, member_name is None for annotations on the structure itself
+#[derive(Debug, Clone)]
+pub struct JavaAnnotationEntity {
+    pub(crate) structure_route: String,
+    pub(crate) member_name: Option<String>,
+    pub(crate) name: String,
+    pub(crate) route: Option<String>,
+    pub(crate) location: JavaSymbolLocation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JavaSupertypeRelation {
+    Extends,
+    Implements,
+}
+
+#[derive(Debug, Clone)]
+pub struct JavaSupertypeEntity {
+    pub(crate) structure_route: String,
+    pub(crate) relation: JavaSupertypeRelation,
+    pub(crate) name: String,
+    pub(crate) route: Option<String>,
+    pub(crate) location: JavaSymbolLocation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JavaReferenceKind {
+    /// Any usage of a type name (i.e. variable types, casts, generics)
+    Type,
+    /// "new JavaType(...)"
+    ObjectCreation,
+    /// "variable.method(...)" or "Type::method", target route is the type of the variable
+    /// when it is known
+    MethodCall,
+    /// "variable.field", "this.field" or an unqualified field of the enclosing structure
+    FieldAccess,
+    /// "import org.test.Type;" or "import static org.test.Type.member;",
+    /// static imports use the member as name and the type as target route
+    Import,
+}
+
+/// Usage of a type or method from any structure of the project
+#[derive(Debug, Clone)]
+pub struct JavaReferenceEntity {
+    pub(crate) structure_route: String,
+    pub(crate) kind: JavaReferenceKind,
+    pub(crate) name: String,
+    pub(crate) target_route: Option<String>,
+    pub(crate) location: JavaSymbolLocation,
+}
+
+/// All the symbols declared or referenced in a single java file
+#[derive(Debug, Default)]
+pub struct JavaFileSymbolsCreate {
+    pub(crate) base_package: String,
+    pub(crate) file_path: String,
+    pub(crate) structures: Vec<JavaStructureEntity>,
+    pub(crate) fields: Vec<JavaFieldEntity>,
+    pub(crate) methods: Vec<JavaMethodEntity>,
+    pub(crate) annotations: Vec<JavaAnnotationEntity>,
+    pub(crate) supertypes: Vec<JavaSupertypeEntity>,
+    pub(crate) references: Vec<JavaReferenceEntity>,
+}
+
+const PARAMETER_TYPES_SEPARATOR: &str = ";";
+
+impl JavaSupertypeRelation {
+    pub(crate) fn as_db_str(&self) -> &'static str {
+        match self {
+            JavaSupertypeRelation::Extends => "extends",
+            JavaSupertypeRelation::Implements => "implements",
+        }
+    }
+
+    fn from_db_str(input: &str) -> Self {
+        match input {
+            "extends" => JavaSupertypeRelation::Extends,
+            _ => JavaSupertypeRelation::Implements,
+        }
+    }
+}
+
+impl JavaReferenceKind {
+    pub(crate) fn as_db_str(&self) -> &'static str {
+        match self {
+            JavaReferenceKind::Type => "type",
+            JavaReferenceKind::ObjectCreation => "object_creation",
+            JavaReferenceKind::MethodCall => "method_call",
+            JavaReferenceKind::FieldAccess => "field_access",
+            JavaReferenceKind::Import => "import",
+        }
+    }
+
+    fn from_db_str(input: &str) -> Self {
+        match input {
+            "object_creation" => JavaReferenceKind::ObjectCreation,
+            "method_call" => JavaReferenceKind::MethodCall,
+            "field_access" => JavaReferenceKind::FieldAccess,
+            "import" => JavaReferenceKind::Import,
+            _ => JavaReferenceKind::Type,
+        }
+    }
+}
+
+pub(crate) fn parameter_types_to_db_str(parameter_types: &[String]) -> String {
+    parameter_types.join(PARAMETER_TYPES_SEPARATOR)
+}
+
+fn parameter_types_from_db_str(input: &str) -> Vec<String> {
+    if input.is_empty() {
+        return vec![];
+    }
+    input
+        .split(PARAMETER_TYPES_SEPARATOR)
+        .map(|parameter_type| parameter_type.to_string())
+        .collect()
+}
+
+/// Location columns must be the last ones of each query:
+/// file_path, start_byte, end_byte, line_number, column_number
+fn location_from_row(row: &Row, first_index: usize) -> JavaSymbolLocation {
+    JavaSymbolLocation {
+        file_path: row
+            .get(first_index)
+            .expect("Java symbol field \"file_path\" missing"),
+        start_byte: row
+            .get(first_index + 1)
+            .expect("Java symbol field \"start_byte\" missing"),
+        end_byte: row
+            .get(first_index + 2)
+            .expect("Java symbol field \"end_byte\" missing"),
+        line: row
+            .get(first_index + 3)
+            .expect("Java symbol field \"line_number\" missing"),
+        column: row
+            .get(first_index + 4)
+            .expect("Java symbol field \"column_number\" missing"),
+    }
+}
+
+impl JavaStructureEntity {
+    pub(crate) const COLUMNS: &'static str =
+        "route, name, structure_type, file_path, start_byte, end_byte, line_number, column_number";
+
+    pub(crate) fn from_row(row: &Row) -> Self {
+        Self {
+            route: row.get(0).expect("JavaStructure field \"route\" missing"),
+            name: row.get(1).expect("JavaStructure field \"name\" missing"),
+            structure_type: row
+                .get(2)
+                .expect("JavaStructure field \"structure_type\" missing"),
+            location: location_from_row(row, 3),
+        }
+    }
+}
+
+impl JavaFieldEntity {
+    pub(crate) const COLUMNS: &'static str = "structure_route, name, data_type, data_type_route, \
+        file_path, start_byte, end_byte, line_number, column_number";
+
+    pub(crate) fn from_row(row: &Row) -> Self {
+        Self {
+            structure_route: row
+                .get(0)
+                .expect("JavaField field \"structure_route\" missing"),
+            name: row.get(1).expect("JavaField field \"name\" missing"),
+            data_type: row.get(2).expect("JavaField field \"data_type\" missing"),
+            data_type_route: row
+                .get(3)
+                .expect("JavaField field \"data_type_route\" missing"),
+            location: location_from_row(row, 4),
+        }
+    }
+}
+
+impl JavaMethodEntity {
+    pub(crate) const COLUMNS: &'static str =
+        "structure_route, name, return_type, return_type_route, parameter_types, \
+        file_path, start_byte, end_byte, line_number, column_number";
+
+    pub(crate) fn from_row(row: &Row) -> Self {
+        let parameter_types: String = row
+            .get(4)
+            .expect("JavaMethod field \"parameter_types\" missing");
+        Self {
+            structure_route: row
+                .get(0)
+                .expect("JavaMethod field \"structure_route\" missing"),
+            name: row.get(1).expect("JavaMethod field \"name\" missing"),
+            return_type: row
+                .get(2)
+                .expect("JavaMethod field \"return_type\" missing"),
+            return_type_route: row
+                .get(3)
+                .expect("JavaMethod field \"return_type_route\" missing"),
+            parameter_types: parameter_types_from_db_str(&parameter_types),
+            location: location_from_row(row, 5),
+        }
+    }
+}
+
+impl JavaAnnotationEntity {
+    pub(crate) const COLUMNS: &'static str = "structure_route, member_name, name, route, \
+        file_path, start_byte, end_byte, line_number, column_number";
+
+    pub(crate) fn from_row(row: &Row) -> Self {
+        Self {
+            structure_route: row
+                .get(0)
+                .expect("JavaAnnotation field \"structure_route\" missing"),
+            member_name: row
+                .get(1)
+                .expect("JavaAnnotation field \"member_name\" missing"),
+            name: row.get(2).expect("JavaAnnotation field \"name\" missing"),
+            route: row.get(3).expect("JavaAnnotation field \"route\" missing"),
+            location: location_from_row(row, 4),
+        }
+    }
+}
+
+impl JavaSupertypeEntity {
+    pub(crate) const COLUMNS: &'static str = "structure_route, relation, name, route, \
+        file_path, start_byte, end_byte, line_number, column_number";
+
+    pub(crate) fn from_row(row: &Row) -> Self {
+        let relation: String = row
+            .get(1)
+            .expect("JavaSupertype field \"relation\" missing");
+        Self {
+            structure_route: row
+                .get(0)
+                .expect("JavaSupertype field \"structure_route\" missing"),
+            relation: JavaSupertypeRelation::from_db_str(&relation),
+            name: row.get(2).expect("JavaSupertype field \"name\" missing"),
+            route: row.get(3).expect("JavaSupertype field \"route\" missing"),
+            location: location_from_row(row, 4),
+        }
+    }
+}
+
+impl JavaReferenceEntity {
+    pub(crate) const COLUMNS: &'static str = "structure_route, kind, name, target_route, \
+        file_path, start_byte, end_byte, line_number, column_number";
+
+    pub(crate) fn from_row(row: &Row) -> Self {
+        let kind: String = row.get(1).expect("JavaReference field \"kind\" missing");
+        Self {
+            structure_route: row
+                .get(0)
+                .expect("JavaReference field \"structure_route\" missing"),
+            kind: JavaReferenceKind::from_db_str(&kind),
+            name: row.get(2).expect("JavaReference field \"name\" missing"),
+            target_route: row
+                .get(3)
+                .expect("JavaReference field \"target_route\" missing"),
+            location: location_from_row(row, 4),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::core::database::model::java_symbol::java_symbol_entity;
+
+    #[test]
+    fn parameter_types_db_str() {
+        let parameter_types = vec!["int".to_string(), "List<String>".to_string()];
+
+        let db_str = java_symbol_entity::parameter_types_to_db_str(&parameter_types);
+
+        assert_eq!("int;List<String>", db_str);
+        assert_eq!(
+            parameter_types,
+            java_symbol_entity::parameter_types_from_db_str(&db_str)
+        );
+        assert!(java_symbol_entity::parameter_types_from_db_str("").is_empty());
+    }
+}
@@ -0,0 +1,4 @@
+pub mod db_java_symbol_delete;
+pub mod db_java_symbol_save;
+pub mod db_java_symbol_search;
+pub mod java_symbol_entity;
@@ -1 +1,3 @@
 pub mod java_import_route;
+pub mod java_scanned_file;
+pub mod java_symbol;
@@ -24,4 +24,13 @@ impl FileCache {
         let result_bytes = &self.content[start_byte..end_byte];
         string_helper::to_str(result_bytes)
     }
+
+    /// Start of the UTF-8 character containing "byte"
+    pub(crate) fn get_char_start_byte(&self, byte: usize) -> usize {
+        let mut result = byte;
+        while 0 < result && result < self.content.len() && 0x80 == self.content[result] & 0xC0 {
+            result -= 1;
+        }
+        result
+    }
 }
@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use crate::core::file_system::file_reader;
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// # get_content_hash
+/// Hash of the file content, it is not a cryptographic hash and it must only be used
+/// to detect file changes.
+pub(crate) fn get_content_hash(file_path: &Path) -> Result<String, String> {
+    let bytes = file_reader::read_all_bytes(file_path)?;
+    Ok(hash_bytes(&bytes))
+}
+
+/// FNV-1a 64 bits hash in hexadecimal format
+pub(crate) fn hash_bytes(bytes: &[u8]) -> String {
+    let mut hash = FNV_OFFSET_BASIS;
+    for byte in bytes {
+        hash ^= *byte as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+
+    format!("{:016x}", hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::file_system::file_hashing::file_hasher;
+
+    #[test]
+    fn hash_bytes_known_values() {
+        assert_eq!("cbf29ce484222325", file_hasher::hash_bytes(b""));
+        assert_eq!("af63dc4c8601ec8c", file_hasher::hash_bytes(b"a"));
+        assert_eq!("85944171f73967e8", file_hasher::hash_bytes(b"foobar"));
+    }
+
+    #[test]
+    fn get_content_hash_same_content() {
+        let file_path = get_current_file_path();
+
+        let first_hash = file_hasher::get_content_hash(&file_path).expect("File must be hashed");
+        let second_hash = file_hasher::get_content_hash(&file_path).expect("File must be hashed");
+
+        assert_eq!(first_hash, second_hash);
+    }
+
+    #[test]
+    fn get_content_hash_non_existing_file() {
+        let file_path = get_current_file_path().join("non_existing_file.rs");
+
+        assert!(file_hasher::get_content_hash(&file_path).is_err());
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
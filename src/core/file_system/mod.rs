@@ -2,6 +2,7 @@ pub(crate) mod directory_browsing;
 pub(crate) mod file_browsing;
 pub(crate) mod file_cache;
 pub(crate) mod file_edition;
+pub(crate) mod file_hashing;
 pub(crate) mod file_overwriting;
 pub(crate) mod file_reader;
 pub(crate) mod path_helper;
@@ -1,2 +1 @@
-#![allow(dead_code)]
 pub mod usecase;
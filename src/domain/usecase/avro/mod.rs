@@ -1 +1,4 @@
 pub mod parser;
+pub mod registry;
+pub mod translator;
+pub mod writer;
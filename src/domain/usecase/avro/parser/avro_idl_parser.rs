@@ -0,0 +1,1123 @@
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use crate::core::file_system::file_reader;
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::observability::logger;
+use crate::domain::usecase::avro::parser::avro_parser;
+use crate::domain::usecase::avro::parser::dto::avro_item::{AvroItem, AvroItemBuilder};
+use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+use crate::domain::usecase::avro::parser::dto::avro_message::AvroMessage;
+use crate::domain::usecase::avro::parser::dto::avro_order::AvroOrder;
+use crate::domain::usecase::avro::parser::dto::avro_protocol::AvroProtocol;
+
+/// # parse
+/// Named types of an Avro IDL (".avdl") file, the imported ones included. The type of a
+/// "schema" declaration is returned last when it is not a reference to a named type.
+pub fn parse(file_path: &Path) -> Result<Vec<AvroItem>, String> {
+    let idl_file = parse_file(file_path, &mut Vec::new())?;
+    let mut result = idl_file.types;
+    if let Some(main_schema) = idl_file.main_schema {
+        if !matches!(main_schema, AvroItemType::Reference(_)) {
+            result.push(AvroItem::builder().item_type(main_schema).build());
+        }
+    }
+
+    Ok(result)
+}
+
+/// # parse_protocol
+/// Named types and messages of the protocol declared in an Avro IDL (".avdl") file.
+pub fn parse_protocol(file_path: &Path) -> Result<AvroProtocol, String> {
+    let idl_file = parse_file(file_path, &mut Vec::new())?;
+    let (name, doc) = idl_file.protocol.ok_or(format!(
+        "Avro protocol not found in file:\n{}\n",
+        try_to_absolute_path(file_path)
+    ))?;
+
+    Ok(AvroProtocol::new(
+        name,
+        idl_file.namespace,
+        doc,
+        idl_file.types,
+        idl_file.messages,
+    ))
+}
+
+const TYPE_DECLARATION_KEYWORDS: [&str; 5] = ["import", "record", "error", "enum", "fixed"];
+
+#[derive(Default)]
+struct IdlFile {
+    /// Name and doc of the protocol
+    protocol: Option<(String, Option<String>)>,
+    namespace: Option<String>,
+    types: Vec<AvroItem>,
+    messages: Vec<AvroMessage>,
+    main_schema: Option<AvroItemType>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum IdlTokenKind {
+    Identifier,
+    /// Name after "@", i.e. "logicalType" in @logicalType("date")
+    Annotation,
+    String,
+    Number,
+    Symbol,
+}
+
+/// Identifiers are stored without backquotes and strings with their quotation marks
+struct IdlToken {
+    kind: IdlTokenKind,
+    start_byte: usize,
+    end_byte: usize,
+    /// Doc comment ("/** ... */") written just before the token
+    doc: Option<String>,
+}
+
+/// Annotation with the tokens of its json value
+struct IdlAnnotation {
+    name: String,
+    start_index: usize,
+    end_index: usize,
+}
+
+struct IdlParser<'a> {
+    file_path: &'a Path,
+    content: String,
+    tokens: Vec<IdlToken>,
+    index: usize,
+    /// Files already parsed, imports of them are skipped
+    visited_files: &'a mut Vec<PathBuf>,
+    result: IdlFile,
+}
+
+fn parse_file(file_path: &Path, visited_files: &mut Vec<PathBuf>) -> Result<IdlFile, String> {
+    visited_files.push(get_canonical_path(file_path));
+    let bytes = file_reader::read_all_bytes(file_path)?;
+    let content = String::from_utf8_lossy(&bytes).to_string();
+    let tokens = tokenize(&content).map_err(|error_byte| {
+        format!(
+            "Invalid avro idl (unterminated comment or string) in file:\n{}\n",
+            get_location(file_path, &content, error_byte)
+        )
+    })?;
+
+    IdlParser {
+        file_path,
+        content,
+        tokens,
+        index: 0,
+        visited_files,
+        result: IdlFile::default(),
+    }
+    .parse()
+}
+
+impl IdlParser<'_> {
+    fn parse(mut self) -> Result<IdlFile, String> {
+        while self.index < self.tokens.len() {
+            let doc = self.get_doc();
+            let annotations = self.parse_annotations()?;
+            let doc = doc.or_else(|| self.get_doc());
+            match self.peek_text() {
+                "namespace" => {
+                    self.index += 1;
+                    self.result.namespace = Some(self.expect_identifier()?);
+                    self.expect(";")?;
+                }
+                "schema" => {
+                    self.index += 1;
+                    let namespace = self.result.namespace.clone();
+                    self.result.main_schema = Some(self.parse_type(namespace.as_deref())?);
+                    self.expect(";")?;
+                }
+                "protocol" => self.parse_protocol(doc, &annotations)?,
+                _ => self.parse_type_declaration(doc, &annotations)?,
+            }
+        }
+
+        Ok(self.result)
+    }
+
+    fn parse_protocol(
+        &mut self,
+        doc: Option<String>,
+        annotations: &[IdlAnnotation],
+    ) -> Result<(), String> {
+        let protocol_index = self.index;
+        self.index += 1;
+        let name = self.expect_identifier()?;
+        if self.result.protocol.is_some() {
+            return Err(self.get_error(protocol_index, "only one protocol can be declared"));
+        }
+        if let Some(namespace) = self.get_string_annotation(annotations, "namespace")? {
+            self.result.namespace = Some(namespace);
+        }
+        self.result.protocol = Some((name, doc));
+
+        self.expect("{")?;
+        while !self.consume("}") {
+            let doc = self.get_doc();
+            let annotations = self.parse_annotations()?;
+            let doc = doc.or_else(|| self.get_doc());
+            if TYPE_DECLARATION_KEYWORDS.contains(&self.peek_text()) {
+                self.parse_type_declaration(doc, &annotations)?;
+            } else {
+                let message = self.parse_message(doc, &annotations)?;
+                self.result.messages.push(message);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_type_declaration(
+        &mut self,
+        doc: Option<String>,
+        annotations: &[IdlAnnotation],
+    ) -> Result<(), String> {
+        let keyword_index = self.next_index("a declaration")?;
+        let keyword = self.get_text(keyword_index).to_string();
+        if "import" == keyword {
+            return self.parse_import();
+        }
+        if !TYPE_DECLARATION_KEYWORDS.contains(&keyword.as_str()) {
+            return Err(self.get_error(
+                keyword_index,
+                &format!("expected a declaration but found \"{}\"", keyword),
+            ));
+        }
+
+        let name_index = self.index;
+        let (name, namespace) = self.parse_name_and_namespace(annotations)?;
+        let mut builder = AvroItem::builder();
+        builder.name(&name);
+        if let Some(namespace) = &namespace {
+            builder.namespace(namespace);
+        }
+        if let Some(doc) = doc {
+            builder.doc(&doc);
+        }
+        if let Some(aliases) = self.get_strings_annotation(annotations, "aliases")? {
+            builder.aliases(aliases);
+        }
+        match keyword.as_str() {
+            "record" | "error" => {
+                let mut fields = Vec::new();
+                self.expect("{")?;
+                while !self.consume("}") {
+                    self.parse_fields(namespace.as_deref(), &mut fields)?;
+                }
+                builder.fields(fields).item_type(if "record" == keyword {
+                    AvroItemType::Record
+                } else {
+                    AvroItemType::Error
+                });
+            }
+            "enum" => {
+                let mut symbols = Vec::new();
+                self.expect("{")?;
+                loop {
+                    symbols.push(self.expect_identifier()?);
+                    if !self.consume(",") {
+                        break;
+                    }
+                }
+                self.expect("}")?;
+                if self.consume("=") {
+                    builder.default(&self.expect_identifier()?);
+                    self.expect(";")?;
+                }
+                builder.symbols(symbols).item_type(AvroItemType::Enum);
+            }
+            _ => {
+                self.expect("(")?;
+                let size = self.expect_number()?;
+                self.expect(")")?;
+                self.expect(";")?;
+                if 0 == size {
+                    return Err(self.get_error(
+                        name_index,
+                        &format!("fixed \"{}\" size must be positive", name),
+                    ));
+                }
+                builder.size(size).item_type(AvroItemType::Fixed);
+            }
+        }
+
+        self.result.types.push(builder.build());
+        Ok(())
+    }
+
+    /// Imported files are resolved from the directory of the importing file, their types
+    /// (and messages) are added before the following declarations
+    fn parse_import(&mut self) -> Result<(), String> {
+        let kind_index = self.index;
+        let kind = self.expect_identifier()?;
+        let file_name = self.expect_string()?;
+        self.expect(";")?;
+
+        let import_path = self
+            .file_path
+            .parent()
+            .map(|dir| dir.join(&file_name))
+            .unwrap_or_else(|| PathBuf::from(&file_name));
+        if self
+            .visited_files
+            .contains(&get_canonical_path(&import_path))
+        {
+            return Ok(());
+        }
+        match kind.as_str() {
+            "idl" => {
+                let imported = parse_file(&import_path, self.visited_files)?;
+                self.result.types.extend(imported.types);
+                self.result.messages.extend(imported.messages);
+            }
+            "protocol" => {
+                self.visited_files.push(get_canonical_path(&import_path));
+                let imported = avro_parser::parse_protocol(&import_path)?;
+                self.result.types.extend(imported.get_types().clone());
+                self.result.messages.extend(imported.get_messages().clone());
+            }
+            "schema" => {
+                self.visited_files.push(get_canonical_path(&import_path));
+                self.result.types.extend(avro_parser::parse(&import_path)?);
+            }
+            _ => {
+                return Err(self.get_error(
+                    kind_index,
+                    &format!(
+                        "import must be \"idl\", \"protocol\" or \"schema\" but found \"{}\"",
+                        kind
+                    ),
+                ))
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Names with dots are full names, otherwise the namespace is the annotated one or
+    /// the one of the file. An empty namespace stands for the null namespace.
+    fn parse_name_and_namespace(
+        &mut self,
+        annotations: &[IdlAnnotation],
+    ) -> Result<(String, Option<String>), String> {
+        let name = self.expect_identifier()?;
+        let (name, namespace) = match name.rsplit_once('.') {
+            Some((namespace, name)) => (name.to_string(), Some(namespace.to_string())),
+            None => match self.get_string_annotation(annotations, "namespace")? {
+                Some(namespace) => (name, Some(namespace)),
+                None => (name, self.result.namespace.clone()),
+            },
+        };
+
+        Ok((name, namespace.filter(|namespace| !namespace.is_empty())))
+    }
+
+    /// Fields sharing a type, i.e. "string name, surname = "";"
+    fn parse_fields(
+        &mut self,
+        namespace: Option<&str>,
+        fields: &mut Vec<AvroItem>,
+    ) -> Result<(), String> {
+        let doc = self.get_doc();
+        let annotations = self.parse_annotations()?;
+        let doc = doc.or_else(|| self.get_doc());
+        let item_type = self.parse_annotated_type(&annotations, namespace)?;
+        loop {
+            let field = self.parse_variable(doc.as_deref(), &annotations, item_type.clone())?;
+            fields.push(field);
+            if !self.consume(",") {
+                break;
+            }
+        }
+
+        self.expect(";")
+    }
+
+    fn parse_message(
+        &mut self,
+        doc: Option<String>,
+        annotations: &[IdlAnnotation],
+    ) -> Result<AvroMessage, String> {
+        let namespace = self.result.namespace.clone();
+        let namespace = namespace.as_deref();
+        let response = if self.consume("void") {
+            AvroItemType::Null
+        } else {
+            self.parse_annotated_type(annotations, namespace)?
+        };
+        let name = self.expect_identifier()?;
+
+        let mut request = Vec::new();
+        self.expect("(")?;
+        if !self.consume(")") {
+            loop {
+                let parameter_doc = self.get_doc();
+                let parameter_annotations = self.parse_annotations()?;
+                let parameter_type =
+                    self.parse_annotated_type(&parameter_annotations, namespace)?;
+                request.push(self.parse_variable(
+                    parameter_doc.as_deref(),
+                    &parameter_annotations,
+                    parameter_type,
+                )?);
+                if self.consume(")") {
+                    break;
+                }
+                self.expect(",")?;
+            }
+        }
+
+        let mut errors = Vec::new();
+        let mut is_one_way = false;
+        if self.consume("throws") {
+            loop {
+                errors.push(self.parse_type(namespace)?);
+                if !self.consume(",") {
+                    break;
+                }
+            }
+        } else if self.consume("oneway") {
+            is_one_way = true;
+        }
+        self.expect(";")?;
+
+        Ok(AvroMessage::new(
+            name, doc, request, response, errors, is_one_way,
+        ))
+    }
+
+    /// Field or message parameter after its type, with its annotations and default value
+    fn parse_variable(
+        &mut self,
+        doc: Option<&str>,
+        type_annotations: &[IdlAnnotation],
+        item_type: AvroItemType,
+    ) -> Result<AvroItem, String> {
+        let annotations = self.parse_annotations()?;
+        let name = self.expect_identifier()?;
+        let mut builder = AvroItem::builder();
+        builder.name(&name).item_type(item_type);
+        if let Some(doc) = doc {
+            builder.doc(doc);
+        }
+        for annotations in [type_annotations, &annotations] {
+            self.set_field_annotations(&mut builder, annotations)?;
+        }
+        if self.consume("=") {
+            let (start_index, end_index) = self.parse_json_value()?;
+            builder.default(&self.get_json_value(start_index, end_index));
+        }
+
+        Ok(builder.build())
+    }
+
+    fn set_field_annotations(
+        &self,
+        builder: &mut AvroItemBuilder,
+        annotations: &[IdlAnnotation],
+    ) -> Result<(), String> {
+        if let Some(order) = self.get_string_annotation(annotations, "order")? {
+            let order = AvroOrder::from_str(&order).map_err(|err| {
+                self.get_error(get_annotation(annotations, "order").start_index, &err)
+            })?;
+            builder.order(order);
+        }
+        if let Some(aliases) = self.get_strings_annotation(annotations, "aliases")? {
+            builder.aliases(aliases);
+        }
+
+        Ok(())
+    }
+
+    fn parse_type(&mut self, namespace: Option<&str>) -> Result<AvroItemType, String> {
+        let annotations = self.parse_annotations()?;
+        self.parse_annotated_type(&annotations, namespace)
+    }
+
+    /// Type after its annotations, "?" makes it nullable (a union with "null" first)
+    fn parse_annotated_type(
+        &mut self,
+        annotations: &[IdlAnnotation],
+        namespace: Option<&str>,
+    ) -> Result<AvroItemType, String> {
+        let type_index = self.next_index("a type")?;
+        let type_name = self.get_text(type_index).to_string();
+        let item_type = match type_name.as_str() {
+            "array" | "map" => {
+                self.expect("<")?;
+                let inner_type = Box::new(self.parse_type(namespace)?);
+                self.expect(">")?;
+                if "array" == type_name {
+                    AvroItemType::Array(inner_type)
+                } else {
+                    AvroItemType::Map(inner_type)
+                }
+            }
+            "union" => {
+                let mut union_types = Vec::new();
+                self.expect("{")?;
+                loop {
+                    let union_type_index = self.index;
+                    let union_type = self.parse_type(namespace)?;
+                    if let AvroItemType::Union(_) = union_type {
+                        return Err(
+                            self.get_error(union_type_index, "unions can not contain other unions")
+                        );
+                    }
+                    union_types.push(union_type);
+                    if !self.consume(",") {
+                        break;
+                    }
+                }
+                self.expect("}")?;
+                AvroItemType::Union(union_types)
+            }
+            "decimal" => {
+                self.expect("(")?;
+                let precision = self.expect_number()?;
+                self.expect(",")?;
+                let scale = self.expect_number()?;
+                self.expect(")")?;
+                if 0 == precision || precision < scale {
+                    return Err(self.get_error(
+                        type_index,
+                        "decimal precision must be positive and not lower than its scale",
+                    ));
+                }
+                to_logical_type(
+                    AvroItemType::Bytes,
+                    AvroLogicalType::Decimal { precision, scale },
+                )
+            }
+            "date" => to_logical_type(AvroItemType::Int, AvroLogicalType::Date),
+            "time_ms" => to_logical_type(AvroItemType::Int, AvroLogicalType::TimeMillis),
+            "timestamp_ms" => to_logical_type(AvroItemType::Long, AvroLogicalType::TimestampMillis),
+            "local_timestamp_ms" => {
+                to_logical_type(AvroItemType::Long, AvroLogicalType::LocalTimestampMillis)
+            }
+            "uuid" => to_logical_type(AvroItemType::String, AvroLogicalType::Uuid),
+            _ if IdlTokenKind::Identifier == self.tokens[type_index].kind => {
+                match AvroItemType::from_primitive_name(&type_name) {
+                    Some(primitive) => primitive,
+                    None => {
+                        AvroItemType::Reference(avro_parser::get_full_name(&type_name, namespace))
+                    }
+                }
+            }
+            _ => {
+                return Err(self.get_error(
+                    type_index,
+                    &format!("expected a type but found \"{}\"", type_name),
+                ))
+            }
+        };
+        let item_type = self.set_logical_type_annotation(item_type, annotations)?;
+
+        if self.consume("?") {
+            return Ok(AvroItemType::Union(vec![AvroItemType::Null, item_type]));
+        }
+        Ok(item_type)
+    }
+
+    /// Invalid logical types are ignored as the specification requires, the annotated
+    /// type is used instead
+    fn set_logical_type_annotation(
+        &self,
+        item_type: AvroItemType,
+        annotations: &[IdlAnnotation],
+    ) -> Result<AvroItemType, String> {
+        let name = match self.get_string_annotation(annotations, "logicalType")? {
+            Some(name) => name,
+            None => return Ok(item_type),
+        };
+        let precision = self
+            .get_number_annotation(annotations, "precision")
+            .unwrap_or(0);
+        let scale = self
+            .get_number_annotation(annotations, "scale")
+            .unwrap_or(0);
+        let logical_type_opt = AvroLogicalType::from_name(&name, precision, scale)
+            .filter(|logical_type| logical_type.is_valid_for(&item_type));
+        match logical_type_opt {
+            Some(AvroLogicalType::Decimal { precision, scale })
+                if 0 == precision || precision < scale => {}
+            Some(logical_type) => return Ok(to_logical_type(item_type, logical_type)),
+            None => {}
+        }
+
+        logger::log_warning(&format!(
+            "Ignored invalid or unknown avro logical type \"{}\" in file:\n{}\n",
+            name,
+            self.get_token_location(get_annotation(annotations, "logicalType").start_index)
+        ));
+        Ok(item_type)
+    }
+
+    fn parse_annotations(&mut self) -> Result<Vec<IdlAnnotation>, String> {
+        let mut result = Vec::new();
+        while let Some(IdlTokenKind::Annotation) =
+            self.tokens.get(self.index).map(|token| token.kind)
+        {
+            let name = self.get_text(self.index).to_string();
+            self.index += 1;
+            self.expect("(")?;
+            let (start_index, end_index) = self.parse_json_value()?;
+            self.expect(")")?;
+            result.push(IdlAnnotation {
+                name,
+                start_index,
+                end_index,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Token indexes of a json value, objects and arrays end with their closing symbol
+    fn parse_json_value(&mut self) -> Result<(usize, usize), String> {
+        let start_index = self.next_index("a json value")?;
+        if !matches!(self.get_text(start_index), "{" | "[") {
+            return Ok((start_index, start_index + 1));
+        }
+
+        let mut depth = 1;
+        while 0 < depth {
+            let index = self.next_index("the end of the json value")?;
+            match self.get_text(index) {
+                "{" | "[" if IdlTokenKind::Symbol == self.tokens[index].kind => depth += 1,
+                "}" | "]" if IdlTokenKind::Symbol == self.tokens[index].kind => depth -= 1,
+                _ => {}
+            }
+        }
+        Ok((start_index, self.index))
+    }
+
+    /// Strings are returned without quotation marks, other values as written
+    fn get_json_value(&self, start_index: usize, end_index: usize) -> String {
+        if end_index == start_index + 1 && IdlTokenKind::String == self.tokens[start_index].kind {
+            return self.get_string(start_index);
+        }
+        self.content[self.tokens[start_index].start_byte..self.tokens[end_index - 1].end_byte]
+            .to_string()
+    }
+
+    fn get_string_annotation(
+        &self,
+        annotations: &[IdlAnnotation],
+        name: &str,
+    ) -> Result<Option<String>, String> {
+        let annotation = match find_annotation(annotations, name) {
+            Some(annotation) => annotation,
+            None => return Ok(None),
+        };
+        if annotation.end_index != annotation.start_index + 1
+            || IdlTokenKind::String != self.tokens[annotation.start_index].kind
+        {
+            return Err(self.get_error(
+                annotation.start_index,
+                &format!("\"@{}\" must be a json string", name),
+            ));
+        }
+
+        Ok(Some(self.get_string(annotation.start_index)))
+    }
+
+    fn get_strings_annotation(
+        &self,
+        annotations: &[IdlAnnotation],
+        name: &str,
+    ) -> Result<Option<Vec<String>>, String> {
+        let annotation = match find_annotation(annotations, name) {
+            Some(annotation) => annotation,
+            None => return Ok(None),
+        };
+        let array_error = || {
+            self.get_error(
+                annotation.start_index,
+                &format!("\"@{}\" must be a json array of strings", name),
+            )
+        };
+        if "[" != self.get_text(annotation.start_index) {
+            return Err(array_error());
+        }
+
+        let mut result = Vec::new();
+        for index in annotation.start_index + 1..annotation.end_index - 1 {
+            match self.tokens[index].kind {
+                IdlTokenKind::String => result.push(self.get_string(index)),
+                IdlTokenKind::Symbol if "," == self.get_text(index) => {}
+                _ => return Err(array_error()),
+            }
+        }
+        Ok(Some(result))
+    }
+
+    fn get_number_annotation(&self, annotations: &[IdlAnnotation], name: &str) -> Option<usize> {
+        find_annotation(annotations, name)
+            .filter(|annotation| IdlTokenKind::Number == self.tokens[annotation.start_index].kind)
+            .and_then(|annotation| self.get_text(annotation.start_index).parse().ok())
+    }
+
+    fn get_doc(&self) -> Option<String> {
+        self.tokens
+            .get(self.index)
+            .and_then(|token| token.doc.clone())
+    }
+
+    fn peek_text(&self) -> &str {
+        if self.index < self.tokens.len() {
+            return self.get_text(self.index);
+        }
+        ""
+    }
+
+    fn get_text(&self, index: usize) -> &str {
+        let token = &self.tokens[index];
+        &self.content[token.start_byte..token.end_byte]
+    }
+
+    /// String content without quotation marks and with the escape sequences resolved
+    fn get_string(&self, index: usize) -> String {
+        let text = self.get_text(index);
+        avro_parser::unescape_json(&text[1..text.len() - 1])
+    }
+
+    /// Keywords and symbols, strings with the same content do not match
+    fn consume(&mut self, text: &str) -> bool {
+        let is_match = self.tokens.get(self.index).is_some_and(|token| {
+            IdlTokenKind::String != token.kind && text == self.get_text(self.index)
+        });
+        if is_match {
+            self.index += 1;
+        }
+        is_match
+    }
+
+    fn expect(&mut self, text: &str) -> Result<(), String> {
+        if self.consume(text) {
+            return Ok(());
+        }
+        Err(self.get_unexpected_error(&format!("\"{}\"", text)))
+    }
+
+    fn expect_identifier(&mut self) -> Result<String, String> {
+        self.expect_kind(IdlTokenKind::Identifier, "a name")
+            .map(|index| self.get_text(index).to_string())
+    }
+
+    fn expect_string(&mut self) -> Result<String, String> {
+        self.expect_kind(IdlTokenKind::String, "a string")
+            .map(|index| self.get_string(index))
+    }
+
+    fn expect_number(&mut self) -> Result<usize, String> {
+        let index = self.expect_kind(IdlTokenKind::Number, "a positive integer")?;
+        self.get_text(index)
+            .parse()
+            .map_err(|_| self.get_unexpected_error("a positive integer"))
+    }
+
+    fn expect_kind(&mut self, kind: IdlTokenKind, expected: &str) -> Result<usize, String> {
+        match self.tokens.get(self.index) {
+            Some(token) if kind == token.kind => {
+                self.index += 1;
+                Ok(self.index - 1)
+            }
+            _ => Err(self.get_unexpected_error(expected)),
+        }
+    }
+
+    fn next_index(&mut self, expected: &str) -> Result<usize, String> {
+        if self.index < self.tokens.len() {
+            self.index += 1;
+            return Ok(self.index - 1);
+        }
+        Err(self.get_unexpected_error(expected))
+    }
+
+    fn get_unexpected_error(&self, expected: &str) -> String {
+        if self.index < self.tokens.len() {
+            return self.get_error(
+                self.index,
+                &format!(
+                    "expected {} but found \"{}\"",
+                    expected,
+                    self.get_text(self.index)
+                ),
+            );
+        }
+        format!(
+            "Invalid avro idl (expected {} but found the end of the file) in file:\n{}\n",
+            expected,
+            get_location(self.file_path, &self.content, self.content.len())
+        )
+    }
+
+    fn get_error(&self, index: usize, message: &str) -> String {
+        format!(
+            "Invalid avro idl ({}) in file:\n{}\n",
+            message,
+            self.get_token_location(index)
+        )
+    }
+
+    fn get_token_location(&self, index: usize) -> String {
+        get_location(self.file_path, &self.content, self.tokens[index].start_byte)
+    }
+}
+
+fn to_logical_type(item_type: AvroItemType, logical_type: AvroLogicalType) -> AvroItemType {
+    AvroItemType::Inline(Box::new(
+        AvroItem::builder()
+            .item_type(item_type)
+            .logical_type(logical_type)
+            .build(),
+    ))
+}
+
+fn find_annotation<'a>(annotations: &'a [IdlAnnotation], name: &str) -> Option<&'a IdlAnnotation> {
+    annotations
+        .iter()
+        .rev()
+        .find(|annotation| name == annotation.name)
+}
+
+fn get_annotation<'a>(annotations: &'a [IdlAnnotation], name: &str) -> &'a IdlAnnotation {
+    find_annotation(annotations, name).expect("Annotation must exist")
+}
+
+fn get_canonical_path(file_path: &Path) -> PathBuf {
+    file_path
+        .canonicalize()
+        .unwrap_or_else(|_| file_path.to_path_buf())
+}
+
+/// "path:line:column", both starting at 1
+fn get_location(file_path: &Path, content: &str, byte: usize) -> String {
+    let previous_content = &content[..byte];
+    let line = previous_content.matches('\n').count() + 1;
+    let line_start = previous_content.rfind('\n').map_or(0, |index| index + 1);
+
+    format!(
+        "{}:{}:{}",
+        try_to_absolute_path(file_path),
+        line,
+        byte - line_start + 1
+    )
+}
+
+/// Tokens without whitespaces and comments, doc comments are attached to the next token.
+/// Unterminated comments and strings return their start byte.
+fn tokenize(content: &str) -> Result<Vec<IdlToken>, usize> {
+    let bytes = content.as_bytes();
+    let mut tokens = Vec::new();
+    let mut doc = None;
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        let (kind, start_byte, end_byte, next_index) = if byte.is_ascii_whitespace() {
+            index += 1;
+            continue;
+        } else if content[index..].starts_with("//") {
+            index = content[index..]
+                .find('\n')
+                .map_or(bytes.len(), |end| index + end);
+            continue;
+        } else if content[index..].starts_with("/*") {
+            let end = content[index + 2..].find("*/").ok_or(index)? + index + 2;
+            if content[index..].starts_with("/**") && end > index + 2 {
+                doc = Some(to_doc(&content[index + 3..end]));
+            }
+            index = end + 2;
+            continue;
+        } else if b'"' == byte {
+            let mut end = index + 1;
+            while end < bytes.len() && b'"' != bytes[end] {
+                if b'\\' == bytes[end] {
+                    end += 1;
+                }
+                end += 1;
+            }
+            if end >= bytes.len() {
+                return Err(index);
+            }
+            (IdlTokenKind::String, index, end + 1, end + 1)
+        } else if b'`' == byte {
+            let end = content[index + 1..].find('`').ok_or(index)? + index + 1;
+            (IdlTokenKind::Identifier, index + 1, end, end + 1)
+        } else if b'@' == byte {
+            let end = find_end(bytes, index + 1, |byte| {
+                byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.' | b'-')
+            });
+            (IdlTokenKind::Annotation, index + 1, end, end)
+        } else if byte.is_ascii_digit()
+            || (b'-' == byte && bytes.get(index + 1).is_some_and(u8::is_ascii_digit))
+        {
+            let mut end = index + 1;
+            while end < bytes.len()
+                && (bytes[end].is_ascii_alphanumeric()
+                    || b'.' == bytes[end]
+                    || (matches!(bytes[end], b'+' | b'-') && matches!(bytes[end - 1], b'e' | b'E')))
+            {
+                end += 1;
+            }
+            (IdlTokenKind::Number, index, end, end)
+        } else if byte.is_ascii_alphabetic() || b'_' == byte {
+            let end = find_end(bytes, index, |byte| {
+                byte.is_ascii_alphanumeric() || matches!(byte, b'_' | b'.')
+            });
+            (IdlTokenKind::Identifier, index, end, end)
+        } else {
+            let end = index + content[index..].chars().next().map_or(1, char::len_utf8);
+            (IdlTokenKind::Symbol, index, end, end)
+        };
+
+        tokens.push(IdlToken {
+            kind,
+            start_byte,
+            end_byte,
+            doc: doc.take(),
+        });
+        index = next_index;
+    }
+
+    Ok(tokens)
+}
+
+fn find_end(bytes: &[u8], start: usize, is_part: impl Fn(u8) -> bool) -> usize {
+    let mut end = start;
+    while end < bytes.len() && is_part(bytes[end]) {
+        end += 1;
+    }
+    end
+}
+
+/// Doc comment content without the leading "*" of each line
+fn to_doc(comment: &str) -> String {
+    let lines: Vec<&str> = comment
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            line.strip_prefix('*').map_or(line, str::trim_start)
+        })
+        .collect();
+
+    lines.join("\n").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_path::get_test_file;
+    use crate::domain::usecase::avro::parser::avro_idl_parser;
+    use crate::domain::usecase::avro::parser::avro_parser;
+    use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
+    use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+    use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+    use crate::domain::usecase::avro::parser::dto::avro_order::AvroOrder;
+
+    #[test]
+    fn parse_protocol_with_imports_and_messages() {
+        let file_path = get_test_file(&get_current_file_path(), "shop.avdl");
+
+        let protocol = avro_idl_parser::parse_protocol(&file_path).expect("Avro idl must be valid");
+
+        assert_eq!("Shop", protocol.get_name());
+        assert_eq!(Some("com.shop".to_string()), protocol.get_namespace());
+        assert_eq!(Some("Shop operations".to_string()), protocol.get_doc());
+        let full_names: Vec<String> = protocol
+            .get_types()
+            .iter()
+            .filter_map(|item| item.get_full_name())
+            .collect();
+        assert_eq!(
+            vec![
+                "com.common.Currency",
+                "com.common.Checksum",
+                "com.shop.Md5",
+                "com.shop.Payment",
+                "com.shop.PaymentFailed",
+            ],
+            full_names
+        );
+        let currency = &protocol.get_types()[0];
+        assert_eq!(
+            Some(vec!["EUR".to_string(), "USD".to_string()]),
+            currency.get_symbols()
+        );
+        assert_eq!(Some("EUR".to_string()), currency.get_default());
+        assert_eq!(Some(16), protocol.get_types()[2].get_size());
+
+        let payment = &protocol.get_types()[3];
+        assert_eq!(
+            Some("A payment\nwith two lines".to_string()),
+            payment.get_doc()
+        );
+        assert_eq!(&vec!["com.legacy.OldPayment"], payment.get_aliases());
+        let fields = payment.get_fields().as_ref().expect("Fields expected");
+        let field_names: Vec<String> = fields.iter().filter_map(|field| field.get_name()).collect();
+        assert_eq!(
+            vec![
+                "id",
+                "amount",
+                "currency",
+                "createdAt",
+                "note",
+                "description",
+                "tags",
+                "metadata",
+                "checksum",
+                "rank",
+                "secondaryRank",
+                "type",
+            ],
+            field_names
+        );
+        assert_eq!(Some("Payment identifier".to_string()), fields[0].get_doc());
+        assert_eq!(
+            &AvroItemType::Inline(Box::new(
+                AvroItem::builder()
+                    .item_type(AvroItemType::String)
+                    .logical_type(AvroLogicalType::Uuid)
+                    .build()
+            )),
+            fields[0].get_item_type()
+        );
+        match fields[1].get_item_type() {
+            AvroItemType::Inline(amount) => assert_eq!(
+                Some(AvroLogicalType::Decimal {
+                    precision: 10,
+                    scale: 2
+                }),
+                amount.get_logical_type()
+            ),
+            other => panic!("Inline decimal expected, found {}", other),
+        }
+        assert_eq!(Some(AvroOrder::Descending), fields[1].get_order());
+        assert_eq!(
+            &AvroItemType::Reference("com.common.Currency".to_string()),
+            fields[2].get_item_type()
+        );
+        match fields[3].get_item_type() {
+            AvroItemType::Inline(created_at) => assert_eq!(
+                Some(AvroLogicalType::TimestampMicros),
+                created_at.get_logical_type()
+            ),
+            other => panic!("Inline timestamp expected, found {}", other),
+        }
+        assert_eq!(
+            &AvroItemType::Union(vec![AvroItemType::Null, AvroItemType::String]),
+            fields[4].get_item_type()
+        );
+        assert_eq!(Some("null".to_string()), fields[4].get_default());
+        assert_eq!(&vec!["comment"], fields[4].get_aliases());
+        assert_eq!(
+            &AvroItemType::Union(vec![AvroItemType::String, AvroItemType::Null]),
+            fields[5].get_item_type()
+        );
+        assert_eq!(
+            Some("No \"description\"".to_string()),
+            fields[5].get_default()
+        );
+        assert_eq!(
+            &AvroItemType::Array(Box::new(AvroItemType::String)),
+            fields[6].get_item_type()
+        );
+        assert_eq!(Some("[]".to_string()), fields[6].get_default());
+        assert_eq!(
+            &AvroItemType::Map(Box::new(AvroItemType::Long)),
+            fields[7].get_item_type()
+        );
+        assert_eq!(Some("{\"a\": 1}".to_string()), fields[7].get_default());
+        assert_eq!(
+            &AvroItemType::Reference("com.common.Checksum".to_string()),
+            fields[8].get_item_type()
+        );
+        assert_eq!(Some("0".to_string()), fields[9].get_default());
+        assert_eq!(Some("-1".to_string()), fields[10].get_default());
+        assert_eq!(&AvroItemType::Int, fields[10].get_item_type());
+        assert_eq!(&AvroItemType::String, fields[11].get_item_type());
+        assert_eq!(
+            &AvroItemType::Error,
+            protocol.get_types()[4].get_item_type()
+        );
+
+        let messages = protocol.get_messages();
+        assert_eq!(3, messages.len());
+        assert_eq!("pay", messages[0].get_name());
+        assert_eq!(Some("Pays an order".to_string()), messages[0].get_doc());
+        assert_eq!(
+            Some("payment".to_string()),
+            messages[0].get_request()[0].get_name()
+        );
+        assert_eq!(
+            Some("false".to_string()),
+            messages[0].get_request()[1].get_default()
+        );
+        assert_eq!(
+            &AvroItemType::Reference("com.shop.Payment".to_string()),
+            messages[0].get_response()
+        );
+        assert_eq!(
+            &vec![AvroItemType::Reference(
+                "com.shop.PaymentFailed".to_string()
+            )],
+            messages[0].get_errors()
+        );
+        assert_eq!(&AvroItemType::Null, messages[1].get_response());
+        assert!(messages[1].get_request().is_empty());
+        assert!(messages[1].is_one_way());
+        assert_eq!("checksum", messages[2].get_name());
+    }
+
+    #[test]
+    fn parse_schema_syntax() {
+        let file_path = get_test_file(&get_current_file_path(), "schema.avdl");
+
+        let avro_items = avro_parser::parse(&file_path).expect("Avro idl must be valid");
+
+        assert_eq!(2, avro_items.len());
+        assert_eq!(
+            Some("com.schema.Node".to_string()),
+            avro_items[0].get_full_name()
+        );
+        let fields = avro_items[0]
+            .get_fields()
+            .as_ref()
+            .expect("Fields expected");
+        assert_eq!(
+            &AvroItemType::Array(Box::new(AvroItemType::Reference(
+                "com.schema.Node".to_string()
+            ))),
+            fields[1].get_item_type()
+        );
+        assert_eq!(
+            &AvroItemType::Map(Box::new(AvroItemType::Reference(
+                "com.schema.Node".to_string()
+            ))),
+            avro_items[1].get_item_type()
+        );
+    }
+
+    #[test]
+    fn parse_invalid_idl_returns_location() {
+        let file_path = get_test_file(&get_current_file_path(), "invalid.avdl");
+
+        let result = avro_idl_parser::parse(&file_path);
+
+        let err = result.expect_err("Field without name must fail");
+        assert!(err.contains("expected a name but found \";\""));
+        assert!(err.contains("invalid.avdl:4:15"));
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -1,199 +1,618 @@
-use std::collections::HashMap;
 use std::path::Path;
+use std::str::FromStr;
 
+use crate::core::file_system::file_cache::FileCache;
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::observability::logger;
 use crate::core::parser::parser_node_trait::ParserNode;
-use crate::core::parser::string_helper::trim_quotation_marks;
-use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
+use crate::domain::usecase::avro::parser::avro_idl_parser;
+use crate::domain::usecase::avro::parser::dto::avro_item::{AvroItem, AvroItemBuilder};
 use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+use crate::domain::usecase::avro::parser::dto::avro_message::AvroMessage;
+use crate::domain::usecase::avro::parser::dto::avro_order::AvroOrder;
+use crate::domain::usecase::avro::parser::dto::avro_protocol::AvroProtocol;
 use crate::domain::usecase::json::parser::dto::json_node::JsonNode;
 use crate::domain::usecase::json::parser::dto::json_node_type::JsonNodeType;
 use crate::domain::usecase::json::parser::json_parser;
 
-pub fn parse(json_file_path: &Path) -> Vec<AvroItem> {
-    let json_root_node = json_parser::parse(json_file_path);
-
-    let object_nodes = filter_json_nodes_first_level(&json_root_node, &JsonNodeType::Object);
-    to_avro_items(object_nodes)
+/// # parse
+/// Schemas of an Avro schema (".avsc") or protocol (".avpr") file. The schema file root
+/// can be a single schema or an array of them, protocols return their declared types.
+/// IDL files (".avdl") are read by the [avro_idl_parser].
+pub fn parse(file_path: &Path) -> Result<Vec<AvroItem>, String> {
+    if is_idl_file(file_path) {
+        return avro_idl_parser::parse(file_path);
+    }
+    let parser = AvroParser::new(file_path)?;
+    let root = parser.get_root_value()?;
+    match root.get_node_type() {
+        Some(JsonNodeType::Object) if parser.to_object(root).get("protocol").is_some() => {
+            Ok(parser.to_protocol(root)?.get_types().clone())
+        }
+        Some(JsonNodeType::Array) => get_array_values(root)
+            .into_iter()
+            .map(|value| parser.to_schema(value, None))
+            .collect(),
+        _ => Ok(vec![parser.to_schema(root, None)?]),
+    }
 }
 
-fn to_avro_items(json_nodes: Vec<JsonNode>) -> Vec<AvroItem> {
-    let mut result = Vec::new();
-    for json_node in json_nodes {
-        result.push(to_avro_item(&json_node));
+/// # parse_protocol
+/// Named types and messages of an Avro protocol (".avpr" or ".avdl") file.
+pub fn parse_protocol(file_path: &Path) -> Result<AvroProtocol, String> {
+    if is_idl_file(file_path) {
+        return avro_idl_parser::parse_protocol(file_path);
     }
+    let parser = AvroParser::new(file_path)?;
+    let root = parser.get_root_value()?;
 
-    result
+    parser.to_protocol(root)
 }
 
-fn to_avro_item(json_node: &JsonNode) -> AvroItem {
-    let pair_nodes = filter_json_nodes_first_level(json_node, &JsonNodeType::Pair);
+struct AvroParser {
+    root_node: JsonNode,
+    file_cache: FileCache,
+}
 
-    let mut json_node_pair_map = HashMap::new();
-    for pair_node in pair_nodes {
-        let key = get_key_from_pair(&pair_node);
-        let value = get_value_node_from_pair(&pair_node);
-        json_node_pair_map.insert(key, value);
-    }
+/// Members of a json object, keys without quotation marks
+struct JsonObject<'a> {
+    members: Vec<(String, &'a JsonNode)>,
+}
 
-    AvroItem::new(
-        get_content(&json_node_pair_map, "\"name\"".to_string()),
-        get_content(&json_node_pair_map, "\"namespace\"".to_string()),
-        get_content(&json_node_pair_map, "\"doc\"".to_string()),
-        get_item_type(&json_node_pair_map),
-        get_avro_symbols(&json_node_pair_map),
-        get_content(&json_node_pair_map, "\"default\"".to_string()),
-        get_fields(&json_node_pair_map),
-    )
+impl<'a> JsonObject<'a> {
+    fn get(&self, key: &str) -> Option<&'a JsonNode> {
+        self.members
+            .iter()
+            .rev()
+            .find(|(member_key, _)| key == member_key)
+            .map(|(_, value)| *value)
+    }
 }
 
-fn get_fields(pair_map: &HashMap<String, JsonNode>) -> Option<Vec<AvroItem>> {
-    let json_node_opt = pair_map.get("\"fields\"");
-    if let Some(json_node) = json_node_opt {
-        if let Some(JsonNodeType::Array) = json_node.get_node_type() {
-            let object_nodes = filter_json_nodes_first_level(json_node, &JsonNodeType::Object);
-            return Some(to_avro_items(object_nodes));
+impl AvroParser {
+    fn new(file_path: &Path) -> Result<Self, String> {
+        let root_node = json_parser::parse(file_path)?;
+        let file_cache = FileCache::from(file_path);
+
+        Ok(Self {
+            root_node,
+            file_cache,
+        })
+    }
+
+    fn get_root_value(&self) -> Result<&JsonNode, String> {
+        self.root_node
+            .get_children()
+            .iter()
+            .find(|child| is_value(child))
+            .ok_or(format!(
+                "Avro schema not found in file:\n{}\n",
+                try_to_absolute_path(self.file_cache.get_path())
+            ))
+    }
+
+    fn to_protocol(&self, node: &JsonNode) -> Result<AvroProtocol, String> {
+        let object = self.to_object_checked(node, "protocol")?;
+        let name = self.get_mandatory_string(&object, node, "protocol")?;
+        let namespace = self.get_optional_string(&object, "namespace")?;
+        let doc = self.get_optional_string(&object, "doc")?;
+
+        let mut types = Vec::new();
+        if let Some(types_node) = object.get("types") {
+            for type_node in self.get_array_checked(types_node, "types")? {
+                types.push(self.to_schema(type_node, namespace.as_deref())?);
+            }
+        }
+
+        let mut messages = Vec::new();
+        if let Some(messages_node) = object.get("messages") {
+            let messages_object = self.to_object_checked(messages_node, "messages")?;
+            for (message_name, message_node) in messages_object.members {
+                messages.push(self.to_message(message_name, message_node, namespace.as_deref())?);
+            }
         }
+
+        Ok(AvroProtocol::new(name, namespace, doc, types, messages))
     }
-    None
-}
 
-fn get_item_type(json_node_pair_map: &HashMap<String, JsonNode>) -> AvroItemType {
-    let type_node_opt = json_node_pair_map.get("\"type\"");
-    if let Some(type_node) = type_node_opt {
-        if let Some(JsonNodeType::String) = type_node.get_node_type() {
-            let content = type_node.get_content();
-            return match content.as_str() {
-                "\"array\"" => {
-                    if let Some(item_type) = json_node_pair_map.get("\"items\"") {
-                        let items_type = get_item_type_base(Some(item_type));
-                        return AvroItemType::ArrayItems(Box::new(items_type));
-                    } else {
-                        panic!("Avro resource must have \"items\" when \"type\" is provided.");
+    fn to_message(
+        &self,
+        name: String,
+        node: &JsonNode,
+        namespace: Option<&str>,
+    ) -> Result<AvroMessage, String> {
+        let object = self.to_object_checked(node, "message")?;
+        let doc = self.get_optional_string(&object, "doc")?;
+        let request_node = object.get("request").ok_or_else(|| {
+            self.get_error(node, &format!("message \"{}\" requires \"request\"", name))
+        })?;
+        let mut request = Vec::new();
+        for parameter_node in self.get_array_checked(request_node, "request")? {
+            request.push(self.to_field(parameter_node, namespace)?);
+        }
+        let response_node = object.get("response").ok_or_else(|| {
+            self.get_error(node, &format!("message \"{}\" requires \"response\"", name))
+        })?;
+        let response = self.to_item_type(response_node, namespace)?;
+        let mut errors = Vec::new();
+        if let Some(errors_node) = object.get("errors") {
+            for error_node in self.get_array_checked(errors_node, "errors")? {
+                errors.push(self.to_item_type(error_node, namespace)?);
+            }
+        }
+        let is_one_way = object
+            .get("one-way")
+            .is_some_and(|one_way| Some(JsonNodeType::True) == one_way.get_node_type());
+
+        Ok(AvroMessage::new(
+            name, doc, request, response, errors, is_one_way,
+        ))
+    }
+
+    /// Schema declared at the root of a file or in the protocol types
+    fn to_schema(&self, node: &JsonNode, namespace: Option<&str>) -> Result<AvroItem, String> {
+        if Some(JsonNodeType::Object) == node.get_node_type() {
+            return self.to_avro_item(node, namespace);
+        }
+
+        Ok(AvroItem::builder()
+            .item_type(self.to_item_type(node, namespace)?)
+            .build())
+    }
+
+    fn to_field(&self, node: &JsonNode, namespace: Option<&str>) -> Result<AvroItem, String> {
+        let object = self.to_object_checked(node, "record field")?;
+        if object.get("name").is_none() {
+            return Err(self.get_error(node, "record field requires \"name\""));
+        }
+
+        self.to_avro_item(node, namespace)
+    }
+
+    fn to_avro_item(
+        &self,
+        node: &JsonNode,
+        enclosing_namespace: Option<&str>,
+    ) -> Result<AvroItem, String> {
+        let object = self.to_object(node);
+        let type_node = object
+            .get("type")
+            .ok_or_else(|| self.get_error(node, "\"type\" is mandatory"))?;
+        let type_keyword = match type_node.get_node_type() {
+            Some(JsonNodeType::String) => Some(self.get_string(type_node)),
+            _ => None,
+        };
+
+        let mut builder = AvroItem::builder();
+        match type_keyword.as_deref() {
+            Some(keyword @ ("record" | "error" | "enum" | "fixed")) => {
+                let (name, namespace) =
+                    self.get_name_and_namespace(&object, node, enclosing_namespace)?;
+                builder.name(&name);
+                if let Some(namespace) = &namespace {
+                    builder.namespace(namespace);
+                }
+                match keyword {
+                    "record" | "error" => {
+                        self.set_record_fields(
+                            &mut builder,
+                            &object,
+                            node,
+                            &name,
+                            namespace.as_deref(),
+                        )?;
+                        builder.item_type(if "record" == keyword {
+                            AvroItemType::Record
+                        } else {
+                            AvroItemType::Error
+                        });
+                    }
+                    "enum" => {
+                        self.set_enum_symbols(&mut builder, &object, node, &name)?;
+                        builder.item_type(AvroItemType::Enum);
+                    }
+                    _ => {
+                        let size = self.get_size(&object, node, &name)?;
+                        builder.size(size).item_type(AvroItemType::Fixed);
                     }
                 }
-                _ => get_item_type_base(type_node_opt),
-            };
+            }
+            Some(keyword @ ("array" | "map")) => {
+                let attribute = if "array" == keyword {
+                    "items"
+                } else {
+                    "values"
+                };
+                let inner_node = object.get(attribute).ok_or_else(|| {
+                    self.get_error(node, &format!("\"{}\" requires \"{}\"", keyword, attribute))
+                })?;
+                let inner_type = Box::new(self.to_item_type(inner_node, enclosing_namespace)?);
+                builder.item_type(if "array" == keyword {
+                    AvroItemType::Array(inner_type)
+                } else {
+                    AvroItemType::Map(inner_type)
+                });
+                self.set_name(&mut builder, &object)?;
+            }
+            _ => {
+                builder.item_type(self.to_item_type(type_node, enclosing_namespace)?);
+                self.set_name(&mut builder, &object)?;
+            }
+        }
+
+        if let Some(doc) = self.get_optional_string(&object, "doc")? {
+            builder.doc(&doc);
+        }
+        if let Some(aliases_node) = object.get("aliases") {
+            builder.aliases(self.get_strings(aliases_node, "aliases")?);
         }
+        if let Some(default_node) = object.get("default") {
+            builder.default(&self.get_json_value(default_node));
+        }
+        if let Some(order_node) = object.get("order") {
+            let order_str = self.get_string_checked(order_node, "order")?;
+            let order =
+                AvroOrder::from_str(&order_str).map_err(|err| self.get_error(order_node, &err))?;
+            builder.order(order);
+        }
+        if let Some(logical_type_node) = object.get("logicalType") {
+            self.set_logical_type(&mut builder, &object, logical_type_node)?;
+        }
+
+        Ok(builder.build())
     }
 
-    get_item_type_base(type_node_opt)
-}
+    fn to_item_type(
+        &self,
+        node: &JsonNode,
+        namespace: Option<&str>,
+    ) -> Result<AvroItemType, String> {
+        match node.get_node_type() {
+            Some(JsonNodeType::String) => {
+                let name = self.get_string(node);
+                if let Some(primitive) = AvroItemType::from_primitive_name(&name) {
+                    return Ok(primitive);
+                }
+                if matches!(
+                    name.as_str(),
+                    "record" | "error" | "enum" | "fixed" | "array" | "map"
+                ) {
+                    return Err(self.get_error(
+                        node,
+                        &format!(
+                            "\"{}\" must be declared as an object with its attributes",
+                            name
+                        ),
+                    ));
+                }
+                Ok(AvroItemType::Reference(get_full_name(&name, namespace)))
+            }
+            Some(JsonNodeType::Array) => {
+                let mut union_types = Vec::new();
+                for value in get_array_values(node) {
+                    let union_type = self.to_item_type(value, namespace)?;
+                    if let AvroItemType::Union(_) = union_type {
+                        return Err(self.get_error(value, "unions can not contain other unions"));
+                    }
+                    union_types.push(union_type);
+                }
+                Ok(AvroItemType::Union(union_types))
+            }
+            Some(JsonNodeType::Object) => {
+                let item = self.to_avro_item(node, namespace)?;
+                if item.is_just_type() {
+                    return Ok(item.get_item_type().clone());
+                }
+                Ok(AvroItemType::Inline(Box::new(item)))
+            }
+            _ => Err(self.get_error(
+                node,
+                "type must be a name, a union (json array) or a json object",
+            )),
+        }
+    }
 
-fn get_item_type_base(type_node_opt: Option<&JsonNode>) -> AvroItemType {
-    if let Some(type_node) = type_node_opt {
-        let json_node_type = type_node.get_node_type();
-        if let Some(JsonNodeType::String) = json_node_type {
-            let content = type_node.get_content();
-            return match content.as_str() {
-                "\"record\"" => AvroItemType::RecordSimple,
-                "\"enum\"" => AvroItemType::Enum,
-                "\"null\"" => AvroItemType::Null,
-                "\"int\"" => AvroItemType::Int,
-                "\"long\"" => AvroItemType::Long,
-                "\"float\"" => AvroItemType::Float,
-                "\"double\"" => AvroItemType::Double,
-                "\"string\"" => AvroItemType::String,
-                "\"bytes\"" => AvroItemType::Bytes,
-                "\"boolean\"" => AvroItemType::Boolean,
-                "\"map\"" => AvroItemType::Map,
-                _ => AvroItemType::RecordName(trim_quotation_marks(content)),
-            };
-        } else if let Some(JsonNodeType::Array) = json_node_type {
-            let object_nodes = filter_types_in_array(type_node);
-            let array_item_types = object_nodes
-                .iter()
-                .map(|item| get_item_type_base(Some(item)))
-                .collect();
-            return AvroItemType::Array(array_item_types);
-        } else if let Some(JsonNodeType::Object) = json_node_type {
-            let avro_item = Box::new(to_avro_item(type_node));
-            return AvroItemType::Record(avro_item);
+    /// Names with dots are full names, otherwise the namespace is the declared one or the
+    /// enclosing one. An empty namespace stands for the null namespace.
+    fn get_name_and_namespace(
+        &self,
+        object: &JsonObject,
+        node: &JsonNode,
+        enclosing_namespace: Option<&str>,
+    ) -> Result<(String, Option<String>), String> {
+        let name = self.get_mandatory_string(object, node, "name")?;
+        let (name, namespace) = match name.rsplit_once('.') {
+            Some((namespace, name)) => (name.to_string(), Some(namespace.to_string())),
+            None => match self.get_optional_string(object, "namespace")? {
+                Some(namespace) => (name, Some(namespace)),
+                None => (name, enclosing_namespace.map(str::to_string)),
+            },
+        };
+
+        Ok((name, namespace.filter(|namespace| !namespace.is_empty())))
+    }
+
+    fn set_name(&self, builder: &mut AvroItemBuilder, object: &JsonObject) -> Result<(), String> {
+        if let Some(name) = self.get_optional_string(object, "name")? {
+            builder.name(&name);
         }
+        Ok(())
     }
 
-    panic!("Avro resource must have base type.");
-}
+    fn set_record_fields(
+        &self,
+        builder: &mut AvroItemBuilder,
+        object: &JsonObject,
+        node: &JsonNode,
+        name: &str,
+        namespace: Option<&str>,
+    ) -> Result<(), String> {
+        let fields_node = object.get("fields").ok_or_else(|| {
+            self.get_error(node, &format!("record \"{}\" requires \"fields\"", name))
+        })?;
+        let mut fields = Vec::new();
+        for field_node in self.get_array_checked(fields_node, "fields")? {
+            fields.push(self.to_field(field_node, namespace)?);
+        }
+        builder.fields(fields);
 
-fn get_content(key_to_value: &HashMap<String, JsonNode>, key: String) -> Option<String> {
-    key_to_value
-        .get(key.as_str())
-        .map(|node| trim_quotation_marks(node.get_content()))
-}
+        Ok(())
+    }
+
+    fn set_enum_symbols(
+        &self,
+        builder: &mut AvroItemBuilder,
+        object: &JsonObject,
+        node: &JsonNode,
+        name: &str,
+    ) -> Result<(), String> {
+        let symbols_node = object.get("symbols").ok_or_else(|| {
+            self.get_error(node, &format!("enum \"{}\" requires \"symbols\"", name))
+        })?;
+        builder.symbols(self.get_strings(symbols_node, "symbols")?);
+
+        Ok(())
+    }
+
+    fn get_size(&self, object: &JsonObject, node: &JsonNode, name: &str) -> Result<usize, String> {
+        let size_node = object.get("size").ok_or_else(|| {
+            self.get_error(node, &format!("fixed \"{}\" requires \"size\"", name))
+        })?;
+        self.get_number(size_node)
+            .ok_or_else(|| self.get_error(size_node, "\"size\" must be a positive integer"))
+    }
+
+    /// Invalid logical types are ignored as the specification requires, the annotated
+    /// type is used instead
+    fn set_logical_type(
+        &self,
+        builder: &mut AvroItemBuilder,
+        object: &JsonObject,
+        logical_type_node: &JsonNode,
+    ) -> Result<(), String> {
+        let name = self.get_string_checked(logical_type_node, "logicalType")?;
+        let precision = object
+            .get("precision")
+            .and_then(|node| self.get_number(node))
+            .unwrap_or(0);
+        let scale = object
+            .get("scale")
+            .and_then(|node| self.get_number(node))
+            .unwrap_or(0);
+        let item = builder.build();
+        let logical_type_opt = AvroLogicalType::from_name(&name, precision, scale)
+            .filter(|logical_type| logical_type.is_valid_for(item.get_item_type()));
+        match logical_type_opt {
+            Some(AvroLogicalType::Decimal { precision, scale })
+                if 0 == precision || precision < scale =>
+            {
+                self.log_ignored_logical_type(logical_type_node, &name)
+            }
+            Some(logical_type) => {
+                builder.logical_type(logical_type);
+            }
+            None => self.log_ignored_logical_type(logical_type_node, &name),
+        }
+
+        Ok(())
+    }
+
+    fn log_ignored_logical_type(&self, node: &JsonNode, name: &str) {
+        logger::log_warning(&format!(
+            "Ignored invalid or unknown avro logical type \"{}\" in file:\n{}\n",
+            name,
+            self.get_location(node)
+        ));
+    }
 
-fn get_avro_symbols(symbols: &HashMap<String, JsonNode>) -> Option<Vec<String>> {
-    if let Some(json_node) = symbols.get("\"symbols\"") {
-        let node_contents = filter_json_nodes_first_level(json_node, &JsonNodeType::String)
+    fn to_object<'a>(&self, node: &'a JsonNode) -> JsonObject<'a> {
+        let members = node
+            .get_children()
             .iter()
-            .map(|json_node| trim_quotation_marks(json_node.get_content()))
+            .filter(|child| Some(JsonNodeType::Pair) == child.get_node_type())
+            .filter_map(|pair| {
+                let mut pair_values = pair.get_children().iter().filter(|child| is_value(child));
+                let key = pair_values.next()?;
+                let value = pair_values.next()?;
+                Some((self.get_string(key), value))
+            })
             .collect();
-        return Some(node_contents);
+
+        JsonObject { members }
     }
 
-    None
-}
+    fn to_object_checked<'a>(
+        &self,
+        node: &'a JsonNode,
+        attribute: &str,
+    ) -> Result<JsonObject<'a>, String> {
+        if Some(JsonNodeType::Object) != node.get_node_type() {
+            return Err(self.get_error(node, &format!("\"{}\" must be a json object", attribute)));
+        }
+        Ok(self.to_object(node))
+    }
 
-fn get_value_node_from_pair(node: &JsonNode) -> JsonNode {
-    node.get_children()
-        .get(2)
-        .expect("Third child expected")
-        .clone()
-}
+    fn get_array_checked<'a>(
+        &self,
+        node: &'a JsonNode,
+        attribute: &str,
+    ) -> Result<Vec<&'a JsonNode>, String> {
+        if Some(JsonNodeType::Array) != node.get_node_type() {
+            return Err(self.get_error(node, &format!("\"{}\" must be a json array", attribute)));
+        }
+        Ok(get_array_values(node))
+    }
 
-fn get_key_from_pair(node: &JsonNode) -> String {
-    node.get_children()
-        .get(0)
-        .expect("First child expected")
-        .get_content()
-}
+    fn get_strings(&self, node: &JsonNode, attribute: &str) -> Result<Vec<String>, String> {
+        self.get_array_checked(node, attribute)?
+            .into_iter()
+            .map(|value| self.get_string_checked(value, attribute))
+            .collect()
+    }
 
-fn filter_json_nodes_first_level(root: &JsonNode, json_node_type: &JsonNodeType) -> Vec<JsonNode> {
-    let mut result_nodes: Vec<JsonNode> = Vec::new();
-    if let Some(node_type) = root.get_node_type() {
-        if json_node_type == &node_type {
-            result_nodes.push(root.clone());
-            if !result_nodes.is_empty() {
-                return result_nodes;
-            }
+    fn get_mandatory_string(
+        &self,
+        object: &JsonObject,
+        node: &JsonNode,
+        attribute: &str,
+    ) -> Result<String, String> {
+        match object.get(attribute) {
+            Some(value) => self.get_string_checked(value, attribute),
+            None => Err(self.get_error(node, &format!("\"{}\" is mandatory", attribute))),
         }
     }
 
-    for child in root.get_children() {
-        for mapping_pairs_node in filter_json_nodes_first_level(child, json_node_type) {
-            result_nodes.push(mapping_pairs_node);
+    fn get_optional_string(
+        &self,
+        object: &JsonObject,
+        attribute: &str,
+    ) -> Result<Option<String>, String> {
+        object
+            .get(attribute)
+            .map(|value| self.get_string_checked(value, attribute))
+            .transpose()
+    }
+
+    fn get_string_checked(&self, node: &JsonNode, attribute: &str) -> Result<String, String> {
+        if Some(JsonNodeType::String) != node.get_node_type() {
+            return Err(self.get_error(node, &format!("\"{}\" must be a json string", attribute)));
+        }
+        Ok(self.get_string(node))
+    }
+
+    /// String content without quotation marks and with the escape sequences resolved
+    fn get_string(&self, node: &JsonNode) -> String {
+        let content = node.get_content_from_cache(&self.file_cache);
+        let content = content
+            .strip_prefix('"')
+            .and_then(|content| content.strip_suffix('"'))
+            .unwrap_or(&content);
+        unescape_json(content)
+    }
+
+    fn get_number(&self, node: &JsonNode) -> Option<usize> {
+        if Some(JsonNodeType::Number) != node.get_node_type() {
+            return None;
         }
+        node.get_content_from_cache(&self.file_cache).parse().ok()
+    }
+
+    /// Strings are returned without quotation marks, other values as written
+    fn get_json_value(&self, node: &JsonNode) -> String {
+        if Some(JsonNodeType::String) == node.get_node_type() {
+            return self.get_string(node);
+        }
+        node.get_content_from_cache(&self.file_cache)
+    }
+
+    fn get_error(&self, node: &JsonNode, message: &str) -> String {
+        format!(
+            "Invalid avro schema ({}) in file:\n{}\n",
+            message,
+            self.get_location(node)
+        )
     }
 
-    result_nodes
+    fn get_location(&self, node: &JsonNode) -> String {
+        format!(
+            "{}:{}:{}",
+            try_to_absolute_path(self.file_cache.get_path()),
+            node.get_start_line(),
+            node.get_start_column()
+        )
+    }
 }
 
-fn filter_types_in_array(array_node: &JsonNode) -> Vec<JsonNode> {
-    let mut result_nodes: Vec<JsonNode> = Vec::new();
-    if let Some(node_type) = array_node.get_node_type() {
-        if !matches!(
-            node_type,
-            JsonNodeType::Array
-                | JsonNodeType::Comma
-                | JsonNodeType::LBrace
-                | JsonNodeType::RBrace
-                | JsonNodeType::Colon
-                | JsonNodeType::LBracket
-                | JsonNodeType::RBracket
-                | JsonNodeType::Null
-        ) {
-            result_nodes.push(array_node.clone());
-            if !result_nodes.is_empty() {
-                return result_nodes;
-            }
+fn is_idl_file(file_path: &Path) -> bool {
+    file_path
+        .extension()
+        .is_some_and(|extension| "avdl" == extension)
+}
+
+/// Names with dots are already full names (i.e. "com.test.Item")
+pub(crate) fn get_full_name(name: &str, namespace: Option<&str>) -> String {
+    match namespace {
+        Some(namespace) if !name.contains('.') && !namespace.is_empty() => {
+            format!("{}.{}", namespace, name)
         }
+        _ => name.to_string(),
     }
+}
+
+fn get_array_values(array_node: &JsonNode) -> Vec<&JsonNode> {
+    array_node
+        .get_children()
+        .iter()
+        .filter(|child| is_value(child))
+        .collect()
+}
+
+fn is_value(node: &JsonNode) -> bool {
+    matches!(
+        node.get_node_type(),
+        Some(JsonNodeType::Object)
+            | Some(JsonNodeType::Array)
+            | Some(JsonNodeType::String)
+            | Some(JsonNodeType::Number)
+            | Some(JsonNodeType::True)
+            | Some(JsonNodeType::False)
+            | Some(JsonNodeType::Null)
+    )
+}
 
-    for child in array_node.get_children() {
-        for mapping_pairs_node in filter_types_in_array(child) {
-            result_nodes.push(mapping_pairs_node);
+pub(crate) fn unescape_json(content: &str) -> String {
+    if !content.contains('\\') {
+        return content.to_string();
+    }
+
+    let mut result = String::new();
+    let mut chars = content.chars();
+    while let Some(current) = chars.next() {
+        if '\\' != current {
+            result.push(current);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some('r') => result.push('\r'),
+            Some('b') => result.push('\u{8}'),
+            Some('f') => result.push('\u{c}'),
+            Some('u') => {
+                let code: String = chars.by_ref().take(4).collect();
+                let unicode_char = u32::from_str_radix(&code, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .unwrap_or(char::REPLACEMENT_CHARACTER);
+                result.push(unicode_char);
+            }
+            Some(escaped) => result.push(escaped),
+            None => {}
         }
     }
 
-    result_nodes
+    result
 }
 
 #[cfg(test)]
@@ -202,15 +621,15 @@ mod tests {
 
     use crate::core::testing::test_path::get_test_file;
     use crate::domain::usecase::avro::parser::avro_parser;
-    use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
     use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+    use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+    use crate::domain::usecase::avro::parser::dto::avro_order::AvroOrder;
 
     #[test]
-    #[ignore = "ArrayItems not yet implemented"]
     fn parse_basic() {
         let file_path = get_test_file(&get_current_file_path(), "avro.avsc");
 
-        let avro_items = avro_parser::parse(&file_path);
+        let avro_items = avro_parser::parse(&file_path).expect("Avro schema must be valid");
 
         assert_eq!(2, avro_items.len());
         let avro_item = avro_items.get(0).expect("First item must exist");
@@ -229,13 +648,12 @@ mod tests {
             avro_item.get_symbols()
         );
         assert_eq!(None, avro_item.get_default());
-        assert_eq!(None, avro_item.get_default());
 
         let avro_item = avro_items.get(1).expect("Second item must exist");
         assert_eq!(Some("ComplexFields".to_string()), avro_item.get_name());
         assert_eq!(Some("com.parser".to_string()), avro_item.get_namespace());
         assert_eq!(Some("Docs".to_string()), avro_item.get_doc());
-        assert_eq!(&AvroItemType::RecordSimple, avro_item.get_item_type());
+        assert_eq!(&AvroItemType::Record, avro_item.get_item_type());
         assert_eq!(None, avro_item.get_symbols());
         assert_eq!(None, avro_item.get_default());
         let fields = avro_item.get_fields().as_ref().expect("Fields expected");
@@ -244,16 +662,6 @@ mod tests {
         assert_eq!(Some("field1".to_string()), first_field.get_name());
         assert_eq!(None, first_field.get_namespace());
         assert_eq!(Some("Field 1".to_string()), first_field.get_doc());
-        let array_strings_avro_item = AvroItem::new(
-            None,
-            None,
-            None,
-            AvroItemType::Array(Vec::new()),
-            None,
-            None,
-            None,
-        );
-        let array_strings_item_type = AvroItemType::Record(Box::new(array_strings_avro_item));
         let item_types = Vec::from([
             AvroItemType::Null,
             AvroItemType::Int,
@@ -263,11 +671,11 @@ mod tests {
             AvroItemType::String,
             AvroItemType::Bytes,
             AvroItemType::Boolean,
-            AvroItemType::Map,
-            array_strings_item_type,
+            AvroItemType::Map(Box::new(AvroItemType::Long)),
+            AvroItemType::Array(Box::new(AvroItemType::String)),
         ]);
         assert_eq!(
-            &AvroItemType::Array(item_types),
+            &AvroItemType::Union(item_types),
             first_field.get_item_type()
         );
         assert_eq!(None, first_field.get_symbols());
@@ -277,12 +685,128 @@ mod tests {
         assert_eq!(None, second_field.get_namespace());
         assert_eq!(Some("Enum example".to_string()), second_field.get_doc());
         assert_eq!(
-            &AvroItemType::RecordName("EnumExample".to_string()),
+            &AvroItemType::Reference("com.parser.EnumExample".to_string()),
             second_field.get_item_type()
         );
         assert_eq!(None, second_field.get_symbols());
         assert_eq!(None, second_field.get_default());
-        assert_eq!(None, second_field.get_default());
+    }
+
+    #[test]
+    fn parse_full_specification() {
+        let file_path = get_test_file(&get_current_file_path(), "full_specification.avsc");
+
+        let avro_items = avro_parser::parse(&file_path).expect("Avro schema must be valid");
+
+        assert_eq!(1, avro_items.len());
+        let payment = &avro_items[0];
+        assert_eq!(
+            Some("com.shop.Payment".to_string()),
+            payment.get_full_name()
+        );
+        assert_eq!(&vec!["com.legacy.OldPayment"], payment.get_aliases());
+        let fields = payment.get_fields().as_ref().expect("Fields expected");
+        let amount = match fields[0].get_item_type() {
+            AvroItemType::Inline(amount) => amount,
+            other => panic!("Inline amount expected, found {}", other),
+        };
+        assert_eq!(
+            Some(AvroLogicalType::Decimal {
+                precision: 10,
+                scale: 2
+            }),
+            amount.get_logical_type()
+        );
+        assert_eq!(&AvroItemType::Bytes, amount.get_item_type());
+        assert_eq!(Some(AvroOrder::Descending), fields[0].get_order());
+        let checksum = match fields[1].get_item_type() {
+            AvroItemType::Inline(checksum) => checksum,
+            other => panic!("Inline fixed expected, found {}", other),
+        };
+        assert_eq!(&AvroItemType::Fixed, checksum.get_item_type());
+        assert_eq!(Some(16), checksum.get_size());
+        assert_eq!(
+            Some("com.shop.Checksum".to_string()),
+            checksum.get_full_name()
+        );
+        assert_eq!(
+            &AvroItemType::Map(Box::new(AvroItemType::Reference(
+                "com.shop.Checksum".to_string()
+            ))),
+            fields[2].get_item_type()
+        );
+        let customer = match fields[3].get_item_type() {
+            AvroItemType::Inline(customer) => customer,
+            other => panic!("Inline record expected, found {}", other),
+        };
+        assert_eq!(
+            Some("com.customer.Customer".to_string()),
+            customer.get_full_name()
+        );
+        let customer_fields = customer.get_fields().as_ref().expect("Fields expected");
+        assert_eq!(
+            &AvroItemType::Reference("com.customer.Address".to_string()),
+            customer_fields[0].get_item_type()
+        );
+        assert_eq!(
+            &AvroItemType::Reference("com.shop.Checksum".to_string()),
+            customer_fields[1].get_item_type()
+        );
+        assert_eq!(Some("{\"a\": 1}".to_string()), fields[4].get_default());
+        assert_eq!(None, fields[5].get_logical_type());
+        assert_eq!(&AvroItemType::Int, fields[5].get_item_type());
+        assert_eq!(
+            Some("Line \"one\"\nLine two".to_string()),
+            fields[6].get_doc()
+        );
+    }
+
+    #[test]
+    fn parse_protocol_types_and_messages() {
+        let file_path = get_test_file(&get_current_file_path(), "protocol.avpr");
+
+        let protocol = avro_parser::parse_protocol(&file_path).expect("Protocol must be valid");
+
+        assert_eq!("Greeter", protocol.get_name());
+        assert_eq!(2, protocol.get_types().len());
+        assert_eq!(
+            Some("com.greeting.Greeting".to_string()),
+            protocol.get_types()[0].get_full_name()
+        );
+        assert_eq!(
+            &AvroItemType::Error,
+            protocol.get_types()[1].get_item_type()
+        );
+        let message = &protocol.get_messages()[0];
+        assert_eq!("hello", message.get_name());
+        assert_eq!(
+            Some("greeting".to_string()),
+            message.get_request()[0].get_name()
+        );
+        assert_eq!(
+            &AvroItemType::Reference("com.greeting.Greeting".to_string()),
+            message.get_response()
+        );
+        assert_eq!(
+            &vec![AvroItemType::Reference("com.greeting.Curse".to_string())],
+            message.get_errors()
+        );
+        assert!(protocol.get_messages()[1].is_one_way());
+        assert_eq!(
+            protocol.get_types(),
+            &avro_parser::parse(&file_path).expect("Protocol types must be parsed")
+        );
+    }
+
+    #[test]
+    fn parse_invalid_schema_returns_location() {
+        let file_path = get_test_file(&get_current_file_path(), "invalid_record.avsc");
+
+        let result = avro_parser::parse(&file_path);
+
+        let err = result.expect_err("Record without fields must fail");
+        assert!(err.contains("record \"Invalid\" requires \"fields\""));
+        assert!(err.contains("invalid_record.avsc:2:3"));
     }
 
     fn get_current_file_path() -> PathBuf {
@@ -1,61 +1,89 @@
 use std::fmt;
 
 use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+use crate::domain::usecase::avro::parser::dto::avro_order::AvroOrder;
 
-#[derive(Debug, PartialEq)]
+/// # AvroItem
+/// Named type declaration (record, enum, fixed), record field or inline type with attributes.
+#[derive(Debug, Clone, PartialEq)]
 pub struct AvroItem {
     name: Option<String>,
     namespace: Option<String>,
     doc: Option<String>,
+    aliases: Vec<String>,
     item_type: AvroItemType,
+    logical_type: Option<AvroLogicalType>,
     symbols: Option<Vec<String>>,
+    size: Option<usize>,
     default: Option<String>,
+    order: Option<AvroOrder>,
     fields: Option<Vec<AvroItem>>,
 }
 
 impl AvroItem {
-    pub fn new(
-        name: Option<String>,
-        namespace: Option<String>,
-        doc: Option<String>,
-        item_type: AvroItemType,
-        symbols: Option<Vec<String>>,
-        default: Option<String>,
-        fields: Option<Vec<AvroItem>>,
-    ) -> AvroItem {
-        AvroItem {
-            name,
-            namespace,
-            doc,
-            item_type,
-            symbols,
-            default,
-            fields,
-        }
+    pub fn builder() -> AvroItemBuilder {
+        AvroItemBuilder::new_builder()
     }
 
     pub fn get_name(&self) -> Option<String> {
         self.name.clone()
     }
+
+    /// # get_namespace
+    /// Namespace of named types, inherited from the enclosing type when it is not declared.
     pub fn get_namespace(&self) -> Option<String> {
         self.namespace.clone()
     }
+
+    /// # get_full_name
+    /// Namespace and name of named types (i.e. "com.test.Item").
+    pub fn get_full_name(&self) -> Option<String> {
+        let name = self.name.as_ref()?;
+        match &self.namespace {
+            Some(namespace) if !namespace.is_empty() => Some(format!("{}.{}", namespace, name)),
+            _ => Some(name.to_string()),
+        }
+    }
+
     pub fn get_doc(&self) -> Option<String> {
         self.doc.clone()
     }
 
+    /// # get_aliases
+    /// Alternate names of a named type or field, as written.
+    pub fn get_aliases(&self) -> &Vec<String> {
+        &self.aliases
+    }
+
     pub fn get_item_type(&self) -> &AvroItemType {
         &self.item_type
     }
 
+    pub fn get_logical_type(&self) -> Option<AvroLogicalType> {
+        self.logical_type
+    }
+
     pub fn get_symbols(&self) -> Option<Vec<String>> {
         self.symbols.clone()
     }
 
+    /// # get_size
+    /// Number of bytes of a fixed type.
+    pub fn get_size(&self) -> Option<usize> {
+        self.size
+    }
+
+    /// # get_default
+    /// Default value as json, strings are unquoted (i.e. "null", "1" or "text").
     pub fn get_default(&self) -> Option<String> {
         self.default.clone()
     }
 
+    pub fn get_order(&self) -> Option<AvroOrder> {
+        self.order
+    }
+
     pub fn get_fields(&self) -> &Option<Vec<AvroItem>> {
         &self.fields
     }
@@ -64,8 +92,12 @@ impl AvroItem {
         self.name.is_none()
             && self.namespace.is_none()
             && self.doc.is_none()
+            && self.aliases.is_empty()
+            && self.logical_type.is_none()
             && self.symbols.is_none()
+            && self.size.is_none()
             && self.default.is_none()
+            && self.order.is_none()
             && self.fields.is_none()
     }
 }
@@ -75,3 +107,106 @@ impl fmt::Display for AvroItem {
         write!(f, "{:?}", self)
     }
 }
+
+pub struct AvroItemBuilder {
+    name: Option<String>,
+    namespace: Option<String>,
+    doc: Option<String>,
+    aliases: Vec<String>,
+    item_type: AvroItemType,
+    logical_type: Option<AvroLogicalType>,
+    symbols: Option<Vec<String>>,
+    size: Option<usize>,
+    default: Option<String>,
+    order: Option<AvroOrder>,
+    fields: Option<Vec<AvroItem>>,
+}
+
+impl AvroItemBuilder {
+    fn new_builder() -> Self {
+        Self {
+            name: None,
+            namespace: None,
+            doc: None,
+            aliases: vec![],
+            item_type: AvroItemType::Null,
+            logical_type: None,
+            symbols: None,
+            size: None,
+            default: None,
+            order: None,
+            fields: None,
+        }
+    }
+
+    pub fn name(&mut self, input: &str) -> &mut Self {
+        self.name = Some(input.to_string());
+        self
+    }
+
+    pub fn namespace(&mut self, input: &str) -> &mut Self {
+        self.namespace = Some(input.to_string());
+        self
+    }
+
+    pub fn doc(&mut self, input: &str) -> &mut Self {
+        self.doc = Some(input.to_string());
+        self
+    }
+
+    pub fn aliases(&mut self, input: Vec<String>) -> &mut Self {
+        self.aliases = input;
+        self
+    }
+
+    pub fn item_type(&mut self, input: AvroItemType) -> &mut Self {
+        self.item_type = input;
+        self
+    }
+
+    pub fn logical_type(&mut self, input: AvroLogicalType) -> &mut Self {
+        self.logical_type = Some(input);
+        self
+    }
+
+    pub fn symbols(&mut self, input: Vec<String>) -> &mut Self {
+        self.symbols = Some(input);
+        self
+    }
+
+    pub fn size(&mut self, input: usize) -> &mut Self {
+        self.size = Some(input);
+        self
+    }
+
+    pub fn default(&mut self, input: &str) -> &mut Self {
+        self.default = Some(input.to_string());
+        self
+    }
+
+    pub fn order(&mut self, input: AvroOrder) -> &mut Self {
+        self.order = Some(input);
+        self
+    }
+
+    pub fn fields(&mut self, input: Vec<AvroItem>) -> &mut Self {
+        self.fields = Some(input);
+        self
+    }
+
+    pub fn build(&mut self) -> AvroItem {
+        AvroItem {
+            name: self.name.clone(),
+            namespace: self.namespace.clone(),
+            doc: self.doc.clone(),
+            aliases: self.aliases.clone(),
+            item_type: self.item_type.clone(),
+            logical_type: self.logical_type,
+            symbols: self.symbols.clone(),
+            size: self.size,
+            default: self.default.clone(),
+            order: self.order,
+            fields: self.fields.clone(),
+        }
+    }
+}
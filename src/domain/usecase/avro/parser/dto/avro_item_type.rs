@@ -2,23 +2,60 @@ use std::fmt;
 
 use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum AvroItemType {
+    // Named type declarations, the item stores their name, fields, symbols or size
+    Record,
+    /// Protocol error, declared as a record
+    Error,
     Enum,
-    RecordSimple,
-    Record(Box<AvroItem>),
-    RecordName(String),
-    Array(Vec<AvroItemType>),
-    ArrayItems(Box<AvroItemType>),
+    Fixed,
+
+    // Complex types
+    /// "array" with the type of its "items"
+    Array(Box<AvroItemType>),
+    /// "map" with the type of its "values", keys are always strings
+    Map(Box<AvroItemType>),
+    Union(Vec<AvroItemType>),
+    /// Type declared inline with attributes, i.e. a nested record or a logical type
+    Inline(Box<AvroItem>),
+    /// Full name of a named type declared elsewhere (i.e. "com.test.Item")
+    Reference(String),
+
+    // Primitive types
     Null,
+    Boolean,
     Int,
     Long,
     Float,
     Double,
-    String,
     Bytes,
-    Boolean,
-    Map,
+    String,
+}
+
+impl AvroItemType {
+    pub(crate) fn from_primitive_name(name: &str) -> Option<Self> {
+        let result = match name {
+            "null" => AvroItemType::Null,
+            "boolean" => AvroItemType::Boolean,
+            "int" => AvroItemType::Int,
+            "long" => AvroItemType::Long,
+            "float" => AvroItemType::Float,
+            "double" => AvroItemType::Double,
+            "bytes" => AvroItemType::Bytes,
+            "string" => AvroItemType::String,
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    /// Record, error, enum or fixed declaration
+    pub fn is_named(&self) -> bool {
+        matches!(
+            self,
+            AvroItemType::Record | AvroItemType::Error | AvroItemType::Enum | AvroItemType::Fixed
+        )
+    }
 }
 
 impl fmt::Display for AvroItemType {
@@ -0,0 +1,85 @@
+use std::fmt;
+
+use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+
+/// # AvroLogicalType
+/// "logicalType" annotating a primitive or fixed type (i.e. {"type": "int", "logicalType": "date"}).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvroLogicalType {
+    /// Arbitrary-precision signed decimal number on "bytes" or "fixed"
+    Decimal {
+        precision: usize,
+        scale: usize,
+    },
+    Uuid,
+    Date,
+    TimeMillis,
+    TimeMicros,
+    TimestampMillis,
+    TimestampMicros,
+    TimestampNanos,
+    LocalTimestampMillis,
+    LocalTimestampMicros,
+    LocalTimestampNanos,
+    Duration,
+}
+
+impl AvroLogicalType {
+    /// Logical type without the decimal attributes, "None" when the name is unknown
+    pub(crate) fn from_name(name: &str, precision: usize, scale: usize) -> Option<Self> {
+        let result = match name {
+            "decimal" => AvroLogicalType::Decimal { precision, scale },
+            "uuid" => AvroLogicalType::Uuid,
+            "date" => AvroLogicalType::Date,
+            "time-millis" => AvroLogicalType::TimeMillis,
+            "time-micros" => AvroLogicalType::TimeMicros,
+            "timestamp-millis" => AvroLogicalType::TimestampMillis,
+            "timestamp-micros" => AvroLogicalType::TimestampMicros,
+            "timestamp-nanos" => AvroLogicalType::TimestampNanos,
+            "local-timestamp-millis" => AvroLogicalType::LocalTimestampMillis,
+            "local-timestamp-micros" => AvroLogicalType::LocalTimestampMicros,
+            "local-timestamp-nanos" => AvroLogicalType::LocalTimestampNanos,
+            "duration" => AvroLogicalType::Duration,
+            _ => return None,
+        };
+        Some(result)
+    }
+
+    pub fn get_name(&self) -> &str {
+        match self {
+            AvroLogicalType::Decimal { .. } => "decimal",
+            AvroLogicalType::Uuid => "uuid",
+            AvroLogicalType::Date => "date",
+            AvroLogicalType::TimeMillis => "time-millis",
+            AvroLogicalType::TimeMicros => "time-micros",
+            AvroLogicalType::TimestampMillis => "timestamp-millis",
+            AvroLogicalType::TimestampMicros => "timestamp-micros",
+            AvroLogicalType::TimestampNanos => "timestamp-nanos",
+            AvroLogicalType::LocalTimestampMillis => "local-timestamp-millis",
+            AvroLogicalType::LocalTimestampMicros => "local-timestamp-micros",
+            AvroLogicalType::LocalTimestampNanos => "local-timestamp-nanos",
+            AvroLogicalType::Duration => "duration",
+        }
+    }
+
+    /// The annotated type defined by the specification, "fixed" is checked by the parser
+    pub(crate) fn is_valid_for(&self, item_type: &AvroItemType) -> bool {
+        match self {
+            AvroLogicalType::Decimal { .. } => {
+                matches!(item_type, AvroItemType::Bytes | AvroItemType::Fixed)
+            }
+            AvroLogicalType::Uuid => {
+                matches!(item_type, AvroItemType::String | AvroItemType::Fixed)
+            }
+            AvroLogicalType::Date | AvroLogicalType::TimeMillis => AvroItemType::Int == *item_type,
+            AvroLogicalType::Duration => AvroItemType::Fixed == *item_type,
+            _ => AvroItemType::Long == *item_type,
+        }
+    }
+}
+
+impl fmt::Display for AvroLogicalType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.get_name())
+    }
+}
@@ -0,0 +1,60 @@
+use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
+use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+
+/// # AvroMessage
+/// Protocol message, the request parameters are declared like record fields.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvroMessage {
+    name: String,
+    doc: Option<String>,
+    request: Vec<AvroItem>,
+    response: AvroItemType,
+    errors: Vec<AvroItemType>,
+    is_one_way: bool,
+}
+
+impl AvroMessage {
+    pub(crate) fn new(
+        name: String,
+        doc: Option<String>,
+        request: Vec<AvroItem>,
+        response: AvroItemType,
+        errors: Vec<AvroItemType>,
+        is_one_way: bool,
+    ) -> Self {
+        Self {
+            name,
+            doc,
+            request,
+            response,
+            errors,
+            is_one_way,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_doc(&self) -> Option<String> {
+        self.doc.clone()
+    }
+
+    pub fn get_request(&self) -> &Vec<AvroItem> {
+        &self.request
+    }
+
+    pub fn get_response(&self) -> &AvroItemType {
+        &self.response
+    }
+
+    /// # get_errors
+    /// Declared errors, without the implicit "string" error of every message.
+    pub fn get_errors(&self) -> &Vec<AvroItemType> {
+        &self.errors
+    }
+
+    pub fn is_one_way(&self) -> bool {
+        self.is_one_way
+    }
+}
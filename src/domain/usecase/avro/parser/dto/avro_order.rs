@@ -0,0 +1,37 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// # AvroOrder
+/// Sort "order" of a record field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvroOrder {
+    Ascending,
+    Descending,
+    Ignore,
+}
+
+impl FromStr for AvroOrder {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<AvroOrder, String> {
+        match input {
+            "ascending" => Ok(AvroOrder::Ascending),
+            "descending" => Ok(AvroOrder::Descending),
+            "ignore" => Ok(AvroOrder::Ignore),
+            _ => Err(format!(
+                "unknown order \"{}\", expected \"ascending\", \"descending\" or \"ignore\"",
+                input
+            )),
+        }
+    }
+}
+
+impl fmt::Display for AvroOrder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AvroOrder::Ascending => write!(f, "ascending"),
+            AvroOrder::Descending => write!(f, "descending"),
+            AvroOrder::Ignore => write!(f, "ignore"),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
+use crate::domain::usecase::avro::parser::dto::avro_message::AvroMessage;
+
+/// # AvroProtocol
+/// Named types and messages of an Avro protocol (".avpr").
+#[derive(Debug, Clone, PartialEq)]
+pub struct AvroProtocol {
+    name: String,
+    namespace: Option<String>,
+    doc: Option<String>,
+    types: Vec<AvroItem>,
+    messages: Vec<AvroMessage>,
+}
+
+impl AvroProtocol {
+    pub(crate) fn new(
+        name: String,
+        namespace: Option<String>,
+        doc: Option<String>,
+        types: Vec<AvroItem>,
+        messages: Vec<AvroMessage>,
+    ) -> Self {
+        Self {
+            name,
+            namespace,
+            doc,
+            types,
+            messages,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_namespace(&self) -> Option<String> {
+        self.namespace.clone()
+    }
+
+    pub fn get_doc(&self) -> Option<String> {
+        self.doc.clone()
+    }
+
+    pub fn get_types(&self) -> &Vec<AvroItem> {
+        &self.types
+    }
+
+    pub fn get_messages(&self) -> &Vec<AvroMessage> {
+        &self.messages
+    }
+}
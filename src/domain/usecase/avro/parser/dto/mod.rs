@@ -1,2 +1,6 @@
 pub mod avro_item;
 pub mod avro_item_type;
+pub mod avro_logical_type;
+pub mod avro_message;
+pub mod avro_order;
+pub mod avro_protocol;
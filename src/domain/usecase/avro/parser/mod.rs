@@ -1,2 +1,3 @@
+pub mod avro_idl_parser;
 pub mod avro_parser;
 pub mod dto;
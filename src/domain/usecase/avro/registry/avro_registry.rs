@@ -0,0 +1,471 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::core::file_system::directory_browsing::directory_browser;
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::domain::usecase::avro::parser::avro_parser;
+use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
+use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+
+const AVRO_EXTENSIONS: [&str; 3] = ["avsc", "avpr", "avdl"];
+
+/// # AvroRegistry
+/// Named types (records, errors, enums and fixed) of a set of Avro files indexed by their
+/// full name, including the ones declared inline in other types. References between
+/// them are resolved when the registry is created, so it is a graph of named types.
+#[derive(Debug)]
+pub struct AvroRegistry {
+    named_types: BTreeMap<String, AvroNamedType>,
+    aliases: HashMap<String, String>,
+    sorted_full_names: Vec<String>,
+    cycles: Vec<Vec<String>>,
+}
+
+#[derive(Debug)]
+struct AvroNamedType {
+    item: AvroItem,
+    file: PathBuf,
+    /// Full names of the named types it references or declares inline
+    dependencies: Vec<String>,
+}
+
+impl AvroRegistry {
+    /// # from_dir
+    /// Registry with the schemas (".avsc"), protocols (".avpr") and IDL files (".avdl") found
+    /// in "dir" and its subdirectories. Duplicated full names and unknown references return
+    /// an error.
+    pub fn from_dir(dir: &Path) -> Result<Self, String> {
+        if !dir.is_dir() {
+            return Err(format!(
+                "Expected avro schemas directory:\n{}\n",
+                try_to_absolute_path(dir)
+            ));
+        }
+
+        Self::from_files(&get_avro_files(dir))
+    }
+
+    /// # from_files
+    /// Registry with the named types declared in the given schema or protocol files.
+    pub fn from_files(files: &[PathBuf]) -> Result<Self, String> {
+        let mut registry = Self {
+            named_types: BTreeMap::new(),
+            aliases: HashMap::new(),
+            sorted_full_names: Vec::new(),
+            cycles: Vec::new(),
+        };
+        for file in files {
+            for item in avro_parser::parse(file)? {
+                if item.get_item_type().is_named() {
+                    registry.register_named_type(&item, file)?;
+                } else {
+                    registry.register_inner_types(item.get_item_type(), &mut Vec::new(), file)?;
+                }
+            }
+        }
+        registry.resolve_dependencies()?;
+        registry.sort_named_types();
+
+        Ok(registry)
+    }
+
+    /// # get_named_type
+    /// Named type with the given full name or alias. Names without namespace not found
+    /// in the null namespace match the only named type with that simple name, if any
+    /// (i.e. "Item" matches "com.test.Item").
+    pub fn get_named_type(&self, name: &str) -> Option<&AvroItem> {
+        self.resolve_name(name)
+            .and_then(|full_name| self.named_types.get(&full_name))
+            .map(|named_type| &named_type.item)
+    }
+
+    /// # get_file
+    /// File where the named type is declared.
+    pub fn get_file(&self, name: &str) -> Option<&Path> {
+        self.resolve_name(name)
+            .and_then(|full_name| self.named_types.get(&full_name))
+            .map(|named_type| named_type.file.as_path())
+    }
+
+    /// # get_dependencies
+    /// Named types directly referenced or declared inline by the named type.
+    pub fn get_dependencies(&self, name: &str) -> Vec<&AvroItem> {
+        self.resolve_name(name)
+            .and_then(|full_name| self.named_types.get(&full_name))
+            .map(|named_type| {
+                named_type
+                    .dependencies
+                    .iter()
+                    .filter_map(|dependency| self.named_types.get(dependency))
+                    .map(|dependency| &dependency.item)
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// # get_sorted_named_types
+    /// All the named types, every type after its dependencies except inside cycles.
+    pub fn get_sorted_named_types(&self) -> Vec<&AvroItem> {
+        self.sorted_full_names
+            .iter()
+            .filter_map(|full_name| self.named_types.get(full_name))
+            .map(|named_type| &named_type.item)
+            .collect()
+    }
+
+    pub fn is_recursive(&self, name: &str) -> bool {
+        self.resolve_name(name)
+            .is_some_and(|full_name| self.cycles.iter().any(|cycle| cycle.contains(&full_name)))
+    }
+}
+
+// Private methods
+impl AvroRegistry {
+    fn register_named_type(&mut self, item: &AvroItem, file: &Path) -> Result<(), String> {
+        let full_name = item.get_full_name().ok_or(format!(
+            "Avro named type without name in file:\n{}\n",
+            try_to_absolute_path(file)
+        ))?;
+        if let Some(existing) = self.named_types.get(&full_name) {
+            // IDL imports declare the imported types again
+            if existing.item == *item {
+                return Ok(());
+            }
+            return Err(format!(
+                "Duplicated avro named type \"{}\" in files:\n{}\n{}\n",
+                full_name,
+                try_to_absolute_path(&existing.file),
+                try_to_absolute_path(file)
+            ));
+        }
+
+        let mut dependencies = Vec::new();
+        if let Some(fields) = item.get_fields() {
+            for field in fields {
+                self.register_inner_types(field.get_item_type(), &mut dependencies, file)?;
+            }
+        }
+        if let Some(aliased_name) = self.aliases.get(&full_name) {
+            return Err(format!(
+                "Avro named type \"{}\" collides with an alias of \"{}\" in file:\n{}\n",
+                full_name,
+                aliased_name,
+                try_to_absolute_path(file)
+            ));
+        }
+        for alias in item.get_aliases() {
+            let alias_full_name = match item.get_namespace() {
+                Some(namespace) if !alias.contains('.') => format!("{}.{}", namespace, alias),
+                _ => alias.to_string(),
+            };
+            let colliding_name = match self.aliases.get(&alias_full_name) {
+                Some(aliased_name) if aliased_name != &full_name => Some(aliased_name.clone()),
+                _ => self
+                    .named_types
+                    .contains_key(&alias_full_name)
+                    .then(|| alias_full_name.clone()),
+            };
+            if let Some(colliding_name) = colliding_name {
+                return Err(format!(
+                    "Avro alias \"{}\" of \"{}\" collides with \"{}\" in file:\n{}\n",
+                    alias_full_name,
+                    full_name,
+                    colliding_name,
+                    try_to_absolute_path(file)
+                ));
+            }
+            self.aliases.insert(alias_full_name, full_name.clone());
+        }
+        self.named_types.insert(
+            full_name,
+            AvroNamedType {
+                item: item.clone(),
+                file: file.to_path_buf(),
+                dependencies,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Named types declared inline are registered too, references are resolved later
+    fn register_inner_types(
+        &mut self,
+        item_type: &AvroItemType,
+        dependencies: &mut Vec<String>,
+        file: &Path,
+    ) -> Result<(), String> {
+        match item_type {
+            AvroItemType::Reference(name) => dependencies.push(name.clone()),
+            AvroItemType::Array(inner_type) | AvroItemType::Map(inner_type) => {
+                self.register_inner_types(inner_type, dependencies, file)?
+            }
+            AvroItemType::Union(union_types) => {
+                for union_type in union_types {
+                    self.register_inner_types(union_type, dependencies, file)?;
+                }
+            }
+            AvroItemType::Inline(item) if item.get_item_type().is_named() => {
+                dependencies.extend(item.get_full_name());
+                self.register_named_type(item, file)?;
+            }
+            AvroItemType::Inline(item) => {
+                self.register_inner_types(item.get_item_type(), dependencies, file)?
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    fn resolve_dependencies(&mut self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        let mut resolved_dependencies = HashMap::new();
+        for (full_name, named_type) in &self.named_types {
+            let mut dependencies = Vec::new();
+            for dependency in &named_type.dependencies {
+                match self.resolve_name(dependency) {
+                    Some(dependency) if !dependencies.contains(&dependency) => {
+                        dependencies.push(dependency)
+                    }
+                    Some(_) => {}
+                    None => errors.push(format!(
+                        "Unknown avro named type \"{}\" referenced by \"{}\" in file:\n{}\n",
+                        dependency,
+                        full_name,
+                        try_to_absolute_path(&named_type.file)
+                    )),
+                }
+            }
+            resolved_dependencies.insert(full_name.clone(), dependencies);
+        }
+        if !errors.is_empty() {
+            return Err(errors.join("\n"));
+        }
+
+        for (full_name, dependencies) in resolved_dependencies {
+            if let Some(named_type) = self.named_types.get_mut(&full_name) {
+                named_type.dependencies = dependencies;
+            }
+        }
+        Ok(())
+    }
+
+    /// Names with namespace are full names, they never match another namespace
+    fn resolve_name(&self, name: &str) -> Option<String> {
+        if self.named_types.contains_key(name) {
+            return Some(name.to_string());
+        }
+        if let Some(full_name) = self.aliases.get(name) {
+            return Some(full_name.clone());
+        }
+        if name.contains('.') {
+            return None;
+        }
+
+        let suffix = format!(".{}", name);
+        let mut candidates: Vec<&String> = self
+            .named_types
+            .keys()
+            .chain(self.aliases.keys())
+            .filter(|candidate| candidate.ends_with(&suffix))
+            .map(|candidate| self.aliases.get(candidate).unwrap_or(candidate))
+            .collect();
+        candidates.sort();
+        candidates.dedup();
+        match candidates.as_slice() {
+            [full_name] => Some(full_name.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Strongly connected components (Tarjan) are found dependencies first, the ones with
+    /// several types or a type referencing itself are cycles
+    fn sort_named_types(&mut self) {
+        let mut sorting = TypeSorting::default();
+        for full_name in self.named_types.keys() {
+            if !sorting.indexes.contains_key(full_name) {
+                self.visit(full_name, &mut sorting);
+            }
+        }
+
+        self.sorted_full_names = sorting.components.iter().flatten().cloned().collect();
+        self.cycles = sorting
+            .components
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1
+                    || self.named_types[&component[0]]
+                        .dependencies
+                        .contains(&component[0])
+            })
+            .collect();
+    }
+
+    fn visit(&self, full_name: &str, sorting: &mut TypeSorting) {
+        let index = sorting.indexes.len();
+        sorting.indexes.insert(full_name.to_string(), index);
+        sorting.low_links.insert(full_name.to_string(), index);
+        sorting.stack.push(full_name.to_string());
+
+        for dependency in &self.named_types[full_name].dependencies {
+            if !sorting.indexes.contains_key(dependency) {
+                self.visit(dependency, sorting);
+                let low_link = sorting.low_links[full_name].min(sorting.low_links[dependency]);
+                sorting.low_links.insert(full_name.to_string(), low_link);
+            } else if sorting.stack.contains(dependency) {
+                let low_link = sorting.low_links[full_name].min(sorting.indexes[dependency]);
+                sorting.low_links.insert(full_name.to_string(), low_link);
+            }
+        }
+
+        if sorting.low_links[full_name] == sorting.indexes[full_name] {
+            let mut component = Vec::new();
+            while let Some(member) = sorting.stack.pop() {
+                let is_root = member == full_name;
+                component.push(member);
+                if is_root {
+                    break;
+                }
+            }
+            component.sort();
+            sorting.components.push(component);
+        }
+    }
+}
+
+#[derive(Default)]
+struct TypeSorting {
+    indexes: HashMap<String, usize>,
+    low_links: HashMap<String, usize>,
+    stack: Vec<String>,
+    components: Vec<Vec<String>>,
+}
+
+/// Sorted by path so the registry does not depend on the file system order
+fn get_avro_files(dir: &Path) -> Vec<PathBuf> {
+    let mut result = Vec::new();
+    let mut paths = directory_browser::read_dir(dir);
+    paths.sort();
+    for path in paths {
+        if path.is_dir() {
+            result.extend(get_avro_files(&path));
+        } else if path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .is_some_and(|extension| AVRO_EXTENSIONS.contains(&extension))
+        {
+            result.push(path);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_path::get_test_dir;
+    use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+    use crate::domain::usecase::avro::registry::avro_registry::AvroRegistry;
+
+    #[test]
+    fn from_dir_resolves_references_across_files() {
+        let dir = get_test_dir(get_current_file_path(), "shop");
+
+        let registry = AvroRegistry::from_dir(&dir).expect("Avro registry must be created");
+
+        let order = registry
+            .get_named_type("com.shop.Order")
+            .expect("Order must be registered");
+        let fields = order.get_fields().as_ref().expect("Order fields expected");
+        assert_eq!(
+            &AvroItemType::Reference("com.customer.Customer".to_string()),
+            fields[0].get_item_type()
+        );
+        assert!(registry
+            .get_file("com.customer.Customer")
+            .is_some_and(|file| file.ends_with("customer/customer.avsc")));
+        assert!(registry.get_named_type("com.customer.Address").is_some());
+        assert!(registry.get_named_type("com.shop.LegacyOrder").is_some());
+        assert!(registry.get_named_type("Money").is_some());
+        assert!(registry.get_named_type("com.other.Money").is_none());
+        let sorted_names: Vec<String> = registry
+            .get_sorted_named_types()
+            .iter()
+            .filter_map(|item| item.get_full_name())
+            .collect();
+        assert_eq!(
+            vec![
+                "com.customer.Address",
+                "com.customer.Customer",
+                "com.shop.Category",
+                "com.shop.Money",
+                "com.shop.Order",
+            ],
+            sorted_names
+        );
+        assert!(registry.is_recursive("com.shop.Category"));
+        assert!(!registry.is_recursive("com.shop.Order"));
+        assert_eq!(
+            &AvroItemType::Reference("com.shop.Money".to_string()),
+            fields[1].get_item_type()
+        );
+    }
+
+    #[test]
+    fn from_dir_with_idl_imports() {
+        let dir = get_test_dir(get_current_file_path(), "idl");
+
+        let registry = AvroRegistry::from_dir(&dir).expect("Avro registry must be created");
+
+        assert!(registry
+            .get_file("com.order.Status")
+            .is_some_and(|file| file.ends_with("order.avdl")));
+        let dependencies: Vec<Option<String>> = registry
+            .get_dependencies("com.order.Order")
+            .iter()
+            .map(|item| item.get_full_name())
+            .collect();
+        assert_eq!(vec![Some("com.order.Status".to_string())], dependencies);
+    }
+
+    #[test]
+    fn from_dir_with_duplicated_named_type_fails() {
+        let dir = get_test_dir(get_current_file_path(), "duplicated");
+
+        let result = AvroRegistry::from_dir(&dir);
+
+        let err = result.expect_err("Duplicated named types must fail");
+        assert!(err.contains("Duplicated avro named type \"com.test.Item\""));
+    }
+
+    #[test]
+    fn from_dir_with_colliding_aliases_fails() {
+        let dir = get_test_dir(get_current_file_path(), "alias_collision");
+
+        let result = AvroRegistry::from_dir(&dir);
+
+        let err = result.expect_err("Colliding aliases must fail");
+        assert!(err.contains(
+            "Avro alias \"com.test.Legacy\" of \"com.test.Order\" collides with \"com.test.Item\""
+        ));
+    }
+
+    #[test]
+    fn from_dir_with_unknown_reference_fails() {
+        let dir = get_test_dir(get_current_file_path(), "unknown");
+
+        let result = AvroRegistry::from_dir(&dir);
+
+        let err = result.expect_err("Unknown references must fail");
+        assert!(err.contains(
+            "Unknown avro named type \"com.test.Missing\" referenced by \"com.test.Item\""
+        ));
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
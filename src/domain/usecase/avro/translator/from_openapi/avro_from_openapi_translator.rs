@@ -0,0 +1,515 @@
+use std::path::{Path, PathBuf};
+
+use crate::domain::usecase::avro::parser::avro_parser::unescape_json;
+use crate::domain::usecase::avro::parser::dto::avro_item::{AvroItem, AvroItemBuilder};
+use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+use crate::domain::usecase::avro::writer::avro_writer;
+use crate::domain::usecase::openapi::parser::dto::openapi_data_type::{
+    IntegerFormat, NumberFormat, OpenapiDataType,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_schema::OpenapiSchema;
+
+/// # openapi_to_avro
+/// Avro records and enums of the given component schemas, dependencies first. Schema names
+/// with dots are full names (i.e. "com.shop.Order"), the other ones get the given namespace.
+/// Nullable properties, which include the non required ones, are "null" unions with a null
+/// default. String formats become logical types and "$ref"s become references to the named
+/// types. Component schemas which are neither objects nor enums are written where they are
+/// referenced.
+pub fn openapi_to_avro(
+    schemas: &[OpenapiSchema],
+    namespace: &str,
+) -> Result<Vec<AvroItem>, String> {
+    let mut translator = AvroFromOpenapiTranslator {
+        schemas,
+        namespace,
+        visited_names: vec![],
+        avro_items: vec![],
+    };
+    for schema in schemas.iter().filter(|schema| is_named_type(schema)) {
+        translator.add_named_type(schema)?;
+    }
+
+    Ok(translator.avro_items)
+}
+
+/// # openapi_to_avsc
+/// Write the Avro schemas of the given component schemas, one ".avsc" file per namespace
+/// named after it (i.e. "com.shop.avsc"). Files are returned in reading order: the ones
+/// referenced by the schemas of another namespace first. Namespaces referencing each other
+/// can not be read in order and are an error.
+pub fn openapi_to_avsc(
+    schemas: &[OpenapiSchema],
+    namespace: &str,
+    output_folder: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let avro_items = openapi_to_avro(schemas, namespace)?;
+
+    let mut result = Vec::new();
+    for item_namespace in get_sorted_namespaces(&avro_items)? {
+        let namespace_items: Vec<AvroItem> = avro_items
+            .iter()
+            .filter(|avro_item| avro_item.get_namespace().unwrap_or_default() == item_namespace)
+            .cloned()
+            .collect();
+        let file_path = output_folder.join(format!("{}.avsc", item_namespace));
+        avro_writer::write(&namespace_items, &file_path)?;
+        result.push(file_path);
+    }
+
+    Ok(result)
+}
+
+struct AvroFromOpenapiTranslator<'a> {
+    schemas: &'a [OpenapiSchema],
+    namespace: &'a str,
+    /// Names of the records and enums already translated or being translated,
+    /// recursive records refer to themselves
+    visited_names: Vec<String>,
+    avro_items: Vec<AvroItem>,
+}
+
+impl AvroFromOpenapiTranslator<'_> {
+    fn add_named_type(&mut self, schema: &OpenapiSchema) -> Result<(), String> {
+        if self.visited_names.contains(schema.get_name()) {
+            return Ok(());
+        }
+        self.visited_names.push(schema.get_name().to_string());
+
+        let (namespace, name) = split_full_name(&self.get_full_name(schema.get_name()));
+        let mut avro_item = self
+            .get_named_item(schema, &name)
+            .map_err(|err| format!("Invalid schema \"{}\" ({})", schema.get_name(), err))?;
+        avro_item.namespace(&namespace);
+        self.avro_items.push(avro_item.build());
+
+        Ok(())
+    }
+
+    /// Record or enum declaration, its namespace is set by the caller
+    fn get_named_item(
+        &mut self,
+        schema: &OpenapiSchema,
+        name: &str,
+    ) -> Result<AvroItemBuilder, String> {
+        check_avro_name(name)?;
+        let mut builder = AvroItem::builder();
+        builder.name(name);
+        if let Some(description) = schema.get_description() {
+            builder.doc(description);
+        }
+
+        if let Some(enum_values) = schema.get_enum_values() {
+            for symbol in enum_values {
+                check_avro_name(symbol)?;
+            }
+            builder
+                .item_type(AvroItemType::Enum)
+                .symbols(enum_values.clone());
+            return Ok(builder);
+        }
+
+        let mut fields = Vec::new();
+        for property in schema.get_properties().iter().flatten() {
+            let field = self
+                .get_field(property, name)
+                .map_err(|err| format!("property \"{}\" {}", property.get_name(), err))?;
+            fields.push(field);
+        }
+        builder.item_type(AvroItemType::Record).fields(fields);
+
+        Ok(builder)
+    }
+
+    fn get_field(
+        &mut self,
+        property: &OpenapiSchema,
+        record_name: &str,
+    ) -> Result<AvroItem, String> {
+        check_avro_name(property.get_name())?;
+        let data_type = &property.get_property_type().ok_or("without type")?;
+        let inline_name = format!(
+            "{}{}",
+            record_name,
+            to_upper_camel_case(property.get_name())
+        );
+        let item_type = if is_named_type(property) {
+            self.get_inline_type(property, &inline_name, data_type)?
+        } else {
+            self.get_item_type(data_type, &inline_name)?
+        };
+
+        let mut builder = AvroItem::builder();
+        builder.name(property.get_name());
+        if let Some(description) = property.get_description() {
+            builder.doc(description);
+        }
+        match (item_type, to_avro_default(property.get_default())) {
+            // Union defaults match their first type
+            (AvroItemType::Union(mut subtypes), Some(default)) if "null" != default => {
+                subtypes.retain(|subtype| AvroItemType::Null != *subtype);
+                subtypes.push(AvroItemType::Null);
+                builder
+                    .item_type(AvroItemType::Union(subtypes))
+                    .default(&default);
+            }
+            (AvroItemType::Union(subtypes), _) if subtypes.contains(&AvroItemType::Null) => {
+                builder
+                    .item_type(AvroItemType::Union(subtypes))
+                    .default("null");
+            }
+            (item_type, default) => {
+                builder.item_type(item_type);
+                if let Some(default) = default {
+                    builder.default(&default);
+                }
+            }
+        }
+
+        Ok(builder.build())
+    }
+
+    /// Properties declared as an object or an enum, in a "null" union when their type is nullable
+    fn get_inline_type(
+        &mut self,
+        property: &OpenapiSchema,
+        inline_name: &str,
+        data_type: &OpenapiDataType,
+    ) -> Result<AvroItemType, String> {
+        let (namespace, name) = split_full_name(&self.get_full_name(inline_name));
+        let mut inline_item = self.get_named_item(property, &name)?;
+        inline_item.namespace(&namespace);
+        let item_type = AvroItemType::Inline(Box::new(inline_item.build()));
+
+        match data_type {
+            OpenapiDataType::Array(subtypes) if subtypes.contains(&OpenapiDataType::Null) => {
+                Ok(AvroItemType::Union(vec![AvroItemType::Null, item_type]))
+            }
+            _ => Ok(item_type),
+        }
+    }
+
+    /// Inline objects and enums are declared where they are used, named after their property
+    fn get_item_type(
+        &mut self,
+        data_type: &OpenapiDataType,
+        inline_name: &str,
+    ) -> Result<AvroItemType, String> {
+        let item_type = match data_type {
+            OpenapiDataType::Integer(IntegerFormat::Int32) => AvroItemType::Int,
+            OpenapiDataType::Integer(IntegerFormat::Int64) => AvroItemType::Long,
+            OpenapiDataType::Number(NumberFormat::Float) => AvroItemType::Float,
+            OpenapiDataType::Number(NumberFormat::Double | NumberFormat::Decimal) => {
+                AvroItemType::Double
+            }
+            OpenapiDataType::String => AvroItemType::String,
+            OpenapiDataType::FormattedString(format) => get_formatted_string_type(format),
+            OpenapiDataType::Null => AvroItemType::Null,
+            OpenapiDataType::Boolean => AvroItemType::Boolean,
+            OpenapiDataType::Bytes => AvroItemType::Bytes,
+            OpenapiDataType::ObjectSimple => {
+                return Err("free-form object without Avro equivalent".to_string())
+            }
+            OpenapiDataType::Object(inline_schema) => {
+                let inline_data_type = inline_schema
+                    .get_schema_type()
+                    .clone()
+                    .unwrap_or(OpenapiDataType::ObjectSimple);
+                self.get_inline_type(inline_schema, inline_name, &inline_data_type)?
+            }
+            OpenapiDataType::ObjectName(name) => self.get_referenced_type(name)?,
+            OpenapiDataType::Array(subtypes) => {
+                let mut union_types = Vec::new();
+                for subtype in subtypes {
+                    union_types.push(self.get_item_type(subtype, inline_name)?);
+                }
+                to_union(union_types)
+            }
+            OpenapiDataType::ArrayItems(items_type) => {
+                AvroItemType::Array(Box::new(self.get_item_type(items_type, inline_name)?))
+            }
+            OpenapiDataType::Map(values_type) => {
+                AvroItemType::Map(Box::new(self.get_item_type(values_type, inline_name)?))
+            }
+        };
+
+        Ok(item_type)
+    }
+
+    fn get_referenced_type(&mut self, name: &str) -> Result<AvroItemType, String> {
+        let schemas = self.schemas;
+        let schema = schemas
+            .iter()
+            .find(|schema| name == schema.get_name())
+            .ok_or(format!("unknown schema \"{}\"", name))?;
+        if is_named_type(schema) {
+            self.add_named_type(schema)?;
+            return Ok(AvroItemType::Reference(self.get_full_name(name)));
+        }
+
+        if self.visited_names.iter().any(|visited| name == visited) {
+            return Err(format!("recursive schema \"{}\" is not an object", name));
+        }
+        self.visited_names.push(name.to_string());
+        let data_type = schema
+            .get_schema_type()
+            .as_ref()
+            .ok_or(format!("schema \"{}\" without type", name))?;
+        let item_type = self.get_item_type(data_type, name);
+        self.visited_names.retain(|visited| name != visited);
+
+        item_type
+    }
+
+    fn get_full_name(&self, name: &str) -> String {
+        if name.contains('.') || self.namespace.is_empty() {
+            return name.to_string();
+        }
+        format!("{}.{}", self.namespace, name)
+    }
+}
+
+/// Unions can not contain other unions, nested ones are flattened without repeated types
+fn to_union(item_types: Vec<AvroItemType>) -> AvroItemType {
+    let mut union_types = Vec::new();
+    for item_type in item_types {
+        let subtypes = match item_type {
+            AvroItemType::Union(subtypes) => subtypes,
+            item_type => vec![item_type],
+        };
+        for subtype in subtypes {
+            if !union_types.contains(&subtype) {
+                union_types.push(subtype);
+            }
+        }
+    }
+    AvroItemType::Union(union_types)
+}
+
+/// Namespaces of the given items, each one after the namespaces its items reference
+fn get_sorted_namespaces(avro_items: &[AvroItem]) -> Result<Vec<String>, String> {
+    let mut dependencies: Vec<(String, Vec<String>)> = Vec::new();
+    for avro_item in avro_items {
+        let item_namespace = avro_item.get_namespace().unwrap_or_default();
+        let mut references = Vec::new();
+        add_references(avro_item, &mut references);
+        let referenced_namespaces: Vec<String> = references
+            .iter()
+            .map(|reference| split_full_name(reference).0)
+            .filter(|referenced_namespace| *referenced_namespace != item_namespace)
+            .collect();
+        match dependencies
+            .iter_mut()
+            .find(|(namespace, _)| *namespace == item_namespace)
+        {
+            Some((_, namespace_dependencies)) => {
+                namespace_dependencies.extend(referenced_namespaces)
+            }
+            None => dependencies.push((item_namespace, referenced_namespaces)),
+        }
+    }
+
+    let mut sorted_namespaces: Vec<String> = Vec::new();
+    while sorted_namespaces.len() < dependencies.len() {
+        let next_namespace = dependencies
+            .iter()
+            .filter(|(namespace, _)| !sorted_namespaces.contains(namespace))
+            .find(|(_, namespace_dependencies)| {
+                namespace_dependencies
+                    .iter()
+                    .all(|dependency| sorted_namespaces.contains(dependency))
+            });
+        match next_namespace {
+            Some((namespace, _)) => sorted_namespaces.push(namespace.to_string()),
+            None => {
+                let cyclic_namespaces: Vec<&str> = dependencies
+                    .iter()
+                    .map(|(namespace, _)| namespace.as_str())
+                    .filter(|namespace| !sorted_namespaces.iter().any(|sorted| sorted == namespace))
+                    .collect();
+                return Err(format!(
+                    "Avro namespaces referencing each other: \"{}\"",
+                    cyclic_namespaces.join("\", \"")
+                ));
+            }
+        }
+    }
+
+    Ok(sorted_namespaces)
+}
+
+/// Full names referenced by the item or its fields, inline items included
+fn add_references(avro_item: &AvroItem, references: &mut Vec<String>) {
+    add_type_references(avro_item.get_item_type(), references);
+    for field in avro_item.get_fields().iter().flatten() {
+        add_references(field, references);
+    }
+}
+
+fn add_type_references(item_type: &AvroItemType, references: &mut Vec<String>) {
+    match item_type {
+        AvroItemType::Reference(full_name) => references.push(full_name.to_string()),
+        AvroItemType::Array(subtype) | AvroItemType::Map(subtype) => {
+            add_type_references(subtype, references)
+        }
+        AvroItemType::Union(subtypes) => {
+            for subtype in subtypes {
+                add_type_references(subtype, references);
+            }
+        }
+        AvroItemType::Inline(inline_item) => add_references(inline_item, references),
+        _ => {}
+    }
+}
+
+/// Objects with properties and enums, the other schemas have no name in Avro
+fn is_named_type(schema: &OpenapiSchema) -> bool {
+    schema.get_enum_values().is_some() || schema.get_properties().is_some()
+}
+
+/// Formats without logical type (i.e. "email") are strings
+fn get_formatted_string_type(format: &str) -> AvroItemType {
+    let (item_type, logical_type) = match format {
+        "uuid" => (AvroItemType::String, AvroLogicalType::Uuid),
+        "date" => (AvroItemType::Int, AvroLogicalType::Date),
+        "time" => (AvroItemType::Int, AvroLogicalType::TimeMillis),
+        "date-time" => (AvroItemType::Long, AvroLogicalType::TimestampMillis),
+        "binary" => return AvroItemType::Bytes,
+        _ => return AvroItemType::String,
+    };
+    let logical_item = AvroItem::builder()
+        .item_type(item_type)
+        .logical_type(logical_type)
+        .build();
+    AvroItemType::Inline(Box::new(logical_item))
+}
+
+/// Avro defaults are stored with unquoted strings
+fn to_avro_default(default: &Option<String>) -> Option<String> {
+    let default = default.as_ref()?;
+    match default
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+    {
+        Some(string_value) => Some(unescape_json(string_value)),
+        None => Some(default.to_string()),
+    }
+}
+
+/// Namespace and simple name, the namespace is empty for names without dots
+fn split_full_name(full_name: &str) -> (String, String) {
+    match full_name.rsplit_once('.') {
+        Some((namespace, name)) => (namespace.to_string(), name.to_string()),
+        None => (String::new(), full_name.to_string()),
+    }
+}
+
+/// Names and enum symbols start with a letter or "_" followed by letters, digits or "_"
+fn check_avro_name(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let is_valid = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || '_' == first)
+        && chars.all(|current| current.is_ascii_alphanumeric() || '_' == current);
+    if !is_valid {
+        return Err(format!("\"{}\" is not a valid Avro name", name));
+    }
+    Ok(())
+}
+
+fn to_upper_camel_case(name: &str) -> String {
+    name.split(|current: char| !current.is_ascii_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_ascii_uppercase().to_string() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_assert::{assert_fail, assert_same_file};
+    use crate::core::testing::test_path::{get_non_existing_test_file, get_test_file};
+    use crate::domain::usecase::avro::parser::avro_parser;
+    use crate::domain::usecase::avro::translator::from_openapi::avro_from_openapi_translator::{
+        openapi_to_avro, openapi_to_avsc,
+    };
+    use crate::domain::usecase::openapi::parser::openapi_parser;
+
+    #[test]
+    fn openapi_to_avsc_test() {
+        let openapi_file = get_test_file(&get_current_file_path(), "shop.yaml");
+        let document = openapi_parser::parse(&openapi_file).expect("OpenAPI must be valid");
+        let output_folder = get_non_existing_test_file(&get_current_file_path(), "generated");
+        fs::create_dir_all(&output_folder).expect("Output folder must be created");
+
+        let avsc_files = openapi_to_avsc(
+            document.get_components().get_schemas(),
+            "com.shop",
+            &output_folder,
+        )
+        .expect("Schemas must be translated");
+
+        for avsc_file in &avsc_files {
+            avro_parser::parse(avsc_file).expect("Avro schema must be valid");
+            let file_name = avsc_file.file_name().expect("File name").to_string_lossy();
+            let expected_file =
+                get_test_file(&get_current_file_path(), &format!("expected/{}", file_name));
+            assert_same_file(&expected_file, avsc_file);
+        }
+        assert_eq!(
+            vec![
+                output_folder.join("com.common.avsc"),
+                output_folder.join("com.shop.avsc")
+            ],
+            avsc_files
+        );
+        fs::remove_dir_all(&output_folder).expect("Output folder must be removed");
+    }
+
+    #[test]
+    fn openapi_to_avro_invalid_name() {
+        let openapi_file = get_test_file(&get_current_file_path(), "invalid.yaml");
+        let document = openapi_parser::parse(&openapi_file).expect("OpenAPI must be valid");
+
+        match openapi_to_avro(document.get_components().get_schemas(), "com.shop") {
+            Ok(_) => assert_fail("Property names must be valid Avro names"),
+            Err(err) => assert_eq!(
+                "Invalid schema \"Order\" (property \"first-name\" \"first-name\" is not a valid Avro name)",
+                err
+            ),
+        }
+    }
+
+    #[test]
+    fn openapi_to_avsc_with_namespaces_referencing_each_other_fails() {
+        let openapi_file = get_test_file(&get_current_file_path(), "cyclic_namespaces.yaml");
+        let document = openapi_parser::parse(&openapi_file).expect("OpenAPI must be valid");
+        let output_folder = get_non_existing_test_file(&get_current_file_path(), "cyclic");
+
+        match openapi_to_avsc(
+            document.get_components().get_schemas(),
+            "com.shop",
+            &output_folder,
+        ) {
+            Ok(_) => assert_fail("Namespaces referencing each other can not be read in order"),
+            Err(err) => assert_eq!(
+                "Avro namespaces referencing each other: \"com.common\", \"com.shop\"",
+                err
+            ),
+        }
+        assert!(!output_folder.exists());
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1 @@
+pub mod avro_from_openapi_translator;
@@ -0,0 +1 @@
+pub mod from_openapi;
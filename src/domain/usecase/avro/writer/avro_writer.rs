@@ -0,0 +1,277 @@
+use std::path::Path;
+
+use crate::core::file_system::file_edition::file_editor;
+use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
+use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+use crate::domain::usecase::openapi::parser::openapi_node::escape_json;
+
+/// # to_avsc_str
+/// Text of an Avro schema file, a single schema is the file root and several ones are
+/// written as an array. Named types are written with their namespace, fields inherit it.
+pub fn to_avsc_str(avro_items: &[AvroItem]) -> String {
+    let value = match avro_items {
+        [avro_item] => get_item_value(avro_item),
+        _ => AvroValue::Sequence(avro_items.iter().map(get_item_value).collect()),
+    };
+    let mut result = String::new();
+    write_json(&mut result, 0, &value);
+    result.push('\n');
+    result
+}
+
+/// # write
+/// Create or replace an Avro schema (".avsc") file.
+pub fn write(avro_items: &[AvroItem], file_path: &Path) -> Result<(), String> {
+    file_editor::create_or_replace_file_with_bytes(file_path, to_avsc_str(avro_items).as_bytes())
+}
+
+/// Schema tree written as JSON
+enum AvroValue {
+    String(String),
+    /// JSON text written as it is: numbers, booleans and default values
+    Raw(String),
+    Sequence(Vec<AvroValue>),
+    Mapping(Vec<(String, AvroValue)>),
+}
+
+/// Named type declaration or type with attributes (i.e. a logical type)
+fn get_item_value(avro_item: &AvroItem) -> AvroValue {
+    if avro_item.is_just_type() {
+        return get_type_value(avro_item.get_item_type());
+    }
+
+    let mut entries = vec![(
+        "type".to_string(),
+        get_declared_type_value(avro_item.get_item_type()),
+    )];
+    push_optional_string(&mut entries, "name", avro_item.get_name());
+    push_optional_string(&mut entries, "namespace", avro_item.get_namespace());
+    push_optional_string(&mut entries, "doc", avro_item.get_doc());
+    if !avro_item.get_aliases().is_empty() {
+        entries.push((
+            "aliases".to_string(),
+            new_strings_value(avro_item.get_aliases()),
+        ));
+    }
+    if let Some(logical_type) = avro_item.get_logical_type() {
+        entries.extend(get_logical_type_entries(&logical_type));
+    }
+    if let Some(symbols) = avro_item.get_symbols() {
+        entries.push(("symbols".to_string(), new_strings_value(&symbols)));
+    }
+    if let Some(size) = avro_item.get_size() {
+        entries.push(("size".to_string(), AvroValue::Raw(size.to_string())));
+    }
+    if let Some(fields) = avro_item.get_fields() {
+        let fields = fields.iter().map(get_field_value).collect();
+        entries.push(("fields".to_string(), AvroValue::Sequence(fields)));
+    }
+    if let Some(default) = avro_item.get_default() {
+        entries.push(get_default_entry(avro_item.get_item_type(), &default));
+    }
+
+    AvroValue::Mapping(entries)
+}
+
+/// Fields declare their type in "type", their other attributes are their own
+fn get_field_value(field: &AvroItem) -> AvroValue {
+    let mut entries = Vec::new();
+    push_optional_string(&mut entries, "name", field.get_name());
+    push_optional_string(&mut entries, "doc", field.get_doc());
+    entries.push(("type".to_string(), get_type_value(field.get_item_type())));
+    if let Some(default) = field.get_default() {
+        entries.push(get_default_entry(field.get_item_type(), &default));
+    }
+    if let Some(order) = field.get_order() {
+        entries.push(("order".to_string(), AvroValue::String(order.to_string())));
+    }
+    if !field.get_aliases().is_empty() {
+        entries.push((
+            "aliases".to_string(),
+            new_strings_value(field.get_aliases()),
+        ));
+    }
+
+    AvroValue::Mapping(entries)
+}
+
+fn get_type_value(item_type: &AvroItemType) -> AvroValue {
+    match item_type {
+        AvroItemType::Array(items_type) => AvroValue::Mapping(vec![
+            new_string_entry("type", "array"),
+            ("items".to_string(), get_type_value(items_type)),
+        ]),
+        AvroItemType::Map(values_type) => AvroValue::Mapping(vec![
+            new_string_entry("type", "map"),
+            ("values".to_string(), get_type_value(values_type)),
+        ]),
+        AvroItemType::Union(subtypes) => {
+            AvroValue::Sequence(subtypes.iter().map(get_type_value).collect())
+        }
+        AvroItemType::Inline(inline_item) => get_item_value(inline_item),
+        AvroItemType::Reference(full_name) => AvroValue::String(full_name.to_string()),
+        declared_type => get_declared_type_value(declared_type),
+    }
+}
+
+/// Type name of a declaration, complex types are only written by [get_type_value]
+fn get_declared_type_value(item_type: &AvroItemType) -> AvroValue {
+    let name = match item_type {
+        AvroItemType::Record => "record",
+        AvroItemType::Error => "error",
+        AvroItemType::Enum => "enum",
+        AvroItemType::Fixed => "fixed",
+        AvroItemType::Null => "null",
+        AvroItemType::Boolean => "boolean",
+        AvroItemType::Int => "int",
+        AvroItemType::Long => "long",
+        AvroItemType::Float => "float",
+        AvroItemType::Double => "double",
+        AvroItemType::Bytes => "bytes",
+        AvroItemType::String => "string",
+        complex_type => return get_type_value(complex_type),
+    };
+    AvroValue::String(name.to_string())
+}
+
+fn get_logical_type_entries(logical_type: &AvroLogicalType) -> Vec<(String, AvroValue)> {
+    let mut entries = vec![new_string_entry("logicalType", logical_type.get_name())];
+    if let AvroLogicalType::Decimal { precision, scale } = logical_type {
+        entries.push((
+            "precision".to_string(),
+            AvroValue::Raw(precision.to_string()),
+        ));
+        entries.push(("scale".to_string(), AvroValue::Raw(scale.to_string())));
+    }
+    entries
+}
+
+/// Defaults are stored with unquoted strings, the type tells if they have to be quoted again.
+/// Union defaults match their first type.
+fn get_default_entry(item_type: &AvroItemType, default: &str) -> (String, AvroValue) {
+    let value = if is_string_default(item_type, default) {
+        AvroValue::String(default.to_string())
+    } else {
+        AvroValue::Raw(default.to_string())
+    };
+    ("default".to_string(), value)
+}
+
+/// # is_string_default
+/// Whether an unquoted default of the given type is a JSON string.
+pub(crate) fn is_string_default(item_type: &AvroItemType, default: &str) -> bool {
+    match item_type {
+        AvroItemType::String | AvroItemType::Bytes | AvroItemType::Enum | AvroItemType::Fixed => {
+            true
+        }
+        AvroItemType::Reference(_) => !default.starts_with('{'),
+        AvroItemType::Union(subtypes) => subtypes
+            .first()
+            .is_some_and(|subtype| is_string_default(subtype, default)),
+        AvroItemType::Inline(inline_item) => {
+            is_string_default(inline_item.get_item_type(), default)
+        }
+        _ => false,
+    }
+}
+
+fn new_string_entry(key: &str, value: &str) -> (String, AvroValue) {
+    (key.to_string(), AvroValue::String(value.to_string()))
+}
+
+fn new_strings_value(values: &[String]) -> AvroValue {
+    AvroValue::Sequence(
+        values
+            .iter()
+            .map(|value| AvroValue::String(value.to_string()))
+            .collect(),
+    )
+}
+
+fn push_optional_string(entries: &mut Vec<(String, AvroValue)>, key: &str, value: Option<String>) {
+    if let Some(value) = value {
+        entries.push((key.to_string(), AvroValue::String(value)));
+    }
+}
+
+/// Types without attributes are written in a single line, i.e. "type": "string"
+fn write_json(result: &mut String, depth: usize, value: &AvroValue) {
+    match value {
+        AvroValue::String(value) => result.push_str(&escape_json(value)),
+        AvroValue::Raw(value) => result.push_str(value),
+        AvroValue::Sequence(items) if items.is_empty() => result.push_str("[]"),
+        AvroValue::Mapping(entries) if entries.is_empty() => result.push_str("{}"),
+        AvroValue::Sequence(items) if items.iter().all(is_scalar) => {
+            result.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    result.push_str(", ");
+                }
+                write_json(result, depth, item);
+            }
+            result.push(']');
+        }
+        AvroValue::Sequence(items) => {
+            result.push_str("[\n");
+            for (index, item) in items.iter().enumerate() {
+                result.push_str(&get_indentation(depth + 1));
+                write_json(result, depth + 1, item);
+                push_json_separator(result, index, items.len());
+            }
+            result.push_str(&get_indentation(depth));
+            result.push(']');
+        }
+        AvroValue::Mapping(entries) => {
+            result.push_str("{\n");
+            for (index, (key, value)) in entries.iter().enumerate() {
+                result.push_str(&get_indentation(depth + 1));
+                result.push_str(&escape_json(key));
+                result.push_str(": ");
+                write_json(result, depth + 1, value);
+                push_json_separator(result, index, entries.len());
+            }
+            result.push_str(&get_indentation(depth));
+            result.push('}');
+        }
+    }
+}
+
+fn is_scalar(value: &AvroValue) -> bool {
+    matches!(value, AvroValue::String(_) | AvroValue::Raw(_))
+}
+
+fn push_json_separator(result: &mut String, index: usize, length: usize) {
+    if index < length - 1 {
+        result.push(',');
+    }
+    result.push('\n');
+}
+
+fn get_indentation(depth: usize) -> String {
+    "  ".repeat(depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_assert::assert_same_as_file;
+    use crate::core::testing::test_path::get_test_file;
+    use crate::domain::usecase::avro::parser::avro_parser;
+    use crate::domain::usecase::avro::writer::avro_writer::to_avsc_str;
+
+    #[test]
+    fn written_schemas_are_parsed_again() {
+        let schema_file = get_test_file(&get_current_file_path(), "shop.avsc");
+        let avro_items = avro_parser::parse(&schema_file).expect("Avro schema must be valid");
+
+        let avsc_str = to_avsc_str(&avro_items);
+
+        assert_same_as_file(&schema_file, &avsc_str);
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
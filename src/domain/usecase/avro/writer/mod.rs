@@ -0,0 +1 @@
+pub mod avro_writer;
@@ -0,0 +1,366 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
+use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+use crate::domain::usecase::avro::registry::avro_registry::AvroRegistry;
+use crate::java::class::JavaClass;
+use crate::java::data_type::JavaDataType;
+use crate::java::dependency::java::time::java_time_factory;
+use crate::java::enumeration::JavaEnum;
+use crate::java::field::JavaField;
+use crate::java::import::JavaImport;
+use crate::java::javadoc::JavaDoc;
+use crate::java::visibility::JavaVisibility::Public;
+
+/// # JavaNullableStyle
+/// Java declaration of the optional avro fields, the unions of "null" and another type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JavaNullableStyle {
+    /// Boxed type that may be null (i.e. "Integer")
+    Nullable,
+    /// Boxed type wrapped in an "Optional" (i.e. "Optional<Integer>")
+    Optional,
+}
+
+/// # avro_to_java
+/// Write a java class with public fields for every record and error of the registry, and a
+/// java enum for every avro enum. Every type is written in "source_dir" (i.e. "src/main/java")
+/// inside the package of its namespace, creating the package folders if needed.
+/// Fixed types are not written, the fields using them are "ByteBuffer" or "BigDecimal".
+/// It returns the written files, dependencies first.
+pub fn avro_to_java(
+    registry: &AvroRegistry,
+    source_dir: &Path,
+    nullable_style: JavaNullableStyle,
+) -> Result<Vec<PathBuf>, String> {
+    if !source_dir.is_dir() {
+        return Err(format!(
+            "Expected java source directory:\n{}\n",
+            try_to_absolute_path(source_dir)
+        ));
+    }
+
+    let translator = JavaFromAvroTranslator {
+        registry,
+        nullable_style,
+    };
+    let mut result = Vec::new();
+    for avro_item in registry.get_sorted_named_types() {
+        let file = match avro_item.get_item_type() {
+            AvroItemType::Record | AvroItemType::Error => {
+                let folder = translator.create_package_folder(source_dir, avro_item)?;
+                translator.write_class(avro_item, &folder)?
+            }
+            AvroItemType::Enum => {
+                let folder = translator.create_package_folder(source_dir, avro_item)?;
+                translator.write_enum(avro_item, &folder)?
+            }
+            _ => continue,
+        };
+        result.push(file);
+    }
+
+    Ok(result)
+}
+
+struct JavaFromAvroTranslator<'a> {
+    registry: &'a AvroRegistry,
+    nullable_style: JavaNullableStyle,
+}
+
+impl JavaFromAvroTranslator<'_> {
+    fn write_class(&self, avro_item: &AvroItem, folder: &Path) -> Result<PathBuf, String> {
+        let name = self.get_name(avro_item)?;
+        let mut fields = Vec::new();
+        for avro_field in avro_item.get_fields().iter().flatten() {
+            fields.push(self.to_field(avro_field, &name)?);
+        }
+
+        let mut builder = JavaClass::builder();
+        builder
+            .folder(folder)
+            .visibility(Public)
+            .name(&name)
+            .fields(fields);
+        if let Some(doc) = avro_item.get_doc() {
+            builder.javadoc(JavaDoc::builder().description(&doc).build());
+        }
+        builder.build()?;
+
+        Ok(folder.join(format!("{}.java", name)))
+    }
+
+    fn write_enum(&self, avro_item: &AvroItem, folder: &Path) -> Result<PathBuf, String> {
+        let name = self.get_name(avro_item)?;
+        let symbols = avro_item.get_symbols().unwrap_or_default();
+
+        let mut builder = JavaEnum::builder();
+        builder
+            .folder(folder)
+            .visibility(Public)
+            .name(&name)
+            .constants(symbols.iter().map(|symbol| symbol.as_str()).collect());
+        if let Some(doc) = avro_item.get_doc() {
+            builder.javadoc(JavaDoc::builder().description(&doc).build());
+        }
+        builder.build()?;
+
+        Ok(folder.join(format!("{}.java", name)))
+    }
+
+    fn to_field(&self, avro_field: &AvroItem, record_name: &str) -> Result<JavaField, String> {
+        let name = avro_field.get_name().ok_or(format!(
+            "Invalid avro record \"{}\" (field without name)",
+            record_name
+        ))?;
+        let data_type = self
+            .to_data_type(
+                avro_field.get_item_type(),
+                avro_field.get_logical_type(),
+                false,
+            )
+            .map_err(|err| format!("Invalid avro field \"{}.{}\" ({})", record_name, name, err))?;
+
+        let mut builder = JavaField::builder();
+        builder.visibility(Public).data_type(data_type).name(&name);
+        if let Some(doc) = avro_field.get_doc() {
+            builder.javadoc(JavaDoc::builder().description(&doc).build());
+        }
+        builder.build()
+    }
+
+    /// Primitive types are "boxed" inside generic types and nullable unions
+    fn to_data_type(
+        &self,
+        item_type: &AvroItemType,
+        logical_type: Option<AvroLogicalType>,
+        boxed: bool,
+    ) -> Result<JavaDataType, String> {
+        if let Some(logical_type) = logical_type {
+            return Ok(to_logical_data_type(logical_type));
+        }
+
+        match item_type {
+            AvroItemType::Boolean if boxed => Ok(get_jdk_type("Boolean")),
+            AvroItemType::Boolean => Ok(JavaDataType::boolean()),
+            AvroItemType::Int if boxed => Ok(get_jdk_type("Integer")),
+            AvroItemType::Int => Ok(JavaDataType::int()),
+            AvroItemType::Long if boxed => Ok(get_jdk_type("Long")),
+            AvroItemType::Long => Ok(JavaDataType::long()),
+            AvroItemType::Float if boxed => Ok(get_jdk_type("Float")),
+            AvroItemType::Float => Ok(JavaDataType::float()),
+            AvroItemType::Double if boxed => Ok(get_jdk_type("Double")),
+            AvroItemType::Double => Ok(JavaDataType::double()),
+            AvroItemType::Bytes => Ok(get_jdk_type("ByteBuffer")),
+            AvroItemType::String => Ok(JavaDataType::string()),
+            AvroItemType::Array(items_type) => {
+                let items = self.to_data_type(items_type, None, true)?;
+                get_jdk_type("List").with_type_arguments(vec![items])
+            }
+            AvroItemType::Map(values_type) => {
+                let values = self.to_data_type(values_type, None, true)?;
+                get_jdk_type("Map").with_type_arguments(vec![JavaDataType::string(), values])
+            }
+            AvroItemType::Union(union_types) => self.to_union_data_type(union_types, boxed),
+            AvroItemType::Inline(inline_item) if inline_item.get_item_type().is_named() => {
+                self.to_named_data_type(inline_item)
+            }
+            AvroItemType::Inline(inline_item) => self.to_data_type(
+                inline_item.get_item_type(),
+                inline_item.get_logical_type(),
+                boxed,
+            ),
+            AvroItemType::Reference(name) => {
+                let named_item = self
+                    .registry
+                    .get_named_type(name)
+                    .ok_or(format!("unknown avro named type \"{}\"", name))?;
+                self.to_named_data_type(named_item)
+            }
+            other => Err(format!("avro type {} without java equivalent", other)),
+        }
+    }
+
+    /// Unions with several non null types are "Object"
+    fn to_union_data_type(
+        &self,
+        union_types: &[AvroItemType],
+        boxed: bool,
+    ) -> Result<JavaDataType, String> {
+        let non_null_types: Vec<&AvroItemType> = union_types
+            .iter()
+            .filter(|union_type| AvroItemType::Null != **union_type)
+            .collect();
+        let is_nullable = non_null_types.len() < union_types.len();
+        let data_type = match non_null_types.as_slice() {
+            [] => return Err("union without non null types".to_string()),
+            [single_type] => self.to_data_type(single_type, None, boxed || is_nullable)?,
+            _ => get_jdk_type("Object"),
+        };
+
+        if is_nullable && JavaNullableStyle::Optional == self.nullable_style {
+            return get_jdk_type("Optional").with_type_arguments(vec![data_type]);
+        }
+        Ok(data_type)
+    }
+
+    fn to_named_data_type(&self, named_item: &AvroItem) -> Result<JavaDataType, String> {
+        if AvroItemType::Fixed == *named_item.get_item_type() {
+            return Ok(match named_item.get_logical_type() {
+                Some(logical_type) => to_logical_data_type(logical_type),
+                None => get_jdk_type("ByteBuffer"),
+            });
+        }
+
+        self.check_namespace(named_item)?;
+        let full_name = named_item.get_full_name().unwrap_or_default();
+        let import = JavaImport::new_explicit_import_without_m2_repo_scan(&full_name)?;
+        Ok(JavaDataType::from_import(import))
+    }
+
+    fn create_package_folder(
+        &self,
+        source_dir: &Path,
+        avro_item: &AvroItem,
+    ) -> Result<PathBuf, String> {
+        let namespace = self.check_namespace(avro_item)?;
+        let folder = source_dir.join(namespace.replace('.', "/"));
+        fs::create_dir_all(&folder).map_err(|err| {
+            format!(
+                "Java package folder could not be created ({}):\n{}\n",
+                err,
+                try_to_absolute_path(&folder)
+            )
+        })?;
+
+        Ok(folder)
+    }
+
+    /// Types of the null namespace would be in the java default package, which can not be imported
+    fn check_namespace(&self, avro_item: &AvroItem) -> Result<String, String> {
+        let name = self.get_name(avro_item)?;
+        avro_item.get_namespace().ok_or_else(|| {
+            let file = self
+                .registry
+                .get_file(&name)
+                .map(try_to_absolute_path)
+                .unwrap_or_default();
+            format!(
+                "Invalid avro named type \"{}\" (a namespace is required as java package) in file:\n{}\n",
+                name, file
+            )
+        })
+    }
+
+    fn get_name(&self, avro_item: &AvroItem) -> Result<String, String> {
+        avro_item
+            .get_name()
+            .ok_or(format!("Avro named type without name: {:?}", avro_item))
+    }
+}
+
+/// Durations are twelve bytes with the months, days and milliseconds, without java equivalent
+fn to_logical_data_type(logical_type: AvroLogicalType) -> JavaDataType {
+    match logical_type {
+        AvroLogicalType::Decimal { .. } => get_jdk_type("BigDecimal"),
+        AvroLogicalType::Uuid => get_jdk_type("UUID"),
+        AvroLogicalType::Date => java_time_factory::create_local_date(),
+        AvroLogicalType::TimeMillis | AvroLogicalType::TimeMicros => {
+            java_time_factory::create_local_time()
+        }
+        AvroLogicalType::TimestampMillis
+        | AvroLogicalType::TimestampMicros
+        | AvroLogicalType::TimestampNanos => java_time_factory::create_instant(),
+        AvroLogicalType::LocalTimestampMillis
+        | AvroLogicalType::LocalTimestampMicros
+        | AvroLogicalType::LocalTimestampNanos => java_time_factory::create_local_date_time(),
+        AvroLogicalType::Duration => get_jdk_type("ByteBuffer"),
+    }
+}
+
+fn get_jdk_type(simple_name: &str) -> JavaDataType {
+    JavaDataType::from_simple_name(simple_name)
+        .unwrap_or_else(|_| panic!("{} java type is in the standard library", simple_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_assert::assert_same_file;
+    use crate::core::testing::test_path;
+    use crate::domain::usecase::avro::registry::avro_registry::AvroRegistry;
+    use crate::domain::usecase::java::translator::from_avro::java_from_avro_translator::{
+        avro_to_java, JavaNullableStyle,
+    };
+
+    #[test]
+    fn avro_to_java_with_nullable_fields() {
+        assert_avro_to_java("nullable", JavaNullableStyle::Nullable);
+    }
+
+    #[test]
+    fn avro_to_java_with_optional_fields() {
+        assert_avro_to_java("optional", JavaNullableStyle::Optional);
+    }
+
+    #[test]
+    fn avro_to_java_without_namespace_fails() {
+        let registry = AvroRegistry::from_dir(&get_test_dir("without_namespace"))
+            .expect("Avro registry must be created");
+        let source_dir = get_source_dir("nullable");
+
+        let result = avro_to_java(&registry, &source_dir, JavaNullableStyle::Nullable);
+
+        assert!(result.is_err_and(|err| err.contains("a namespace is required as java package")));
+    }
+
+    fn assert_avro_to_java(project: &str, nullable_style: JavaNullableStyle) {
+        let registry =
+            AvroRegistry::from_dir(&get_test_dir("avro")).expect("Avro registry must be created");
+        let source_dir = get_source_dir(project);
+        let package_dir = source_dir.join("org/test/shop");
+
+        let files = avro_to_java(&registry, &source_dir, nullable_style)
+            .expect("Java files must be written");
+
+        let mut file_names: Vec<String> = files
+            .iter()
+            .map(|file| file.file_name().unwrap().to_string_lossy().to_string())
+            .collect();
+        file_names.sort();
+        assert_eq!(
+            vec![
+                "Customer.java",
+                "Order.java",
+                "OrderLine.java",
+                "OrderStatus.java"
+            ],
+            file_names
+        );
+        let expected_dir = get_test_dir("expected").join(project);
+        for file_name in file_names {
+            assert_same_file(
+                &expected_dir.join(&file_name),
+                &package_dir.join(&file_name),
+            );
+        }
+        fs::remove_dir_all(&package_dir).expect("Generated package must be removed");
+    }
+
+    fn get_source_dir(project: &str) -> PathBuf {
+        get_test_dir(project).join("src/main/java")
+    }
+
+    fn get_test_dir(name: &str) -> PathBuf {
+        test_path::get_test_dir(get_current_file_path(), name)
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
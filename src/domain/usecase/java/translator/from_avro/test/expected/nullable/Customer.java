@@ -0,0 +1,7 @@
+package org.test.shop;
+
+public class Customer {
+    public String name;
+
+    public String email;
+}
@@ -0,0 +1,41 @@
+package org.test.shop;
+
+import java.nio.ByteBuffer;
+import java.time.Instant;
+import java.time.LocalDate;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+import org.test.shop.Customer;
+import org.test.shop.OrderLine;
+import org.test.shop.OrderStatus;
+
+/**
+ * Order placed by a customer.
+ */
+public class Order {
+    /**
+     * Order identifier.
+     */
+    public long id;
+
+    public Customer customer;
+
+    public OrderStatus status;
+
+    public List<OrderLine> lines;
+
+    public Map<String, Long> attributes;
+
+    public Double discount;
+
+    public Instant createdAt;
+
+    public LocalDate deliveryDate;
+
+    public UUID trackingId;
+
+    public ByteBuffer checksum;
+
+    public Order previousOrder;
+}
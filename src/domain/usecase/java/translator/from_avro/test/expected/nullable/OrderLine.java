@@ -0,0 +1,11 @@
+package org.test.shop;
+
+import java.math.BigDecimal;
+
+public class OrderLine {
+    public String product;
+
+    public int quantity;
+
+    public BigDecimal price;
+}
@@ -0,0 +1,9 @@
+package org.test.shop;
+
+import java.util.Optional;
+
+public class Customer {
+    public String name;
+
+    public Optional<String> email;
+}
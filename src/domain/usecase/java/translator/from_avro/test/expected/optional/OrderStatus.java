@@ -0,0 +1,10 @@
+package org.test.shop;
+
+/**
+ * Lifecycle of an order.
+ */
+public enum OrderStatus {
+    CREATED,
+    PAID,
+    SHIPPED
+}
@@ -0,0 +1,927 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::domain::usecase::openapi::parser::dto::openapi_data_type::{
+    IntegerFormat, NumberFormat, OpenapiDataType,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_document::OpenapiDocument;
+use crate::domain::usecase::openapi::parser::dto::openapi_path::{
+    OpenapiHttpMethod, OpenapiMediaType, OpenapiOperation, OpenapiParameter,
+    OpenapiParameterLocation,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_schema::OpenapiSchema;
+use crate::java::annotation_usage::JavaAnnotationUsage;
+use crate::java::class::JavaClass;
+use crate::java::comment::JavaComment;
+use crate::java::data_type::JavaDataType;
+use crate::java::dependency::com::fasterxml::jackson::core::jackson_annotations::java_jackson_annotations_factory;
+use crate::java::dependency::java::time::java_time_factory;
+use crate::java::dependency::org::springframework::spring_web::java_spring_web_factory;
+use crate::java::enumeration::JavaEnum;
+use crate::java::field::JavaField;
+use crate::java::import::JavaImport;
+use crate::java::interface::JavaInterface;
+use crate::java::javadoc::JavaDoc;
+use crate::java::method::JavaMethod;
+use crate::java::scanner::file::java_file::JavaFile;
+use crate::java::scanner::package::java_package_scanner;
+use crate::java::variable::JavaVariable;
+use crate::java::visibility::JavaVisibility::Public;
+
+/// Start of the line comment of the generated files, the files without it are never overwritten
+const GENERATED_COMMENT: &str = "Generated from the OpenAPI contract";
+
+const JAVA_KEYWORDS: [&str; 53] = [
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+/// # openapi_to_spring
+/// Write a spring "@RestController" interface in "controller_folder" for every tag of the
+/// document operations, the operations without tags are in "DefaultApi". The schemas used by
+/// the operations are written in "dto_folder", as java classes with public fields or java enums.
+/// The folders are created if needed, and must be inside the source folder of a java project.
+///
+/// Files written by a previous generation are overwritten. When any other file would be
+/// overwritten, like a hand-written implementation, nothing is written and an error is returned.
+/// It returns the written files, the DTOs first.
+pub fn openapi_to_spring(
+    document: &OpenapiDocument,
+    controller_folder: &Path,
+    dto_folder: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    get_package_route(controller_folder)?;
+    let mut translator = JavaFromOpenapiTranslator {
+        document,
+        dto_package: get_package_route(dto_folder)?,
+        dtos: vec![],
+        dto_schemas: vec![],
+    };
+    for schema in document.get_components().get_schemas() {
+        if is_dto_schema(schema) {
+            translator.add_dto(&to_upper_camel_case(schema.get_name()), schema)?;
+        }
+    }
+    let controllers = translator.get_controllers()?;
+
+    let dto_files: Vec<PathBuf> = translator
+        .dtos
+        .iter()
+        .map(|dto| get_java_file(dto_folder, dto.get_name()))
+        .collect();
+    let controller_files: Vec<PathBuf> = controllers
+        .iter()
+        .map(|controller| get_java_file(controller_folder, &controller.name))
+        .collect();
+    for file in dto_files.iter().chain(controller_files.iter()) {
+        check_generated_file(file)?;
+    }
+
+    create_folder(dto_folder)?;
+    for dto in &translator.dtos {
+        translator.write_dto(dto, dto_folder)?;
+    }
+    create_folder(controller_folder)?;
+    for controller in &controllers {
+        translator.write_controller(controller, controller_folder)?;
+    }
+
+    Ok(dto_files.into_iter().chain(controller_files).collect())
+}
+
+enum JavaDto {
+    Class {
+        name: String,
+        description: Option<String>,
+        fields: Vec<JavaField>,
+    },
+    Enum {
+        name: String,
+        description: Option<String>,
+        constants: Vec<String>,
+    },
+}
+
+impl JavaDto {
+    fn get_name(&self) -> &str {
+        match self {
+            JavaDto::Class { name, .. } | JavaDto::Enum { name, .. } => name,
+        }
+    }
+}
+
+struct SpringController {
+    name: String,
+    description: Option<String>,
+    methods: Vec<JavaMethod>,
+}
+
+struct JavaFromOpenapiTranslator<'a> {
+    document: &'a OpenapiDocument,
+    dto_package: String,
+    /// Dependencies first, inline schemas are named after the place where they are declared
+    dtos: Vec<JavaDto>,
+    /// Schema of every java type name, registered before its DTO is created
+    dto_schemas: Vec<(String, OpenapiSchema)>,
+}
+
+impl JavaFromOpenapiTranslator<'_> {
+    fn get_controllers(&mut self) -> Result<Vec<SpringController>, String> {
+        let mut controllers: Vec<SpringController> = Vec::new();
+        for path in self.document.get_paths() {
+            for operation in path.get_operations() {
+                let tag = operation
+                    .get_tags()
+                    .first()
+                    .cloned()
+                    .unwrap_or("default".to_string());
+                let method = self.create_method(path.get_route(), operation)?;
+
+                let name = format!("{}Api", to_upper_camel_case(&tag));
+                match controllers
+                    .iter_mut()
+                    .find(|controller| name == controller.name)
+                {
+                    Some(controller) => controller.methods.push(method),
+                    None => controllers.push(SpringController {
+                        name,
+                        description: self.get_tag_description(&tag),
+                        methods: vec![method],
+                    }),
+                }
+            }
+        }
+
+        Ok(controllers)
+    }
+
+    fn create_method(
+        &mut self,
+        route: &str,
+        operation: &OpenapiOperation,
+    ) -> Result<JavaMethod, String> {
+        let method = operation.get_method();
+        let name = match operation.get_operation_id() {
+            Some(operation_id) => to_lower_camel_case(&operation_id),
+            None => to_lower_camel_case(&format!("{} {}", method, route)),
+        };
+        check_java_identifier(&name).map_err(|err| {
+            format!(
+                "Invalid OpenAPI operation \"{} {}\" ({})",
+                method, route, err
+            )
+        })?;
+        let type_name = to_upper_camel_case(&name);
+
+        let mut javadoc = JavaDoc::builder();
+        let description: Vec<String> = operation
+            .get_summary()
+            .into_iter()
+            .chain(operation.get_description())
+            .collect();
+        javadoc.description(&description.join("\n\n"));
+        let mut parameters = Vec::new();
+        for parameter in operation.get_parameters() {
+            let variable = self
+                .create_parameter(parameter, &type_name)
+                .map_err(|err| format!("Invalid OpenAPI operation \"{}\" ({})", name, err))?;
+            if let Some(description) = parameter.get_description() {
+                javadoc.param(variable.get_name(), &description);
+            }
+            parameters.push(variable);
+        }
+        if let Some(request_body) = operation.get_request_body() {
+            let data_type = match get_body_schema(request_body.get_content()) {
+                Some(schema) => self
+                    .get_data_type(schema, &format!("{}Request", type_name), true)
+                    .map_err(|err| format!("Invalid OpenAPI operation \"{}\" ({})", name, err))?,
+                None => get_jdk_type("Object"),
+            };
+            let body_name = get_body_parameter_name(&parameters);
+            parameters.push(
+                JavaVariable::builder()
+                    .annotations(vec![
+                        java_spring_web_factory::create_request_body_annotation_usage(
+                            request_body.is_required(),
+                        ),
+                    ])
+                    .data_type(data_type)
+                    .name(&body_name)
+                    .build()?,
+            );
+            if let Some(description) = request_body.get_description() {
+                javadoc.param(&body_name, &description);
+            }
+        }
+        if operation.is_deprecated() {
+            javadoc.other_tag("@deprecated");
+        }
+
+        let response_opt = operation
+            .get_responses()
+            .iter()
+            .find(|response| response.get_status_code().starts_with('2'));
+        let response_type =
+            match response_opt.and_then(|response| get_body_schema(response.get_content())) {
+                Some(schema) => self
+                    .get_data_type(schema, &format!("{}Response", type_name), true)
+                    .map_err(|err| format!("Invalid OpenAPI operation \"{}\" ({})", name, err))?,
+                None => get_jdk_type("Void"),
+            };
+
+        let mut builder = JavaMethod::builder();
+        builder
+            .annotations(vec![get_mapping_annotation_usage(method, route)])
+            .return_type(java_spring_web_factory::create_response_entity(
+                response_type,
+            ))
+            .name(&name)
+            .parameters(parameters);
+        let javadoc = javadoc.build();
+        if !javadoc.get_description().is_empty()
+            || !javadoc.get_params().is_empty()
+            || operation.is_deprecated()
+        {
+            builder.javadoc(javadoc);
+        }
+        builder.build()
+    }
+
+    /// Optional parameters are "boxed" types, spring sets them to null when they are missing
+    fn create_parameter(
+        &mut self,
+        parameter: &OpenapiParameter,
+        type_name: &str,
+    ) -> Result<JavaVariable, String> {
+        let name = to_lower_camel_case(parameter.get_name());
+        check_java_identifier(&name)
+            .map_err(|err| format!("parameter \"{}\", {}", parameter.get_name(), err))?;
+        let required = parameter.is_required();
+        let data_type = match parameter.get_schema() {
+            Some(schema) => self
+                .get_data_type(
+                    schema,
+                    &format!("{}{}", type_name, to_upper_camel_case(&name)),
+                    !required,
+                )
+                .map_err(|err| format!("parameter \"{}\", {}", parameter.get_name(), err))?,
+            None => JavaDataType::string(),
+        };
+        let annotation = match parameter.get_location() {
+            OpenapiParameterLocation::Path => {
+                java_spring_web_factory::create_path_variable_annotation_usage(parameter.get_name())
+            }
+            OpenapiParameterLocation::Query => {
+                java_spring_web_factory::create_request_param_annotation_usage(
+                    parameter.get_name(),
+                    required,
+                )
+            }
+            OpenapiParameterLocation::Header => {
+                java_spring_web_factory::create_request_header_annotation_usage(
+                    parameter.get_name(),
+                    required,
+                )
+            }
+            OpenapiParameterLocation::Cookie => {
+                java_spring_web_factory::create_cookie_value_annotation_usage(
+                    parameter.get_name(),
+                    required,
+                )
+            }
+        };
+
+        JavaVariable::builder()
+            .annotations(vec![annotation])
+            .data_type(data_type)
+            .name(&name)
+            .build()
+    }
+
+    /// Add the java class or enum of a schema, if it was not already added. Different schemas
+    /// with the same java name (i.e. "order_item" and "OrderItem") are an error.
+    fn add_dto(&mut self, name: &str, schema: &OpenapiSchema) -> Result<JavaDataType, String> {
+        let registered_schema = self
+            .dto_schemas
+            .iter()
+            .find(|(dto_name, _)| name == dto_name)
+            .map(|(_, registered_schema)| registered_schema);
+        if let Some(registered_schema) = registered_schema {
+            if !is_same_dto_schema(registered_schema, schema) {
+                return Err(format!(
+                    "Invalid OpenAPI schema \"{}\" (java type \"{}\" is already generated for schema \"{}\")",
+                    schema.get_name(),
+                    name,
+                    registered_schema.get_name()
+                ));
+            }
+        } else {
+            self.dto_schemas.push((name.to_string(), schema.clone()));
+            check_java_identifier(name)
+                .map_err(|err| format!("Invalid OpenAPI schema \"{}\" ({})", name, err))?;
+            let description = schema.get_description().clone();
+            let dto = match schema.get_properties() {
+                Some(properties) => {
+                    let mut fields = Vec::new();
+                    for property in properties {
+                        fields.push(self.create_field(property, name).map_err(|err| {
+                            format!(
+                                "Invalid OpenAPI schema \"{}\" (property \"{}\", {})",
+                                name,
+                                property.get_name(),
+                                err
+                            )
+                        })?);
+                    }
+                    JavaDto::Class {
+                        name: name.to_string(),
+                        description,
+                        fields,
+                    }
+                }
+                None => {
+                    let constants = schema.get_enum_values().clone().unwrap_or_default();
+                    for constant in &constants {
+                        check_java_identifier(constant).map_err(|err| {
+                            format!("Invalid OpenAPI schema \"{}\" (enum value {})", name, err)
+                        })?;
+                    }
+                    JavaDto::Enum {
+                        name: name.to_string(),
+                        description,
+                        constants,
+                    }
+                }
+            };
+            self.dtos.push(dto);
+        }
+
+        let import = JavaImport::new_explicit_import_without_m2_repo_scan(&format!(
+            "{}.{}",
+            self.dto_package, name
+        ))?;
+        Ok(JavaDataType::from_import(import))
+    }
+
+    /// Properties without a valid java name (i.e. "first-name") are renamed with "@JsonProperty"
+    fn create_field(
+        &mut self,
+        property: &OpenapiSchema,
+        class_name: &str,
+    ) -> Result<JavaField, String> {
+        let name = to_lower_camel_case(property.get_name());
+        check_java_identifier(&name)?;
+        let data_type = if is_dto_schema(property) {
+            let dto_name = format!("{}{}", class_name, to_upper_camel_case(&name));
+            self.add_dto(&dto_name, property)?
+        } else {
+            let schema_type = property.get_property_type().ok_or("schema without type")?;
+            self.get_data_type(
+                &schema_type,
+                &format!("{}{}", class_name, to_upper_camel_case(&name)),
+                false,
+            )?
+        };
+
+        let mut builder = JavaField::builder();
+        builder
+            .visibility(Public)
+            .data_type(data_type.without_package_imports(&self.dto_package))
+            .name(&name);
+        if &name != property.get_name() {
+            builder.annotations(vec![
+                java_jackson_annotations_factory::create_json_property_annotation_usage(
+                    property.get_name(),
+                ),
+            ]);
+        }
+        if let Some(description) = property.get_description() {
+            builder.javadoc(JavaDoc::builder().description(description).build());
+        }
+        builder.build()
+    }
+
+    /// Primitive types are "boxed" inside generic types and nullable unions.
+    /// Inline schemas are named "type_name".
+    fn get_data_type(
+        &mut self,
+        data_type: &OpenapiDataType,
+        type_name: &str,
+        boxed: bool,
+    ) -> Result<JavaDataType, String> {
+        match data_type {
+            OpenapiDataType::Integer(IntegerFormat::Int32) if boxed => Ok(get_jdk_type("Integer")),
+            OpenapiDataType::Integer(IntegerFormat::Int32) => Ok(JavaDataType::int()),
+            OpenapiDataType::Integer(IntegerFormat::Int64) if boxed => Ok(get_jdk_type("Long")),
+            OpenapiDataType::Integer(IntegerFormat::Int64) => Ok(JavaDataType::long()),
+            OpenapiDataType::Number(NumberFormat::Float) if boxed => Ok(get_jdk_type("Float")),
+            OpenapiDataType::Number(NumberFormat::Float) => Ok(JavaDataType::float()),
+            OpenapiDataType::Number(NumberFormat::Double) if boxed => Ok(get_jdk_type("Double")),
+            OpenapiDataType::Number(NumberFormat::Double) => Ok(JavaDataType::double()),
+            OpenapiDataType::Number(NumberFormat::Decimal) => Ok(get_jdk_type("BigDecimal")),
+            OpenapiDataType::Boolean if boxed => Ok(get_jdk_type("Boolean")),
+            OpenapiDataType::Boolean => Ok(JavaDataType::boolean()),
+            OpenapiDataType::String => Ok(JavaDataType::string()),
+            OpenapiDataType::FormattedString(format) => Ok(to_formatted_data_type(format)),
+            OpenapiDataType::Bytes => Ok(get_jdk_type("ByteBuffer")),
+            OpenapiDataType::ObjectSimple => Ok(get_jdk_type("Object")),
+            OpenapiDataType::Object(schema) => self.add_dto(type_name, schema),
+            OpenapiDataType::ObjectName(name) => self.get_named_data_type(name, boxed),
+            OpenapiDataType::Array(union_types) => {
+                self.get_union_data_type(union_types, type_name, boxed)
+            }
+            OpenapiDataType::ArrayItems(items_type) => {
+                let items = self.get_data_type(items_type, &format!("{}Item", type_name), true)?;
+                get_jdk_type("List").with_type_arguments(vec![items])
+            }
+            OpenapiDataType::Map(values_type) => {
+                let values =
+                    self.get_data_type(values_type, &format!("{}Value", type_name), true)?;
+                get_jdk_type("Map").with_type_arguments(vec![JavaDataType::string(), values])
+            }
+            OpenapiDataType::Null => Err("null type without java equivalent".to_string()),
+        }
+    }
+
+    /// Unions with several non null types are "Object"
+    fn get_union_data_type(
+        &mut self,
+        union_types: &[OpenapiDataType],
+        type_name: &str,
+        boxed: bool,
+    ) -> Result<JavaDataType, String> {
+        let non_null_types: Vec<&OpenapiDataType> = union_types
+            .iter()
+            .filter(|union_type| OpenapiDataType::Null != **union_type)
+            .collect();
+        let is_nullable = non_null_types.len() < union_types.len();
+        match non_null_types.as_slice() {
+            [] => Err("union without non null types".to_string()),
+            [single_type] => self.get_data_type(single_type, type_name, boxed || is_nullable),
+            _ => Ok(get_jdk_type("Object")),
+        }
+    }
+
+    /// Component schemas without java class or enum are replaced by their type
+    fn get_named_data_type(&mut self, name: &str, boxed: bool) -> Result<JavaDataType, String> {
+        let schema = self
+            .document
+            .get_components()
+            .get_schema(name)
+            .ok_or(format!("unknown OpenAPI schema \"{}\"", name))?;
+        if is_dto_schema(schema) {
+            return self.add_dto(&to_upper_camel_case(name), schema);
+        }
+
+        let schema_type = schema
+            .get_schema_type()
+            .as_ref()
+            .ok_or(format!("OpenAPI schema \"{}\" without type", name))?;
+        self.get_data_type(schema_type, &to_upper_camel_case(name), boxed)
+    }
+
+    fn write_dto(&self, dto: &JavaDto, folder: &Path) -> Result<(), String> {
+        match dto {
+            JavaDto::Class {
+                name,
+                description,
+                fields,
+            } => {
+                let mut builder = JavaClass::builder();
+                builder
+                    .folder(folder)
+                    .comments(vec![self.get_generated_comment()])
+                    .visibility(Public)
+                    .name(name)
+                    .fields(fields.clone());
+                if let Some(description) = description {
+                    builder.javadoc(JavaDoc::builder().description(description).build());
+                }
+                builder.build()?;
+            }
+            JavaDto::Enum {
+                name,
+                description,
+                constants,
+            } => {
+                let mut builder = JavaEnum::builder();
+                builder
+                    .folder(folder)
+                    .comments(vec![self.get_generated_comment()])
+                    .visibility(Public)
+                    .name(name)
+                    .constants(constants.iter().map(|constant| constant.as_str()).collect());
+                if let Some(description) = description {
+                    builder.javadoc(JavaDoc::builder().description(description).build());
+                }
+                builder.build()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn write_controller(&self, controller: &SpringController, folder: &Path) -> Result<(), String> {
+        let mut builder = JavaInterface::builder();
+        builder
+            .folder(folder)
+            .comments(vec![self.get_generated_comment()])
+            .annotations(vec![
+                java_spring_web_factory::create_rest_controller_annotation_usage(),
+            ])
+            .visibility(Public)
+            .name(&controller.name)
+            .methods(controller.methods.clone());
+        if let Some(description) = &controller.description {
+            builder.javadoc(JavaDoc::builder().description(description).build());
+        }
+        builder.build()?;
+
+        Ok(())
+    }
+
+    fn get_generated_comment(&self) -> JavaComment {
+        let info = self.document.get_info();
+        JavaComment::Line(format!(
+            "{} \"{}\" {}, changes will be overwritten",
+            GENERATED_COMMENT,
+            info.get_title(),
+            info.get_version()
+        ))
+    }
+
+    fn get_tag_description(&self, tag: &str) -> Option<String> {
+        self.document
+            .get_tags()
+            .iter()
+            .find(|document_tag| tag == document_tag.get_name())
+            .and_then(|document_tag| document_tag.get_description())
+    }
+}
+
+/// Schemas written as the same java type, their names and descriptions are not compared
+fn is_same_dto_schema(schema: &OpenapiSchema, other: &OpenapiSchema) -> bool {
+    schema.get_properties() == other.get_properties()
+        && schema.get_enum_values() == other.get_enum_values()
+}
+
+/// Objects with properties and string enums, integer enums are written as integers
+fn is_dto_schema(schema: &OpenapiSchema) -> bool {
+    if schema.get_properties().is_some() {
+        return true;
+    }
+    schema.get_enum_values().is_some()
+        && matches!(
+            schema.get_schema_type().as_ref().map(remove_null_type),
+            Some(OpenapiDataType::String)
+        )
+}
+
+fn remove_null_type(data_type: &OpenapiDataType) -> &OpenapiDataType {
+    match data_type {
+        OpenapiDataType::Array(union_types) => match union_types.as_slice() {
+            [OpenapiDataType::Null, single_type] => single_type,
+            _ => data_type,
+        },
+        _ => data_type,
+    }
+}
+
+fn to_formatted_data_type(format: &str) -> JavaDataType {
+    match format {
+        "date" => java_time_factory::create_local_date(),
+        "date-time" => java_time_factory::create_offset_date_time(),
+        "uuid" => get_jdk_type("UUID"),
+        "binary" => get_jdk_type("ByteBuffer"),
+        _ => JavaDataType::string(),
+    }
+}
+
+/// JSON schema of the body, any other media type if there is no JSON one
+fn get_body_schema(content: &[OpenapiMediaType]) -> Option<&OpenapiDataType> {
+    content
+        .iter()
+        .find(|media_type| "application/json" == media_type.get_media_type())
+        .or(content.first())
+        .and_then(|media_type| media_type.get_schema())
+}
+
+/// "body", unless an OpenAPI parameter already has that name
+fn get_body_parameter_name(parameters: &[JavaVariable]) -> String {
+    let mut name = "body".to_string();
+    let mut index = 1;
+    while parameters
+        .iter()
+        .any(|parameter| name == parameter.get_name())
+    {
+        name = format!("body{}", index);
+        index += 1;
+    }
+    name
+}
+
+fn get_mapping_annotation_usage(method: OpenapiHttpMethod, route: &str) -> JavaAnnotationUsage {
+    match method {
+        OpenapiHttpMethod::Get => {
+            java_spring_web_factory::create_get_mapping_annotation_usage(route)
+        }
+        OpenapiHttpMethod::Put => {
+            java_spring_web_factory::create_put_mapping_annotation_usage(route)
+        }
+        OpenapiHttpMethod::Post => {
+            java_spring_web_factory::create_post_mapping_annotation_usage(route)
+        }
+        OpenapiHttpMethod::Delete => {
+            java_spring_web_factory::create_delete_mapping_annotation_usage(route)
+        }
+        OpenapiHttpMethod::Patch => {
+            java_spring_web_factory::create_patch_mapping_annotation_usage(route)
+        }
+        other => java_spring_web_factory::create_request_mapping_annotation_usage(
+            route,
+            &other.to_string().to_uppercase(),
+        ),
+    }
+}
+
+/// Existing files are only overwritten when the generated comment leads their type
+fn check_generated_file(file: &Path) -> Result<(), String> {
+    if file.exists() && !is_generated_file(file) {
+        return Err(format!(
+            "Java file not generated from an OpenAPI contract, it would be overwritten:\n{}\n",
+            try_to_absolute_path(file)
+        ));
+    }
+
+    Ok(())
+}
+
+fn is_generated_file(file: &Path) -> bool {
+    JavaFile::from_user_input_path(file).is_ok_and(|java_file| {
+        matches!(
+            java_file.get_structure().get_comments().first(),
+            Some(JavaComment::Line(text)) if text.starts_with(GENERATED_COMMENT)
+        )
+    })
+}
+
+fn get_package_route(folder: &Path) -> Result<String, String> {
+    java_package_scanner::get_package_route_opt_from_dir_no_check(folder).ok_or(format!(
+        "Invalid output folder outside of java project:\n{}\n",
+        try_to_absolute_path(folder)
+    ))
+}
+
+fn create_folder(folder: &Path) -> Result<(), String> {
+    fs::create_dir_all(folder).map_err(|err| {
+        format!(
+            "Java package folder could not be created ({}):\n{}\n",
+            err,
+            try_to_absolute_path(folder)
+        )
+    })
+}
+
+fn get_java_file(folder: &Path, name: &str) -> PathBuf {
+    folder.join(format!("{}.java", name))
+}
+
+fn check_java_identifier(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || '_' == first || '$' == first)
+        && chars.all(|char| char.is_alphanumeric() || '_' == char || '$' == char);
+    if !is_identifier || JAVA_KEYWORDS.contains(&name) {
+        return Err(format!("\"{}\" is not a valid java identifier", name));
+    }
+
+    Ok(())
+}
+
+/// Words separated by any other character than letters and digits (i.e. "X-Request-Id"
+/// is "XRequestId"), the first letter of every word is uppercase
+fn to_upper_camel_case(input: &str) -> String {
+    input
+        .split(|char: char| !char.is_alphanumeric())
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+fn to_lower_camel_case(input: &str) -> String {
+    let upper_camel_case = to_upper_camel_case(input);
+    let mut chars = upper_camel_case.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn get_jdk_type(simple_name: &str) -> JavaDataType {
+    JavaDataType::from_simple_name(simple_name)
+        .unwrap_or_else(|_| panic!("{} java type is in the standard library", simple_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use crate::core::file_system::file_reader;
+    use crate::core::testing::test_assert::assert_same_file;
+    use crate::core::testing::test_path;
+    use crate::domain::usecase::java::translator::from_openapi::java_from_openapi_translator::openapi_to_spring;
+    use crate::domain::usecase::openapi::parser::openapi_parser;
+
+    #[test]
+    fn openapi_to_spring_controllers_and_dtos() {
+        let document = openapi_parser::parse(&get_test_dir("openapi").join("shop.yaml"))
+            .expect("OpenAPI document must be parsed");
+        let package_dir = get_test_dir("generated").join("src/main/java/org/test");
+        let controller_dir = package_dir.join("api");
+        let dto_dir = package_dir.join("model");
+
+        let files = openapi_to_spring(&document, &controller_dir, &dto_dir)
+            .expect("Spring files must be written");
+
+        assert_eq!(
+            vec![
+                "model/OrderStatus.java",
+                "model/OrderLinesItem.java",
+                "model/OrderDelivery.java",
+                "model/OrderChannel.java",
+                "model/Order.java",
+                "model/Customer.java",
+                "model/CreateOrderRequest.java",
+                "api/OrdersApi.java",
+                "api/CustomersApi.java",
+                "api/DefaultApi.java",
+            ],
+            get_relative_paths(&files, &package_dir)
+        );
+        let expected_dir = get_test_dir("expected");
+        for file in &files {
+            let relative_path = file.strip_prefix(&package_dir).unwrap();
+            assert_same_file(&expected_dir.join(relative_path), file);
+        }
+
+        let implementation = controller_dir.join("OrdersController.java");
+        let implementation_content =
+            "package org.test.api;\n\npublic class OrdersController implements OrdersApi {\n}\n";
+        fs::write(&implementation, implementation_content).expect("Implementation must be written");
+        let regenerated_files = openapi_to_spring(&document, &controller_dir, &dto_dir)
+            .expect("Spring files must be written again");
+
+        assert_eq!(files, regenerated_files);
+        assert_eq!(
+            implementation_content,
+            file_reader::read_to_string(&implementation)
+        );
+        fs::remove_dir_all(&controller_dir).expect("Generated controllers must be removed");
+        fs::remove_dir_all(&dto_dir).expect("Generated DTOs must be removed");
+    }
+
+    #[test]
+    fn openapi_to_spring_does_not_overwrite_hand_written_files() {
+        let document = openapi_parser::parse(&get_test_dir("openapi").join("shop.yaml"))
+            .expect("OpenAPI document must be parsed");
+        let package_dir = get_test_dir("hand_written").join("src/main/java/org/test");
+        let hand_written_file = package_dir.join("model/Order.java");
+        let content = file_reader::read_to_string(&hand_written_file);
+
+        let result = openapi_to_spring(
+            &document,
+            &package_dir.join("api"),
+            &package_dir.join("model"),
+        );
+
+        assert!(result.is_err_and(|err| err.contains("not generated from an OpenAPI contract")));
+        assert_eq!(content, file_reader::read_to_string(&hand_written_file));
+        assert!(!package_dir.join("api").exists());
+        assert!(!package_dir.join("model/OrderStatus.java").exists());
+    }
+
+    #[test]
+    fn openapi_to_spring_does_not_overwrite_files_with_the_comment_in_their_body() {
+        let document = openapi_parser::parse(&get_test_dir("openapi").join("shop.yaml"))
+            .expect("OpenAPI document must be parsed");
+        let package_dir = get_test_dir("marker_in_body").join("src/main/java/org/test");
+        let hand_written_file = package_dir.join("model/Customer.java");
+        let content = file_reader::read_to_string(&hand_written_file);
+
+        let result = openapi_to_spring(
+            &document,
+            &package_dir.join("api"),
+            &package_dir.join("model"),
+        );
+
+        assert!(result.is_err_and(|err| err.contains("not generated from an OpenAPI contract")));
+        assert_eq!(content, file_reader::read_to_string(&hand_written_file));
+        assert!(!package_dir.join("api").exists());
+    }
+
+    #[test]
+    fn openapi_to_spring_with_clashing_schema_names_fails() {
+        let package_dir = get_test_dir("hand_written").join("src/main/java/org/test");
+        for (file_name, expected_err) in [
+            (
+                "clashing_inline_name.yaml",
+                "Invalid OpenAPI schema \"OrderDelivery\" (java type \"OrderDelivery\" is already generated for schema \"delivery\")",
+            ),
+            (
+                "clashing_component_names.yaml",
+                "Invalid OpenAPI schema \"OrderItem\" (java type \"OrderItem\" is already generated for schema \"order_item\")",
+            ),
+        ] {
+            let document = openapi_parser::parse(&get_test_dir("openapi").join(file_name))
+                .expect("OpenAPI document must be parsed");
+
+            let result = openapi_to_spring(
+                &document,
+                &package_dir.join("api"),
+                &package_dir.join("model"),
+            );
+
+            assert_eq!(Err(expected_err.to_string()), result, "{}", file_name);
+            assert!(!package_dir.join("api").exists());
+        }
+    }
+
+    fn get_relative_paths(files: &[PathBuf], dir: &Path) -> Vec<String> {
+        files
+            .iter()
+            .map(|file| {
+                file.strip_prefix(dir)
+                    .unwrap()
+                    .to_string_lossy()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    fn get_test_dir(name: &str) -> PathBuf {
+        test_path::get_test_dir(get_current_file_path(), name)
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
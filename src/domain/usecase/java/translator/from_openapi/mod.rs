@@ -0,0 +1 @@
+pub mod java_from_openapi_translator;
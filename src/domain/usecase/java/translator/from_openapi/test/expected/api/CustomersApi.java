@@ -0,0 +1,14 @@
+package org.test.api;
+
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.PathVariable;
+import org.springframework.web.bind.annotation.RestController;
+import org.test.model.Customer;
+
+// Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+@RestController
+public interface CustomersApi {
+    @GetMapping("/customers/{customerId}")
+    ResponseEntity<Customer> getCustomer(@PathVariable("customerId") long customerId);
+}
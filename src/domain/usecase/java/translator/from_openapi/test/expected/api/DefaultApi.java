@@ -0,0 +1,12 @@
+package org.test.api;
+
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.RestController;
+
+// Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+@RestController
+public interface DefaultApi {
+    @GetMapping("/health")
+    ResponseEntity<String> getHealth();
+}
@@ -0,0 +1,50 @@
+package org.test.api;
+
+import java.util.List;
+import java.util.UUID;
+import org.springframework.http.ResponseEntity;
+import org.springframework.web.bind.annotation.CookieValue;
+import org.springframework.web.bind.annotation.DeleteMapping;
+import org.springframework.web.bind.annotation.GetMapping;
+import org.springframework.web.bind.annotation.PathVariable;
+import org.springframework.web.bind.annotation.PostMapping;
+import org.springframework.web.bind.annotation.RequestBody;
+import org.springframework.web.bind.annotation.RequestHeader;
+import org.springframework.web.bind.annotation.RequestMapping;
+import org.springframework.web.bind.annotation.RequestMethod;
+import org.springframework.web.bind.annotation.RequestParam;
+import org.springframework.web.bind.annotation.RestController;
+import org.test.model.CreateOrderRequest;
+import org.test.model.Order;
+import org.test.model.OrderStatus;
+
+// Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+/**
+ * Orders of the customers
+ */
+@RestController
+public interface OrdersApi {
+    /**
+     * List the orders
+     *
+     * @param pageSize Orders per page
+     */
+    @GetMapping("/orders")
+    ResponseEntity<List<Order>> listOrders(@RequestParam(value = "status", required = false) OrderStatus status, @RequestParam(value = "page-size", required = false) Integer pageSize, @RequestHeader("X-Tenant") String xTenant);
+
+    /**
+     * @param body Note printed on the order
+     * @param body1 Order to create
+     */
+    @PostMapping("/orders")
+    ResponseEntity<Order> createOrder(@RequestParam(value = "body", required = false) String body, @RequestBody CreateOrderRequest body1);
+
+    /**
+     * @deprecated
+     */
+    @DeleteMapping("/orders/{orderId}")
+    ResponseEntity<Void> deleteOrder(@PathVariable("orderId") UUID orderId, @CookieValue(value = "session", required = false) String session);
+
+    @RequestMapping(value = "/orders/{orderId}", method = RequestMethod.HEAD)
+    ResponseEntity<Void> headOrdersOrderId(@PathVariable("orderId") UUID orderId);
+}
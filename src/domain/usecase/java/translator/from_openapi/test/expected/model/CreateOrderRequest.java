@@ -0,0 +1,8 @@
+package org.test.model;
+
+// Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+public class CreateOrderRequest {
+    public long customerId;
+
+    public Integer priority;
+}
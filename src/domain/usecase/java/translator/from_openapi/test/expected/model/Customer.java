@@ -0,0 +1,13 @@
+package org.test.model;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+
+// Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+public class Customer {
+    public Long id;
+
+    public String name;
+
+    @JsonProperty("first-name")
+    public String firstName;
+}
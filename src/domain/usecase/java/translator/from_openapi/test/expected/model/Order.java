@@ -0,0 +1,31 @@
+package org.test.model;
+
+import java.time.OffsetDateTime;
+import java.util.List;
+import java.util.Map;
+import java.util.UUID;
+
+// Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+/**
+ * Order placed by a customer
+ */
+public class Order {
+    public UUID id;
+
+    public OrderStatus status;
+
+    public double total;
+
+    public OffsetDateTime createdAt;
+
+    public List<OrderLinesItem> lines;
+
+    /**
+     * Where the order is delivered
+     */
+    public OrderDelivery delivery;
+
+    public OrderChannel channel;
+
+    public Map<String, Long> attributes;
+}
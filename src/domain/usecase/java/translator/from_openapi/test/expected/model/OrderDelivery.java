@@ -0,0 +1,13 @@
+package org.test.model;
+
+import java.time.LocalDate;
+
+// Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+/**
+ * Where the order is delivered
+ */
+public class OrderDelivery {
+    public String address;
+
+    public LocalDate day;
+}
@@ -0,0 +1,7 @@
+package org.test.model;
+
+// Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+public enum OrderStatus {
+    open,
+    closed
+}
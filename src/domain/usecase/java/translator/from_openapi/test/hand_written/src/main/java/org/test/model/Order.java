@@ -0,0 +1,5 @@
+package org.test.model;
+
+public class Order {
+    public String handWritten;
+}
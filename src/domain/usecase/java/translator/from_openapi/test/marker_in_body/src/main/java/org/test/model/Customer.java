@@ -0,0 +1,6 @@
+package org.test.model;
+
+public class Customer {
+    // Generated from the OpenAPI contract "Shop" 1.0.0, changes will be overwritten
+    public String handWritten;
+}
@@ -0,0 +1,2 @@
+pub mod from_avro;
+pub mod from_openapi;
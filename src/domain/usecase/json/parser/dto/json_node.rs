@@ -5,6 +5,7 @@ use std::str::FromStr;
 
 use tree_sitter::{Node, Tree};
 
+use crate::core::file_system::path_helper::try_to_absolute_path;
 use crate::core::parser::parser_node_trait::ParserNode;
 use crate::domain::usecase::json::parser::dto::json_node_type::JsonNodeType;
 
@@ -13,43 +14,51 @@ pub(crate) struct JsonNode {
     file_path: PathBuf,
     start_byte: usize,
     end_byte: usize,
+    start_line: usize,
+    start_column: usize,
     children: Vec<JsonNode>,
     node_type: Option<JsonNodeType>,
 }
 
 impl JsonNode {
-    fn new_internal(node: Node, file_path: &Path) -> Self {
+    fn new_internal(node: Node, file_path: &Path) -> Result<Self, String> {
+        if node.is_error() || node.is_missing() {
+            return Err(format!(
+                "Invalid json in file:\n{}:{}:{}\n",
+                try_to_absolute_path(file_path),
+                node.start_position().row + 1,
+                node.start_position().column + 1
+            ));
+        }
         let children = node
             .children(&mut node.walk())
             .map(|child| JsonNode::new_internal(child, file_path))
-            .collect();
+            .collect::<Result<Vec<JsonNode>, String>>()?;
 
-        JsonNode {
+        Ok(JsonNode {
             file_path: PathBuf::from(file_path),
             start_byte: node.start_byte(),
             end_byte: node.end_byte(),
+            start_line: node.start_position().row + 1,
+            start_column: node.start_position().column + 1,
             children,
-            node_type: match JsonNodeType::from_str(node.kind()) {
-                Ok(v) => Some(v),
-                Err(_e) => panic!("Not possible to parse YamlNode: {}", node.kind()),
-            },
-        }
+            node_type: JsonNodeType::from_str(node.kind()).ok(),
+        })
     }
 }
 
 impl ParserNode<JsonNodeType> for JsonNode {
     fn from_path(file_path: &Path) -> Result<Self, String> {
-        let file_path_str = file_path.to_str().expect("File path must exist");
-        let file_content = fs::read_to_string(file_path_str).unwrap_or_else(|_| {
-            panic!(
-                "File path \"{}\" should exists to parse json node",
-                file_path_str
+        let file_content = fs::read_to_string(file_path).map_err(|err| {
+            format!(
+                "Error reading json file ({}):\n{}\n",
+                err,
+                try_to_absolute_path(file_path)
             )
-        });
+        })?;
 
-        let _tree = parse_json(file_content.as_str());
-        let new_json_node = JsonNode::new_internal(_tree.root_node(), file_path);
-        Ok(new_json_node)
+        let tree = parse_json(file_content.as_str());
+        JsonNode::new_internal(tree.root_node(), file_path)
     }
 
     fn get_start_byte(&self) -> usize {
@@ -100,6 +109,16 @@ impl JsonNode {
     pub fn get_children(&self) -> &Vec<JsonNode> {
         &self.children
     }
+
+    /// Line where the node starts, the first line is 1
+    pub(crate) fn get_start_line(&self) -> usize {
+        self.start_line
+    }
+
+    /// Column (in bytes) where the node starts, the first column is 1
+    pub(crate) fn get_start_column(&self) -> usize {
+        self.start_column
+    }
 }
 
 fn parse_json(code: &str) -> Tree {
@@ -18,7 +18,10 @@ pub enum JsonNodeType {
     Colon, // :
     Array,
     Null,
+    True,
+    False,
     EscapeSequence,
+    Comment,
 }
 
 impl FromStr for JsonNodeType {
@@ -41,7 +44,10 @@ impl FromStr for JsonNodeType {
             ":" => Ok(JsonNodeType::Colon),
             "array" => Ok(JsonNodeType::Array),
             "null" => Ok(JsonNodeType::Null),
+            "true" => Ok(JsonNodeType::True),
+            "false" => Ok(JsonNodeType::False),
             "escape_sequence" => Ok(JsonNodeType::EscapeSequence),
+            "comment" => Ok(JsonNodeType::Comment),
 
             _ => Err(()),
         }
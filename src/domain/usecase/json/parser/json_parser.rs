@@ -3,8 +3,8 @@ use std::path::Path;
 use crate::core::parser::parser_node_trait::ParserNode;
 use crate::domain::usecase::json::parser::dto::json_node::JsonNode;
 
-pub(crate) fn parse(yaml_file_path: &Path) -> JsonNode {
-    JsonNode::from_path(yaml_file_path).expect("It should parse json correctly")
+pub(crate) fn parse(json_file_path: &Path) -> Result<JsonNode, String> {
+    JsonNode::from_path(json_file_path)
 }
 
 #[cfg(test)]
@@ -20,7 +20,7 @@ mod tests {
     fn parse_single_file_recognizes_all_tokens() {
         let file_path = get_test_file(&get_current_file_path(), "basic.json");
 
-        let root_node = parse(&file_path);
+        let root_node = parse(&file_path).expect("Json must be parsed");
 
         let tree_str = root_node.get_tree_str();
         let expect_result_file_path =
@@ -1,3 +1,4 @@
 pub mod avro;
+pub mod java;
 pub mod json;
 pub mod openapi;
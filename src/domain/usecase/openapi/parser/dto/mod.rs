@@ -1,2 +1,5 @@
 pub mod openapi_data_type;
+pub mod openapi_document;
+pub mod openapi_path;
 pub mod openapi_schema;
+pub mod openapi_security;
@@ -2,22 +2,28 @@ use std::fmt;
 
 use crate::domain::usecase::openapi::parser::dto::openapi_schema::OpenapiSchema;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum OpenapiDataType {
     Integer(IntegerFormat),
     String,
+    /// String with a format (i.e. "date", "date-time" or "uuid")
+    FormattedString(String),
     Null,
     Boolean,
     Number(NumberFormat),
     Bytes,
     ObjectSimple,
+    /// Inline schema, an object with properties or an enum
     Object(Box<OpenapiSchema>),
     ObjectName(String),
+    /// Union of types, written as a nullable type or as "oneOf" with several non null types
     Array(Vec<OpenapiDataType>),
     ArrayItems(Box<OpenapiDataType>),
+    /// Object with string keys and values of the given type ("additionalProperties")
+    Map(Box<OpenapiDataType>),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum IntegerFormat {
     Int32,
     Int64,
@@ -30,10 +36,12 @@ impl fmt::Display for IntegerFormat {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum NumberFormat {
     Float,
     Double,
+    /// Arbitrary precision number, written without format
+    Decimal,
 }
 
 impl fmt::Display for NumberFormat {
@@ -58,6 +66,32 @@ impl OpenapiDataType {
     pub fn new_double_type() -> OpenapiDataType {
         OpenapiDataType::Number(NumberFormat::Double)
     }
+
+    pub fn new_decimal_type() -> OpenapiDataType {
+        OpenapiDataType::Number(NumberFormat::Decimal)
+    }
+
+    /// Null or a union with null
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            OpenapiDataType::Null => true,
+            OpenapiDataType::Array(data_types) => data_types.contains(&OpenapiDataType::Null),
+            _ => false,
+        }
+    }
+
+    /// # into_nullable
+    /// Union of null and the type, null goes first.
+    pub fn into_nullable(self) -> OpenapiDataType {
+        match self {
+            data_type if data_type.is_nullable() => data_type,
+            OpenapiDataType::Array(mut data_types) => {
+                data_types.insert(0, OpenapiDataType::Null);
+                OpenapiDataType::Array(data_types)
+            }
+            data_type => OpenapiDataType::Array(vec![OpenapiDataType::Null, data_type]),
+        }
+    }
 }
 
 impl fmt::Display for OpenapiDataType {
@@ -0,0 +1,240 @@
+use crate::domain::usecase::openapi::parser::dto::openapi_path::OpenapiPath;
+use crate::domain::usecase::openapi::parser::dto::openapi_schema::OpenapiSchema;
+use crate::domain::usecase::openapi::parser::dto::openapi_security::{
+    OpenapiSecurityRequirement, OpenapiSecurityScheme,
+};
+
+/// # OpenapiDocument
+/// OpenAPI 3 document with its "$ref" already resolved, schemas of other files
+/// referenced by the document are part of its components.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiDocument {
+    openapi: String,
+    info: OpenapiInfo,
+    servers: Vec<OpenapiServer>,
+    paths: Vec<OpenapiPath>,
+    components: OpenapiComponents,
+    security: Vec<OpenapiSecurityRequirement>,
+    tags: Vec<OpenapiTag>,
+}
+
+impl OpenapiDocument {
+    pub fn builder() -> OpenapiDocumentBuilder {
+        OpenapiDocumentBuilder::new_builder()
+    }
+
+    /// # get_openapi
+    /// Version of the specification (i.e. "3.0.3" or "3.1.0").
+    pub fn get_openapi(&self) -> &str {
+        &self.openapi
+    }
+
+    pub fn get_info(&self) -> &OpenapiInfo {
+        &self.info
+    }
+
+    pub fn get_servers(&self) -> &Vec<OpenapiServer> {
+        &self.servers
+    }
+
+    pub fn get_paths(&self) -> &Vec<OpenapiPath> {
+        &self.paths
+    }
+
+    pub fn get_components(&self) -> &OpenapiComponents {
+        &self.components
+    }
+
+    /// # get_security
+    /// Security requirements of every operation without its own "security".
+    pub fn get_security(&self) -> &Vec<OpenapiSecurityRequirement> {
+        &self.security
+    }
+
+    pub fn get_tags(&self) -> &Vec<OpenapiTag> {
+        &self.tags
+    }
+}
+
+pub struct OpenapiDocumentBuilder {
+    openapi: Option<String>,
+    info: Option<OpenapiInfo>,
+    servers: Vec<OpenapiServer>,
+    paths: Vec<OpenapiPath>,
+    components: OpenapiComponents,
+    security: Vec<OpenapiSecurityRequirement>,
+    tags: Vec<OpenapiTag>,
+}
+
+impl OpenapiDocumentBuilder {
+    fn new_builder() -> Self {
+        Self {
+            openapi: None,
+            info: None,
+            servers: vec![],
+            paths: vec![],
+            components: OpenapiComponents::new(vec![], vec![]),
+            security: vec![],
+            tags: vec![],
+        }
+    }
+
+    pub fn openapi(&mut self, input: &str) -> &mut Self {
+        self.openapi = Some(input.to_string());
+        self
+    }
+
+    pub fn info(&mut self, input: OpenapiInfo) -> &mut Self {
+        self.info = Some(input);
+        self
+    }
+
+    pub fn servers(&mut self, input: Vec<OpenapiServer>) -> &mut Self {
+        self.servers = input;
+        self
+    }
+
+    pub fn paths(&mut self, input: Vec<OpenapiPath>) -> &mut Self {
+        self.paths = input;
+        self
+    }
+
+    pub fn components(&mut self, input: OpenapiComponents) -> &mut Self {
+        self.components = input;
+        self
+    }
+
+    pub fn security(&mut self, input: Vec<OpenapiSecurityRequirement>) -> &mut Self {
+        self.security = input;
+        self
+    }
+
+    pub fn tags(&mut self, input: Vec<OpenapiTag>) -> &mut Self {
+        self.tags = input;
+        self
+    }
+
+    pub fn build(&mut self) -> Result<OpenapiDocument, String> {
+        let openapi = self
+            .openapi
+            .clone()
+            .ok_or("Invalid openapi document build, openapi version is mandatory".to_string())?;
+        let info = self
+            .info
+            .clone()
+            .ok_or("Invalid openapi document build, info is mandatory".to_string())?;
+
+        Ok(OpenapiDocument {
+            openapi,
+            info,
+            servers: self.servers.clone(),
+            paths: self.paths.clone(),
+            components: self.components.clone(),
+            security: self.security.clone(),
+            tags: self.tags.clone(),
+        })
+    }
+}
+
+/// # OpenapiInfo
+/// Metadata of the API.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiInfo {
+    title: String,
+    description: Option<String>,
+    version: String,
+}
+
+impl OpenapiInfo {
+    pub fn new(title: String, description: Option<String>, version: String) -> Self {
+        Self {
+            title,
+            description,
+            version,
+        }
+    }
+
+    pub fn get_title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    /// # get_version
+    /// Version of the API, not the one of the specification.
+    pub fn get_version(&self) -> &str {
+        &self.version
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiServer {
+    url: String,
+    description: Option<String>,
+}
+
+impl OpenapiServer {
+    pub fn new(url: String, description: Option<String>) -> Self {
+        Self { url, description }
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiTag {
+    name: String,
+    description: Option<String>,
+}
+
+impl OpenapiTag {
+    pub fn new(name: String, description: Option<String>) -> Self {
+        Self { name, description }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+}
+
+/// # OpenapiComponents
+/// Reusable schemas and security schemes. Parameters, request bodies and responses
+/// components are inlined where they are referenced.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiComponents {
+    schemas: Vec<OpenapiSchema>,
+    security_schemes: Vec<OpenapiSecurityScheme>,
+}
+
+impl OpenapiComponents {
+    pub fn new(schemas: Vec<OpenapiSchema>, security_schemes: Vec<OpenapiSecurityScheme>) -> Self {
+        Self {
+            schemas,
+            security_schemes,
+        }
+    }
+
+    pub fn get_schemas(&self) -> &Vec<OpenapiSchema> {
+        &self.schemas
+    }
+
+    pub fn get_schema(&self, name: &str) -> Option<&OpenapiSchema> {
+        self.schemas.iter().find(|schema| name == schema.get_name())
+    }
+
+    pub fn get_security_schemes(&self) -> &Vec<OpenapiSecurityScheme> {
+        &self.security_schemes
+    }
+}
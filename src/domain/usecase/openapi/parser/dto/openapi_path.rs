@@ -0,0 +1,372 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::domain::usecase::openapi::parser::dto::openapi_data_type::OpenapiDataType;
+use crate::domain::usecase::openapi::parser::dto::openapi_security::OpenapiSecurityRequirement;
+
+/// # OpenapiPath
+/// Operations available for a route (i.e. "/pets/{petId}").
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiPath {
+    route: String,
+    operations: Vec<OpenapiOperation>,
+}
+
+impl OpenapiPath {
+    pub fn new(route: String, operations: Vec<OpenapiOperation>) -> Self {
+        Self { route, operations }
+    }
+
+    pub fn get_route(&self) -> &str {
+        &self.route
+    }
+
+    pub fn get_operations(&self) -> &Vec<OpenapiOperation> {
+        &self.operations
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum OpenapiHttpMethod {
+    Get,
+    Put,
+    Post,
+    Delete,
+    Options,
+    Head,
+    Patch,
+    Trace,
+}
+
+impl OpenapiHttpMethod {
+    /// # values
+    /// Methods in the order of the OpenAPI path item object.
+    pub fn values() -> [OpenapiHttpMethod; 8] {
+        [
+            OpenapiHttpMethod::Get,
+            OpenapiHttpMethod::Put,
+            OpenapiHttpMethod::Post,
+            OpenapiHttpMethod::Delete,
+            OpenapiHttpMethod::Options,
+            OpenapiHttpMethod::Head,
+            OpenapiHttpMethod::Patch,
+            OpenapiHttpMethod::Trace,
+        ]
+    }
+}
+
+impl FromStr for OpenapiHttpMethod {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<OpenapiHttpMethod, ()> {
+        OpenapiHttpMethod::values()
+            .into_iter()
+            .find(|method| method.to_string() == input)
+            .ok_or(())
+    }
+}
+
+impl fmt::Display for OpenapiHttpMethod {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
+    }
+}
+
+/// # OpenapiOperation
+/// Operation of a path, the parameters include the ones declared for the whole path.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiOperation {
+    method: OpenapiHttpMethod,
+    operation_id: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    tags: Vec<String>,
+    parameters: Vec<OpenapiParameter>,
+    request_body: Option<OpenapiRequestBody>,
+    responses: Vec<OpenapiResponse>,
+    security: Option<Vec<OpenapiSecurityRequirement>>,
+    deprecated: bool,
+}
+
+impl OpenapiOperation {
+    pub fn builder(method: OpenapiHttpMethod) -> OpenapiOperationBuilder {
+        OpenapiOperationBuilder::new_builder(method)
+    }
+
+    pub fn get_method(&self) -> OpenapiHttpMethod {
+        self.method
+    }
+
+    pub fn get_operation_id(&self) -> Option<String> {
+        self.operation_id.clone()
+    }
+
+    pub fn get_summary(&self) -> Option<String> {
+        self.summary.clone()
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_tags(&self) -> &Vec<String> {
+        &self.tags
+    }
+
+    pub fn get_parameters(&self) -> &Vec<OpenapiParameter> {
+        &self.parameters
+    }
+
+    pub fn get_request_body(&self) -> Option<&OpenapiRequestBody> {
+        self.request_body.as_ref()
+    }
+
+    pub fn get_responses(&self) -> &Vec<OpenapiResponse> {
+        &self.responses
+    }
+
+    /// # get_security
+    /// Security requirements of the operation, None when the document ones apply.
+    pub fn get_security(&self) -> Option<&Vec<OpenapiSecurityRequirement>> {
+        self.security.as_ref()
+    }
+
+    pub fn is_deprecated(&self) -> bool {
+        self.deprecated
+    }
+}
+
+pub struct OpenapiOperationBuilder {
+    operation: OpenapiOperation,
+}
+
+impl OpenapiOperationBuilder {
+    fn new_builder(method: OpenapiHttpMethod) -> Self {
+        Self {
+            operation: OpenapiOperation {
+                method,
+                operation_id: None,
+                summary: None,
+                description: None,
+                tags: vec![],
+                parameters: vec![],
+                request_body: None,
+                responses: vec![],
+                security: None,
+                deprecated: false,
+            },
+        }
+    }
+
+    pub fn operation_id(&mut self, input: Option<String>) -> &mut Self {
+        self.operation.operation_id = input;
+        self
+    }
+
+    pub fn summary(&mut self, input: Option<String>) -> &mut Self {
+        self.operation.summary = input;
+        self
+    }
+
+    pub fn description(&mut self, input: Option<String>) -> &mut Self {
+        self.operation.description = input;
+        self
+    }
+
+    pub fn tags(&mut self, input: Vec<String>) -> &mut Self {
+        self.operation.tags = input;
+        self
+    }
+
+    pub fn parameters(&mut self, input: Vec<OpenapiParameter>) -> &mut Self {
+        self.operation.parameters = input;
+        self
+    }
+
+    pub fn request_body(&mut self, input: Option<OpenapiRequestBody>) -> &mut Self {
+        self.operation.request_body = input;
+        self
+    }
+
+    pub fn responses(&mut self, input: Vec<OpenapiResponse>) -> &mut Self {
+        self.operation.responses = input;
+        self
+    }
+
+    pub fn security(&mut self, input: Option<Vec<OpenapiSecurityRequirement>>) -> &mut Self {
+        self.operation.security = input;
+        self
+    }
+
+    pub fn deprecated(&mut self, input: bool) -> &mut Self {
+        self.operation.deprecated = input;
+        self
+    }
+
+    pub fn build(&mut self) -> OpenapiOperation {
+        self.operation.clone()
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum OpenapiParameterLocation {
+    Path,
+    Query,
+    Header,
+    Cookie,
+}
+
+impl FromStr for OpenapiParameterLocation {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<OpenapiParameterLocation, ()> {
+        match input {
+            "path" => Ok(OpenapiParameterLocation::Path),
+            "query" => Ok(OpenapiParameterLocation::Query),
+            "header" => Ok(OpenapiParameterLocation::Header),
+            "cookie" => Ok(OpenapiParameterLocation::Cookie),
+            _ => Err(()),
+        }
+    }
+}
+
+impl fmt::Display for OpenapiParameterLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", format!("{:?}", self).to_lowercase())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiParameter {
+    name: String,
+    location: OpenapiParameterLocation,
+    description: Option<String>,
+    required: bool,
+    schema: Option<OpenapiDataType>,
+}
+
+impl OpenapiParameter {
+    pub fn new(
+        name: String,
+        location: OpenapiParameterLocation,
+        description: Option<String>,
+        required: bool,
+        schema: Option<OpenapiDataType>,
+    ) -> Self {
+        Self {
+            name,
+            location,
+            description,
+            required,
+            schema,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_location(&self) -> OpenapiParameterLocation {
+        self.location
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    /// # is_required
+    /// Path parameters are always required.
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    pub fn get_schema(&self) -> Option<&OpenapiDataType> {
+        self.schema.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiRequestBody {
+    description: Option<String>,
+    required: bool,
+    content: Vec<OpenapiMediaType>,
+}
+
+impl OpenapiRequestBody {
+    pub fn new(
+        description: Option<String>,
+        required: bool,
+        content: Vec<OpenapiMediaType>,
+    ) -> Self {
+        Self {
+            description,
+            required,
+            content,
+        }
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn is_required(&self) -> bool {
+        self.required
+    }
+
+    pub fn get_content(&self) -> &Vec<OpenapiMediaType> {
+        &self.content
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiResponse {
+    status_code: String,
+    description: String,
+    content: Vec<OpenapiMediaType>,
+}
+
+impl OpenapiResponse {
+    pub fn new(status_code: String, description: String, content: Vec<OpenapiMediaType>) -> Self {
+        Self {
+            status_code,
+            description,
+            content,
+        }
+    }
+
+    /// # get_status_code
+    /// HTTP status code, a range (i.e. "2XX") or "default".
+    pub fn get_status_code(&self) -> &str {
+        &self.status_code
+    }
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn get_content(&self) -> &Vec<OpenapiMediaType> {
+        &self.content
+    }
+}
+
+/// # OpenapiMediaType
+/// Schema of a request or response body for a media type (i.e. "application/json").
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiMediaType {
+    media_type: String,
+    schema: Option<OpenapiDataType>,
+}
+
+impl OpenapiMediaType {
+    pub fn new(media_type: String, schema: Option<OpenapiDataType>) -> Self {
+        Self { media_type, schema }
+    }
+
+    pub fn get_media_type(&self) -> &str {
+        &self.media_type
+    }
+
+    pub fn get_schema(&self) -> Option<&OpenapiDataType> {
+        self.schema.as_ref()
+    }
+}
@@ -0,0 +1,132 @@
+/// # OpenapiSecurityScheme
+/// Security scheme declared in the components, referenced by name from the
+/// security requirements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiSecurityScheme {
+    name: String,
+    description: Option<String>,
+    scheme_type: OpenapiSecuritySchemeType,
+}
+
+impl OpenapiSecurityScheme {
+    pub fn new(
+        name: String,
+        description: Option<String>,
+        scheme_type: OpenapiSecuritySchemeType,
+    ) -> Self {
+        Self {
+            name,
+            description,
+            scheme_type,
+        }
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_description(&self) -> Option<String> {
+        self.description.clone()
+    }
+
+    pub fn get_scheme_type(&self) -> &OpenapiSecuritySchemeType {
+        &self.scheme_type
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpenapiSecuritySchemeType {
+    ApiKey {
+        name: String,
+        location: OpenapiApiKeyLocation,
+    },
+    /// HTTP authentication scheme (i.e. "basic" or "bearer")
+    Http {
+        scheme: String,
+        bearer_format: Option<String>,
+    },
+    OAuth2(Vec<OpenapiOAuthFlow>),
+    OpenIdConnect(String),
+    MutualTls,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenapiApiKeyLocation {
+    Query,
+    Header,
+    Cookie,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenapiOAuthFlowType {
+    Implicit,
+    Password,
+    ClientCredentials,
+    AuthorizationCode,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiOAuthFlow {
+    flow_type: OpenapiOAuthFlowType,
+    authorization_url: Option<String>,
+    token_url: Option<String>,
+    refresh_url: Option<String>,
+    /// Scope names with their description
+    scopes: Vec<(String, String)>,
+}
+
+impl OpenapiOAuthFlow {
+    pub fn new(
+        flow_type: OpenapiOAuthFlowType,
+        authorization_url: Option<String>,
+        token_url: Option<String>,
+        refresh_url: Option<String>,
+        scopes: Vec<(String, String)>,
+    ) -> Self {
+        Self {
+            flow_type,
+            authorization_url,
+            token_url,
+            refresh_url,
+            scopes,
+        }
+    }
+
+    pub fn get_flow_type(&self) -> OpenapiOAuthFlowType {
+        self.flow_type
+    }
+
+    pub fn get_authorization_url(&self) -> Option<String> {
+        self.authorization_url.clone()
+    }
+
+    pub fn get_token_url(&self) -> Option<String> {
+        self.token_url.clone()
+    }
+
+    pub fn get_refresh_url(&self) -> Option<String> {
+        self.refresh_url.clone()
+    }
+
+    pub fn get_scopes(&self) -> &Vec<(String, String)> {
+        &self.scopes
+    }
+}
+
+/// # OpenapiSecurityRequirement
+/// Security schemes that must be satisfied together, with the scopes required by each one.
+/// A list of requirements is satisfied when any of them is.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpenapiSecurityRequirement {
+    schemes: Vec<(String, Vec<String>)>,
+}
+
+impl OpenapiSecurityRequirement {
+    pub fn new(schemes: Vec<(String, Vec<String>)>) -> Self {
+        Self { schemes }
+    }
+
+    pub fn get_schemes(&self) -> &Vec<(String, Vec<String>)> {
+        &self.schemes
+    }
+}
@@ -1 +1,3 @@
 pub mod dto;
+pub(crate) mod openapi_node;
+pub mod openapi_parser;
@@ -0,0 +1,561 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::core::file_system::file_cache::FileCache;
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::parser::parser_node_trait::ParserNode;
+use crate::domain::usecase::avro::parser::avro_parser::unescape_json;
+use crate::domain::usecase::json::parser::dto::json_node::JsonNode;
+use crate::domain::usecase::json::parser::dto::json_node_type::JsonNodeType;
+use crate::domain::usecase::json::parser::json_parser;
+use crate::yaml::parser::dto::yaml_node::YamlNode;
+use crate::yaml::parser::dto::yaml_node_type::YamlNodeType;
+
+/// # OpenapiNode
+/// Value of a YAML or JSON document with the location where it starts,
+/// so OpenAPI documents are read the same way whatever their format is.
+#[derive(Debug, Clone)]
+pub(crate) struct OpenapiNode {
+    value: OpenapiNodeValue,
+    start_line: usize,
+    start_column: usize,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum OpenapiNodeValue {
+    Null,
+    Boolean(bool),
+    /// Number as written in the document
+    Number(String),
+    String(String),
+    Sequence(Vec<OpenapiNode>),
+    /// Keys in declaration order
+    Mapping(Vec<(String, OpenapiNode)>),
+}
+
+impl OpenapiNode {
+    /// # from_path
+    /// Files with "json" extension are parsed as JSON, any other one as YAML.
+    pub(crate) fn from_path(file_path: &Path) -> Result<Self, String> {
+        if !file_path.is_file() {
+            return Err(format!(
+                "Openapi file not found:\n{}\n",
+                try_to_absolute_path(file_path)
+            ));
+        }
+        let is_json = file_path
+            .extension()
+            .is_some_and(|extension| "json" == extension);
+        if is_json {
+            let root_node = json_parser::parse(file_path)?;
+            JsonLoader::new(file_path).read_node(&root_node)
+        } else {
+            let root_node = YamlNode::from_path(file_path)?;
+            YamlLoader::new(file_path).load(&root_node)
+        }
+    }
+
+    pub(crate) fn get_value(&self) -> &OpenapiNodeValue {
+        &self.value
+    }
+
+    /// Line where the node starts, the first line is 1
+    pub(crate) fn get_start_line(&self) -> usize {
+        self.start_line
+    }
+
+    /// Column (in bytes) where the node starts, the first column is 1
+    pub(crate) fn get_start_column(&self) -> usize {
+        self.start_column
+    }
+
+    /// Value of a mapping key, None for missing keys or other kind of nodes
+    pub(crate) fn get(&self, key: &str) -> Option<&OpenapiNode> {
+        self.get_entries()?
+            .iter()
+            .find(|(entry_key, _)| key == entry_key)
+            .map(|(_, value)| value)
+    }
+
+    pub(crate) fn get_entries(&self) -> Option<&Vec<(String, OpenapiNode)>> {
+        match &self.value {
+            OpenapiNodeValue::Mapping(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_items(&self) -> Option<&Vec<OpenapiNode>> {
+        match &self.value {
+            OpenapiNodeValue::Sequence(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_str(&self) -> Option<&str> {
+        match &self.value {
+            OpenapiNodeValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn get_bool(&self) -> Option<bool> {
+        match &self.value {
+            OpenapiNodeValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Scalars as text (strings without quotation marks), None for sequences and mappings
+    pub(crate) fn get_scalar_str(&self) -> Option<String> {
+        match &self.value {
+            OpenapiNodeValue::Null => Some("null".to_string()),
+            OpenapiNodeValue::Boolean(value) => Some(value.to_string()),
+            OpenapiNodeValue::Number(value) | OpenapiNodeValue::String(value) => {
+                Some(value.clone())
+            }
+            _ => None,
+        }
+    }
+
+    pub(crate) fn is_null(&self) -> bool {
+        matches!(self.value, OpenapiNodeValue::Null)
+    }
+
+    /// # get_pointer
+    /// Node of a JSON pointer (i.e. "/components/schemas/Pet"), the empty pointer is the node itself.
+    pub(crate) fn get_pointer(&self, pointer: &str) -> Option<&OpenapiNode> {
+        if pointer.is_empty() {
+            return Some(self);
+        }
+        let mut current = self;
+        for token in pointer.strip_prefix('/')?.split('/') {
+            let token = token.replace("~1", "/").replace("~0", "~");
+            current = match &current.value {
+                OpenapiNodeValue::Mapping(_) => current.get(&token)?,
+                OpenapiNodeValue::Sequence(items) => items.get(token.parse::<usize>().ok()?)?,
+                _ => return None,
+            };
+        }
+        Some(current)
+    }
+
+    /// JSON text of the node, valid YAML too
+    pub(crate) fn to_json(&self) -> String {
+        match &self.value {
+            OpenapiNodeValue::Null => "null".to_string(),
+            OpenapiNodeValue::Boolean(value) => value.to_string(),
+            OpenapiNodeValue::Number(value) => value.clone(),
+            OpenapiNodeValue::String(value) => escape_json(value),
+            OpenapiNodeValue::Sequence(items) => {
+                let items: Vec<String> = items.iter().map(|item| item.to_json()).collect();
+                format!("[{}]", items.join(", "))
+            }
+            OpenapiNodeValue::Mapping(entries) => {
+                let entries: Vec<String> = entries
+                    .iter()
+                    .map(|(key, value)| format!("{}: {}", escape_json(key), value.to_json()))
+                    .collect();
+                format!("{{{}}}", entries.join(", "))
+            }
+        }
+    }
+}
+
+pub(crate) fn escape_json(value: &str) -> String {
+    let mut result = String::from('"');
+    for current in value.chars() {
+        match current {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            control if control < ' ' => result.push_str(&format!("\\u{:04x}", control as u32)),
+            _ => result.push(current),
+        }
+    }
+    result.push('"');
+    result
+}
+
+struct JsonLoader {
+    file_cache: FileCache,
+}
+
+impl JsonLoader {
+    fn new(file_path: &Path) -> Self {
+        Self {
+            file_cache: FileCache::from(file_path),
+        }
+    }
+
+    fn read_node(&self, node: &JsonNode) -> Result<OpenapiNode, String> {
+        let value = match node.get_node_type() {
+            Some(JsonNodeType::Document) => {
+                let value = node
+                    .get_children()
+                    .iter()
+                    .find(|child| is_json_value(child))
+                    .ok_or(self.get_error(node, "empty document"))?;
+                return self.read_node(value);
+            }
+            Some(JsonNodeType::Object) => {
+                let mut entries = vec![];
+                for pair in node
+                    .get_children()
+                    .iter()
+                    .filter(|child| Some(JsonNodeType::Pair) == child.get_node_type())
+                {
+                    let mut pair_values = pair
+                        .get_children()
+                        .iter()
+                        .filter(|child| is_json_value(child));
+                    if let (Some(key), Some(value)) = (pair_values.next(), pair_values.next()) {
+                        entries.push((self.get_string(key), self.read_node(value)?));
+                    }
+                }
+                OpenapiNodeValue::Mapping(entries)
+            }
+            Some(JsonNodeType::Array) => OpenapiNodeValue::Sequence(
+                node.get_children()
+                    .iter()
+                    .filter(|child| is_json_value(child))
+                    .map(|child| self.read_node(child))
+                    .collect::<Result<Vec<OpenapiNode>, String>>()?,
+            ),
+            Some(JsonNodeType::String) => OpenapiNodeValue::String(self.get_string(node)),
+            Some(JsonNodeType::Number) => {
+                OpenapiNodeValue::Number(node.get_content_from_cache(&self.file_cache))
+            }
+            Some(JsonNodeType::True) => OpenapiNodeValue::Boolean(true),
+            Some(JsonNodeType::False) => OpenapiNodeValue::Boolean(false),
+            Some(JsonNodeType::Null) => OpenapiNodeValue::Null,
+            _ => return Err(self.get_error(node, "unexpected json value")),
+        };
+
+        Ok(OpenapiNode {
+            value,
+            start_line: node.get_start_line(),
+            start_column: node.get_start_column(),
+        })
+    }
+
+    fn get_string(&self, node: &JsonNode) -> String {
+        let content = node.get_content_from_cache(&self.file_cache);
+        let content = content
+            .strip_prefix('"')
+            .and_then(|content| content.strip_suffix('"'))
+            .unwrap_or(&content);
+        unescape_json(content)
+    }
+
+    fn get_error(&self, node: &JsonNode, message: &str) -> String {
+        format!(
+            "Invalid json ({}) in file:\n{}:{}:{}\n",
+            message,
+            try_to_absolute_path(self.file_cache.get_path()),
+            node.get_start_line(),
+            node.get_start_column()
+        )
+    }
+}
+
+fn is_json_value(node: &JsonNode) -> bool {
+    matches!(
+        node.get_node_type(),
+        Some(JsonNodeType::Object)
+            | Some(JsonNodeType::Array)
+            | Some(JsonNodeType::String)
+            | Some(JsonNodeType::Number)
+            | Some(JsonNodeType::True)
+            | Some(JsonNodeType::False)
+            | Some(JsonNodeType::Null)
+    )
+}
+
+struct YamlLoader {
+    file_cache: FileCache,
+    anchors: HashMap<String, OpenapiNode>,
+}
+
+impl YamlLoader {
+    fn new(file_path: &Path) -> Self {
+        Self {
+            file_cache: FileCache::from(file_path),
+            anchors: HashMap::new(),
+        }
+    }
+
+    fn load(&mut self, root_node: &YamlNode) -> Result<OpenapiNode, String> {
+        if let Some(error_node) = find_error(root_node) {
+            return Err(self.get_error(error_node, "syntax error"));
+        }
+        let value = root_node
+            .get_children()
+            .iter()
+            .find(|child| Some(YamlNodeType::Document) == child.get_node_type())
+            .and_then(|document| get_yaml_values(document).into_iter().next());
+        match value {
+            Some(value) => self.read_node(value),
+            None => Err(self.get_error(root_node, "empty document")),
+        }
+    }
+
+    fn read_node(&mut self, node: &YamlNode) -> Result<OpenapiNode, String> {
+        let value = match node.get_node_type() {
+            Some(YamlNodeType::BlockNode) | Some(YamlNodeType::FlowNode) => {
+                return self.read_decorated_node(node)
+            }
+            Some(YamlNodeType::BlockMapping) | Some(YamlNodeType::FlowMapping) => {
+                let mut entries = vec![];
+                for child in node.get_children() {
+                    match child.get_node_type() {
+                        Some(YamlNodeType::BlockMappingPair) | Some(YamlNodeType::FlowPair) => {
+                            entries.push(self.read_entry(child)?)
+                        }
+                        Some(YamlNodeType::FlowNode) => {
+                            entries.push((self.read_key(child)?, self.new_null(child)))
+                        }
+                        _ => {}
+                    }
+                }
+                OpenapiNodeValue::Mapping(entries)
+            }
+            Some(YamlNodeType::BlockSequence) | Some(YamlNodeType::FlowSequence) => {
+                let mut items = vec![];
+                for child in node.get_children() {
+                    match child.get_node_type() {
+                        Some(YamlNodeType::BlockSequenceItem) => {
+                            items.push(match get_yaml_values(child).first() {
+                                Some(value) => self.read_node(value)?,
+                                None => self.new_null(child),
+                            })
+                        }
+                        Some(YamlNodeType::FlowNode) => items.push(self.read_node(child)?),
+                        Some(YamlNodeType::FlowPair) => {
+                            let entry = self.read_entry(child)?;
+                            items.push(self.new_node(child, OpenapiNodeValue::Mapping(vec![entry])))
+                        }
+                        _ => {}
+                    }
+                }
+                OpenapiNodeValue::Sequence(items)
+            }
+            Some(YamlNodeType::PlainScalar) => {
+                let content = self.get_content(node);
+                let scalar_type = node
+                    .get_children()
+                    .first()
+                    .and_then(|child| child.get_node_type());
+                match scalar_type {
+                    Some(YamlNodeType::IntegerScalar) | Some(YamlNodeType::FloatScalar) => {
+                        OpenapiNodeValue::Number(content)
+                    }
+                    Some(YamlNodeType::BooleanScalar) => {
+                        OpenapiNodeValue::Boolean("true" == content.to_lowercase())
+                    }
+                    Some(YamlNodeType::NullScalar) => OpenapiNodeValue::Null,
+                    _ => OpenapiNodeValue::String(fold_lines(&content)),
+                }
+            }
+            Some(YamlNodeType::DoubleQuoteScalar) => {
+                let content = self.get_content(node);
+                let content = strip_quotes(&content, '"');
+                OpenapiNodeValue::String(unescape_json(&fold_lines(content)))
+            }
+            Some(YamlNodeType::SingleQuoteScalar) => {
+                let content = self.get_content(node);
+                let content = strip_quotes(&content, '\'');
+                OpenapiNodeValue::String(fold_lines(content).replace("''", "'"))
+            }
+            Some(YamlNodeType::BlockScalar) => {
+                OpenapiNodeValue::String(get_block_scalar(&self.get_content(node)))
+            }
+            Some(YamlNodeType::Alias) => {
+                let content = self.get_content(node);
+                let anchor_name = content.trim_start_matches('*');
+                match self.anchors.get(anchor_name) {
+                    Some(anchored) => return Ok(anchored.clone()),
+                    None => {
+                        return Err(
+                            self.get_error(node, &format!("unknown alias \"{}\"", anchor_name))
+                        )
+                    }
+                }
+            }
+            _ => return Err(self.get_error(node, "unexpected yaml node")),
+        };
+
+        Ok(self.new_node(node, value))
+    }
+
+    /// Node with its optional anchor and tag
+    fn read_decorated_node(&mut self, node: &YamlNode) -> Result<OpenapiNode, String> {
+        let mut anchor_name = None;
+        let mut is_string_tag = false;
+        let mut value = None;
+        for child in node.get_children() {
+            match child.get_node_type() {
+                Some(YamlNodeType::Anchor) => {
+                    anchor_name = Some(self.get_content(child).trim_start_matches('&').to_string())
+                }
+                Some(YamlNodeType::Tag) => is_string_tag = "!!str" == self.get_content(child),
+                Some(YamlNodeType::Comment) => {}
+                _ if value.is_none() => value = Some(self.read_node(child)?),
+                _ => {}
+            }
+        }
+
+        let mut result = value.unwrap_or(self.new_null(node));
+        if is_string_tag {
+            if let Some(scalar) = result.get_scalar_str() {
+                result.value = OpenapiNodeValue::String(scalar);
+            }
+        }
+        if let Some(anchor_name) = anchor_name {
+            self.anchors.insert(anchor_name, result.clone());
+        }
+        Ok(result)
+    }
+
+    /// Key and value of a pair, missing values are null
+    fn read_entry(&mut self, pair: &YamlNode) -> Result<(String, OpenapiNode), String> {
+        let children = pair.get_children();
+        let colon_index = children
+            .iter()
+            .position(|child| Some(YamlNodeType::Colon) == child.get_node_type())
+            .unwrap_or(children.len());
+        let key = children[..colon_index]
+            .iter()
+            .find(|child| is_yaml_value(child))
+            .ok_or(self.get_error(pair, "missing key"))?;
+        let key = self.read_key(key)?;
+        let value = match children[colon_index..]
+            .iter()
+            .find(|child| is_yaml_value(child))
+        {
+            Some(value) => self.read_node(value)?,
+            None => self.new_null(pair),
+        };
+        Ok((key, value))
+    }
+
+    fn read_key(&mut self, node: &YamlNode) -> Result<String, String> {
+        self.read_node(node)?
+            .get_scalar_str()
+            .ok_or(self.get_error(node, "keys must be scalars"))
+    }
+
+    fn new_node(&self, node: &YamlNode, value: OpenapiNodeValue) -> OpenapiNode {
+        OpenapiNode {
+            value,
+            start_line: node.get_start_line(),
+            start_column: node.get_start_column(),
+        }
+    }
+
+    fn new_null(&self, node: &YamlNode) -> OpenapiNode {
+        self.new_node(node, OpenapiNodeValue::Null)
+    }
+
+    fn get_content(&self, node: &YamlNode) -> String {
+        node.get_content_from_cache(&self.file_cache)
+    }
+
+    fn get_error(&self, node: &YamlNode, message: &str) -> String {
+        format!(
+            "Invalid yaml ({}) in file:\n{}:{}:{}\n",
+            message,
+            try_to_absolute_path(self.file_cache.get_path()),
+            node.get_start_line(),
+            node.get_start_column()
+        )
+    }
+}
+
+fn find_error(node: &YamlNode) -> Option<&YamlNode> {
+    if Some(YamlNodeType::Error) == node.get_node_type() {
+        return Some(node);
+    }
+    node.get_children().iter().find_map(find_error)
+}
+
+fn get_yaml_values(node: &YamlNode) -> Vec<&YamlNode> {
+    node.get_children()
+        .iter()
+        .filter(|child| is_yaml_value(child))
+        .collect()
+}
+
+fn is_yaml_value(node: &YamlNode) -> bool {
+    matches!(
+        node.get_node_type(),
+        Some(YamlNodeType::BlockNode) | Some(YamlNodeType::FlowNode)
+    )
+}
+
+fn strip_quotes(content: &str, quote: char) -> &str {
+    content
+        .strip_prefix(quote)
+        .and_then(|content| content.strip_suffix(quote))
+        .unwrap_or(content)
+}
+
+/// Multi-line flow scalars: line breaks are spaces and empty lines are line breaks
+fn fold_lines(content: &str) -> String {
+    if !content.contains('\n') {
+        return content.to_string();
+    }
+    let mut result = String::new();
+    for line in content.lines().map(|line| line.trim()) {
+        if line.is_empty() {
+            result.push('\n');
+            continue;
+        }
+        if !result.is_empty() && !result.ends_with('\n') {
+            result.push(' ');
+        }
+        result.push_str(line);
+    }
+    result
+}
+
+/// Literal ("|") or folded (">") block scalar without its header and indentation
+fn get_block_scalar(content: &str) -> String {
+    let mut lines = content.lines();
+    let header = lines.next().unwrap_or_default().trim();
+    let lines: Vec<&str> = lines.collect();
+    let indentation = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let lines: Vec<&str> = lines
+        .iter()
+        .map(|line| line.get(indentation..).unwrap_or_default())
+        .collect();
+
+    let mut result = if header.starts_with('>') {
+        let mut folded = String::new();
+        for line in &lines {
+            if line.is_empty() {
+                folded.push('\n');
+            } else {
+                if !folded.is_empty() && !folded.ends_with('\n') {
+                    folded.push(' ');
+                }
+                folded.push_str(line);
+            }
+        }
+        folded
+    } else {
+        lines.join("\n")
+    };
+    let content_length = result.trim_end_matches('\n').len();
+    result.truncate(content_length);
+    if !header.contains('-') && !result.is_empty() {
+        result.push('\n');
+    }
+    result
+}
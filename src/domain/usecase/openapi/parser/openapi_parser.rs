@@ -0,0 +1,1399 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::str::FromStr;
+
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::domain::usecase::openapi::parser::dto::openapi_data_type::OpenapiDataType;
+use crate::domain::usecase::openapi::parser::dto::openapi_document::{
+    OpenapiComponents, OpenapiDocument, OpenapiInfo, OpenapiServer, OpenapiTag,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_path::{
+    OpenapiHttpMethod, OpenapiMediaType, OpenapiOperation, OpenapiParameter,
+    OpenapiParameterLocation, OpenapiPath, OpenapiRequestBody, OpenapiResponse,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_schema::OpenapiSchema;
+use crate::domain::usecase::openapi::parser::dto::openapi_security::{
+    OpenapiApiKeyLocation, OpenapiOAuthFlow, OpenapiOAuthFlowType, OpenapiSecurityRequirement,
+    OpenapiSecurityScheme, OpenapiSecuritySchemeType,
+};
+use crate::domain::usecase::openapi::parser::openapi_node::{OpenapiNode, OpenapiNodeValue};
+
+const COMPONENT_SCHEMAS_POINTER: &str = "/components/schemas/";
+const MAX_REF_DEPTH: usize = 64;
+
+/// # parse
+/// OpenAPI 3.0 or 3.1 document of a YAML or JSON file (".json" extension).
+/// Every "$ref" is resolved, local ones and the ones relative to the file where they are written.
+/// Schemas of other files are added to the document components, parameters, request bodies
+/// and responses are inlined.
+pub fn parse(file_path: &Path) -> Result<OpenapiDocument, String> {
+    let mut parser = OpenapiParser::new(file_path)?;
+    parser.parse_document()
+}
+
+struct OpenapiParser {
+    root_file: PathBuf,
+    documents: HashMap<PathBuf, Rc<OpenapiNode>>,
+    security_scheme_names: Vec<String>,
+    /// Schemas of other files, added to the components when they are referenced
+    imported_schemas: Vec<OpenapiSchema>,
+    /// Names of the imported schemas with the file and JSON pointer they are read from
+    imported_schema_refs: Vec<(String, PathBuf, String)>,
+    ref_depth: usize,
+}
+
+impl OpenapiParser {
+    fn new(file_path: &Path) -> Result<Self, String> {
+        let root_file = get_canonical_path(file_path);
+        let root_node = OpenapiNode::from_path(&root_file)?;
+        let mut documents = HashMap::new();
+        documents.insert(root_file.clone(), Rc::new(root_node));
+
+        Ok(Self {
+            root_file,
+            documents,
+            security_scheme_names: vec![],
+            imported_schemas: vec![],
+            imported_schema_refs: vec![],
+            ref_depth: 0,
+        })
+    }
+
+    fn parse_document(&mut self) -> Result<OpenapiDocument, String> {
+        let file = self.root_file.clone();
+        let root = self.documents[&file].clone();
+        self.check_mapping(&file, &root, "openapi document")?;
+
+        let openapi = self.get_mandatory_scalar(&file, &root, "openapi")?;
+        if !openapi.starts_with("3.") {
+            return Err(self.get_error(
+                &file,
+                root.get("openapi").unwrap_or(&root),
+                &format!("unsupported version \"{}\", expected 3.0 or 3.1", openapi),
+            ));
+        }
+        let info_node =
+            root.get("info")
+                .ok_or(self.get_error(&file, &root, "\"info\" is mandatory"))?;
+        let info = self.parse_info(&file, info_node)?;
+        let servers = self.parse_servers(&file, root.get("servers"))?;
+        let tags = self.parse_tags(&file, root.get("tags"))?;
+
+        let components = root.get("components");
+        let security_schemes = self.parse_security_schemes(&file, components)?;
+        self.security_scheme_names = security_schemes
+            .iter()
+            .map(|scheme| scheme.get_name().to_string())
+            .collect();
+        let mut schemas = self.parse_component_schemas(&file, components)?;
+        let security = self.parse_security(&file, root.get("security"))?;
+        let paths = self.parse_paths(&file, root.get("paths"))?;
+        schemas.append(&mut self.imported_schemas);
+
+        OpenapiDocument::builder()
+            .openapi(&openapi)
+            .info(info)
+            .servers(servers)
+            .paths(paths)
+            .components(OpenapiComponents::new(schemas, security_schemes))
+            .security(security)
+            .tags(tags)
+            .build()
+    }
+
+    fn parse_info(&self, file: &Path, node: &OpenapiNode) -> Result<OpenapiInfo, String> {
+        self.check_mapping(file, node, "info")?;
+        Ok(OpenapiInfo::new(
+            self.get_mandatory_scalar(file, node, "title")?,
+            self.get_optional_string(file, node, "description")?,
+            self.get_mandatory_scalar(file, node, "version")?,
+        ))
+    }
+
+    fn parse_servers(
+        &self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiServer>, String> {
+        self.get_items(file, node, "servers")?
+            .iter()
+            .map(|server| {
+                self.check_mapping(file, server, "server")?;
+                Ok(OpenapiServer::new(
+                    self.get_mandatory_scalar(file, server, "url")?,
+                    self.get_optional_string(file, server, "description")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn parse_tags(
+        &self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiTag>, String> {
+        self.get_items(file, node, "tags")?
+            .iter()
+            .map(|tag| {
+                self.check_mapping(file, tag, "tag")?;
+                Ok(OpenapiTag::new(
+                    self.get_mandatory_scalar(file, tag, "name")?,
+                    self.get_optional_string(file, tag, "description")?,
+                ))
+            })
+            .collect()
+    }
+
+    fn parse_component_schemas(
+        &mut self,
+        file: &Path,
+        components: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiSchema>, String> {
+        let schemas = components.and_then(|components| components.get("schemas"));
+        let mut result = vec![];
+        for (name, schema) in self.get_entries(file, schemas, "schemas")? {
+            result.push(self.parse_schema(&name, file, &schema)?);
+        }
+        Ok(result)
+    }
+
+    fn parse_paths(
+        &mut self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiPath>, String> {
+        let mut paths = vec![];
+        for (route, path_item) in self.get_entries(file, node, "paths")? {
+            if route.starts_with("x-") {
+                continue;
+            }
+            if !route.starts_with('/') {
+                return Err(self.get_error(
+                    file,
+                    &path_item,
+                    &format!("path \"{}\" must start with \"/\"", route),
+                ));
+            }
+            let (path_file, path_item) = self.deref(file, &path_item)?;
+            self.check_mapping(&path_file, &path_item, "path item")?;
+            let path_parameters = self.parse_parameters(&path_file, path_item.get("parameters"))?;
+
+            let mut operations = vec![];
+            for method in OpenapiHttpMethod::values() {
+                if let Some(operation) = path_item.get(&method.to_string()) {
+                    operations.push(self.parse_operation(
+                        method,
+                        &path_file,
+                        operation,
+                        &path_parameters,
+                    )?);
+                }
+            }
+            paths.push(OpenapiPath::new(route, operations));
+        }
+        Ok(paths)
+    }
+
+    fn parse_operation(
+        &mut self,
+        method: OpenapiHttpMethod,
+        file: &Path,
+        node: &OpenapiNode,
+        path_parameters: &[OpenapiParameter],
+    ) -> Result<OpenapiOperation, String> {
+        self.check_mapping(file, node, &method.to_string())?;
+        let operation_parameters = self.parse_parameters(file, node.get("parameters"))?;
+        let mut parameters: Vec<OpenapiParameter> = path_parameters
+            .iter()
+            .filter(|path_parameter| {
+                !operation_parameters.iter().any(|parameter| {
+                    parameter.get_name() == path_parameter.get_name()
+                        && parameter.get_location() == path_parameter.get_location()
+                })
+            })
+            .cloned()
+            .collect();
+        parameters.extend(operation_parameters);
+
+        let request_body = match node.get("requestBody") {
+            Some(request_body) => Some(self.parse_request_body(file, request_body)?),
+            None => None,
+        };
+        let security = match node.get("security") {
+            Some(security) => Some(self.parse_security(file, Some(security))?),
+            None => None,
+        };
+
+        Ok(OpenapiOperation::builder(method)
+            .operation_id(self.get_optional_string(file, node, "operationId")?)
+            .summary(self.get_optional_string(file, node, "summary")?)
+            .description(self.get_optional_string(file, node, "description")?)
+            .tags(self.get_strings(file, node.get("tags"), "tags")?)
+            .parameters(parameters)
+            .request_body(request_body)
+            .responses(self.parse_responses(file, node.get("responses"))?)
+            .security(security)
+            .deprecated(self.get_optional_bool(file, node, "deprecated")?)
+            .build())
+    }
+
+    fn parse_parameters(
+        &mut self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiParameter>, String> {
+        let mut parameters = vec![];
+        for parameter in self.get_items(file, node, "parameters")? {
+            let (parameter_file, parameter) = self.deref(file, &parameter)?;
+            parameters.push(self.parse_parameter(&parameter_file, &parameter)?);
+        }
+        Ok(parameters)
+    }
+
+    fn parse_parameter(
+        &mut self,
+        file: &Path,
+        node: &OpenapiNode,
+    ) -> Result<OpenapiParameter, String> {
+        self.check_mapping(file, node, "parameter")?;
+        let name = self.get_mandatory_scalar(file, node, "name")?;
+        let location_str = self.get_mandatory_scalar(file, node, "in")?;
+        let location = OpenapiParameterLocation::from_str(&location_str).map_err(|_| {
+            self.get_error(
+                file,
+                node.get("in").unwrap_or(node),
+                &format!(
+                    "parameter location \"{}\" must be path, query, header or cookie",
+                    location_str
+                ),
+            )
+        })?;
+        let required = OpenapiParameterLocation::Path == location
+            || self.get_optional_bool(file, node, "required")?;
+        let schema = match node.get("schema") {
+            Some(schema) => Some(self.parse_data_type(file, schema)?),
+            None => self
+                .parse_content(file, node.get("content"))?
+                .into_iter()
+                .find_map(|media_type| media_type.get_schema().cloned()),
+        };
+
+        Ok(OpenapiParameter::new(
+            name,
+            location,
+            self.get_optional_string(file, node, "description")?,
+            required,
+            schema,
+        ))
+    }
+
+    fn parse_request_body(
+        &mut self,
+        file: &Path,
+        node: &OpenapiNode,
+    ) -> Result<OpenapiRequestBody, String> {
+        let (file, node) = self.deref(file, node)?;
+        self.check_mapping(&file, &node, "requestBody")?;
+
+        Ok(OpenapiRequestBody::new(
+            self.get_optional_string(&file, &node, "description")?,
+            self.get_optional_bool(&file, &node, "required")?,
+            self.parse_content(&file, node.get("content"))?,
+        ))
+    }
+
+    fn parse_responses(
+        &mut self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiResponse>, String> {
+        let mut responses = vec![];
+        for (status_code, response) in self.get_entries(file, node, "responses")? {
+            if status_code.starts_with("x-") {
+                continue;
+            }
+            let (response_file, response) = self.deref(file, &response)?;
+            self.check_mapping(&response_file, &response, "response")?;
+            responses.push(OpenapiResponse::new(
+                status_code,
+                self.get_optional_string(&response_file, &response, "description")?
+                    .unwrap_or_default(),
+                self.parse_content(&response_file, response.get("content"))?,
+            ));
+        }
+        Ok(responses)
+    }
+
+    fn parse_content(
+        &mut self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiMediaType>, String> {
+        let mut content = vec![];
+        for (media_type, media_type_node) in self.get_entries(file, node, "content")? {
+            self.check_mapping(file, &media_type_node, &media_type)?;
+            let schema = match media_type_node.get("schema") {
+                Some(schema) => Some(self.parse_data_type(file, schema)?),
+                None => None,
+            };
+            content.push(OpenapiMediaType::new(media_type, schema));
+        }
+        Ok(content)
+    }
+
+    fn parse_security_schemes(
+        &mut self,
+        file: &Path,
+        components: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiSecurityScheme>, String> {
+        let node = components.and_then(|components| components.get("securitySchemes"));
+        let mut security_schemes = vec![];
+        for (name, scheme) in self.get_entries(file, node, "securitySchemes")? {
+            let (scheme_file, scheme) = self.deref(file, &scheme)?;
+            security_schemes.push(self.parse_security_scheme(name, &scheme_file, &scheme)?);
+        }
+        Ok(security_schemes)
+    }
+
+    fn parse_security_scheme(
+        &self,
+        name: String,
+        file: &Path,
+        node: &OpenapiNode,
+    ) -> Result<OpenapiSecurityScheme, String> {
+        self.check_mapping(file, node, "security scheme")?;
+        let scheme_type = match self.get_mandatory_scalar(file, node, "type")?.as_str() {
+            "apiKey" => {
+                let location = match self.get_mandatory_scalar(file, node, "in")?.as_str() {
+                    "query" => OpenapiApiKeyLocation::Query,
+                    "header" => OpenapiApiKeyLocation::Header,
+                    "cookie" => OpenapiApiKeyLocation::Cookie,
+                    other => {
+                        return Err(self.get_error(
+                            file,
+                            node.get("in").unwrap_or(node),
+                            &format!(
+                                "api key location \"{}\" must be query, header or cookie",
+                                other
+                            ),
+                        ))
+                    }
+                };
+                OpenapiSecuritySchemeType::ApiKey {
+                    name: self.get_mandatory_scalar(file, node, "name")?,
+                    location,
+                }
+            }
+            "http" => OpenapiSecuritySchemeType::Http {
+                scheme: self.get_mandatory_scalar(file, node, "scheme")?,
+                bearer_format: self.get_optional_string(file, node, "bearerFormat")?,
+            },
+            "oauth2" => {
+                let flows = node.get("flows").ok_or(self.get_error(
+                    file,
+                    node,
+                    "\"flows\" is mandatory",
+                ))?;
+                OpenapiSecuritySchemeType::OAuth2(self.parse_oauth_flows(file, flows)?)
+            }
+            "openIdConnect" => OpenapiSecuritySchemeType::OpenIdConnect(
+                self.get_mandatory_scalar(file, node, "openIdConnectUrl")?,
+            ),
+            "mutualTLS" => OpenapiSecuritySchemeType::MutualTls,
+            other => {
+                return Err(self.get_error(
+                    file,
+                    node.get("type").unwrap_or(node),
+                    &format!("unknown security scheme type \"{}\"", other),
+                ))
+            }
+        };
+
+        Ok(OpenapiSecurityScheme::new(
+            name,
+            self.get_optional_string(file, node, "description")?,
+            scheme_type,
+        ))
+    }
+
+    fn parse_oauth_flows(
+        &self,
+        file: &Path,
+        node: &OpenapiNode,
+    ) -> Result<Vec<OpenapiOAuthFlow>, String> {
+        let mut flows = vec![];
+        for (flow_name, flow) in self.get_entries(file, Some(node), "flows")? {
+            let flow_type = match flow_name.as_str() {
+                "implicit" => OpenapiOAuthFlowType::Implicit,
+                "password" => OpenapiOAuthFlowType::Password,
+                "clientCredentials" => OpenapiOAuthFlowType::ClientCredentials,
+                "authorizationCode" => OpenapiOAuthFlowType::AuthorizationCode,
+                _ => continue,
+            };
+            self.check_mapping(file, &flow, &flow_name)?;
+            let scopes = self
+                .get_entries(file, flow.get("scopes"), "scopes")?
+                .into_iter()
+                .map(|(scope, description)| {
+                    (scope, description.get_scalar_str().unwrap_or_default())
+                })
+                .collect();
+            flows.push(OpenapiOAuthFlow::new(
+                flow_type,
+                self.get_optional_string(file, &flow, "authorizationUrl")?,
+                self.get_optional_string(file, &flow, "tokenUrl")?,
+                self.get_optional_string(file, &flow, "refreshUrl")?,
+                scopes,
+            ));
+        }
+        Ok(flows)
+    }
+
+    fn parse_security(
+        &self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+    ) -> Result<Vec<OpenapiSecurityRequirement>, String> {
+        let mut requirements = vec![];
+        for requirement in self.get_items(file, node, "security")? {
+            let mut schemes = vec![];
+            for (name, scopes) in self.get_entries(file, Some(&requirement), "security")? {
+                if !self.security_scheme_names.contains(&name) {
+                    return Err(self.get_error(
+                        file,
+                        &requirement,
+                        &format!("unknown security scheme \"{}\"", name),
+                    ));
+                }
+                let scopes = self.get_strings(file, Some(&scopes), &name)?;
+                schemes.push((name, scopes));
+            }
+            requirements.push(OpenapiSecurityRequirement::new(schemes));
+        }
+        Ok(requirements)
+    }
+
+    /// Schema with its own name, like the components and the object properties
+    fn parse_schema(
+        &mut self,
+        name: &str,
+        file: &Path,
+        node: &OpenapiNode,
+    ) -> Result<OpenapiSchema, String> {
+        if node.get_bool().is_some() {
+            return Ok(OpenapiSchema::new_basic_type(
+                name.to_string(),
+                None,
+                OpenapiDataType::ObjectSimple,
+            ));
+        }
+        self.check_mapping(file, node, name)?;
+        let description = self.get_optional_string(file, node, "description")?;
+        let example = node
+            .get("example")
+            .or(node
+                .get("examples")
+                .and_then(|examples| examples.get_items())
+                .and_then(|examples| examples.first()))
+            .map(|example| example.to_json());
+        let default = node.get("default").map(|default| default.to_json());
+
+        let schema = if node.get("$ref").is_some() {
+            OpenapiSchema::new_basic_type(
+                name.to_string(),
+                description,
+                self.parse_data_type(file, node)?,
+            )
+        } else if let Some(all_of) = node.get("allOf") {
+            self.parse_all_of_schema(name, description, file, node, all_of)?
+        } else if node.get("properties").is_some() {
+            OpenapiSchema::new_record(
+                name.to_string(),
+                description,
+                self.parse_properties(file, node)?,
+            )
+        } else if let Some(enum_values) = node.get("enum") {
+            let values = self
+                .get_items(file, Some(enum_values), "enum")?
+                .iter()
+                .filter(|value| !value.is_null())
+                .filter_map(|value| value.get_scalar_str())
+                .collect();
+            OpenapiSchema::new_enum(name, description, values)
+                .with_schema_type(self.parse_plain_data_type(file, node)?)
+        } else {
+            OpenapiSchema::new_basic_type(
+                name.to_string(),
+                description,
+                self.parse_data_type(file, node)?,
+            )
+        };
+
+        let schema = match schema.get_schema_type() {
+            Some(schema_type) if is_nullable(node) => {
+                let schema_type = schema_type.clone().into_nullable();
+                schema.with_schema_type(schema_type)
+            }
+            _ => schema,
+        };
+        Ok(schema.with_example(example).with_default(default))
+    }
+
+    /// Properties of every "allOf" schema merged into a single object
+    fn parse_all_of_schema(
+        &mut self,
+        name: &str,
+        description: Option<String>,
+        file: &Path,
+        node: &OpenapiNode,
+        all_of: &OpenapiNode,
+    ) -> Result<OpenapiSchema, String> {
+        let parts = self.get_items(file, Some(all_of), "allOf")?;
+        if let [part] = parts.as_slice() {
+            if node.get("properties").is_none() {
+                return Ok(OpenapiSchema::new_basic_type(
+                    name.to_string(),
+                    description,
+                    self.parse_data_type(file, part)?,
+                ));
+            }
+        }
+
+        let mut properties = vec![];
+        for part in parts {
+            let (part_file, part) = self.deref(file, &part)?;
+            let part_schema = self.parse_schema(name, &part_file, &part)?;
+            properties.extend(part_schema.get_properties().clone().unwrap_or_default());
+        }
+        properties.extend(self.parse_properties(file, node)?);
+
+        Ok(OpenapiSchema::new_record(
+            name.to_string(),
+            description,
+            properties,
+        ))
+    }
+
+    /// Properties listed as "required" are required, their nullability is only in their type
+    fn parse_properties(
+        &mut self,
+        file: &Path,
+        node: &OpenapiNode,
+    ) -> Result<Vec<OpenapiSchema>, String> {
+        let required = self.get_strings(file, node.get("required"), "required")?;
+        let mut properties = vec![];
+        for (property_name, property) in
+            self.get_entries(file, node.get("properties"), "properties")?
+        {
+            let schema = self.parse_schema(&property_name, file, &property)?;
+            properties.push(schema.with_required(required.contains(&property_name)));
+        }
+        Ok(properties)
+    }
+
+    /// Type of a schema without name, like array items or request bodies.
+    /// Objects with properties and enums are inline schemas.
+    fn parse_data_type(
+        &mut self,
+        file: &Path,
+        node: &OpenapiNode,
+    ) -> Result<OpenapiDataType, String> {
+        if node.get_bool().is_some() {
+            return Ok(OpenapiDataType::ObjectSimple);
+        }
+        self.check_mapping(file, node, "schema")?;
+
+        let data_type = if let Some(reference) = self.get_ref(file, node)? {
+            self.parse_ref_data_type(file, &reference, node)?
+        } else if let Some(members) = node.get("oneOf").or(node.get("anyOf")) {
+            let mut data_types = vec![];
+            for member in self.get_items(file, Some(members), "oneOf")? {
+                match self.parse_data_type(file, &member)? {
+                    OpenapiDataType::Null => data_types.insert(0, OpenapiDataType::Null),
+                    data_type => data_types.push(data_type),
+                }
+            }
+            OpenapiDataType::Array(data_types)
+        } else if node.get("allOf").is_some()
+            || node.get("properties").is_some()
+            || node.get("enum").is_some()
+        {
+            let schema = self.parse_schema("", file, node)?;
+            match schema.get_schema_type() {
+                Some(schema_type)
+                    if schema.get_properties().is_none() && schema.get_enum_values().is_none() =>
+                {
+                    schema_type.clone()
+                }
+                _ => return Ok(OpenapiDataType::Object(Box::new(schema))),
+            }
+        } else {
+            self.parse_plain_data_type(file, node)?
+        };
+
+        if is_nullable(node) {
+            return Ok(data_type.into_nullable());
+        }
+        Ok(data_type)
+    }
+
+    /// Type of the schema from its "type" and "format"
+    fn parse_plain_data_type(
+        &mut self,
+        file: &Path,
+        node: &OpenapiNode,
+    ) -> Result<OpenapiDataType, String> {
+        let type_node = match node.get("type") {
+            Some(type_node) => type_node,
+            None if node.get("items").is_some() => return self.parse_type(file, node, "array"),
+            None => return self.parse_type(file, node, "object"),
+        };
+        if let Some(type_name) = type_node.get_str() {
+            return self.parse_type(file, node, type_name);
+        }
+
+        let type_names = self.get_strings(file, Some(type_node), "type")?;
+        let mut data_types = vec![];
+        if type_names.iter().any(|type_name| "null" == type_name) {
+            data_types.push(OpenapiDataType::Null);
+        }
+        for type_name in type_names.iter().filter(|type_name| "null" != *type_name) {
+            data_types.push(self.parse_type(file, node, type_name)?);
+        }
+        if let [data_type] = data_types.as_slice() {
+            return Ok(data_type.clone());
+        }
+        Ok(OpenapiDataType::Array(data_types))
+    }
+
+    fn parse_type(
+        &mut self,
+        file: &Path,
+        node: &OpenapiNode,
+        type_name: &str,
+    ) -> Result<OpenapiDataType, String> {
+        let format = self.get_optional_string(file, node, "format")?;
+        let data_type = match type_name {
+            "string" => match format {
+                Some(format) if "byte" == format => OpenapiDataType::Bytes,
+                Some(format) => OpenapiDataType::FormattedString(format),
+                None => OpenapiDataType::String,
+            },
+            "integer" if Some("int64") == format.as_deref() => OpenapiDataType::new_int64_type(),
+            "integer" => OpenapiDataType::new_int32_type(),
+            "number" if Some("float") == format.as_deref() => OpenapiDataType::new_float_type(),
+            "number" => OpenapiDataType::new_double_type(),
+            "boolean" => OpenapiDataType::Boolean,
+            "null" => OpenapiDataType::Null,
+            "array" => {
+                let items = match node.get("items") {
+                    Some(items) => self.parse_data_type(file, items)?,
+                    None => OpenapiDataType::ObjectSimple,
+                };
+                OpenapiDataType::ArrayItems(Box::new(items))
+            }
+            "object" => match node.get("additionalProperties") {
+                Some(values) if values.get_entries().is_some() => {
+                    OpenapiDataType::Map(Box::new(self.parse_data_type(file, values)?))
+                }
+                Some(values) if Some(true) == values.get_bool() => {
+                    OpenapiDataType::Map(Box::new(OpenapiDataType::ObjectSimple))
+                }
+                _ => OpenapiDataType::ObjectSimple,
+            },
+            other => {
+                return Err(self.get_error(
+                    file,
+                    node.get("type").unwrap_or(node),
+                    &format!("unknown type \"{}\"", other),
+                ))
+            }
+        };
+        Ok(data_type)
+    }
+
+    /// Component schemas are referenced by name, schemas of other files are added
+    /// to the components. Any other schema is inlined.
+    fn parse_ref_data_type(
+        &mut self,
+        file: &Path,
+        reference: &str,
+        node: &OpenapiNode,
+    ) -> Result<OpenapiDataType, String> {
+        let (file_part, pointer) = split_ref(reference);
+        let target_file = get_ref_file(file, file_part);
+        let schema_name = match pointer.strip_prefix(COMPONENT_SCHEMAS_POINTER) {
+            Some(name) if !name.contains('/') => Some(name.replace("~1", "/").replace("~0", "~")),
+            Some(_) => None,
+            None if pointer.is_empty() => target_file
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().to_string()),
+            None => None,
+        };
+
+        match schema_name {
+            Some(name) if target_file == self.root_file => {
+                self.resolve(file, reference, node)?;
+                Ok(OpenapiDataType::ObjectName(name))
+            }
+            Some(name) => {
+                self.import_schema(file, reference, node, &name)?;
+                Ok(OpenapiDataType::ObjectName(name))
+            }
+            None => {
+                let (target_file, target) = self.resolve(file, reference, node)?;
+                self.ref_depth += 1;
+                if self.ref_depth > MAX_REF_DEPTH {
+                    return Err(self.get_error(
+                        file,
+                        node,
+                        &format!("circular $ref \"{}\"", reference),
+                    ));
+                }
+                let data_type = self.parse_data_type(&target_file, &target);
+                self.ref_depth -= 1;
+                data_type
+            }
+        }
+    }
+
+    fn import_schema(
+        &mut self,
+        file: &Path,
+        reference: &str,
+        node: &OpenapiNode,
+        name: &str,
+    ) -> Result<(), String> {
+        let (file_part, pointer) = split_ref(reference);
+        let target_file = get_ref_file(file, file_part);
+        if let Some((_, imported_file, imported_pointer)) = self
+            .imported_schema_refs
+            .iter()
+            .find(|(imported_name, _, _)| name == imported_name)
+        {
+            if target_file == *imported_file && pointer == imported_pointer {
+                return Ok(());
+            }
+            return Err(self.get_error(
+                file,
+                node,
+                &format!(
+                    "$ref \"{}\" has the name of a different imported schema",
+                    reference
+                ),
+            ));
+        }
+        let root = self.documents[&self.root_file].clone();
+        let root_schema_pointer = format!("{}{}", COMPONENT_SCHEMAS_POINTER, name);
+        if root.get_pointer(&root_schema_pointer).is_some() {
+            return Err(self.get_error(
+                file,
+                node,
+                &format!(
+                    "$ref \"{}\" has the name of a different component schema",
+                    reference
+                ),
+            ));
+        }
+
+        self.imported_schema_refs
+            .push((name.to_string(), target_file, pointer.to_string()));
+        let (target_file, target) = self.resolve(file, reference, node)?;
+        let schema = self.parse_schema(name, &target_file, &target)?;
+        self.imported_schemas.push(schema);
+        Ok(())
+    }
+
+    /// Node itself or the target of its "$ref", with the file where it is written
+    fn deref(&mut self, file: &Path, node: &OpenapiNode) -> Result<(PathBuf, OpenapiNode), String> {
+        let mut result = (file.to_path_buf(), node.clone());
+        for _ in 0..MAX_REF_DEPTH {
+            match self.get_ref(&result.0, &result.1)? {
+                Some(reference) => result = self.resolve(&result.0, &reference, &result.1)?,
+                None => return Ok(result),
+            }
+        }
+        Err(self.get_error(file, node, "circular $ref"))
+    }
+
+    fn resolve(
+        &mut self,
+        file: &Path,
+        reference: &str,
+        node: &OpenapiNode,
+    ) -> Result<(PathBuf, OpenapiNode), String> {
+        let unresolved_error = || {
+            format!(
+                "Invalid openapi (unresolved $ref \"{}\") in file:\n{}:{}:{}\n",
+                reference,
+                try_to_absolute_path(file),
+                node.get_start_line(),
+                node.get_start_column()
+            )
+        };
+        let (file_part, pointer) = split_ref(reference);
+        let target_file = get_ref_file(file, file_part);
+        if !self.documents.contains_key(&target_file) {
+            if !target_file.is_file() {
+                return Err(unresolved_error());
+            }
+            let document = OpenapiNode::from_path(&target_file)?;
+            self.documents
+                .insert(target_file.clone(), Rc::new(document));
+        }
+
+        match self.documents[&target_file].get_pointer(pointer) {
+            Some(target) => Ok((target_file, target.clone())),
+            None => Err(unresolved_error()),
+        }
+    }
+
+    fn get_ref(&self, file: &Path, node: &OpenapiNode) -> Result<Option<String>, String> {
+        match node.get("$ref") {
+            Some(reference) => match reference.get_str() {
+                Some(reference) => Ok(Some(reference.to_string())),
+                None => Err(self.get_error(file, reference, "\"$ref\" must be a string")),
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn check_mapping(
+        &self,
+        file: &Path,
+        node: &OpenapiNode,
+        attribute: &str,
+    ) -> Result<(), String> {
+        match node.get_value() {
+            OpenapiNodeValue::Mapping(_) => Ok(()),
+            _ => Err(self.get_error(file, node, &format!("\"{}\" must be an object", attribute))),
+        }
+    }
+
+    /// Entries of an optional mapping, extensions ("x-" keys) included
+    fn get_entries(
+        &self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+        attribute: &str,
+    ) -> Result<Vec<(String, OpenapiNode)>, String> {
+        match node {
+            Some(node) if node.is_null() => Ok(vec![]),
+            Some(node) => match node.get_entries() {
+                Some(entries) => Ok(entries.clone()),
+                None => {
+                    Err(self.get_error(file, node, &format!("\"{}\" must be an object", attribute)))
+                }
+            },
+            None => Ok(vec![]),
+        }
+    }
+
+    fn get_items(
+        &self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+        attribute: &str,
+    ) -> Result<Vec<OpenapiNode>, String> {
+        match node {
+            Some(node) if node.is_null() => Ok(vec![]),
+            Some(node) => match node.get_items() {
+                Some(items) => Ok(items.clone()),
+                None => {
+                    Err(self.get_error(file, node, &format!("\"{}\" must be a list", attribute)))
+                }
+            },
+            None => Ok(vec![]),
+        }
+    }
+
+    fn get_strings(
+        &self,
+        file: &Path,
+        node: Option<&OpenapiNode>,
+        attribute: &str,
+    ) -> Result<Vec<String>, String> {
+        self.get_items(file, node, attribute)?
+            .iter()
+            .map(|item| {
+                item.get_scalar_str().ok_or(self.get_error(
+                    file,
+                    item,
+                    &format!("\"{}\" must be a list of strings", attribute),
+                ))
+            })
+            .collect()
+    }
+
+    /// Mandatory text, numbers and booleans are accepted as written (i.e. version: 1.0)
+    fn get_mandatory_scalar(
+        &self,
+        file: &Path,
+        node: &OpenapiNode,
+        attribute: &str,
+    ) -> Result<String, String> {
+        let value = node.get(attribute).ok_or(self.get_error(
+            file,
+            node,
+            &format!("\"{}\" is mandatory", attribute),
+        ))?;
+        match value.get_value() {
+            OpenapiNodeValue::Sequence(_) | OpenapiNodeValue::Mapping(_) => {
+                Err(self.get_error(file, value, &format!("\"{}\" must be a string", attribute)))
+            }
+            _ => Ok(value.get_scalar_str().unwrap_or_default()),
+        }
+    }
+
+    fn get_optional_string(
+        &self,
+        file: &Path,
+        node: &OpenapiNode,
+        attribute: &str,
+    ) -> Result<Option<String>, String> {
+        match node.get(attribute) {
+            Some(value) if value.is_null() => Ok(None),
+            Some(value) => match value.get_str() {
+                Some(value) => Ok(Some(value.to_string())),
+                None => {
+                    Err(self.get_error(file, value, &format!("\"{}\" must be a string", attribute)))
+                }
+            },
+            None => Ok(None),
+        }
+    }
+
+    fn get_optional_bool(
+        &self,
+        file: &Path,
+        node: &OpenapiNode,
+        attribute: &str,
+    ) -> Result<bool, String> {
+        match node.get(attribute) {
+            Some(value) => value.get_bool().ok_or(self.get_error(
+                file,
+                value,
+                &format!("\"{}\" must be a boolean", attribute),
+            )),
+            None => Ok(false),
+        }
+    }
+
+    fn get_error(&self, file: &Path, node: &OpenapiNode, message: &str) -> String {
+        format!(
+            "Invalid openapi ({}) in file:\n{}:{}:{}\n",
+            message,
+            try_to_absolute_path(file),
+            node.get_start_line(),
+            node.get_start_column()
+        )
+    }
+}
+
+/// File and JSON pointer of a "$ref" (i.e. "common.yaml#/components/schemas/Error")
+fn split_ref(reference: &str) -> (&str, &str) {
+    match reference.split_once('#') {
+        Some((file_part, pointer)) => (file_part, pointer),
+        None => (reference, ""),
+    }
+}
+
+/// Files of a "$ref" are relative to the file where the "$ref" is written
+fn get_ref_file(file: &Path, file_part: &str) -> PathBuf {
+    if file_part.is_empty() {
+        return file.to_path_buf();
+    }
+    let folder = file.parent().unwrap_or(Path::new(""));
+    get_canonical_path(&folder.join(file_part))
+}
+
+fn get_canonical_path(file: &Path) -> PathBuf {
+    fs::canonicalize(file).unwrap_or(file.to_path_buf())
+}
+
+/// "nullable: true" in OpenAPI 3.0 or "null" in the types of OpenAPI 3.1
+fn is_nullable(node: &OpenapiNode) -> bool {
+    let is_nullable_type = node
+        .get("type")
+        .and_then(|type_node| type_node.get_items())
+        .is_some_and(|types| {
+            types
+                .iter()
+                .any(|type_node| Some("null") == type_node.get_str())
+        });
+    is_nullable_type || node.get("nullable").and_then(|node| node.get_bool()) == Some(true)
+}
+
+/// Union of the null type and the given one
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_path::get_test_file;
+    use crate::domain::usecase::openapi::parser::dto::openapi_data_type::OpenapiDataType;
+    use crate::domain::usecase::openapi::parser::dto::openapi_path::{
+        OpenapiHttpMethod, OpenapiOperation, OpenapiParameterLocation, OpenapiPath,
+    };
+    use crate::domain::usecase::openapi::parser::dto::openapi_security::{
+        OpenapiApiKeyLocation, OpenapiOAuthFlowType, OpenapiSecuritySchemeType,
+    };
+    use crate::domain::usecase::openapi::parser::openapi_parser;
+
+    #[test]
+    fn parse_yaml_document() {
+        let file_path = get_test_file(&get_current_file_path(), "petstore.yaml");
+
+        let document = openapi_parser::parse(&file_path).expect("Openapi must be valid");
+
+        assert_eq!("3.0.3", document.get_openapi());
+        let info = document.get_info();
+        assert_eq!("Petstore", info.get_title());
+        assert_eq!("1.0", info.get_version());
+        assert_eq!(
+            Some("Pets of the store, with their owners".to_string()),
+            info.get_description()
+        );
+        let server = document.get_servers().first().expect("Server expected");
+        assert_eq!("https://petstore.test/v1", server.get_url());
+        assert_eq!(Some("Production".to_string()), server.get_description());
+        let tag = document.get_tags().first().expect("Tag expected");
+        assert_eq!("pets", tag.get_name());
+        assert_eq!(
+            Some("Pets: everything about them".to_string()),
+            tag.get_description()
+        );
+        let security = document.get_security();
+        assert_eq!(1, security.len());
+        assert_eq!(
+            &vec![("api_key".to_string(), vec![])],
+            security[0].get_schemes()
+        );
+
+        let paths = document.get_paths();
+        assert_eq!(2, paths.len());
+        assert_eq!("/pets", paths[0].get_route());
+        let list_pets =
+            get_operation(&paths[0], OpenapiHttpMethod::Get).expect("List operation expected");
+        assert_eq!(Some("listPets".to_string()), list_pets.get_operation_id());
+        assert_eq!(Some("List all pets".to_string()), list_pets.get_summary());
+        assert_eq!(&vec!["pets".to_string()], list_pets.get_tags());
+        assert_eq!(None, list_pets.get_security());
+        let parameters = list_pets.get_parameters();
+        assert_eq!(2, parameters.len());
+        assert_eq!("limit", parameters[0].get_name());
+        assert_eq!(
+            OpenapiParameterLocation::Query,
+            parameters[0].get_location()
+        );
+        assert!(!parameters[0].is_required());
+        assert_eq!(
+            Some(&OpenapiDataType::new_int32_type()),
+            parameters[0].get_schema()
+        );
+        assert_eq!(
+            Some(&OpenapiDataType::ObjectName("PetStatus".to_string())),
+            parameters[1].get_schema()
+        );
+        let responses = list_pets.get_responses();
+        assert_eq!(2, responses.len());
+        assert_eq!("200", responses[0].get_status_code());
+        assert_eq!("A list of pets", responses[0].get_description());
+        let content = responses[0]
+            .get_content()
+            .first()
+            .expect("Content expected");
+        assert_eq!("application/json", content.get_media_type());
+        assert_eq!(
+            Some(&OpenapiDataType::ArrayItems(Box::new(
+                OpenapiDataType::ObjectName("Pet".to_string())
+            ))),
+            content.get_schema()
+        );
+        assert_eq!("default", responses[1].get_status_code());
+        assert_eq!("Unexpected error", responses[1].get_description());
+        assert_eq!(
+            Some(&OpenapiDataType::ObjectName("Error".to_string())),
+            responses[1].get_content()[0].get_schema()
+        );
+
+        let create_pet =
+            get_operation(&paths[0], OpenapiHttpMethod::Post).expect("Create operation expected");
+        let request_body = create_pet.get_request_body().expect("Body expected");
+        assert!(request_body.is_required());
+        assert_eq!(
+            Some(&OpenapiDataType::ObjectName("NewPet".to_string())),
+            request_body.get_content()[0].get_schema()
+        );
+        assert_eq!("201", create_pet.get_responses()[0].get_status_code());
+        let create_security = create_pet.get_security().expect("Security expected");
+        assert_eq!(
+            &vec![("oauth".to_string(), vec!["write:pets".to_string()])],
+            create_security[0].get_schemes()
+        );
+
+        assert_eq!("/pets/{petId}", paths[1].get_route());
+        let operations = paths[1].get_operations();
+        assert_eq!(2, operations.len());
+        let get_pet = &operations[0];
+        assert_eq!(OpenapiHttpMethod::Get, get_pet.get_method());
+        assert!(get_pet.is_deprecated());
+        let parameters = get_pet.get_parameters();
+        assert_eq!(2, parameters.len());
+        assert_eq!("petId", parameters[0].get_name());
+        assert_eq!(OpenapiParameterLocation::Path, parameters[0].get_location());
+        assert!(parameters[0].is_required());
+        assert_eq!(
+            Some("Id of the pet".to_string()),
+            parameters[0].get_description()
+        );
+        assert_eq!(
+            OpenapiParameterLocation::Header,
+            parameters[1].get_location()
+        );
+        assert_eq!(
+            Some(&OpenapiDataType::FormattedString("uuid".to_string())),
+            parameters[1].get_schema()
+        );
+        let delete_pet = &operations[1];
+        assert_eq!(OpenapiHttpMethod::Delete, delete_pet.get_method());
+        assert_eq!(Some(&vec![]), delete_pet.get_security());
+        assert_eq!(1, delete_pet.get_parameters().len());
+    }
+
+    #[test]
+    fn parse_yaml_component_schemas() {
+        let file_path = get_test_file(&get_current_file_path(), "petstore.yaml");
+
+        let document = openapi_parser::parse(&file_path).expect("Openapi must be valid");
+
+        let components = document.get_components();
+        let schema_names: Vec<&String> = components
+            .get_schemas()
+            .iter()
+            .map(|schema| schema.get_name())
+            .collect();
+        assert_eq!(
+            vec!["Pet", "PetStatus", "NewPet", "Owner", "ErrorCode", "Error"],
+            schema_names
+        );
+
+        let pet = components.get_schema("Pet").expect("Pet expected");
+        assert_eq!(
+            &Some("A pet of the store".to_string()),
+            pet.get_description()
+        );
+        assert_eq!(vec!["id", "name"], pet.get_required_properties());
+        let properties = pet.get_properties().as_ref().expect("Properties expected");
+        let property_types: Vec<Option<OpenapiDataType>> = properties
+            .iter()
+            .map(|property| property.get_property_type())
+            .collect();
+        let nullable = |data_type: OpenapiDataType| {
+            Some(OpenapiDataType::Array(vec![
+                OpenapiDataType::Null,
+                data_type,
+            ]))
+        };
+        assert_eq!(
+            vec![
+                Some(OpenapiDataType::new_int64_type()),
+                Some(OpenapiDataType::String),
+                nullable(OpenapiDataType::String),
+                nullable(OpenapiDataType::ObjectName("PetStatus".to_string())),
+                nullable(OpenapiDataType::FormattedString("date".to_string())),
+                nullable(OpenapiDataType::ObjectName("Owner".to_string())),
+                nullable(OpenapiDataType::Map(Box::new(OpenapiDataType::String))),
+                property_types[7].clone(),
+            ],
+            property_types
+        );
+        assert_eq!(&Some("\"Rex\"".to_string()), properties[1].get_example());
+        let Some(OpenapiDataType::Array(vaccines_types)) = &property_types[7] else {
+            panic!("Nullable vaccines expected")
+        };
+        let OpenapiDataType::ArrayItems(vaccine) = &vaccines_types[1] else {
+            panic!("Vaccines array expected")
+        };
+        let OpenapiDataType::Object(vaccine) = vaccine.as_ref() else {
+            panic!("Inline vaccine schema expected")
+        };
+        assert_eq!(1, vaccine.get_properties().as_ref().map_or(0, Vec::len));
+
+        let status = components.get_schema("PetStatus").expect("Status expected");
+        assert_eq!(
+            &Some(vec!["available".to_string(), "sold".to_string()]),
+            status.get_enum_values()
+        );
+        assert_eq!(&Some("\"available\"".to_string()), status.get_default());
+
+        let new_pet = components.get_schema("NewPet").expect("New pet expected");
+        let new_pet_properties = new_pet
+            .get_properties()
+            .as_ref()
+            .expect("Merged properties");
+        assert_eq!(9, new_pet_properties.len());
+        assert_eq!("notes", new_pet_properties[8].get_name());
+
+        let owner = components.get_schema("Owner").expect("Owner file expected");
+        let owner_properties = owner.get_properties().as_ref().expect("Owner properties");
+        assert_eq!(
+            Some(OpenapiDataType::Array(vec![
+                OpenapiDataType::Null,
+                OpenapiDataType::ArrayItems(Box::new(OpenapiDataType::ObjectName(
+                    "Pet".to_string()
+                )))
+            ])),
+            owner_properties[1].get_property_type()
+        );
+        let error = components.get_schema("Error").expect("Error expected");
+        assert_eq!(vec!["code".to_string()], error.get_required_properties());
+        let error_code = components.get_schema("ErrorCode").expect("Code expected");
+        assert_eq!(
+            &Some(OpenapiDataType::new_int32_type()),
+            error_code.get_schema_type()
+        );
+    }
+
+    #[test]
+    fn parse_yaml_security_schemes() {
+        let file_path = get_test_file(&get_current_file_path(), "petstore.yaml");
+
+        let document = openapi_parser::parse(&file_path).expect("Openapi must be valid");
+
+        let schemes = document.get_components().get_security_schemes();
+        assert_eq!(3, schemes.len());
+        assert_eq!("api_key", schemes[0].get_name());
+        assert_eq!(
+            &OpenapiSecuritySchemeType::ApiKey {
+                name: "X-API-Key".to_string(),
+                location: OpenapiApiKeyLocation::Header,
+            },
+            schemes[0].get_scheme_type()
+        );
+        assert_eq!(
+            &OpenapiSecuritySchemeType::Http {
+                scheme: "bearer".to_string(),
+                bearer_format: Some("JWT".to_string()),
+            },
+            schemes[1].get_scheme_type()
+        );
+        let OpenapiSecuritySchemeType::OAuth2(flows) = schemes[2].get_scheme_type() else {
+            panic!("OAuth2 scheme expected")
+        };
+        assert_eq!(OpenapiOAuthFlowType::Implicit, flows[0].get_flow_type());
+        assert_eq!(
+            Some("https://petstore.test/oauth".to_string()),
+            flows[0].get_authorization_url()
+        );
+        assert_eq!(
+            &vec![
+                ("write:pets".to_string(), "Modify pets".to_string()),
+                ("read:pets".to_string(), "Read pets".to_string())
+            ],
+            flows[0].get_scopes()
+        );
+    }
+
+    #[test]
+    fn parse_json_document() {
+        let file_path = get_test_file(&get_current_file_path(), "petstore.json");
+
+        let document = openapi_parser::parse(&file_path).expect("Openapi must be valid");
+
+        assert_eq!("3.1.0", document.get_openapi());
+        assert_eq!("Petstore \"JSON\"", document.get_info().get_title());
+        let update_pet = get_operation(&document.get_paths()[0], OpenapiHttpMethod::Put)
+            .expect("Update operation expected");
+        assert_eq!(Some("updatePet".to_string()), update_pet.get_operation_id());
+        assert!(!update_pet
+            .get_request_body()
+            .expect("Body expected")
+            .is_required());
+
+        let pet = document
+            .get_components()
+            .get_schema("Pet")
+            .expect("Pet expected");
+        assert_eq!(
+            vec!["id".to_string(), "nickname".to_string()],
+            pet.get_required_properties()
+        );
+        let properties = pet.get_properties().as_ref().expect("Properties expected");
+        assert_eq!(
+            &Some(OpenapiDataType::Array(vec![
+                OpenapiDataType::Null,
+                OpenapiDataType::String
+            ])),
+            properties[1].get_schema_type()
+        );
+        assert_eq!(&Some("1.5".to_string()), properties[2].get_default());
+        assert_eq!(
+            &Some(OpenapiDataType::Array(vec![
+                OpenapiDataType::String,
+                OpenapiDataType::new_int32_type()
+            ])),
+            properties[3].get_schema_type()
+        );
+        assert!(!properties[3].is_required());
+        assert_eq!(
+            &OpenapiSecuritySchemeType::OpenIdConnect(
+                "https://petstore.test/.well-known".to_string()
+            ),
+            document.get_components().get_security_schemes()[0].get_scheme_type()
+        );
+    }
+
+    #[test]
+    fn parse_unresolved_ref_returns_location() {
+        let file_path = get_test_file(&get_current_file_path(), "unresolved_ref.yaml");
+
+        let err = openapi_parser::parse(&file_path).expect_err("Unresolved $ref must fail");
+
+        assert!(err.contains("unresolved $ref \"#/components/schemas/Item\""));
+        assert!(err.contains("unresolved_ref.yaml:14:17"));
+    }
+
+    #[test]
+    fn parse_imported_schemas_with_the_same_name_fails() {
+        let file_path = get_test_file(&get_current_file_path(), "clashing_imports.yaml");
+
+        let err = openapi_parser::parse(&file_path).expect_err("Clashing imports must fail");
+
+        assert!(err.contains(
+            "$ref \"clashing_imports/b/common.yaml#/components/schemas/Owner\" has the name of a different imported schema"
+        ));
+        assert!(err.contains("clashing_imports.yaml:14:11"));
+    }
+
+    #[test]
+    fn parse_missing_title_returns_location() {
+        let file_path = get_test_file(&get_current_file_path(), "missing_title.json");
+
+        let err = openapi_parser::parse(&file_path).expect_err("Missing title must fail");
+
+        assert!(err.contains("\"title\" is mandatory"));
+        assert!(err.contains("missing_title.json:3:11"));
+    }
+
+    #[test]
+    fn parse_invalid_yaml_returns_location() {
+        let file_path = get_test_file(&get_current_file_path(), "invalid_syntax.yaml");
+
+        let err = openapi_parser::parse(&file_path).expect_err("Invalid yaml must fail");
+
+        assert!(err.contains("Invalid yaml"));
+        assert!(err.contains("invalid_syntax.yaml:"));
+    }
+
+    fn get_operation(path: &OpenapiPath, method: OpenapiHttpMethod) -> Option<&OpenapiOperation> {
+        path.get_operations()
+            .iter()
+            .find(|operation| method == operation.get_method())
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -1 +1 @@
-mod openapi_from_avro_translator;
+pub mod openapi_from_avro_translator;
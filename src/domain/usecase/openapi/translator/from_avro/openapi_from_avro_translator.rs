@@ -1,135 +1,198 @@
 use crate::domain::usecase::avro::parser::dto::avro_item::AvroItem;
 use crate::domain::usecase::avro::parser::dto::avro_item_type::AvroItemType;
+use crate::domain::usecase::avro::parser::dto::avro_logical_type::AvroLogicalType;
+use crate::domain::usecase::avro::writer::avro_writer;
 use crate::domain::usecase::openapi::parser::dto::openapi_data_type::OpenapiDataType;
+use crate::domain::usecase::openapi::parser::dto::openapi_document::{
+    OpenapiComponents, OpenapiDocument, OpenapiInfo,
+};
 use crate::domain::usecase::openapi::parser::dto::openapi_schema::OpenapiSchema;
+use crate::domain::usecase::openapi::parser::openapi_node::escape_json;
+use crate::domain::usecase::openapi::writer::openapi_writer;
+use crate::domain::usecase::openapi::writer::openapi_writer::OpenapiFormat;
 
-pub fn avro_to_openapi_str(schemas: &[AvroItem]) -> String {
-    let schemas = to_component_schemas(schemas);
+/// OpenAPI version of the translated documents
+const OPENAPI_VERSION: &str = "3.0.3";
 
-    to_openapi_str(&schemas)
-}
+/// # avro_to_openapi_str
+/// YAML text of an OpenAPI document without paths, whose component schemas are the given
+/// Avro schemas. It is written by "openapi_writer".
+pub fn avro_to_openapi_str(schemas: &[AvroItem], info: OpenapiInfo) -> Result<String, String> {
+    let document = OpenapiDocument::builder()
+        .openapi(OPENAPI_VERSION)
+        .info(info)
+        .components(OpenapiComponents::new(
+            to_component_schemas(schemas),
+            vec![],
+        ))
+        .build()?;
 
-fn to_component_schemas(avro_items: &[AvroItem]) -> Vec<OpenapiSchema> {
-    avro_items.iter().map(to_component_schema).collect()
+    Ok(openapi_writer::to_str(&document, OpenapiFormat::Yaml))
 }
 
-fn to_openapi_str(schemas: &Vec<OpenapiSchema>) -> String {
-    let mut result = String::new();
-    let schemas_len = schemas.len();
-    for (index, schema) in schemas.iter().enumerate() {
-        result += schema.to_string().as_str();
-        if index < (schemas_len - 1) {
-            result += "\n"
+/// Named types declared inline (i.e. a nested record) are component schemas too,
+/// written after the schema declaring them
+fn to_component_schemas(avro_items: &[AvroItem]) -> Vec<OpenapiSchema> {
+    let mut result = Vec::new();
+    for avro_item in avro_items {
+        result.push(to_component_schema(avro_item));
+        for field in avro_item.get_fields().iter().flatten() {
+            add_inline_named_schemas(field.get_item_type(), &mut result);
         }
     }
     result
 }
 
-pub fn to_component_schema(avro_item: &AvroItem) -> OpenapiSchema {
-    if let AvroItemType::Enum = avro_item.get_item_type() {
-        if let Some(symbols) = avro_item.get_symbols() {
-            return OpenapiSchema::new_enum(
-                &avro_item.get_name().expect("Avro name expected"),
-                avro_item.get_doc(),
-                symbols,
-            );
+fn add_inline_named_schemas(avro_item_type: &AvroItemType, schemas: &mut Vec<OpenapiSchema>) {
+    match avro_item_type {
+        AvroItemType::Array(inner_type) | AvroItemType::Map(inner_type) => {
+            add_inline_named_schemas(inner_type, schemas)
         }
-    } else if let AvroItemType::RecordSimple = avro_item.get_item_type() {
-        let mut properties_result = Vec::new();
-        if let Some(fields) = avro_item.get_fields() {
-            for field in fields {
-                properties_result.push(to_component_schema(field));
+        AvroItemType::Union(subtypes) => {
+            for subtype in subtypes {
+                add_inline_named_schemas(subtype, schemas);
             }
         }
+        AvroItemType::Inline(inline_item) if inline_item.get_item_type().is_named() => {
+            schemas.extend(to_component_schemas(&[inline_item.as_ref().clone()]))
+        }
+        AvroItemType::Inline(inline_item) => {
+            add_inline_named_schemas(inline_item.get_item_type(), schemas)
+        }
+        _ => {}
+    }
+}
 
-        return OpenapiSchema::new_record(
-            avro_item.get_name().unwrap(),
+/// Fields are required unless they have a default, "null" unions are nullable properties.
+/// Null defaults of nullable unions are skipped, the property is already optional.
+pub fn to_component_schema(avro_item: &AvroItem) -> OpenapiSchema {
+    let name = avro_item.get_name().unwrap_or_default();
+    match avro_item.get_item_type() {
+        AvroItemType::Enum => OpenapiSchema::new_enum(
+            &name,
             avro_item.get_doc(),
-            properties_result,
-        );
-    } else if let AvroItemType::Array(subtypes) = avro_item.get_item_type() {
-        let mut subtypes_result = Vec::new();
-        for subtype in subtypes {
-            subtypes_result.push(to_data_type(subtype))
+            avro_item.get_symbols().unwrap_or_default(),
+        ),
+        AvroItemType::Record | AvroItemType::Error => {
+            let properties = avro_item
+                .get_fields()
+                .iter()
+                .flatten()
+                .map(to_component_schema)
+                .collect();
+            OpenapiSchema::new_record(name, avro_item.get_doc(), properties)
         }
+        item_type => {
+            let data_type = match avro_item.get_logical_type() {
+                Some(logical_type) => to_logical_data_type(&logical_type, item_type),
+                None => to_data_type(item_type),
+            };
+            OpenapiSchema::new_basic_type(name, avro_item.get_doc(), data_type)
+                .with_default(get_default(avro_item))
+                .with_required(avro_item.get_default().is_none())
+        }
+    }
+}
 
-        return OpenapiSchema::new_basic_type(
-            avro_item.get_name().unwrap(),
-            avro_item.get_doc(),
-            OpenapiDataType::Array(subtypes_result),
-        );
-    } else if let AvroItemType::Record(_record) = avro_item.get_item_type() {
-        let _a = 0;
+/// Defaults as JSON, strings are quoted again according to the type
+fn get_default(avro_item: &AvroItem) -> Option<String> {
+    let default = avro_item.get_default()?;
+    let item_type = avro_item.get_item_type();
+    if "null" == default && is_null_first_union(item_type) {
+        return None;
+    }
+
+    if avro_writer::is_string_default(item_type, &default) {
+        Some(escape_json(&default))
     } else {
-        return OpenapiSchema::new_basic_type(
-            avro_item.get_name().unwrap(),
-            avro_item.get_doc(),
-            to_data_type(avro_item.get_item_type()),
-        );
+        Some(default)
     }
+}
 
-    panic!(
-        "Error translating avro item {} (doc: {})",
-        avro_item.get_name().unwrap(),
-        avro_item.get_doc().unwrap()
-    );
+fn is_null_first_union(avro_item_type: &AvroItemType) -> bool {
+    match avro_item_type {
+        AvroItemType::Union(subtypes) => subtypes
+            .first()
+            .is_some_and(|subtype| AvroItemType::Null == *subtype),
+        _ => false,
+    }
 }
 
+/// Named types are referenced by their simple name, declarations without attributes
+/// (i.e. a logical type) are translated as their underlying type
 pub fn to_data_type(avro_item_type: &AvroItemType) -> OpenapiDataType {
-    if let AvroItemType::Int = avro_item_type {
-        return OpenapiDataType::new_int32_type();
-    } else if let AvroItemType::Long = avro_item_type {
-        return OpenapiDataType::new_int64_type();
-    } else if let AvroItemType::Float = avro_item_type {
-        return OpenapiDataType::new_float_type();
-    } else if let AvroItemType::Double = avro_item_type {
-        return OpenapiDataType::new_double_type();
-    } else if let AvroItemType::Null = avro_item_type {
-        return OpenapiDataType::Null;
-    } else if let AvroItemType::String = avro_item_type {
-        return OpenapiDataType::String;
-    } else if let AvroItemType::Boolean = avro_item_type {
-        return OpenapiDataType::Boolean;
-    } else if let AvroItemType::Bytes = avro_item_type {
-        return OpenapiDataType::Bytes;
-    } else if let AvroItemType::RecordName(record_name) = avro_item_type {
-        return OpenapiDataType::ObjectName(record_name.clone());
-    } else if let AvroItemType::Array(subtypes) = avro_item_type {
-        let mut translated_types = Vec::new();
-        for subtype in subtypes {
-            translated_types.push(to_data_type(subtype));
+    match avro_item_type {
+        AvroItemType::Int => OpenapiDataType::new_int32_type(),
+        AvroItemType::Long => OpenapiDataType::new_int64_type(),
+        AvroItemType::Float => OpenapiDataType::new_float_type(),
+        AvroItemType::Double => OpenapiDataType::new_double_type(),
+        AvroItemType::Null => OpenapiDataType::Null,
+        AvroItemType::String | AvroItemType::Enum => OpenapiDataType::String,
+        AvroItemType::Boolean => OpenapiDataType::Boolean,
+        AvroItemType::Bytes | AvroItemType::Fixed => OpenapiDataType::Bytes,
+        AvroItemType::Record | AvroItemType::Error => OpenapiDataType::ObjectSimple,
+        AvroItemType::Reference(full_name) => {
+            let record_name = full_name.rsplit('.').next().unwrap_or(full_name);
+            OpenapiDataType::ObjectName(record_name.to_string())
+        }
+        AvroItemType::Union(subtypes) => {
+            OpenapiDataType::Array(subtypes.iter().map(to_data_type).collect())
         }
-        return OpenapiDataType::Array(translated_types);
-    } else if let AvroItemType::ArrayItems(items_type) = avro_item_type {
-        let translated_type = to_data_type(items_type);
-        return OpenapiDataType::ArrayItems(Box::new(translated_type));
-    } else if let AvroItemType::Record(record_box) = avro_item_type {
-        if record_box.is_just_type() {
-            return to_data_type(record_box.get_item_type());
-        } else {
-            panic!("TODO: translate compound types");
+        AvroItemType::Array(items_type) => {
+            OpenapiDataType::ArrayItems(Box::new(to_data_type(items_type)))
         }
+        AvroItemType::Map(values_type) => OpenapiDataType::Map(Box::new(to_data_type(values_type))),
+        AvroItemType::Inline(inline_item) if inline_item.get_item_type().is_named() => {
+            OpenapiDataType::ObjectName(inline_item.get_name().unwrap_or_default())
+        }
+        AvroItemType::Inline(inline_item) => match inline_item.get_logical_type() {
+            Some(logical_type) => to_logical_data_type(&logical_type, inline_item.get_item_type()),
+            None => to_data_type(inline_item.get_item_type()),
+        },
     }
+}
 
-    panic!("Error translating avro item type");
+/// Logical types without a matching format are translated as their underlying type
+fn to_logical_data_type(
+    logical_type: &AvroLogicalType,
+    avro_item_type: &AvroItemType,
+) -> OpenapiDataType {
+    match logical_type {
+        AvroLogicalType::Decimal { .. } => OpenapiDataType::new_decimal_type(),
+        AvroLogicalType::Uuid => OpenapiDataType::FormattedString("uuid".to_string()),
+        AvroLogicalType::Date => OpenapiDataType::FormattedString("date".to_string()),
+        AvroLogicalType::TimeMillis | AvroLogicalType::TimeMicros => {
+            OpenapiDataType::FormattedString("time".to_string())
+        }
+        AvroLogicalType::TimestampMillis
+        | AvroLogicalType::TimestampMicros
+        | AvroLogicalType::TimestampNanos => {
+            OpenapiDataType::FormattedString("date-time".to_string())
+        }
+        _ => to_data_type(avro_item_type),
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::fs;
     use std::path::PathBuf;
 
     use crate::core::file_system::file_edition::file_editor;
     use crate::core::testing::test_assert::assert_same_as_file;
     use crate::core::testing::test_path::{get_non_existing_test_file, get_test_file};
     use crate::domain::usecase::avro::parser::avro_parser;
+    use crate::domain::usecase::openapi::parser::dto::openapi_document::OpenapiInfo;
+    use crate::domain::usecase::openapi::parser::openapi_parser;
     use crate::domain::usecase::openapi::translator::from_avro::openapi_from_avro_translator::avro_to_openapi_str;
 
     #[test]
     fn avro_to_openapi_str_enum() {
         let file_path = get_test_file(&get_current_file_path(), "enum.avsc");
-        let avro_items = avro_parser::parse(&file_path);
+        let avro_items = avro_parser::parse(&file_path).expect("Avro schema must be valid");
 
-        let openapi_str = avro_to_openapi_str(&avro_items);
+        let openapi_str =
+            avro_to_openapi_str(&avro_items, get_info()).expect("Avro schemas must be translated");
 
         let expect_result_file_path =
             get_test_file(&get_current_file_path(), "enum_translated.yaml");
@@ -139,9 +202,10 @@ mod tests {
     #[test]
     fn avro_to_openapi_str_basic_fields() {
         let file_path = get_test_file(&get_current_file_path(), "avro_basic_fields.avsc");
-        let avro_items = avro_parser::parse(&file_path);
+        let avro_items = avro_parser::parse(&file_path).expect("Avro schema must be valid");
 
-        let openapi_str = avro_to_openapi_str(&avro_items);
+        let openapi_str =
+            avro_to_openapi_str(&avro_items, get_info()).expect("Avro schemas must be translated");
 
         let expect_result_file_path = get_test_file(
             &get_current_file_path(),
@@ -154,9 +218,10 @@ mod tests {
     fn avro_to_openapi_str_array_fields() {
         let current_file_path = get_current_file_path();
         let file_path = get_test_file(&current_file_path, "avro_array_fields.avsc");
-        let avro_items = avro_parser::parse(&file_path);
+        let avro_items = avro_parser::parse(&file_path).expect("Avro schema must be valid");
 
-        let openapi_str = avro_to_openapi_str(&avro_items);
+        let openapi_str =
+            avro_to_openapi_str(&avro_items, get_info()).expect("Avro schemas must be translated");
 
         let expect_result_file_path = get_test_file(
             &current_file_path,
@@ -165,22 +230,77 @@ mod tests {
         assert_same_as_file(&expect_result_file_path, &openapi_str)
     }
 
+    #[test]
+    fn avro_to_openapi_str_nested_fields() {
+        let current_file_path = get_current_file_path();
+        let file_path = get_test_file(&current_file_path, "avro_nested_fields.avsc");
+        let avro_items = avro_parser::parse(&file_path).expect("Avro schema must be valid");
+
+        let openapi_str =
+            avro_to_openapi_str(&avro_items, get_info()).expect("Avro schemas must be translated");
+
+        let expect_result_file_path = get_test_file(
+            &current_file_path,
+            "avro_nested_fields_translated_to_openapi.yaml",
+        );
+        assert_same_as_file(&expect_result_file_path, &openapi_str)
+    }
+
+    #[test]
+    fn translated_docs_and_symbols_are_parsed_again() {
+        let current_file_path = get_current_file_path();
+        let file_path = get_test_file(&current_file_path, "avro_special_strings.avsc");
+        let avro_items = avro_parser::parse(&file_path).expect("Avro schema must be valid");
+        let openapi_str =
+            avro_to_openapi_str(&avro_items, get_info()).expect("Avro schemas must be translated");
+        let result_file_path =
+            get_non_existing_test_file(&current_file_path, "avro_special_strings.yaml");
+        file_editor::create_or_replace_file_with_bytes(&result_file_path, openapi_str.as_bytes())
+            .expect("File creation must succeed");
+
+        let document = openapi_parser::parse(&result_file_path);
+        fs::remove_file(&result_file_path).expect("Result file must be removed");
+
+        let document = document.expect("Translated OpenAPI must be valid");
+        let answer = document
+            .get_components()
+            .get_schema("Answer")
+            .expect("Answer expected");
+        assert_eq!(
+            &Some("Reply: \"yes\" or \"no\"\nNull when unknown".to_string()),
+            answer.get_description()
+        );
+        assert_eq!(
+            &Some(vec![
+                "yes".to_string(),
+                "no".to_string(),
+                "null".to_string()
+            ]),
+            answer.get_enum_values()
+        );
+    }
+
     #[test]
     #[ignore]
     fn test() {
         let current_file_path = get_current_file_path();
         let file_path = get_test_file(&current_file_path, "test.avsc");
-        let avro_items = avro_parser::parse(&file_path);
+        let avro_items = avro_parser::parse(&file_path).expect("Avro schema must be valid");
 
-        let openapi_str = avro_to_openapi_str(&avro_items);
+        let openapi_str =
+            avro_to_openapi_str(&avro_items, get_info()).expect("Avro schemas must be translated");
 
         let result_file_path =
             get_non_existing_test_file(&current_file_path, "test_translated_to_openapi.yaml");
 
-        file_editor::create_or_replace_file_with_bytes(&result_file_path, &openapi_str.as_bytes())
+        file_editor::create_or_replace_file_with_bytes(&result_file_path, openapi_str.as_bytes())
             .expect("File creation must succeed");
     }
 
+    fn get_info() -> OpenapiInfo {
+        OpenapiInfo::new("Shop".to_string(), None, "1.0.0".to_string())
+    }
+
     fn get_current_file_path() -> PathBuf {
         PathBuf::from(file!())
     }
@@ -0,0 +1 @@
+pub mod openapi_from_java_translator;
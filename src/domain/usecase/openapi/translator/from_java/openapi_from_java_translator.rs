@@ -0,0 +1,436 @@
+use std::fs;
+use std::panic;
+use std::path::{Path, PathBuf};
+
+use crate::core::file_system::file_edition::file_editor;
+use crate::domain::usecase::openapi::parser::dto::openapi_data_type::OpenapiDataType;
+use crate::domain::usecase::openapi::parser::dto::openapi_document::{
+    OpenapiComponents, OpenapiDocument,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_schema::OpenapiSchema;
+use crate::domain::usecase::openapi::parser::openapi_parser;
+use crate::domain::usecase::openapi::writer::openapi_writer;
+use crate::java::annotation_usage::JavaAnnotationUsage;
+use crate::java::class::JavaClass;
+use crate::java::data_type::JavaDataType;
+use crate::java::enumeration::JavaEnum;
+use crate::java::field::JavaField;
+use crate::java::scanner::file::java_file::JavaFile;
+use crate::java::scanner::file::java_structure_type::JavaStructureType;
+use crate::yaml::parser::writer::yaml_writer;
+
+/// Bean Validation constraints of the values that can not be null
+const REQUIRED_ANNOTATIONS: [&str; 3] = ["NotNull", "NotEmpty", "NotBlank"];
+
+/// # java_to_openapi_schemas
+/// Component schemas of the given DTO classes and of the project classes and enums used by
+/// their fields, dependencies first. Static fields and fields annotated with "@JsonIgnore"
+/// are skipped, "@JsonProperty" renames the property. Fields are required when they are
+/// annotated with "@NotNull", "@NotEmpty", "@NotBlank" or "@JsonProperty(required = true)"
+/// or when their type is primitive, any other field is nullable. Fields of the project superclasses are included first.
+/// The other Bean Validation constraints, i.e. "@Size", "@Min", "@Max" or "@Pattern", are not translated.
+/// Schemas are named after the simple name of their java type, two types with the same name are an error.
+pub fn java_to_openapi_schemas(classes: &[JavaClass]) -> Result<Vec<OpenapiSchema>, String> {
+    let mut translator = OpenapiFromJavaTranslator {
+        visited: vec![],
+        schemas: vec![],
+    };
+    for class in classes {
+        translator.add_class_schema(class, &class.get_self_import().get_route())?;
+    }
+
+    Ok(translator.schemas)
+}
+
+/// # java_to_openapi
+/// Add the component schemas of the given DTO classes to an existing OpenAPI document.
+/// YAML documents keep the rest of the file as it is, the schemas with the same name are
+/// removed before merging the new ones with "yaml_writer::overwrite". JSON documents are
+/// written again from the parsed document, replacing the schemas with the same name.
+pub fn java_to_openapi(classes: &[JavaClass], openapi_file: &Path) -> Result<(), String> {
+    let document = openapi_parser::parse(openapi_file)?;
+    let schemas = java_to_openapi_schemas(classes)?;
+
+    if openapi_file
+        .extension()
+        .is_some_and(|extension| "json" == extension)
+    {
+        return openapi_writer::write(&with_schemas(&document, schemas)?, openapi_file);
+    }
+
+    let names: Vec<String> = schemas
+        .iter()
+        .map(|schema| schema.get_name().to_string())
+        .collect();
+    yaml_writer::remove(openapi_file, &["components", "schemas"], &names)?;
+
+    let components = OpenapiComponents::new(schemas, vec![]);
+    let components_file = get_components_file(openapi_file);
+    file_editor::create_or_replace_file_with_bytes(
+        &components_file,
+        openapi_writer::components_to_yaml_str(&components, document.get_openapi()).as_bytes(),
+    )?;
+    let merged = panic::catch_unwind(|| yaml_writer::overwrite(openapi_file, &components_file));
+    let removed = fs::remove_file(&components_file).map_err(|err| {
+        format!(
+            "Temporary file could not be removed ({}):\n{}\n",
+            err,
+            components_file.display()
+        )
+    });
+    merged.map_err(|_| {
+        format!(
+            "Component schemas could not be merged into the OpenAPI document:\n{}\n",
+            openapi_file.display()
+        )
+    })?;
+    removed
+}
+
+struct OpenapiFromJavaTranslator {
+    /// Schema names and routes of the classes and enums already translated or being translated,
+    /// recursive types refer to themselves
+    visited: Vec<(String, String)>,
+    schemas: Vec<OpenapiSchema>,
+}
+
+impl OpenapiFromJavaTranslator {
+    fn add_class_schema(&mut self, class: &JavaClass, route: &str) -> Result<(), String> {
+        let name = class.get_name().to_string();
+        if self.visit(&name, route)? {
+            return Ok(());
+        }
+
+        let mut properties = Vec::new();
+        for field in get_fields(class) {
+            if let Some(property) = self.get_property(&field).map_err(|err| {
+                format!(
+                    "Invalid java DTO field \"{}.{}\" ({})",
+                    name,
+                    field.get_name(),
+                    err
+                )
+            })? {
+                properties.push(property);
+            }
+        }
+        let description = class
+            .get_javadoc()
+            .map(|javadoc| javadoc.get_description().to_string())
+            .filter(|description| !description.is_empty());
+        self.schemas
+            .push(OpenapiSchema::new_record(name, description, properties));
+
+        Ok(())
+    }
+
+    fn add_enum_schema(&mut self, java_enum: &JavaEnum, route: &str) -> Result<(), String> {
+        let name = java_enum.get_name().to_string();
+        if self.visit(&name, route)? {
+            return Ok(());
+        }
+
+        let description = java_enum
+            .get_javadoc()
+            .map(|javadoc| javadoc.get_description().to_string())
+            .filter(|description| !description.is_empty());
+        self.schemas.push(OpenapiSchema::new_enum(
+            &name,
+            description,
+            java_enum.get_constants().clone(),
+        ));
+
+        Ok(())
+    }
+
+    /// Whether the java type was already visited, a schema name can not be used by two types
+    fn visit(&mut self, name: &str, route: &str) -> Result<bool, String> {
+        match self
+            .visited
+            .iter()
+            .find(|(visited_name, _)| name == visited_name)
+        {
+            Some((_, visited_route)) if route == visited_route => Ok(true),
+            Some((_, visited_route)) => Err(format!(
+                "Invalid java DTO \"{}\" (schema \"{}\" is already used by \"{}\")",
+                route, name, visited_route
+            )),
+            None => {
+                self.visited.push((name.to_string(), route.to_string()));
+                Ok(false)
+            }
+        }
+    }
+
+    fn get_property(&mut self, field: &JavaField) -> Result<Option<OpenapiSchema>, String> {
+        let annotations = field.get_annotations();
+        if field.is_static() || find_annotation(annotations, "JsonIgnore").is_some() {
+            return Ok(None);
+        }
+
+        let json_property = find_annotation(annotations, "JsonProperty");
+        let name = json_property
+            .and_then(|annotation| get_parameter(annotation, "value"))
+            .unwrap_or(field.get_name().to_string());
+        let is_required = field.get_data_type().is_primitive()
+            || REQUIRED_ANNOTATIONS
+                .iter()
+                .any(|required| find_annotation(annotations, required).is_some())
+            || json_property.and_then(|annotation| get_parameter(annotation, "required"))
+                == Some("true".to_string());
+        let data_type = self.get_data_type(field.get_data_type())?;
+        let data_type = if is_required {
+            data_type
+        } else {
+            data_type.into_nullable()
+        };
+        let description = field
+            .get_javadoc()
+            .map(|javadoc| javadoc.get_description().to_string())
+            .filter(|description| !description.is_empty());
+
+        Ok(Some(OpenapiSchema::new_basic_type(
+            name,
+            description,
+            data_type,
+        )))
+    }
+
+    fn get_data_type(&mut self, data_type: &JavaDataType) -> Result<OpenapiDataType, String> {
+        let type_arguments = data_type.get_type_arguments();
+        let data_type = match get_route(data_type).as_str() {
+            "int" | "Integer" | "short" | "Short" | "byte" | "Byte" => {
+                OpenapiDataType::new_int32_type()
+            }
+            "long" | "Long" | "java.math.BigInteger" => OpenapiDataType::new_int64_type(),
+            "float" | "Float" => OpenapiDataType::new_float_type(),
+            "double" | "Double" => OpenapiDataType::new_double_type(),
+            "java.math.BigDecimal" => OpenapiDataType::new_decimal_type(),
+            "boolean" | "Boolean" => OpenapiDataType::Boolean,
+            "char" | "Character" | "String" => OpenapiDataType::String,
+            "Object" => OpenapiDataType::ObjectSimple,
+            "java.util.UUID" => OpenapiDataType::FormattedString("uuid".to_string()),
+            "java.time.LocalDate" => OpenapiDataType::FormattedString("date".to_string()),
+            "java.time.OffsetDateTime"
+            | "java.time.ZonedDateTime"
+            | "java.time.LocalDateTime"
+            | "java.time.Instant" => OpenapiDataType::FormattedString("date-time".to_string()),
+            "java.nio.ByteBuffer" => OpenapiDataType::Bytes,
+            "java.util.List" | "java.util.Set" | "java.util.Collection" => {
+                let items = match type_arguments.first() {
+                    Some(items_type) => self.get_data_type(items_type)?,
+                    None => OpenapiDataType::ObjectSimple,
+                };
+                OpenapiDataType::ArrayItems(Box::new(items))
+            }
+            "java.util.Map" => {
+                let values = match type_arguments.get(1) {
+                    Some(values_type) => self.get_data_type(values_type)?,
+                    None => OpenapiDataType::ObjectSimple,
+                };
+                OpenapiDataType::Map(Box::new(values))
+            }
+            "java.util.Optional" => {
+                let value_type = type_arguments
+                    .first()
+                    .ok_or("Optional without type argument")?;
+                self.get_data_type(value_type)?.into_nullable()
+            }
+            _ => self.get_named_data_type(data_type)?,
+        };
+
+        Ok(data_type)
+    }
+
+    /// Classes and enums of the project are component schemas
+    fn get_named_data_type(&mut self, data_type: &JavaDataType) -> Result<OpenapiDataType, String> {
+        let unsupported_error = format!("java type \"{}\" without OpenAPI equivalent", data_type);
+        let file = data_type
+            .get_import_opt()
+            .and_then(|import| import.get_specific_file().ok())
+            .ok_or(unsupported_error.clone())?;
+
+        let route = get_route(data_type);
+        let name = match JavaFile::from_user_input_path(&file)?.get_main_structure_type() {
+            JavaStructureType::Class => {
+                let class = JavaClass::from(&file)?;
+                self.add_class_schema(&class, &route)?;
+                class.get_name().to_string()
+            }
+            JavaStructureType::Enum => {
+                let java_enum = JavaEnum::from(&file)?;
+                self.add_enum_schema(&java_enum, &route)?;
+                java_enum.get_name().to_string()
+            }
+            _ => return Err(unsupported_error),
+        };
+
+        Ok(OpenapiDataType::ObjectName(name))
+    }
+}
+
+/// Fields of the project superclasses first
+fn get_fields(class: &JavaClass) -> Vec<JavaField> {
+    let mut fields = class
+        .get_extended_class()
+        .map(|extended_class| get_fields(&extended_class))
+        .unwrap_or_default();
+    fields.extend(class.get_fields().iter().cloned());
+    fields
+}
+
+/// Route of the imported types, simple name of the primitive and "java.lang" ones
+fn get_route(data_type: &JavaDataType) -> String {
+    match data_type.get_import_opt() {
+        Some(import) => import.get_route(),
+        None => {
+            let name = data_type.to_string();
+            name.split('<').next().unwrap_or_default().to_string()
+        }
+    }
+}
+
+fn find_annotation<'a>(
+    annotations: &'a [JavaAnnotationUsage],
+    name: &str,
+) -> Option<&'a JavaAnnotationUsage> {
+    annotations
+        .iter()
+        .find(|annotation| name == annotation.get_name())
+}
+
+/// Value of an annotation element without the quotes of the string literals
+fn get_parameter(annotation: &JavaAnnotationUsage, name: &str) -> Option<String> {
+    annotation
+        .get_parameters()
+        .iter()
+        .find(|(parameter_name, _)| name == parameter_name)
+        .map(|(_, value)| value.trim_matches('"').to_string())
+}
+
+/// Component schemas of the document with the given ones, replacing the ones with the same name
+fn with_schemas(
+    document: &OpenapiDocument,
+    schemas: Vec<OpenapiSchema>,
+) -> Result<OpenapiDocument, String> {
+    let components = document.get_components();
+    let mut result_schemas = components.get_schemas().clone();
+    for schema in schemas {
+        match result_schemas
+            .iter_mut()
+            .find(|existing| existing.get_name() == schema.get_name())
+        {
+            Some(existing) => *existing = schema,
+            None => result_schemas.push(schema),
+        }
+    }
+
+    OpenapiDocument::builder()
+        .openapi(document.get_openapi())
+        .info(document.get_info().clone())
+        .servers(document.get_servers().clone())
+        .paths(document.get_paths().clone())
+        .components(OpenapiComponents::new(
+            result_schemas,
+            components.get_security_schemes().clone(),
+        ))
+        .security(document.get_security().clone())
+        .tags(document.get_tags().clone())
+        .build()
+}
+
+/// Hidden file next to the document, so documents of different folders never share it
+fn get_components_file(openapi_file: &Path) -> PathBuf {
+    let file_name = openapi_file
+        .file_name()
+        .map(|file_name| file_name.to_string_lossy().to_string())
+        .unwrap_or_default();
+    openapi_file.with_file_name(format!(".{}.components.yaml", file_name))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::file_system::file_edition::file_editor::copy;
+    use crate::core::testing::test_assert::assert_same_as_file;
+    use crate::core::testing::test_path::{
+        get_java_test_file, get_non_existing_test_file, get_test_file,
+    };
+    use crate::domain::usecase::openapi::parser::dto::openapi_document::OpenapiComponents;
+    use crate::domain::usecase::openapi::translator::from_java::openapi_from_java_translator::{
+        get_components_file, java_to_openapi, java_to_openapi_schemas,
+    };
+    use crate::domain::usecase::openapi::writer::openapi_writer::components_to_yaml_str;
+    use crate::java::class::JavaClass;
+
+    #[test]
+    fn java_to_openapi_schemas_test() {
+        let schemas = java_to_openapi_schemas(&get_order_classes()).expect("Valid DTO classes");
+
+        let components_str =
+            components_to_yaml_str(&OpenapiComponents::new(schemas, vec![]), "3.0.3");
+
+        let expected_file = get_test_file(&get_current_file_path(), "expected/components.yaml");
+        assert_same_as_file(&expected_file, &components_str);
+    }
+
+    #[test]
+    fn java_to_openapi_schemas_with_clashing_names_fails() {
+        let legacy_order_file =
+            get_java_test_file(get_current_file_path(), "project", "legacy/Order.java");
+        let mut classes = get_order_classes();
+        classes.push(JavaClass::from(&legacy_order_file).expect("Order must be a java class"));
+
+        let result = java_to_openapi_schemas(&classes);
+
+        let err = result.expect_err("Two schemas can not be named \"Order\"");
+        assert!(err.contains("org.test.legacy.Order"));
+        assert!(err.contains("org.test.dto.Order"));
+    }
+
+    #[test]
+    fn java_to_openapi_yaml() {
+        let result_data = merge_into_copy("shop.yaml");
+
+        let expected_file = get_test_file(&get_current_file_path(), "expected/shop.yaml");
+        assert_same_as_file(&expected_file, &result_data);
+    }
+
+    #[test]
+    fn java_to_openapi_json() {
+        let result_data = merge_into_copy("shop.json");
+
+        let expected_file = get_test_file(&get_current_file_path(), "expected/shop.json");
+        assert_same_as_file(&expected_file, &result_data);
+    }
+
+    fn merge_into_copy(openapi_file_name: &str) -> String {
+        let original_file = get_test_file(
+            &get_current_file_path(),
+            &format!("openapi/{}", openapi_file_name),
+        );
+        let copy_file = get_non_existing_test_file(
+            &get_current_file_path(),
+            &format!("openapi/copy_{}", openapi_file_name),
+        );
+        copy(&original_file, &copy_file).expect("File should be created");
+
+        java_to_openapi(&get_order_classes(), &copy_file).expect("Valid DTO classes");
+        assert!(!get_components_file(&copy_file).exists());
+
+        let result_data = fs::read_to_string(&copy_file).expect("Result file must be readable");
+        fs::remove_file(&copy_file).expect("Result file must be removed");
+        result_data
+    }
+
+    fn get_order_classes() -> Vec<JavaClass> {
+        let order_file = get_java_test_file(get_current_file_path(), "project", "dto/Order.java");
+        vec![JavaClass::from(&order_file).expect("Order must be a java class")]
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1,11 @@
+package org.test.customer;
+
+import java.time.LocalDate;
+
+public class Customer {
+    private String name;
+
+    private LocalDate birthday;
+
+    private boolean vip;
+}
@@ -0,0 +1,10 @@
+package org.test.dto;
+
+import java.util.UUID;
+
+import jakarta.validation.constraints.NotNull;
+
+public abstract class BaseEntity {
+    @NotNull
+    private UUID id;
+}
@@ -0,0 +1,46 @@
+package org.test.dto;
+
+import java.math.BigDecimal;
+import java.time.OffsetDateTime;
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.Optional;
+
+import com.fasterxml.jackson.annotation.JsonIgnore;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import jakarta.validation.constraints.NotEmpty;
+import jakarta.validation.constraints.NotNull;
+
+import org.test.customer.Customer;
+
+/**
+ * Order placed by a customer
+ */
+public class Order extends BaseEntity {
+    public static final int MAX_LINES = 50;
+
+    @NotNull
+    private OrderStatus status;
+
+    /**
+     * Total amount with taxes
+     */
+    @JsonProperty("total_amount")
+    private BigDecimal total;
+
+    @JsonProperty(value = "created", required = true)
+    private OffsetDateTime createdAt;
+
+    @NotEmpty
+    private List<OrderLine> lines = new ArrayList<>();
+
+    private Customer customer;
+
+    private Map<String, Long> attributes;
+
+    private Optional<String> comment;
+
+    @JsonIgnore
+    private String internalNote;
+}
@@ -0,0 +1,12 @@
+package org.test.dto;
+
+import jakarta.validation.constraints.NotBlank;
+
+public class OrderLine {
+    @NotBlank
+    private String product;
+
+    private int quantity;
+
+    private Order order;
+}
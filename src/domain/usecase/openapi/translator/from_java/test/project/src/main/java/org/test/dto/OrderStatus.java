@@ -0,0 +1,9 @@
+package org.test.dto;
+
+/**
+ * Status of an order
+ */
+public enum OrderStatus {
+    OPEN,
+    CLOSED
+}
@@ -0,0 +1,5 @@
+package org.test.legacy;
+
+public class Order {
+    private String code;
+}
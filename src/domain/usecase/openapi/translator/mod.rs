@@ -1 +1,2 @@
-mod from_avro;
+pub mod from_avro;
+pub mod from_java;
@@ -0,0 +1 @@
+pub mod openapi_writer;
@@ -0,0 +1,892 @@
+use std::path::Path;
+
+use crate::core::file_system::file_edition::file_editor;
+use crate::domain::usecase::openapi::parser::dto::openapi_data_type::{
+    IntegerFormat, NumberFormat, OpenapiDataType,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_document::{
+    OpenapiComponents, OpenapiDocument, OpenapiInfo,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_path::{
+    OpenapiMediaType, OpenapiOperation, OpenapiParameter, OpenapiParameterLocation, OpenapiPath,
+    OpenapiRequestBody, OpenapiResponse,
+};
+use crate::domain::usecase::openapi::parser::dto::openapi_schema::OpenapiSchema;
+use crate::domain::usecase::openapi::parser::dto::openapi_security::{
+    OpenapiApiKeyLocation, OpenapiOAuthFlow, OpenapiOAuthFlowType, OpenapiSecurityRequirement,
+    OpenapiSecurityScheme, OpenapiSecuritySchemeType,
+};
+use crate::domain::usecase::openapi::parser::openapi_node::escape_json;
+
+const COMPONENT_SCHEMAS_REF: &str = "#/components/schemas/";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpenapiFormat {
+    Yaml,
+    Json,
+}
+
+/// # to_str
+/// Text of a whole OpenAPI document. Keys are always written in the same order,
+/// nullable types are written as the document version expects them
+/// ("nullable: true" for 3.0, "null" type for 3.1).
+pub fn to_str(document: &OpenapiDocument, format: OpenapiFormat) -> String {
+    let writer = OpenapiWriter {
+        is_version_3_1: document.get_openapi().starts_with("3.1"),
+    };
+    let value = writer.get_document_value(document);
+    to_format_str(&value, format)
+}
+
+/// # components_to_yaml_str
+/// YAML text of a document with only its "components", written as the "openapi" version
+/// expects them. It is meant to be merged into an existing YAML document.
+pub fn components_to_yaml_str(components: &OpenapiComponents, openapi: &str) -> String {
+    let writer = OpenapiWriter {
+        is_version_3_1: openapi.starts_with("3.1"),
+    };
+    let entries = writer
+        .get_components_value(components)
+        .map(|value| vec![("components".to_string(), value)])
+        .unwrap_or_default();
+    to_format_str(&OpenapiValue::Mapping(entries), OpenapiFormat::Yaml)
+}
+
+fn to_format_str(value: &OpenapiValue, format: OpenapiFormat) -> String {
+    match format {
+        OpenapiFormat::Yaml => {
+            let mut result = String::new();
+            write_yaml_mapping_entries(&mut result, 0, get_entries(value));
+            result
+        }
+        OpenapiFormat::Json => {
+            let mut result = String::new();
+            write_json(&mut result, 0, value);
+            result.push('\n');
+            result
+        }
+    }
+}
+
+/// # write
+/// Files with "json" extension are written as JSON, any other one as YAML.
+pub fn write(document: &OpenapiDocument, file_path: &Path) -> Result<(), String> {
+    let is_json = file_path
+        .extension()
+        .is_some_and(|extension| "json" == extension);
+    let format = if is_json {
+        OpenapiFormat::Json
+    } else {
+        OpenapiFormat::Yaml
+    };
+    file_editor::create_or_replace_file_with_bytes(file_path, to_str(document, format).as_bytes())
+}
+
+/// Document tree shared by the YAML and JSON output
+enum OpenapiValue {
+    String(String),
+    /// JSON text written as it is: numbers, booleans or default and example values
+    Raw(String),
+    Sequence(Vec<OpenapiValue>),
+    Mapping(Vec<(String, OpenapiValue)>),
+}
+
+struct OpenapiWriter {
+    is_version_3_1: bool,
+}
+
+impl OpenapiWriter {
+    fn get_document_value(&self, document: &OpenapiDocument) -> OpenapiValue {
+        let mut entries = vec![
+            new_string_entry("openapi", document.get_openapi()),
+            ("info".to_string(), self.get_info_value(document.get_info())),
+        ];
+        if !document.get_servers().is_empty() {
+            let servers = document
+                .get_servers()
+                .iter()
+                .map(|server| {
+                    let mut server_entries = vec![new_string_entry("url", server.get_url())];
+                    push_optional_string(
+                        &mut server_entries,
+                        "description",
+                        server.get_description(),
+                    );
+                    OpenapiValue::Mapping(server_entries)
+                })
+                .collect();
+            entries.push(("servers".to_string(), OpenapiValue::Sequence(servers)));
+        }
+        if !document.get_tags().is_empty() {
+            let tags = document
+                .get_tags()
+                .iter()
+                .map(|tag| {
+                    let mut tag_entries = vec![new_string_entry("name", tag.get_name())];
+                    push_optional_string(&mut tag_entries, "description", tag.get_description());
+                    OpenapiValue::Mapping(tag_entries)
+                })
+                .collect();
+            entries.push(("tags".to_string(), OpenapiValue::Sequence(tags)));
+        }
+        if !document.get_security().is_empty() {
+            entries.push((
+                "security".to_string(),
+                get_security_value(document.get_security()),
+            ));
+        }
+        let paths = document
+            .get_paths()
+            .iter()
+            .map(|path| (path.get_route().to_string(), self.get_path_value(path)))
+            .collect();
+        entries.push(("paths".to_string(), OpenapiValue::Mapping(paths)));
+        if let Some(components) = self.get_components_value(document.get_components()) {
+            entries.push(("components".to_string(), components));
+        }
+
+        OpenapiValue::Mapping(entries)
+    }
+
+    fn get_info_value(&self, info: &OpenapiInfo) -> OpenapiValue {
+        let mut entries = vec![new_string_entry("title", info.get_title())];
+        push_optional_string(&mut entries, "description", info.get_description());
+        entries.push(new_string_entry("version", info.get_version()));
+        OpenapiValue::Mapping(entries)
+    }
+
+    fn get_path_value(&self, path: &OpenapiPath) -> OpenapiValue {
+        OpenapiValue::Mapping(
+            path.get_operations()
+                .iter()
+                .map(|operation| {
+                    (
+                        operation.get_method().to_string(),
+                        self.get_operation_value(operation),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn get_operation_value(&self, operation: &OpenapiOperation) -> OpenapiValue {
+        let mut entries = vec![];
+        if !operation.get_tags().is_empty() {
+            entries.push(("tags".to_string(), new_strings_value(operation.get_tags())));
+        }
+        push_optional_string(&mut entries, "summary", operation.get_summary());
+        push_optional_string(&mut entries, "description", operation.get_description());
+        push_optional_string(&mut entries, "operationId", operation.get_operation_id());
+        if operation.is_deprecated() {
+            entries.push(new_raw_entry("deprecated", "true"));
+        }
+        if !operation.get_parameters().is_empty() {
+            let parameters = operation
+                .get_parameters()
+                .iter()
+                .map(|parameter| self.get_parameter_value(parameter))
+                .collect();
+            entries.push(("parameters".to_string(), OpenapiValue::Sequence(parameters)));
+        }
+        if let Some(request_body) = operation.get_request_body() {
+            entries.push((
+                "requestBody".to_string(),
+                self.get_request_body_value(request_body),
+            ));
+        }
+        let responses = operation
+            .get_responses()
+            .iter()
+            .map(|response| {
+                (
+                    response.get_status_code().to_string(),
+                    self.get_response_value(response),
+                )
+            })
+            .collect();
+        entries.push(("responses".to_string(), OpenapiValue::Mapping(responses)));
+        if let Some(security) = operation.get_security() {
+            entries.push(("security".to_string(), get_security_value(security)));
+        }
+
+        OpenapiValue::Mapping(entries)
+    }
+
+    fn get_parameter_value(&self, parameter: &OpenapiParameter) -> OpenapiValue {
+        let mut entries = vec![
+            new_string_entry("name", parameter.get_name()),
+            new_string_entry("in", &parameter.get_location().to_string()),
+        ];
+        push_optional_string(&mut entries, "description", parameter.get_description());
+        if parameter.is_required() || OpenapiParameterLocation::Path == parameter.get_location() {
+            entries.push(new_raw_entry("required", "true"));
+        }
+        if let Some(schema) = parameter.get_schema() {
+            entries.push(("schema".to_string(), self.get_data_type_value(schema)));
+        }
+        OpenapiValue::Mapping(entries)
+    }
+
+    fn get_request_body_value(&self, request_body: &OpenapiRequestBody) -> OpenapiValue {
+        let mut entries = vec![];
+        push_optional_string(&mut entries, "description", request_body.get_description());
+        if request_body.is_required() {
+            entries.push(new_raw_entry("required", "true"));
+        }
+        entries.push((
+            "content".to_string(),
+            self.get_content_value(request_body.get_content()),
+        ));
+        OpenapiValue::Mapping(entries)
+    }
+
+    fn get_response_value(&self, response: &OpenapiResponse) -> OpenapiValue {
+        let mut entries = vec![new_string_entry("description", response.get_description())];
+        if !response.get_content().is_empty() {
+            entries.push((
+                "content".to_string(),
+                self.get_content_value(response.get_content()),
+            ));
+        }
+        OpenapiValue::Mapping(entries)
+    }
+
+    fn get_content_value(&self, content: &[OpenapiMediaType]) -> OpenapiValue {
+        OpenapiValue::Mapping(
+            content
+                .iter()
+                .map(|media_type| {
+                    let mut entries = vec![];
+                    if let Some(schema) = media_type.get_schema() {
+                        entries.push(("schema".to_string(), self.get_data_type_value(schema)));
+                    }
+                    (
+                        media_type.get_media_type().to_string(),
+                        OpenapiValue::Mapping(entries),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    fn get_components_value(&self, components: &OpenapiComponents) -> Option<OpenapiValue> {
+        let mut entries = vec![];
+        if !components.get_schemas().is_empty() {
+            let schemas = components
+                .get_schemas()
+                .iter()
+                .map(|schema| {
+                    (
+                        schema.get_name().to_string(),
+                        OpenapiValue::Mapping(self.get_schema_entries(schema)),
+                    )
+                })
+                .collect();
+            entries.push(("schemas".to_string(), OpenapiValue::Mapping(schemas)));
+        }
+        if !components.get_security_schemes().is_empty() {
+            let security_schemes = components
+                .get_security_schemes()
+                .iter()
+                .map(|scheme| {
+                    (
+                        scheme.get_name().to_string(),
+                        get_security_scheme_value(scheme),
+                    )
+                })
+                .collect();
+            entries.push((
+                "securitySchemes".to_string(),
+                OpenapiValue::Mapping(security_schemes),
+            ));
+        }
+
+        if entries.is_empty() {
+            return None;
+        }
+        Some(OpenapiValue::Mapping(entries))
+    }
+
+    /// Properties are optional when they are not required, nullable ones are written as such
+    fn get_schema_entries(&self, schema: &OpenapiSchema) -> Vec<(String, OpenapiValue)> {
+        let schema_type = schema
+            .get_schema_type()
+            .clone()
+            .unwrap_or(OpenapiDataType::ObjectSimple);
+        let (data_type, is_nullable) = split_null(schema_type);
+
+        let mut last_entries = vec![];
+        if let Some(example) = schema.get_example() {
+            last_entries.push(new_raw_entry("example", example));
+        }
+        if let Some(default) = schema.get_default() {
+            last_entries.push(new_raw_entry("default", default));
+        }
+        if schema.get_properties().is_none() && schema.get_enum_values().is_none() {
+            return self.get_typed_entries(
+                &data_type,
+                is_nullable,
+                schema.get_description().clone(),
+                last_entries,
+            );
+        }
+
+        let mut entries = vec![];
+        push_optional_string(
+            &mut entries,
+            "description",
+            schema.get_description().clone(),
+        );
+        entries.extend(self.get_simple_type_entries(&data_type, is_nullable));
+        entries.extend(last_entries);
+        if let Some(enum_values) = schema.get_enum_values() {
+            let is_string = matches!(
+                data_type,
+                OpenapiDataType::String | OpenapiDataType::FormattedString(_)
+            );
+            let values = enum_values
+                .iter()
+                .map(|value| match value.parse::<f64>() {
+                    Ok(_) if !is_string => OpenapiValue::Raw(value.clone()),
+                    _ => OpenapiValue::String(value.clone()),
+                })
+                .collect();
+            entries.push(("enum".to_string(), OpenapiValue::Sequence(values)));
+        }
+        let required_properties = schema.get_required_properties();
+        if !required_properties.is_empty() {
+            entries.push((
+                "required".to_string(),
+                new_strings_value(&required_properties),
+            ));
+        }
+        if let Some(properties) = schema.get_properties() {
+            let properties = properties
+                .iter()
+                .map(|property| {
+                    (
+                        property.get_name().to_string(),
+                        OpenapiValue::Mapping(self.get_schema_entries(property)),
+                    )
+                })
+                .collect();
+            entries.push(("properties".to_string(), OpenapiValue::Mapping(properties)));
+        }
+        entries
+    }
+
+    /// Schema of a data type without name, like array items or request bodies
+    fn get_data_type_value(&self, data_type: &OpenapiDataType) -> OpenapiValue {
+        let (data_type, is_nullable) = split_null(data_type.clone());
+        OpenapiValue::Mapping(self.get_typed_entries(&data_type, is_nullable, None, vec![]))
+    }
+
+    /// References can not have siblings in OpenAPI 3.0, they are wrapped with "allOf"
+    fn get_typed_entries(
+        &self,
+        data_type: &OpenapiDataType,
+        is_nullable: bool,
+        description: Option<String>,
+        last_entries: Vec<(String, OpenapiValue)>,
+    ) -> Vec<(String, OpenapiValue)> {
+        let mut entries = vec![];
+        push_optional_string(&mut entries, "description", description);
+        match data_type {
+            OpenapiDataType::ObjectName(name) => {
+                let reference = get_ref_value(name);
+                if self.is_version_3_1 && is_nullable {
+                    let null_type = OpenapiValue::Mapping(
+                        self.get_simple_type_entries(&OpenapiDataType::Null, false),
+                    );
+                    entries.push((
+                        "oneOf".to_string(),
+                        OpenapiValue::Sequence(vec![reference, null_type]),
+                    ));
+                } else if self.is_version_3_1
+                    || (entries.is_empty() && last_entries.is_empty() && !is_nullable)
+                {
+                    entries.extend(get_entries_owned(reference));
+                } else {
+                    entries.push(("allOf".to_string(), OpenapiValue::Sequence(vec![reference])));
+                    if is_nullable {
+                        entries.push(new_raw_entry("nullable", "true"));
+                    }
+                }
+            }
+            OpenapiDataType::Array(data_types) => {
+                let mut members: Vec<OpenapiValue> = data_types
+                    .iter()
+                    .map(|member| self.get_data_type_value(member))
+                    .collect();
+                if is_nullable && self.is_version_3_1 {
+                    members.push(self.get_data_type_value(&OpenapiDataType::Null));
+                }
+                entries.push(("oneOf".to_string(), OpenapiValue::Sequence(members)));
+                if is_nullable && !self.is_version_3_1 {
+                    entries.push(new_raw_entry("nullable", "true"));
+                }
+            }
+            OpenapiDataType::Object(schema) => {
+                let mut schema_entries = self.get_schema_entries(schema);
+                if is_nullable {
+                    let nullable_schema = schema
+                        .as_ref()
+                        .clone()
+                        .with_schema_type(to_nullable(schema.get_schema_type().clone()));
+                    schema_entries = self.get_schema_entries(&nullable_schema);
+                }
+                entries.extend(schema_entries);
+            }
+            _ => entries.extend(self.get_simple_type_entries(data_type, is_nullable)),
+        }
+        entries.extend(last_entries);
+        entries
+    }
+
+    fn get_simple_type_entries(
+        &self,
+        data_type: &OpenapiDataType,
+        is_nullable: bool,
+    ) -> Vec<(String, OpenapiValue)> {
+        let (type_name, format) = match data_type {
+            OpenapiDataType::Integer(IntegerFormat::Int32) => ("integer", Some("int32")),
+            OpenapiDataType::Integer(IntegerFormat::Int64) => ("integer", Some("int64")),
+            OpenapiDataType::Number(NumberFormat::Float) => ("number", Some("float")),
+            OpenapiDataType::Number(NumberFormat::Double) => ("number", Some("double")),
+            OpenapiDataType::Number(NumberFormat::Decimal) => ("number", None),
+            OpenapiDataType::String => ("string", None),
+            OpenapiDataType::FormattedString(format) => ("string", Some(format.as_str())),
+            OpenapiDataType::Bytes => ("string", Some("byte")),
+            OpenapiDataType::Boolean => ("boolean", None),
+            OpenapiDataType::Null => ("null", None),
+            OpenapiDataType::ArrayItems(_) => ("array", None),
+            _ => ("object", None),
+        };
+
+        let mut entries = vec![];
+        if OpenapiDataType::Null == *data_type && !self.is_version_3_1 {
+            entries.push(new_raw_entry("nullable", "true"));
+            return entries;
+        }
+        if is_nullable && self.is_version_3_1 {
+            let type_names = [type_name.to_string(), "null".to_string()];
+            entries.push(("type".to_string(), new_strings_value(&type_names)));
+        } else {
+            entries.push(new_string_entry("type", type_name));
+        }
+        if let Some(format) = format {
+            entries.push(new_string_entry("format", format));
+        }
+        match data_type {
+            OpenapiDataType::ArrayItems(items) => {
+                entries.push(("items".to_string(), self.get_data_type_value(items)))
+            }
+            OpenapiDataType::Map(values) => entries.push((
+                "additionalProperties".to_string(),
+                self.get_data_type_value(values),
+            )),
+            _ => {}
+        }
+        if is_nullable && !self.is_version_3_1 {
+            entries.push(new_raw_entry("nullable", "true"));
+        }
+        entries
+    }
+}
+
+fn get_security_value(requirements: &[OpenapiSecurityRequirement]) -> OpenapiValue {
+    OpenapiValue::Sequence(
+        requirements
+            .iter()
+            .map(|requirement| {
+                OpenapiValue::Mapping(
+                    requirement
+                        .get_schemes()
+                        .iter()
+                        .map(|(name, scopes)| (name.clone(), new_strings_value(scopes)))
+                        .collect(),
+                )
+            })
+            .collect(),
+    )
+}
+
+fn get_security_scheme_value(scheme: &OpenapiSecurityScheme) -> OpenapiValue {
+    let type_name = match scheme.get_scheme_type() {
+        OpenapiSecuritySchemeType::ApiKey { .. } => "apiKey",
+        OpenapiSecuritySchemeType::Http { .. } => "http",
+        OpenapiSecuritySchemeType::OAuth2(_) => "oauth2",
+        OpenapiSecuritySchemeType::OpenIdConnect(_) => "openIdConnect",
+        OpenapiSecuritySchemeType::MutualTls => "mutualTLS",
+    };
+    let mut entries = vec![new_string_entry("type", type_name)];
+    push_optional_string(&mut entries, "description", scheme.get_description());
+    match scheme.get_scheme_type() {
+        OpenapiSecuritySchemeType::ApiKey { name, location } => {
+            let location = match location {
+                OpenapiApiKeyLocation::Query => "query",
+                OpenapiApiKeyLocation::Header => "header",
+                OpenapiApiKeyLocation::Cookie => "cookie",
+            };
+            entries.push(new_string_entry("name", name));
+            entries.push(new_string_entry("in", location));
+        }
+        OpenapiSecuritySchemeType::Http {
+            scheme,
+            bearer_format,
+        } => {
+            entries.push(new_string_entry("scheme", scheme));
+            push_optional_string(&mut entries, "bearerFormat", bearer_format.clone());
+        }
+        OpenapiSecuritySchemeType::OAuth2(flows) => {
+            let flows = flows
+                .iter()
+                .map(|flow| (get_flow_name(flow).to_string(), get_flow_value(flow)))
+                .collect();
+            entries.push(("flows".to_string(), OpenapiValue::Mapping(flows)));
+        }
+        OpenapiSecuritySchemeType::OpenIdConnect(url) => {
+            entries.push(new_string_entry("openIdConnectUrl", url))
+        }
+        OpenapiSecuritySchemeType::MutualTls => {}
+    }
+    OpenapiValue::Mapping(entries)
+}
+
+fn get_flow_name(flow: &OpenapiOAuthFlow) -> &str {
+    match flow.get_flow_type() {
+        OpenapiOAuthFlowType::Implicit => "implicit",
+        OpenapiOAuthFlowType::Password => "password",
+        OpenapiOAuthFlowType::ClientCredentials => "clientCredentials",
+        OpenapiOAuthFlowType::AuthorizationCode => "authorizationCode",
+    }
+}
+
+fn get_flow_value(flow: &OpenapiOAuthFlow) -> OpenapiValue {
+    let mut entries = vec![];
+    push_optional_string(
+        &mut entries,
+        "authorizationUrl",
+        flow.get_authorization_url(),
+    );
+    push_optional_string(&mut entries, "tokenUrl", flow.get_token_url());
+    push_optional_string(&mut entries, "refreshUrl", flow.get_refresh_url());
+    let scopes = flow
+        .get_scopes()
+        .iter()
+        .map(|(scope, description)| (scope.clone(), OpenapiValue::String(description.clone())))
+        .collect();
+    entries.push(("scopes".to_string(), OpenapiValue::Mapping(scopes)));
+    OpenapiValue::Mapping(entries)
+}
+
+fn get_ref_value(name: &str) -> OpenapiValue {
+    OpenapiValue::Mapping(vec![new_string_entry(
+        "$ref",
+        &format!("{}{}", COMPONENT_SCHEMAS_REF, name),
+    )])
+}
+
+/// Type without the null type of a union, and whether the null type was there
+fn split_null(data_type: OpenapiDataType) -> (OpenapiDataType, bool) {
+    let OpenapiDataType::Array(data_types) = data_type else {
+        return (data_type, false);
+    };
+    let is_nullable = data_types.contains(&OpenapiDataType::Null);
+    let mut data_types: Vec<OpenapiDataType> = data_types
+        .into_iter()
+        .filter(|data_type| OpenapiDataType::Null != *data_type)
+        .collect();
+    match data_types.len() {
+        0 => (OpenapiDataType::Null, false),
+        1 => (data_types.remove(0), is_nullable),
+        _ => (OpenapiDataType::Array(data_types), is_nullable),
+    }
+}
+
+fn to_nullable(data_type: Option<OpenapiDataType>) -> OpenapiDataType {
+    let data_type = data_type.unwrap_or(OpenapiDataType::ObjectSimple);
+    OpenapiDataType::Array(vec![OpenapiDataType::Null, data_type])
+}
+
+fn new_string_entry(key: &str, value: &str) -> (String, OpenapiValue) {
+    (key.to_string(), OpenapiValue::String(value.to_string()))
+}
+
+fn new_raw_entry(key: &str, value: &str) -> (String, OpenapiValue) {
+    (key.to_string(), OpenapiValue::Raw(value.to_string()))
+}
+
+fn new_strings_value(values: &[String]) -> OpenapiValue {
+    OpenapiValue::Sequence(
+        values
+            .iter()
+            .map(|value| OpenapiValue::String(value.clone()))
+            .collect(),
+    )
+}
+
+fn push_optional_string(
+    entries: &mut Vec<(String, OpenapiValue)>,
+    key: &str,
+    value: Option<String>,
+) {
+    if let Some(value) = value {
+        entries.push((key.to_string(), OpenapiValue::String(value)));
+    }
+}
+
+fn get_entries(value: &OpenapiValue) -> &[(String, OpenapiValue)] {
+    match value {
+        OpenapiValue::Mapping(entries) => entries,
+        _ => &[],
+    }
+}
+
+fn get_entries_owned(value: OpenapiValue) -> Vec<(String, OpenapiValue)> {
+    match value {
+        OpenapiValue::Mapping(entries) => entries,
+        _ => vec![],
+    }
+}
+
+fn write_yaml_mapping_entries(
+    result: &mut String,
+    depth: usize,
+    entries: &[(String, OpenapiValue)],
+) {
+    for (index, (key, value)) in entries.iter().enumerate() {
+        // The first entry of a sequence item follows its hyphen
+        if index > 0 || !result.ends_with("- ") {
+            result.push_str(&get_indentation(depth));
+        }
+        result.push_str(&to_yaml_scalar(key));
+        result.push(':');
+        write_yaml_value(result, depth, value);
+    }
+}
+
+/// Value after its key, nested collections are written in the next lines
+fn write_yaml_value(result: &mut String, depth: usize, value: &OpenapiValue) {
+    match value {
+        OpenapiValue::String(value) => {
+            result.push(' ');
+            result.push_str(&to_yaml_scalar(value));
+            result.push('\n');
+        }
+        OpenapiValue::Raw(value) => {
+            result.push(' ');
+            result.push_str(&to_single_line_json(value));
+            result.push('\n');
+        }
+        OpenapiValue::Sequence(items) if items.is_empty() => result.push_str(" []\n"),
+        OpenapiValue::Mapping(entries) if entries.is_empty() => result.push_str(" {}\n"),
+        OpenapiValue::Sequence(items) => {
+            result.push('\n');
+            for item in items {
+                result.push_str(&get_indentation(depth + 1));
+                result.push('-');
+                match item {
+                    OpenapiValue::Mapping(entries) if !entries.is_empty() => {
+                        result.push(' ');
+                        write_yaml_mapping_entries(result, depth + 2, entries);
+                    }
+                    _ => write_yaml_value(result, depth + 2, item),
+                }
+            }
+        }
+        OpenapiValue::Mapping(entries) => {
+            result.push('\n');
+            write_yaml_mapping_entries(result, depth + 1, entries);
+        }
+    }
+}
+
+fn write_json(result: &mut String, depth: usize, value: &OpenapiValue) {
+    match value {
+        OpenapiValue::String(value) => result.push_str(&escape_json(value)),
+        OpenapiValue::Raw(value) => result.push_str(&to_single_line_json(value)),
+        OpenapiValue::Sequence(items) if items.is_empty() => result.push_str("[]"),
+        OpenapiValue::Mapping(entries) if entries.is_empty() => result.push_str("{}"),
+        OpenapiValue::Sequence(items) => {
+            result.push_str("[\n");
+            for (index, item) in items.iter().enumerate() {
+                result.push_str(&get_indentation(depth + 1));
+                write_json(result, depth + 1, item);
+                push_json_separator(result, index, items.len());
+            }
+            result.push_str(&get_indentation(depth));
+            result.push(']');
+        }
+        OpenapiValue::Mapping(entries) => {
+            result.push_str("{\n");
+            for (index, (key, value)) in entries.iter().enumerate() {
+                result.push_str(&get_indentation(depth + 1));
+                result.push_str(&escape_json(key));
+                result.push_str(": ");
+                write_json(result, depth + 1, value);
+                push_json_separator(result, index, entries.len());
+            }
+            result.push_str(&get_indentation(depth));
+            result.push('}');
+        }
+    }
+}
+
+fn push_json_separator(result: &mut String, index: usize, length: usize) {
+    if index < length - 1 {
+        result.push(',');
+    }
+    result.push('\n');
+}
+
+/// Line breaks can not be part of JSON strings, so they are only formatting
+fn to_single_line_json(value: &str) -> String {
+    if !value.contains('\n') {
+        return value.to_string();
+    }
+    value
+        .lines()
+        .map(|line| line.trim())
+        .collect::<Vec<&str>>()
+        .join(" ")
+}
+
+/// Strings are quoted when they would be read as another type or break the YAML syntax,
+/// double quotation marks are only used for the ones with escape sequences
+fn to_yaml_scalar(value: &str) -> String {
+    if !is_quote_required(value) {
+        return value.to_string();
+    }
+    if value.chars().any(|current| current.is_control()) {
+        return escape_json(value);
+    }
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+fn is_quote_required(value: &str) -> bool {
+    const INDICATORS: &str = "-?:,[]{}#&*!|>'\"%@`";
+    const RESERVED: [&str; 12] = [
+        "true", "false", "yes", "no", "on", "off", "y", "n", "null", "~", ".inf", ".nan",
+    ];
+    let Some(first) = value.chars().next() else {
+        return true;
+    };
+    INDICATORS.contains(first)
+        || value.trim() != value
+        || value.contains(": ")
+        || value.contains(" #")
+        || value.ends_with(':')
+        || value.chars().any(|current| current.is_control())
+        || RESERVED.contains(&value.to_lowercase().trim_start_matches(['+', '-']))
+        || is_number_like(value)
+}
+
+/// Numbers, also octal or hexadecimal ones, and dates
+fn is_number_like(value: &str) -> bool {
+    let lowercase = value.to_lowercase();
+    let is_date = value.len() >= 10
+        && value.as_bytes()[4] == b'-'
+        && value.as_bytes()[7] == b'-'
+        && value.as_bytes()[..4].iter().all(u8::is_ascii_digit);
+    value.parse::<f64>().is_ok()
+        || lowercase.starts_with("0x")
+        || lowercase.starts_with("0o")
+        || is_date
+}
+
+fn get_indentation(depth: usize) -> String {
+    "  ".repeat(depth)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_assert::assert_same_as_file;
+    use crate::core::testing::test_path::{get_non_existing_test_file, get_test_file};
+    use crate::domain::usecase::openapi::parser::dto::openapi_data_type::OpenapiDataType;
+    use crate::domain::usecase::openapi::parser::openapi_parser;
+    use crate::domain::usecase::openapi::writer::openapi_writer;
+    use crate::domain::usecase::openapi::writer::openapi_writer::OpenapiFormat;
+
+    #[test]
+    fn write_yaml_document() {
+        let file_path = get_test_file(&get_current_file_path(), "shop.yaml");
+        let document = openapi_parser::parse(&file_path).expect("Openapi must be valid");
+
+        let result = openapi_writer::to_str(&document, OpenapiFormat::Yaml);
+
+        let expected_file_path = get_test_file(&get_current_file_path(), "shop_expected.yaml");
+        assert_same_as_file(&expected_file_path, &result);
+    }
+
+    #[test]
+    fn write_json_document() {
+        let file_path = get_test_file(&get_current_file_path(), "shop_3_1.json");
+        let document = openapi_parser::parse(&file_path).expect("Openapi must be valid");
+
+        let result = openapi_writer::to_str(&document, OpenapiFormat::Json);
+
+        let expected_file_path = get_test_file(&get_current_file_path(), "shop_3_1_expected.json");
+        assert_same_as_file(&expected_file_path, &result);
+    }
+
+    #[test]
+    fn written_documents_are_parsed_again() {
+        for (input, output) in [
+            ("shop.yaml", "shop_written.yaml"),
+            ("shop.yaml", "shop_written.json"),
+            ("shop_3_1.json", "shop_3_1_written.yaml"),
+            ("shop_3_1.json", "shop_3_1_written.json"),
+        ] {
+            let file_path = get_test_file(&get_current_file_path(), input);
+            let document = openapi_parser::parse(&file_path).expect("Openapi must be valid");
+            let output_path = get_non_existing_test_file(&get_current_file_path(), output);
+
+            openapi_writer::write(&document, &output_path).expect("Openapi must be written");
+
+            let written_document =
+                openapi_parser::parse(&output_path).expect("Written openapi must be valid");
+            fs::remove_file(&output_path).expect("Written openapi must be removed");
+            assert_eq!(document, written_document, "{} as {}", input, output);
+        }
+    }
+
+    #[test]
+    fn written_required_nullable_property_is_parsed_again() {
+        let file_path = get_test_file(&get_current_file_path(), "shop.yaml");
+        let document = openapi_parser::parse(&file_path).expect("Openapi must be valid");
+        let output_path =
+            get_non_existing_test_file(&get_current_file_path(), "shop_required_nullable.yaml");
+
+        openapi_writer::write(&document, &output_path).expect("Openapi must be written");
+
+        let written_document =
+            openapi_parser::parse(&output_path).expect("Written openapi must be valid");
+        fs::remove_file(&output_path).expect("Written openapi must be removed");
+        let order = written_document
+            .get_components()
+            .get_schema("Order")
+            .expect("Order expected");
+        let coupon = order
+            .get_properties()
+            .iter()
+            .flatten()
+            .find(|property| "coupon" == property.get_name())
+            .expect("Coupon expected");
+        assert!(coupon.is_required());
+        assert_eq!(
+            &Some(OpenapiDataType::Array(vec![
+                OpenapiDataType::Null,
+                OpenapiDataType::String
+            ])),
+            coupon.get_schema_type()
+        );
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -1,6 +1,7 @@
 use std::fmt;
 
 use crate::core::file_system::file_cache::FileCache;
+use crate::core::parser::parser_node_trait::ParserNode;
 use crate::java::import::JavaImport;
 use crate::java::indentation_config::JavaIndentation;
 use crate::java::parser::java_node::JavaNode;
@@ -10,7 +11,10 @@ use crate::java::scanner::file::java_file_imports::JavaFileImports;
 #[derive(Debug, Clone)]
 pub struct JavaAnnotationUsage {
     explicit_import: JavaImport,
-    // TODO: allow annotation parameters
+    /// Element names with their value as java expression (i.e. "required" and "false")
+    parameters: Vec<(String, String)>,
+    /// Types used by the parameter values (i.e. "RequestMethod" of "RequestMethod.HEAD")
+    parameter_imports: Vec<JavaImport>,
 }
 
 impl JavaAnnotationUsage {
@@ -22,27 +26,31 @@ impl JavaAnnotationUsage {
     pub fn get_name(&self) -> String {
         self.get_self_import().get_last_node().clone()
     }
+
+    /// # get_parameters
+    /// Element names with their value as written in java, a single value without
+    /// element name (i.e. "@GetMapping(\"/pets\")") is named "value".
+    pub fn get_parameters(&self) -> &Vec<(String, String)> {
+        &self.parameters
+    }
 }
 
 impl JavaAnnotationUsage {
     // Crate or private methods
     pub(crate) fn to_file_string(&self, indentation: &JavaIndentation) -> String {
-        format!(
-            "{}@{}\n",
-            indentation.get_current_indentation(),
-            self.get_name()
-        )
+        format!("{}{}\n", indentation.get_current_indentation(), self)
     }
 
     pub(crate) fn get_imports(&self) -> Vec<&JavaImport> {
-        vec![self.get_self_import()]
+        let mut imports = vec![self.get_self_import()];
+        imports.extend(self.parameter_imports.iter());
+        imports
     }
 
     pub(crate) fn get_self_import(&self) -> &JavaImport {
         &self.explicit_import
     }
 
-    /// TODO: add annotation parameters analysis
     pub(crate) fn new_from_java_node_unchecked(
         root_java_node: &JavaNode,
         file_imports: &JavaFileImports,
@@ -51,16 +59,57 @@ impl JavaAnnotationUsage {
         let id_node = Self::get_annotation_id_node(root_java_node).expect("Expected id");
         let explicit_import =
             file_imports.get_explicit_import_from_identifier(id_node, java_file_cache)?;
+        let parameters = Self::get_parameters_from_node(root_java_node, java_file_cache);
+
+        Ok(Self::new(explicit_import, parameters))
+    }
+
+    fn get_parameters_from_node(
+        node: &JavaNode,
+        java_file_cache: &FileCache,
+    ) -> Vec<(String, String)> {
+        let argument_list_opt = node
+            .get_children()
+            .iter()
+            .find(|child| Some(JavaNodeType::AnnotationArgumentList) == child.get_node_type());
+        let mut parameters = Vec::new();
+        for argument in argument_list_opt
+            .iter()
+            .flat_map(|list| list.get_children())
+        {
+            match argument.get_node_type() {
+                Some(JavaNodeType::LParentheses)
+                | Some(JavaNodeType::RParentheses)
+                | Some(JavaNodeType::Comma) => {}
+                Some(JavaNodeType::ElementValuePair) => {
+                    let children = argument.get_children();
+                    if let (Some(name), Some(value)) = (children.first(), children.last()) {
+                        parameters.push((
+                            name.get_content_from_cache(java_file_cache),
+                            value.get_content_from_cache(java_file_cache),
+                        ));
+                    }
+                }
+                _ => parameters.push((
+                    "value".to_string(),
+                    argument.get_content_from_cache(java_file_cache),
+                )),
+            }
+        }
 
-        Ok(JavaAnnotationUsage { explicit_import })
+        parameters
     }
 
     fn get_annotation_id_node(node: &JavaNode) -> Option<&JavaNode> {
         node.get_children().get(1)
     }
 
-    fn new(explicit_import: JavaImport) -> JavaAnnotationUsage {
-        JavaAnnotationUsage { explicit_import }
+    fn new(explicit_import: JavaImport, parameters: Vec<(String, String)>) -> JavaAnnotationUsage {
+        JavaAnnotationUsage {
+            explicit_import,
+            parameters,
+            parameter_imports: vec![],
+        }
     }
 }
 
@@ -75,9 +124,42 @@ pub(crate) fn is_java_node_annotation(node_type: &JavaNodeType) -> bool {
     &JavaNodeType::Annotation == node_type || &JavaNodeType::MarkerAnnotation == node_type
 }
 
+/// Indentation and annotations of a declaration, leaving "result" where its modifiers start.
+/// Annotations are written in their own lines unless "on_same_line" is set.
+pub(crate) fn write_annotations(
+    result: &mut String,
+    annotations: &[JavaAnnotationUsage],
+    indentation: &JavaIndentation,
+    on_same_line: bool,
+) {
+    if on_same_line {
+        *result += indentation.get_current_indentation().as_str();
+        for annotation in annotations {
+            *result += format!("{} ", annotation).as_str();
+        }
+        return;
+    }
+
+    for annotation in annotations {
+        *result += annotation.to_file_string(indentation).as_str();
+    }
+    *result += indentation.get_current_indentation().as_str();
+}
+
 impl fmt::Display for JavaAnnotationUsage {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         write!(fmt, "@{}", self.get_name())?;
+        match self.get_parameters().as_slice() {
+            [] => {}
+            [(name, value)] if "value" == name => write!(fmt, "({})", value)?,
+            parameters => {
+                let elements: Vec<String> = parameters
+                    .iter()
+                    .map(|(name, value)| format!("{} = {}", name, value))
+                    .collect();
+                write!(fmt, "({})", elements.join(", "))?;
+            }
+        }
 
         Ok(())
     }
@@ -85,11 +167,17 @@ impl fmt::Display for JavaAnnotationUsage {
 
 pub struct JavaAnnotationUsageBuilder {
     import_raw: Option<String>,
+    parameters: Vec<(String, String)>,
+    parameter_imports_raw: Vec<String>,
 }
 
 impl JavaAnnotationUsageBuilder {
     fn new_builder() -> Self {
-        Self { import_raw: None }
+        Self {
+            import_raw: None,
+            parameters: vec![],
+            parameter_imports_raw: vec![],
+        }
     }
 
     pub(crate) fn import(&mut self, input: &str) -> &mut Self {
@@ -97,14 +185,36 @@ impl JavaAnnotationUsageBuilder {
         self
     }
 
+    /// # parameter
+    /// Annotation element with its value as java expression, string values must
+    /// be quoted (i.e. "\"/pets\"").
+    pub(crate) fn parameter(&mut self, name: &str, value: &str) -> &mut Self {
+        self.parameters.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// # parameter_import
+    /// Type used by a parameter value, like the enum of a constant (i.e.
+    /// "org.springframework.web.bind.annotation.RequestMethod" for "RequestMethod.HEAD").
+    pub(crate) fn parameter_import(&mut self, input: &str) -> &mut Self {
+        self.parameter_imports_raw.push(input.to_string());
+        self
+    }
+
     pub fn build(&mut self) -> Result<JavaAnnotationUsage, String> {
         let import_str = self
             .import_raw
             .clone()
             .ok_or("Missing java annotation explicit import")?;
         let import = JavaImport::new_explicit_import(&import_str)?;
+        let mut parameter_imports = vec![];
+        for parameter_import in &self.parameter_imports_raw {
+            parameter_imports.push(JavaImport::new_explicit_import(parameter_import)?);
+        }
 
-        Ok(JavaAnnotationUsage::new(import))
+        let mut result = JavaAnnotationUsage::new(import, self.parameters.clone());
+        result.parameter_imports = parameter_imports;
+        Ok(result)
     }
 }
 
@@ -122,6 +232,27 @@ mod tests {
         assert_eq!("@Service", annotation.to_string());
     }
 
+    #[test]
+    fn to_string_with_parameters() {
+        let single_value = JavaAnnotationUsage::builder()
+            .import("org.springframework.web.bind.annotation.GetMapping")
+            .parameter("value", "\"/pets\"")
+            .build()
+            .expect("Annotation must be created");
+        let several_elements = JavaAnnotationUsage::builder()
+            .import("org.springframework.web.bind.annotation.RequestParam")
+            .parameter("value", "\"limit\"")
+            .parameter("required", "false")
+            .build()
+            .expect("Annotation must be created");
+
+        assert_eq!("@GetMapping(\"/pets\")", single_value.to_string());
+        assert_eq!(
+            "@RequestParam(value = \"limit\", required = false)",
+            several_elements.to_string()
+        );
+    }
+
     #[test]
     fn get_imports() {
         let import_package = "org.springframework.stereotype.Service";
@@ -137,4 +268,28 @@ mod tests {
             assert_eq!(import_package, result_import.get_route());
         }
     }
+
+    #[test]
+    fn get_imports_with_parameter_imports() {
+        let annotation = JavaAnnotationUsage::builder()
+            .import("org.springframework.web.bind.annotation.RequestMapping")
+            .parameter("method", "RequestMethod.HEAD")
+            .parameter_import("org.springframework.web.bind.annotation.RequestMethod")
+            .build()
+            .expect("Annotation must be created");
+
+        let routes: Vec<String> = annotation
+            .get_imports()
+            .iter()
+            .map(|import| import.get_route())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "org.springframework.web.bind.annotation.RequestMapping",
+                "org.springframework.web.bind.annotation.RequestMethod"
+            ],
+            routes
+        );
+    }
 }
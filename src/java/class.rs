@@ -1,14 +1,22 @@
 use std::path::{Path, PathBuf};
 
+use crate::core::database::model::java_symbol::java_symbol_entity::JavaStructureEntity;
 use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::observability::logger;
 use crate::java::annotation_usage::JavaAnnotationUsage;
+use crate::java::comment;
+use crate::java::comment::JavaComment;
 use crate::java::field::JavaField;
 use crate::java::import::JavaImport;
+use crate::java::import_layout_config::JavaImportLayout;
 use crate::java::interface::JavaInterface;
+use crate::java::javadoc::JavaDoc;
 use crate::java::method::JavaMethod;
 use crate::java::scanner::file::java_file::JavaFile;
 use crate::java::scanner::file::java_structure::JavaStructure;
 use crate::java::scanner::file::java_structure_type::JavaStructureType;
+use crate::java::scanner::hierarchy::java_type_hierarchy;
+use crate::java::supertype::JavaSupertype;
 use crate::java::visibility::JavaVisibility;
 
 /// # JavaClass
@@ -90,6 +98,27 @@ impl JavaClass {
         Ok(())
     }
 
+    /// # organize_imports
+    /// Remove the unused and duplicated imports of the class file and sort them
+    /// following the given layout.
+    pub fn organize_imports(&mut self, layout: &JavaImportLayout) -> Result<(), String> {
+        self.scanned_file = self.scanned_file.organize_imports(layout)?;
+
+        Ok(())
+    }
+
+    /// # get_comments
+    /// Get the comments written before the class declaration, including its javadoc.
+    pub fn get_comments(&self) -> &Vec<JavaComment> {
+        self.get_structure().get_comments()
+    }
+
+    /// # get_javadoc
+    /// Get the javadoc of the class if any.
+    pub fn get_javadoc(&self) -> Option<&JavaDoc> {
+        comment::get_javadoc(self.get_comments())
+    }
+
     /// # get_annotations
     /// Get the java annotations of the class
     pub fn get_annotations(&self) -> &Vec<JavaAnnotationUsage> {
@@ -120,6 +149,28 @@ impl JavaClass {
         self.get_structure().is_final()
     }
 
+    /// # is_sealed
+    /// It returns if the current JavaClass is sealed.
+    pub fn is_sealed(&self) -> bool {
+        self.get_structure().is_sealed()
+    }
+
+    /// # is_non_sealed
+    /// It returns if the current JavaClass is non-sealed.
+    pub fn is_non_sealed(&self) -> bool {
+        self.get_structure().is_non_sealed()
+    }
+
+    /// # get_permitted_subtypes
+    /// Routes of the "permits" clause, i.e. "org.test.Circle".
+    pub fn get_permitted_subtypes(&self) -> Vec<String> {
+        self.get_structure()
+            .get_permitted_subtypes()
+            .iter()
+            .map(|import| import.get_route())
+            .collect()
+    }
+
     /// # get_name
     /// It returns the current JavaClass name.
     pub fn get_name(&self) -> &str {
@@ -144,6 +195,46 @@ impl JavaClass {
         self.get_structure().get_implemented_interfaces()
     }
 
+    /// # get_all_supertypes
+    /// Get the classes and interfaces that the current JavaClass extends or implements,
+    /// directly or transitively, nearest first. Unlike get_extended_class, external types
+    /// (i.e. "java.lang.AutoCloseable") and types that can not be resolved are also returned.
+    pub fn get_all_supertypes(&self) -> Result<Vec<JavaSupertype>, String> {
+        java_type_hierarchy::get_supertypes(self.get_file_path(), &self.get_route())
+    }
+
+    /// # get_subclasses
+    /// Get the classes of the project extending the current JavaClass, directly or transitively.
+    pub fn get_subclasses(&self) -> Result<Vec<JavaClass>, String> {
+        let subtypes = java_type_hierarchy::get_subtypes(self.get_file_path(), &self.get_route())?;
+
+        Ok(Self::from_structure_entities(&subtypes))
+    }
+
+    /// # get_inherited_methods
+    /// Get the methods declared in the project supertypes and not in the current JavaClass.
+    pub fn get_inherited_methods(&self) -> Result<Vec<JavaMethod>, String> {
+        let supertypes = self.get_all_supertypes()?;
+
+        Ok(java_type_hierarchy::get_inherited_methods(
+            self.get_structure(),
+            &supertypes,
+        ))
+    }
+
+    /// # get_overridden_methods
+    /// Get the methods of the current JavaClass that override a supertype method.
+    pub fn get_overridden_methods(&self) -> Result<Vec<JavaMethod>, String> {
+        let supertypes = self.get_all_supertypes()?;
+
+        java_type_hierarchy::get_overridden_methods(
+            self.get_file_path(),
+            &self.get_route(),
+            self.get_structure(),
+            &supertypes,
+        )
+    }
+
     /// # get_methods
     /// Get the methods of the current JavaClass.
     pub fn get_methods(&self) -> &Vec<JavaMethod> {
@@ -188,6 +279,23 @@ impl JavaClass {
         Ok(java_class)
     }
 
+    /// Structures that are not classes or can not be scanned are skipped
+    pub(crate) fn from_structure_entities(structures: &[JavaStructureEntity]) -> Vec<Self> {
+        structures
+            .iter()
+            .filter(|structure| structure.structure_type == "class")
+            .filter_map(
+                |structure| match Self::from(Path::new(&structure.location.file_path)) {
+                    Ok(java_class) => Some(java_class),
+                    Err(err) => {
+                        logger::log_warning(&err);
+                        None
+                    }
+                },
+            )
+            .collect()
+    }
+
     pub(crate) fn get_structure(&self) -> &JavaStructure {
         self.get_scanned_file().get_structure()
     }
@@ -196,6 +304,14 @@ impl JavaClass {
         &self.scanned_file
     }
 
+    fn get_file_path(&self) -> &Path {
+        self.get_scanned_file().get_file_path()
+    }
+
+    fn get_route(&self) -> String {
+        self.get_self_import().get_route()
+    }
+
     #[cfg(test)]
     pub(crate) fn get_file(&self) -> &PathBuf {
         self.get_scanned_file().get_file_path()
@@ -214,14 +330,19 @@ impl JavaClass {
 pub struct JavaClassBuilder {
     folder: Option<PathBuf>,
 
+    comments: Vec<JavaComment>,
     annotations: Vec<JavaAnnotationUsage>,
     visibility: JavaVisibility,
     is_static: bool,
     is_final: bool,
     is_abstract: bool,
+    is_sealed: bool,
+    is_non_sealed: bool,
 
     extended_class: Vec<JavaImport>, // TODO: use JavaClass instead, do not expose JavaImport
     implemented_interfaces: Vec<JavaImport>, // TODO: use JavaInterface instead, do not expose JavaImport
+    static_imports: Vec<String>,
+    permitted_subtypes: Vec<String>,
 
     name: Option<String>,
     fields: Vec<JavaField>,
@@ -232,13 +353,18 @@ impl JavaClassBuilder {
     fn new_builder() -> Self {
         Self {
             folder: None,
+            comments: vec![],
             annotations: vec![],
             visibility: JavaVisibility::Package,
             is_static: false,
             is_final: false,
             is_abstract: false,
+            is_sealed: false,
+            is_non_sealed: false,
             extended_class: Vec::new(),
             implemented_interfaces: vec![],
+            static_imports: vec![],
+            permitted_subtypes: vec![],
             name: None,
             fields: vec![],
             methods: vec![],
@@ -250,6 +376,19 @@ impl JavaClassBuilder {
         self
     }
 
+    /// # comments
+    /// Comments written before the class annotations, sorted from top to bottom.
+    pub fn comments(&mut self, input: Vec<JavaComment>) -> &mut Self {
+        self.comments = input;
+        self
+    }
+
+    /// Javadoc written right before the class annotations.
+    pub fn javadoc(&mut self, input: JavaDoc) -> &mut Self {
+        comment::set_javadoc(&mut self.comments, input);
+        self
+    }
+
     pub fn annotations(&mut self, input: Vec<JavaAnnotationUsage>) -> &mut Self {
         self.annotations = input;
         self
@@ -273,6 +412,16 @@ impl JavaClassBuilder {
         self.is_abstract = input;
         self
     }
+
+    pub fn is_sealed(&mut self, input: bool) -> &mut Self {
+        self.is_sealed = input;
+        self
+    }
+
+    pub fn is_non_sealed(&mut self, input: bool) -> &mut Self {
+        self.is_non_sealed = input;
+        self
+    }
     pub fn extended_class(&mut self, input: JavaClass) -> &mut Self {
         self.extended_class = vec![input.get_self_import()];
         self
@@ -286,6 +435,21 @@ impl JavaClassBuilder {
         self
     }
 
+    /// Static members imported by the class besides the ones required by its methods
+    /// (i.e. "java.util.concurrent.TimeUnit.SECONDS").
+    pub fn static_imports(&mut self, input: Vec<&str>) -> &mut Self {
+        self.static_imports = input.iter().map(|route| route.to_string()).collect();
+        self
+    }
+
+    /// # permits
+    /// Routes of the subclasses allowed to extend a sealed class (i.e. "org.test.Circle").
+    /// The ones found in the project must extend the class.
+    pub fn permits(&mut self, input: Vec<&str>) -> &mut Self {
+        self.permitted_subtypes = input.iter().map(|route| route.to_string()).collect();
+        self
+    }
+
     pub fn name(&mut self, input: &str) -> &mut Self {
         self.name = Some(input.to_string());
         self
@@ -325,17 +489,38 @@ impl JavaClassBuilder {
         }
 
         // TODO: validate extended_class & implemented_interfaces
+        let static_imports = self
+            .static_imports
+            .iter()
+            .map(|route| JavaImport::new_static_import(route))
+            .collect::<Result<Vec<JavaImport>, String>>()
+            .map_err(|err| format!("Invalid java class \"{}\" build, {}", name, err))?;
+        if self.is_sealed {
+            java_type_hierarchy::check_permitted_subtypes(folder, &name, &self.permitted_subtypes)
+                .map_err(|err| format!("Invalid java class \"{}\" build, {}", name, err))?;
+        }
+        let permitted_subtypes = self
+            .permitted_subtypes
+            .iter()
+            .map(|route| JavaImport::new_explicit_import_without_m2_repo_scan(route))
+            .collect::<Result<Vec<JavaImport>, String>>()
+            .map_err(|err| format!("Invalid java class \"{}\" build, {}", name, err))?;
 
         let file = folder.join(format!("{}.java", name));
         return match JavaStructure::builder()
             .structure_type(JavaStructureType::Class)
+            .comments(self.comments.clone())
             .annotations(self.annotations.clone())
             .visibility(self.visibility)
             .is_static(self.is_static)
             .is_final(self.is_final)
             .is_abstract(self.is_abstract)
+            .is_sealed(self.is_sealed)
+            .is_non_sealed(self.is_non_sealed)
             .extended_classes(self.extended_class.clone())
             .implemented_interfaces(self.implemented_interfaces.clone())
+            .permitted_subtypes(permitted_subtypes)
+            .static_imports(static_imports)
             .name(&name)
             .fields(self.fields.clone())
             .methods(self.methods.clone())
@@ -355,12 +540,15 @@ mod tests {
     use crate::core::testing::test_assert::{assert_fail, assert_same_file};
     use crate::core::testing::test_path;
     use crate::java::class::JavaClass;
+    use crate::java::comment::JavaComment;
     use crate::java::data_type::JavaDataType;
     use crate::java::dependency::java::time::java_time_factory;
     use crate::java::dependency::org::springframework::spring_context::java_spring_context_factory;
     use crate::java::field::JavaField;
     use crate::java::interface::JavaInterface;
+    use crate::java::javadoc::JavaDoc;
     use crate::java::method::JavaMethod;
+    use crate::java::variable::JavaVariable;
     use crate::java::visibility::JavaVisibility;
 
     #[test]
@@ -484,6 +672,72 @@ mod tests {
         }
     }
 
+    #[test]
+    fn class_with_throws_and_varargs() {
+        let file_path = get_test_file("ClassWithThrowsAndVarargs");
+
+        let java_class = JavaClass::from(&file_path).expect("Class must be scanned");
+
+        let method = java_class.get_methods().first().expect("Method expected");
+        let throws: Vec<String> = method
+            .get_throws()
+            .iter()
+            .map(|exception_type| exception_type.to_string())
+            .collect();
+        assert_eq!(vec!["IOException", "IllegalStateException"], throws);
+        let parameters: Vec<String> = method
+            .get_parameters()
+            .iter()
+            .map(|parameter| parameter.to_string())
+            .collect();
+        assert_eq!(
+            vec!["@PathVariable final UUID id", "String... names"],
+            parameters
+        );
+        assert!(method.get_parameters()[1].is_varargs());
+        let imports: Vec<String> = method
+            .get_imports()
+            .iter()
+            .map(|import| import.get_route())
+            .collect();
+        assert_eq!(
+            vec![
+                "org.springframework.web.bind.annotation.PathVariable",
+                "java.util.UUID",
+                "java.io.IOException"
+            ],
+            imports
+        );
+    }
+
+    #[test]
+    fn build_class_with_invalid_method_modifiers_fails() {
+        let default_method = JavaMethod::builder()
+            .is_default(true)
+            .name("defaultMethod")
+            .build()
+            .expect("defaultMethod is expected to be valid");
+        let abstract_method = JavaMethod::builder()
+            .is_abstract(true)
+            .name("abstractMethod")
+            .build()
+            .expect("abstractMethod is expected to be valid");
+
+        let default_result = JavaClass::builder()
+            .folder(&get_java_class_root_test_folder())
+            .name("ClassWithDefaultMethod")
+            .methods(vec![default_method])
+            .build();
+        let abstract_result = JavaClass::builder()
+            .folder(&get_java_class_root_test_folder())
+            .name("ClassWithAbstractMethod")
+            .methods(vec![abstract_method])
+            .build();
+
+        assert!(default_result.is_err_and(|err| err.contains("only allowed in interfaces")));
+        assert!(abstract_result.is_err_and(|err| err.contains("only allowed in abstract classes")));
+    }
+
     #[test]
     fn insert_method_in_new_class() {
         let folder = get_java_class_root_test_folder().join("insertmethod");
@@ -507,6 +761,208 @@ mod tests {
         }
     }
 
+    #[test]
+    fn new_from_path_keeps_comments() {
+        let java_class =
+            JavaClass::from(&get_test_file("CommentedService")).expect("Class must be scanned");
+
+        assert_eq!(
+            &JavaComment::Line("Generated from the user schema".to_string()),
+            &java_class.get_comments()[0]
+        );
+        let class_javadoc = java_class.get_javadoc().expect("Class javadoc expected");
+        assert_eq!("Service with comments.", class_javadoc.get_description());
+        assert_eq!(&vec!["@see java.util.UUID"], class_javadoc.get_other_tags());
+        let fields = java_class.get_fields();
+        assert_eq!(
+            &vec![JavaComment::Line("Identifier of the service".to_string())],
+            fields[0].get_comments()
+        );
+        assert!(fields[1].get_comments().is_empty());
+        let method_javadoc = java_class.get_methods()[0]
+            .get_javadoc()
+            .expect("Method javadoc expected");
+        assert_eq!("Get the identifier.", method_javadoc.get_description());
+        assert_eq!("prefix", method_javadoc.get_params()[0].get_name());
+        assert_eq!(
+            &Some("the identifier".to_string()),
+            method_javadoc.get_return_description()
+        );
+        assert_eq!(
+            "IllegalStateException",
+            method_javadoc.get_throws()[0].get_name()
+        );
+    }
+
+    #[test]
+    fn insert_method_keeps_comments() {
+        let folder = get_java_class_root_test_folder().join("comments");
+        let java_class =
+            JavaClass::from(&get_test_file("CommentedService")).expect("Class must be scanned");
+        let mut copied_class = java_class
+            .copy_to(&folder)
+            .expect("Java class must be copied");
+
+        copied_class
+            .insert_method(&get_new_method())
+            .expect("Method must be inserted");
+
+        let file_path = folder.join("CommentedService.java");
+        assert_same_file(&folder.join("ExpectedCommentedService.java"), &file_path);
+        fs::remove_file(&file_path).expect("Result file must be removed");
+    }
+
+    #[test]
+    fn build_class_with_javadoc() {
+        let folder = get_java_class_root_test_folder();
+        let file_path = folder.join("DocumentedService.java");
+        let field = JavaField::builder()
+            .comments(vec![JavaComment::Line(
+                "Flag used by the service".to_string(),
+            )])
+            .javadoc(
+                JavaDoc::builder()
+                    .description("Whether the service is enabled.")
+                    .build(),
+            )
+            .visibility(JavaVisibility::Private)
+            .data_type(JavaDataType::boolean())
+            .name("field")
+            .build()
+            .expect("field is expected to be valid");
+        let method = JavaMethod::builder()
+            .javadoc(
+                JavaDoc::builder()
+                    .description("New method.")
+                    .param("value", "input value")
+                    .return_description("the result")
+                    .throws("IllegalArgumentException", "if the value is negative")
+                    .build(),
+            )
+            .return_type(JavaDataType::int())
+            .name("newMethod")
+            .parameters(vec![JavaVariable::new_final_int("value")])
+            .build()
+            .expect("newMethod is expected to be valid");
+
+        let java_class = JavaClass::builder()
+            .folder(&folder)
+            .javadoc(
+                JavaDoc::builder()
+                    .description("Service generated with javadoc.")
+                    .build(),
+            )
+            .visibility(JavaVisibility::Public)
+            .name("DocumentedService")
+            .fields(vec![field])
+            .methods(vec![method])
+            .build()
+            .expect("Java class must be created");
+
+        assert_same_file(&folder.join("ExpectedDocumentedService.java"), &file_path);
+        assert_eq!(
+            "Service generated with javadoc.",
+            java_class
+                .get_javadoc()
+                .expect("Javadoc must be scanned back")
+                .get_description()
+        );
+        fs::remove_file(&file_path).expect("Result file must be removed");
+    }
+
+    #[test]
+    fn class_hierarchy() {
+        let folder = test_path::get_java_project_test_folder(get_current_file_path(), "hierarchy");
+        let java_class = JavaClass::from(&folder.join("DatabaseRepository.java"))
+            .expect("Java class must be scanned");
+
+        let supertypes = java_class
+            .get_all_supertypes()
+            .expect("Supertypes expected");
+        let inherited_methods = java_class
+            .get_inherited_methods()
+            .expect("Inherited methods expected");
+        let overridden_methods = java_class
+            .get_overridden_methods()
+            .expect("Overridden methods expected");
+
+        let supertypes: Vec<(&str, Option<&str>, bool, usize)> = supertypes
+            .iter()
+            .map(|supertype| {
+                (
+                    supertype.get_name(),
+                    supertype.get_route(),
+                    supertype.get_file().is_some(),
+                    supertype.get_depth(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    "AbstractRepository",
+                    Some("org.test.AbstractRepository"),
+                    true,
+                    1
+                ),
+                ("PortRepository", Some("org.test.PortRepository"), true, 2),
+                ("AutoCloseable", Some("java.lang.AutoCloseable"), false, 2),
+                ("ExternalPort", None, false, 2),
+                ("BasePort", Some("org.test.BasePort"), true, 3),
+            ],
+            supertypes
+        );
+        let inherited_methods: Vec<&str> = inherited_methods
+            .iter()
+            .map(|method| method.get_name())
+            .collect();
+        assert_eq!(vec!["close", "describe"], inherited_methods);
+        let overridden_methods: Vec<&str> = overridden_methods
+            .iter()
+            .map(|method| method.get_name())
+            .collect();
+        assert_eq!(vec!["find", "toString"], overridden_methods);
+    }
+
+    #[test]
+    fn class_overridden_methods_with_overloads() {
+        let folder = test_path::get_java_project_test_folder(get_current_file_path(), "hierarchy");
+        let java_class = JavaClass::from(&folder.join("DatabaseRepository.java"))
+            .expect("Java class must be scanned");
+
+        let overridden_methods = java_class
+            .get_overridden_methods()
+            .expect("Overridden methods expected");
+
+        let overridden_methods: Vec<(&str, Vec<String>)> = overridden_methods
+            .iter()
+            .map(|method| {
+                let parameter_types = method
+                    .get_parameters()
+                    .iter()
+                    .map(|parameter| parameter.get_data_type().to_string())
+                    .collect();
+                (method.get_name(), parameter_types)
+            })
+            .collect();
+        assert_eq!(
+            vec![("find", vec!["String".to_string()]), ("toString", vec![]),],
+            overridden_methods
+        );
+    }
+
+    #[test]
+    fn class_subclasses() {
+        let folder = test_path::get_java_project_test_folder(get_current_file_path(), "hierarchy");
+        let java_class = JavaClass::from(&folder.join("AbstractRepository.java"))
+            .expect("Java class must be scanned");
+
+        let subclasses = java_class.get_subclasses().expect("Subclasses expected");
+
+        assert_eq!(1, subclasses.len());
+        assert_eq!("DatabaseRepository", subclasses[0].get_name());
+    }
+
     fn get_new_method() -> JavaMethod {
         JavaMethod::builder()
             .return_type(JavaDataType::int())
@@ -526,13 +982,100 @@ mod tests {
 
     fn new_method_returning_offset_date_time() -> JavaMethod {
         JavaMethod::builder()
-            .return_type(java_time_factory::_create_offset_date_time())
+            .return_type(java_time_factory::create_offset_date_time())
             .visibility(JavaVisibility::Public)
             .name("newInsertedMethod")
             .build()
             .expect("newInsertedMethod is expected to be valid")
     }
 
+    #[test]
+    fn build_sealed_class_with_permits() {
+        let folder = get_java_class_root_test_folder().join("sealed");
+        let file_path = folder.join("Shape.java");
+
+        let java_class = JavaClass::builder()
+            .folder(&folder)
+            .visibility(JavaVisibility::Public)
+            .is_abstract(true)
+            .is_sealed(true)
+            .permits(vec!["org.test.sealed.Circle", "org.test.sealed.Square"])
+            .name("Shape")
+            .build()
+            .expect("Java class must be created");
+
+        assert_same_file(&folder.join("ExpectedShape.java"), &file_path);
+        assert!(java_class.is_sealed());
+        assert_eq!(
+            vec!["org.test.sealed.Circle", "org.test.sealed.Square"],
+            java_class.get_permitted_subtypes()
+        );
+        fs::remove_file(&file_path).expect("Result file must be removed");
+    }
+
+    #[test]
+    fn build_sealed_class_with_clashing_permits() {
+        let folder = get_java_class_root_test_folder().join("sealed");
+        let file_path = folder.join("Figure.java");
+
+        JavaClass::builder()
+            .folder(&folder)
+            .visibility(JavaVisibility::Public)
+            .is_abstract(true)
+            .is_sealed(true)
+            .permits(vec![
+                "org.test.sealed.flat.Circle",
+                "org.test.sealed.solid.Circle",
+            ])
+            .name("Figure")
+            .build()
+            .expect("Java class must be created");
+
+        assert_same_file(&folder.join("ExpectedFigure.java"), &file_path);
+        fs::remove_file(&file_path).expect("Result file must be removed");
+    }
+
+    #[test]
+    fn build_sealed_class_with_unrelated_permitted_class_fails() {
+        let folder = get_java_class_root_test_folder().join("sealed");
+
+        let result = JavaClass::builder()
+            .folder(&folder)
+            .is_sealed(true)
+            .permits(vec!["org.test.sealed.Triangle"])
+            .name("ShapeWithTriangle")
+            .build();
+
+        assert!(result.is_err_and(|err| err.contains(
+            "permitted subtype \"org.test.sealed.Triangle\" does not extend or implement \"org.test.sealed.ShapeWithTriangle\""
+        )));
+        assert!(!folder.join("ShapeWithTriangle.java").exists());
+    }
+
+    #[test]
+    fn build_class_with_permits_without_sealed_fails() {
+        let folder = get_java_class_root_test_folder().join("sealed");
+
+        let result = JavaClass::builder()
+            .folder(&folder)
+            .permits(vec!["org.test.sealed.Circle"])
+            .name("NotSealedShape")
+            .build();
+
+        assert!(result.is_err_and(|err| err.contains("only sealed structures")));
+    }
+
+    #[test]
+    fn new_non_sealed_class_from_path() {
+        let file_path = get_java_class_root_test_folder().join("sealed/Square.java");
+
+        let java_class = JavaClass::from(&file_path).expect("Java class must be scanned");
+
+        assert!(java_class.is_non_sealed());
+        assert!(!java_class.is_sealed());
+        assert_eq!(1, java_class.get_implemented_interfaces().len());
+    }
+
     fn get_test_file(structure_name: &str) -> PathBuf {
         get_java_class_root_test_folder().join(format!("{}.java", structure_name).as_str())
     }
@@ -0,0 +1,802 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use regex::Regex;
+
+use crate::core::file_system::file_cache::FileCache;
+use crate::core::file_system::file_reader;
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::observability::logger;
+use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::import_layout_config::{JavaImportGroup, JavaImportLayout};
+use crate::java::indentation_config::JavaIndentation;
+use crate::yaml::parser::dto::yaml_node::YamlNode;
+use crate::yaml::parser::dto::yaml_node_type::YamlNodeType;
+
+const EDITORCONFIG_FILE_NAME: &str = ".editorconfig";
+
+static CODE_STYLE: Mutex<Option<JavaCodeStyle>> = Mutex::new(None);
+
+/// # JavaBraceStyle
+/// Placement of the opening brace of structures and methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JavaBraceStyle {
+    /// "class Service {"
+    EndOfLine,
+    /// The brace is written alone in the next line
+    NextLine,
+}
+
+/// # JavaCodeStyle
+/// Formatting of the java code written by genco, both in generated files and edits.
+#[derive(Debug, Clone)]
+pub struct JavaCodeStyle {
+    indentation: String,
+    line_ending: String,
+    brace_style: JavaBraceStyle,
+    blank_lines_between_members: usize,
+    type_annotations_on_same_line: bool,
+    method_annotations_on_same_line: bool,
+    field_annotations_on_same_line: bool,
+    /// Without layout, new files keep the type imports before the static ones
+    import_layout: Option<JavaImportLayout>,
+}
+
+/// # set_code_style
+/// Use the given style for every java file written from now on, ignoring ".editorconfig" files.
+///
+/// ```
+/// use genco::java::code_style_config::{self, JavaCodeStyle};
+///
+/// let style = JavaCodeStyle::builder().indentation("  ").build();
+/// code_style_config::set_code_style(style);
+/// ```
+pub fn set_code_style(style: JavaCodeStyle) {
+    *CODE_STYLE
+        .lock()
+        .expect("Code style lock must not be poisoned") = Some(style);
+}
+
+/// # get_code_style
+/// Style to write the java file: the one configured with "set_code_style" or else the one
+/// defined by the ".editorconfig" files of the java project.
+pub(crate) fn get_code_style(java_file: &Path) -> JavaCodeStyle {
+    let configured_style = CODE_STYLE
+        .lock()
+        .expect("Code style lock must not be poisoned")
+        .clone();
+    if let Some(style) = configured_style {
+        return style;
+    }
+
+    JavaCodeStyle::from_editorconfig(java_file).unwrap_or_else(|err| {
+        logger::log_warning(&err);
+        JavaCodeStyle::default()
+    })
+}
+
+impl JavaCodeStyle {
+    // Public methods
+    pub fn builder() -> JavaCodeStyleBuilder {
+        JavaCodeStyleBuilder::new_builder()
+    }
+
+    /// # from_editorconfig
+    /// Style for "java_file" declared in the ".editorconfig" files of its directories.
+    /// Directories are checked up to the first file with "root = true" or the filesystem root.
+    /// Besides the standard properties, IntelliJ "ij_java_*" properties are recognized.
+    pub fn from_editorconfig(java_file: &Path) -> Result<Self, String> {
+        let properties = get_editorconfig_properties(java_file)?;
+        let mut builder = Self::builder();
+        for (key, value) in get_editorconfig_style_properties(&properties) {
+            builder.property(&key, &value).map_err(|err| {
+                format!(
+                    "Invalid code style in \".editorconfig\" for file:\n\"{}\"\n{}",
+                    try_to_absolute_path(java_file),
+                    err
+                )
+            })?;
+        }
+
+        Ok(builder.build())
+    }
+
+    /// # from_yaml
+    /// Style declared in a YAML profile with the keys "indent_style", "indent_size",
+    /// "end_of_line", "brace_style", "blank_lines_between_members",
+    /// "type_annotations_on_same_line", "method_annotations_on_same_line",
+    /// "field_annotations_on_same_line", "import_groups", "wildcard_threshold"
+    /// and "static_wildcard_threshold".
+    pub fn from_yaml(profile_file: &Path) -> Result<Self, String> {
+        let yaml_root = YamlNode::from_path(profile_file)?;
+        let file_cache = FileCache::from(profile_file);
+        let mut builder = Self::builder();
+        for pair in get_yaml_mapping_pairs(&yaml_root) {
+            let (key, value) = get_yaml_pair_strings(pair, &file_cache).ok_or(format!(
+                "Invalid code style profile, \"key: value\" expected in file:\n\"{}\"\n",
+                try_to_absolute_path(profile_file)
+            ))?;
+            builder.property(&key, &value).map_err(|err| {
+                format!(
+                    "Invalid code style profile:\n\"{}\"\n{}",
+                    try_to_absolute_path(profile_file),
+                    err
+                )
+            })?;
+        }
+
+        Ok(builder.build())
+    }
+
+    pub fn get_indentation(&self) -> &str {
+        &self.indentation
+    }
+
+    pub fn get_line_ending(&self) -> &str {
+        &self.line_ending
+    }
+
+    pub fn get_brace_style(&self) -> JavaBraceStyle {
+        self.brace_style
+    }
+
+    pub fn get_blank_lines_between_members(&self) -> usize {
+        self.blank_lines_between_members
+    }
+
+    pub fn is_type_annotations_on_same_line(&self) -> bool {
+        self.type_annotations_on_same_line
+    }
+
+    pub fn is_method_annotations_on_same_line(&self) -> bool {
+        self.method_annotations_on_same_line
+    }
+
+    pub fn is_field_annotations_on_same_line(&self) -> bool {
+        self.field_annotations_on_same_line
+    }
+
+    /// # get_import_layout
+    /// Layout of the imports of new files, if any was configured.
+    pub fn get_import_layout(&self) -> Option<&JavaImportLayout> {
+        self.import_layout.as_ref()
+    }
+}
+
+impl JavaCodeStyle {
+    // Crate or private methods
+    pub(crate) fn default() -> Self {
+        JavaCodeStyleBuilder::new_builder().build()
+    }
+
+    /// Indentation of the given level using the style indentation unit
+    pub(crate) fn get_indentation_at(&self, level: usize) -> JavaIndentation {
+        JavaIndentation::builder()
+            .base_indentation(&self.indentation)
+            .current_level(level)
+            .build()
+    }
+
+    /// Opening brace after a declaration written with the given indentation, including the newline
+    pub(crate) fn get_opening_brace(&self, indentation: &JavaIndentation) -> String {
+        match self.brace_style {
+            JavaBraceStyle::EndOfLine => " {\n".to_string(),
+            JavaBraceStyle::NextLine => format!("\n{}{{\n", indentation.get_current_indentation()),
+        }
+    }
+
+    pub(crate) fn get_members_separator(&self) -> String {
+        "\n".repeat(self.blank_lines_between_members)
+    }
+
+    /// Content written with "\n" is converted to the style line ending
+    pub(crate) fn apply_line_ending(&self, content: &str) -> String {
+        if "\n" == self.line_ending {
+            return content.to_string();
+        }
+
+        content.replace('\n', &self.line_ending)
+    }
+}
+
+pub struct JavaCodeStyleBuilder {
+    indentation: String,
+    line_ending: String,
+    brace_style: JavaBraceStyle,
+    blank_lines_between_members: usize,
+    type_annotations_on_same_line: bool,
+    method_annotations_on_same_line: bool,
+    field_annotations_on_same_line: bool,
+    import_layout: Option<JavaImportLayout>,
+    indent_with_tabs: bool,
+}
+
+impl JavaCodeStyleBuilder {
+    fn new_builder() -> JavaCodeStyleBuilder {
+        JavaCodeStyleBuilder {
+            indentation: "    ".to_string(),
+            line_ending: "\n".to_string(),
+            brace_style: JavaBraceStyle::EndOfLine,
+            blank_lines_between_members: 1,
+            type_annotations_on_same_line: false,
+            method_annotations_on_same_line: false,
+            field_annotations_on_same_line: false,
+            import_layout: None,
+            indent_with_tabs: false,
+        }
+    }
+
+    pub fn indentation(&mut self, input: &str) -> &mut Self {
+        self.indentation = input.to_string();
+        self
+    }
+
+    /// # line_ending
+    /// "\n" or "\r\n"
+    pub fn line_ending(&mut self, input: &str) -> &mut Self {
+        self.line_ending = input.to_string();
+        self
+    }
+
+    pub fn brace_style(&mut self, input: JavaBraceStyle) -> &mut Self {
+        self.brace_style = input;
+        self
+    }
+
+    pub fn blank_lines_between_members(&mut self, input: usize) -> &mut Self {
+        self.blank_lines_between_members = input;
+        self
+    }
+
+    /// # type_annotations_on_same_line
+    /// Write class, interface and enum annotations before their modifiers instead of in
+    /// their own lines.
+    pub fn type_annotations_on_same_line(&mut self, input: bool) -> &mut Self {
+        self.type_annotations_on_same_line = input;
+        self
+    }
+
+    /// # method_annotations_on_same_line
+    /// Write method annotations before the method modifiers instead of in their own lines.
+    pub fn method_annotations_on_same_line(&mut self, input: bool) -> &mut Self {
+        self.method_annotations_on_same_line = input;
+        self
+    }
+
+    /// # field_annotations_on_same_line
+    /// Write field annotations before the field modifiers instead of in their own lines.
+    pub fn field_annotations_on_same_line(&mut self, input: bool) -> &mut Self {
+        self.field_annotations_on_same_line = input;
+        self
+    }
+
+    pub fn import_layout(&mut self, input: JavaImportLayout) -> &mut Self {
+        self.import_layout = Some(input);
+        self
+    }
+
+    pub fn build(&mut self) -> JavaCodeStyle {
+        JavaCodeStyle {
+            indentation: self.indentation.clone(),
+            line_ending: self.line_ending.clone(),
+            brace_style: self.brace_style,
+            blank_lines_between_members: self.blank_lines_between_members,
+            type_annotations_on_same_line: self.type_annotations_on_same_line,
+            method_annotations_on_same_line: self.method_annotations_on_same_line,
+            field_annotations_on_same_line: self.field_annotations_on_same_line,
+            import_layout: self.import_layout.clone(),
+        }
+    }
+
+    /// Properties shared by the YAML profiles and the ".editorconfig" files
+    fn property(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "indent_style" => match value {
+                "tab" => {
+                    self.indent_with_tabs = true;
+                    self.indentation = "\t".to_string();
+                }
+                "space" => {
+                    self.indent_with_tabs = false;
+                    self.indentation = self.indentation.replace('\t', "    ");
+                }
+                _ => return Err(get_invalid_value_error(key, value)),
+            },
+            "indent_size" if !self.indent_with_tabs => {
+                self.indentation = " ".repeat(parse_number(key, value)?);
+            }
+            "indent_size" => {}
+            "end_of_line" => match value {
+                "lf" => self.line_ending = "\n".to_string(),
+                "crlf" => self.line_ending = "\r\n".to_string(),
+                "cr" => self.line_ending = "\r".to_string(),
+                _ => return Err(get_invalid_value_error(key, value)),
+            },
+            "brace_style" => match value {
+                "end_of_line" => self.brace_style = JavaBraceStyle::EndOfLine,
+                "next_line" => self.brace_style = JavaBraceStyle::NextLine,
+                _ => return Err(get_invalid_value_error(key, value)),
+            },
+            "blank_lines_between_members" => {
+                self.blank_lines_between_members = parse_number(key, value)?;
+            }
+            "type_annotations_on_same_line" => {
+                self.type_annotations_on_same_line = parse_bool(key, value)?;
+            }
+            "method_annotations_on_same_line" => {
+                self.method_annotations_on_same_line = parse_bool(key, value)?;
+            }
+            "field_annotations_on_same_line" => {
+                self.field_annotations_on_same_line = parse_bool(key, value)?;
+            }
+            "import_groups" => {
+                let groups = value
+                    .split(',')
+                    .map(|group| parse_import_group(group.trim()))
+                    .collect::<Result<Vec<JavaImportGroup>, String>>()?;
+                let layout = self.get_configured_import_layout();
+                self.import_layout = Some(
+                    JavaImportLayout::builder()
+                        .groups(groups)
+                        .wildcard_threshold(layout.get_wildcard_threshold())
+                        .static_wildcard_threshold(layout.get_static_wildcard_threshold())
+                        .build(),
+                );
+            }
+            "wildcard_threshold" => {
+                let layout = self.get_configured_import_layout();
+                self.import_layout = Some(
+                    JavaImportLayout::builder()
+                        .groups(layout.get_groups().clone())
+                        .wildcard_threshold(parse_number(key, value)?)
+                        .static_wildcard_threshold(layout.get_static_wildcard_threshold())
+                        .build(),
+                );
+            }
+            "static_wildcard_threshold" => {
+                let layout = self.get_configured_import_layout();
+                self.import_layout = Some(
+                    JavaImportLayout::builder()
+                        .groups(layout.get_groups().clone())
+                        .wildcard_threshold(layout.get_wildcard_threshold())
+                        .static_wildcard_threshold(parse_number(key, value)?)
+                        .build(),
+                );
+            }
+            _ => return Err(format!("Unknown code style property \"{}\"", key)),
+        }
+
+        Ok(())
+    }
+
+    /// Layout changed by an import property, the default one if none was configured yet
+    fn get_configured_import_layout(&self) -> JavaImportLayout {
+        self.import_layout
+            .clone()
+            .unwrap_or_else(|| JavaImportLayout::builder().build())
+    }
+}
+
+fn parse_bool(key: &str, value: &str) -> Result<bool, String> {
+    match value {
+        "true" => Ok(true),
+        "false" => Ok(false),
+        _ => Err(get_invalid_value_error(key, value)),
+    }
+}
+
+fn parse_number(key: &str, value: &str) -> Result<usize, String> {
+    value
+        .parse::<usize>()
+        .map_err(|_| get_invalid_value_error(key, value))
+}
+
+fn parse_import_group(value: &str) -> Result<JavaImportGroup, String> {
+    match value {
+        "static" => Ok(JavaImportGroup::Static),
+        "java" => Ok(JavaImportGroup::Java),
+        "javax" => Ok(JavaImportGroup::Javax),
+        "third_party" => Ok(JavaImportGroup::ThirdParty),
+        "project" => Ok(JavaImportGroup::Project),
+        _ => Err(get_invalid_value_error("import_groups", value)),
+    }
+}
+
+fn get_invalid_value_error(key: &str, value: &str) -> String {
+    format!(
+        "Invalid value \"{}\" for code style property \"{}\"",
+        value, key
+    )
+}
+
+/// Properties of the sections matching the java file, inner files override outer ones
+fn get_editorconfig_properties(java_file: &Path) -> Result<HashMap<String, String>, String> {
+    let mut editorconfig_files = Vec::new();
+    for dir in java_file.ancestors().skip(1) {
+        let editorconfig_file = dir.join(EDITORCONFIG_FILE_NAME);
+        if editorconfig_file.is_file() {
+            let content = file_reader::read_to_string(&editorconfig_file);
+            let is_root = parse_editorconfig(&content)
+                .0
+                .get("root")
+                .is_some_and(|root| "true" == root);
+            editorconfig_files.push((dir.to_path_buf(), content));
+            if is_root {
+                break;
+            }
+        }
+    }
+
+    let mut properties = HashMap::new();
+    for (dir, content) in editorconfig_files.iter().rev() {
+        let relative_path = get_relative_path(java_file, dir);
+        for (pattern, section_properties) in parse_editorconfig(content).1 {
+            if is_matching_section(&pattern, &relative_path)? {
+                properties.extend(section_properties);
+            }
+        }
+    }
+
+    Ok(properties)
+}
+
+/// Section glob pattern with its properties in order
+type EditorconfigSection = (String, Vec<(String, String)>);
+
+/// Preamble properties and the properties of each section in order
+fn parse_editorconfig(content: &str) -> (HashMap<String, String>, Vec<EditorconfigSection>) {
+    let mut preamble = HashMap::new();
+    let mut sections: Vec<EditorconfigSection> = Vec::new();
+    for line in content.lines().map(str::trim) {
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(pattern) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            sections.push((pattern.to_string(), Vec::new()));
+        } else if let Some((key, value)) = line.split_once('=') {
+            let key = key.trim().to_lowercase();
+            let value = value.trim().to_lowercase();
+            match sections.last_mut() {
+                Some((_, properties)) => properties.push((key, value)),
+                None => {
+                    preamble.insert(key, value);
+                }
+            }
+        }
+    }
+
+    (preamble, sections)
+}
+
+/// Glob patterns without "/" match the file name in any directory
+fn is_matching_section(pattern: &str, relative_path: &str) -> Result<bool, String> {
+    let mut regex_str = String::new();
+    if !pattern.contains('/') {
+        regex_str += "(?:.*/)?";
+    }
+    let mut chars = pattern.trim_start_matches('/').chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if Some(&'*') == chars.peek() => {
+                chars.next();
+                regex_str += ".*";
+            }
+            '*' => regex_str += "[^/]*",
+            '?' => regex_str += "[^/]",
+            '{' => regex_str += "(?:",
+            '}' => regex_str += ")",
+            ',' => regex_str += "|",
+            _ => regex_str += &regex::escape(&c.to_string()),
+        }
+    }
+
+    Regex::new(&format!("^{}$", regex_str))
+        .map(|regex| regex.is_match(relative_path))
+        .map_err(|_| format!("Invalid \".editorconfig\" section \"[{}]\"", pattern))
+}
+
+fn get_relative_path(java_file: &Path, dir: &Path) -> String {
+    let relative_path: PathBuf = java_file
+        .strip_prefix(dir)
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|_| java_file.to_path_buf());
+
+    relative_path
+        .iter()
+        .map(|node| node.to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Translation of the ".editorconfig" properties into code style properties
+fn get_editorconfig_style_properties(
+    properties: &HashMap<String, String>,
+) -> Vec<(String, String)> {
+    let mut result = Vec::new();
+    let mut add = |key: &str, value: Option<&String>| {
+        if let Some(value) = value {
+            result.push((key.to_string(), value.to_string()));
+        }
+    };
+
+    add("indent_style", properties.get("indent_style"));
+    let indent_size = properties
+        .get("indent_size")
+        .filter(|indent_size| "tab" != indent_size.as_str())
+        .or(properties.get("tab_width"));
+    add("indent_size", indent_size);
+    add("end_of_line", properties.get("end_of_line"));
+    let brace_style = properties
+        .get("ij_java_class_brace_style")
+        .map(|brace_style| {
+            if brace_style.starts_with("next_line") {
+                return "next_line".to_string();
+            }
+            brace_style.to_string()
+        });
+    add("brace_style", brace_style.as_ref());
+    add(
+        "blank_lines_between_members",
+        properties.get("ij_java_blank_lines_around_method"),
+    );
+    let class_annotation_wrap = properties
+        .get("ij_java_class_annotation_wrap")
+        .map(|wrap| is_annotation_wrap_off(wrap));
+    add(
+        "type_annotations_on_same_line",
+        class_annotation_wrap.as_ref(),
+    );
+    let method_annotation_wrap = properties
+        .get("ij_java_method_annotation_wrap")
+        .map(|wrap| is_annotation_wrap_off(wrap));
+    add(
+        "method_annotations_on_same_line",
+        method_annotation_wrap.as_ref(),
+    );
+    add(
+        "field_annotations_on_same_line",
+        properties.get("ij_java_do_not_wrap_after_single_annotation"),
+    );
+    let import_groups = properties
+        .get("ij_java_imports_layout")
+        .map(|layout| get_import_groups_from_intellij_layout(layout.as_str()));
+    add("import_groups", import_groups.as_ref());
+    add(
+        "wildcard_threshold",
+        properties.get("ij_java_class_count_to_use_import_on_demand"),
+    );
+    add(
+        "static_wildcard_threshold",
+        properties.get("ij_java_names_count_to_use_static_import_on_demand"),
+    );
+
+    result
+}
+
+/// IntelliJ annotation wraps other than "off" write each annotation in its own line
+fn is_annotation_wrap_off(wrap: &str) -> String {
+    ("off" == wrap).to_string()
+}
+
+/// IntelliJ layouts, i.e. "$*,|,java.**,|,javax.**,|,*", only support the
+/// entries equivalent to the import groups
+fn get_import_groups_from_intellij_layout(layout: &str) -> String {
+    layout
+        .split(',')
+        .filter_map(|entry| match entry.trim() {
+            "$*" => Some("static"),
+            "java.**" => Some("java"),
+            "javax.**" => Some("javax"),
+            "*" => Some("third_party"),
+            _ => None,
+        })
+        .collect::<Vec<&str>>()
+        .join(",")
+}
+
+fn get_yaml_mapping_pairs(yaml_root: &YamlNode) -> Vec<&YamlNode> {
+    let mut node = yaml_root;
+    while let Some(child) = node.get_children().first() {
+        if Some(YamlNodeType::BlockMapping) == node.get_node_type() {
+            break;
+        }
+        node = child;
+    }
+
+    node.get_children()
+        .iter()
+        .filter(|child| Some(YamlNodeType::BlockMappingPair) == child.get_node_type())
+        .collect()
+}
+
+/// Sequences are joined with "," (i.e. "static,java")
+fn get_yaml_pair_strings(pair: &YamlNode, file_cache: &FileCache) -> Option<(String, String)> {
+    let (key, value_node) = pair.get_block_mapping_pair_string_to_block()?;
+    let sequence_items = get_yaml_sequence_items(value_node, file_cache);
+    if !sequence_items.is_empty() {
+        return Some((key, sequence_items.join(",")));
+    }
+
+    pair.get_block_mapping_pair_strings()
+        .map(|(key, value)| (key, value.trim_matches(['"', '\'']).to_string()))
+}
+
+fn get_yaml_sequence_items(node: &YamlNode, file_cache: &FileCache) -> Vec<String> {
+    let sequence = node
+        .get_children()
+        .iter()
+        .find(|child| Some(YamlNodeType::BlockSequence) == child.get_node_type());
+    match sequence {
+        Some(sequence) => sequence
+            .get_children()
+            .iter()
+            .filter_map(|item| item.get_children().last())
+            .map(|item_value| {
+                item_value
+                    .get_content_from_cache(file_cache)
+                    .trim()
+                    .to_string()
+            })
+            .collect(),
+        None => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::file_system::file_reader;
+    use crate::core::testing::test_assert::assert_same_file;
+    use crate::core::testing::test_path;
+    use crate::java::class::JavaClass;
+    use crate::java::code_style_config::{JavaBraceStyle, JavaCodeStyle};
+    use crate::java::data_type::JavaDataType;
+    use crate::java::dependency::org::junit::jupiter::junit_jupiter_api::java_junit_jupiter_api_factory;
+    use crate::java::dependency::org::springframework::spring_beans::java_spring_beans_factory;
+    use crate::java::dependency::org::springframework::spring_context::java_spring_context_factory;
+    use crate::java::field::JavaField;
+    use crate::java::import_layout_config::JavaImportGroup;
+    use crate::java::method::JavaMethod;
+    use crate::java::visibility::JavaVisibility;
+
+    #[test]
+    fn from_editorconfig_merges_matching_sections() {
+        let java_file = get_test_folder().join("AnyClass.java");
+
+        let style = JavaCodeStyle::from_editorconfig(&java_file).expect("Valid editorconfig");
+
+        assert_eq!("  ", style.get_indentation());
+        assert_eq!("\r\n", style.get_line_ending());
+        assert_eq!(JavaBraceStyle::NextLine, style.get_brace_style());
+        assert_eq!(2, style.get_blank_lines_between_members());
+        assert!(style.is_method_annotations_on_same_line());
+        assert!(!style.is_type_annotations_on_same_line());
+        assert!(style.is_field_annotations_on_same_line());
+        let import_layout = style
+            .get_import_layout()
+            .expect("Import layout must be configured");
+        assert_eq!(
+            &vec![
+                JavaImportGroup::Static,
+                JavaImportGroup::Java,
+                JavaImportGroup::ThirdParty
+            ],
+            import_layout.get_groups()
+        );
+        assert_eq!(999, import_layout.get_wildcard_threshold());
+    }
+
+    #[test]
+    fn from_editorconfig_inner_file_overrides_outer_ones() {
+        let java_file = get_test_folder().join("nested").join("AnyClass.java");
+
+        let style = JavaCodeStyle::from_editorconfig(&java_file).expect("Valid editorconfig");
+
+        assert_eq!("   ", style.get_indentation());
+        assert_eq!(JavaBraceStyle::NextLine, style.get_brace_style());
+    }
+
+    #[test]
+    fn from_editorconfig_outside_java_project() {
+        let java_file = test_path::get_test_dir(get_current_file_path(), "editorconfig_parent")
+            .join("project/src/main/java/org/test/AnyClass.java");
+
+        let style = JavaCodeStyle::from_editorconfig(&java_file).expect("Valid editorconfig");
+
+        assert_eq!("      ", style.get_indentation());
+    }
+
+    #[test]
+    fn from_yaml_profile() {
+        let profile = get_test_project_folder().join("profile.yaml");
+
+        let style = JavaCodeStyle::from_yaml(&profile).expect("Valid profile");
+
+        assert_eq!("\t", style.get_indentation());
+        assert_eq!("\n", style.get_line_ending());
+        assert_eq!(JavaBraceStyle::NextLine, style.get_brace_style());
+        assert_eq!(0, style.get_blank_lines_between_members());
+        assert!(style.is_type_annotations_on_same_line());
+        assert!(style.is_field_annotations_on_same_line());
+        let import_layout = style
+            .get_import_layout()
+            .expect("Import layout must be configured");
+        assert_eq!(
+            &vec![
+                JavaImportGroup::Static,
+                JavaImportGroup::Project,
+                JavaImportGroup::ThirdParty
+            ],
+            import_layout.get_groups()
+        );
+        assert_eq!(4, import_layout.get_wildcard_threshold());
+        assert_eq!(2, import_layout.get_static_wildcard_threshold());
+    }
+
+    #[test]
+    fn from_yaml_invalid_value_fails() {
+        let profile = get_test_project_folder().join("invalid_profile.yaml");
+
+        let result = JavaCodeStyle::from_yaml(&profile);
+
+        assert!(result.is_err_and(|err| err.contains("\"same_line\"")));
+    }
+
+    #[test]
+    fn build_class_with_editorconfig_style() {
+        let folder = get_test_folder();
+        let file_path = folder.join("StyledService.java");
+        let field = JavaField::builder()
+            .annotations(vec![
+                java_spring_beans_factory::_create_autowired_annotation_usage(),
+            ])
+            .visibility(JavaVisibility::Private)
+            .data_type(JavaDataType::string())
+            .name("field")
+            .build()
+            .expect("field is expected to be valid");
+        let method = JavaMethod::builder()
+            .annotations(vec![
+                java_junit_jupiter_api_factory::_create_test_annotation_usage(),
+            ])
+            .return_type(JavaDataType::int())
+            .name("newMethod")
+            .build()
+            .expect("newMethod is expected to be valid");
+
+        JavaClass::builder()
+            .folder(&folder)
+            .annotations(vec![
+                java_spring_context_factory::_create_service_annotation_usage(),
+            ])
+            .visibility(JavaVisibility::Public)
+            .name("StyledService")
+            .fields(vec![field])
+            .methods(vec![method])
+            .build()
+            .expect("Java class must be created");
+
+        assert_same_file(&folder.join("ExpectedStyledService.java"), &file_path);
+        let content = file_reader::read_to_string(&file_path);
+        assert!(!content.replace("\r\n", "").contains('\n'));
+        fs::remove_file(&file_path).expect("Result file must be removed");
+    }
+
+    fn get_test_folder() -> PathBuf {
+        test_path::get_java_project_test_folder(get_current_file_path(), "code_style")
+    }
+
+    fn get_test_project_folder() -> PathBuf {
+        test_path::get_test_dir(get_current_file_path(), "code_style")
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1,168 @@
+use crate::core::file_system::file_cache::FileCache;
+use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::indentation_config::JavaIndentation;
+use crate::java::javadoc::JavaDoc;
+use crate::java::parser::java_node::JavaNode;
+use crate::java::parser::java_node_type::JavaNodeType;
+
+/// # JavaComment
+/// Comment written right before a class, interface, field or method.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JavaComment {
+    /// "// text", the text is stored without the leading slashes
+    Line(String),
+    /// "/* text */", the text is stored without delimiters and leading "*"
+    Block(String),
+    /// "/** text */"
+    Javadoc(JavaDoc),
+}
+
+impl JavaComment {
+    pub(crate) fn from_content(content: &str) -> JavaComment {
+        let content = content.trim();
+        if let Some(text) = content.strip_prefix("//") {
+            return JavaComment::Line(text.strip_prefix(' ').unwrap_or(text).to_string());
+        }
+        if content.starts_with("/**") && "/**/" != content {
+            return JavaComment::Javadoc(JavaDoc::from_comment(content));
+        }
+
+        let inner_content = content.trim_start_matches("/*").trim_end_matches("*/");
+        let text = inner_content
+            .lines()
+            .map(|line| {
+                let line = line.trim();
+                let line = line.strip_prefix('*').unwrap_or(line);
+                line.strip_prefix(' ').unwrap_or(line).trim_end()
+            })
+            .collect::<Vec<&str>>()
+            .join("\n");
+        JavaComment::Block(text.trim_matches('\n').to_string())
+    }
+
+    pub(crate) fn to_file_string(&self, indentation: &JavaIndentation) -> String {
+        let current_indentation = indentation.get_current_indentation();
+        match self {
+            JavaComment::Line(text) => {
+                format!("{}// {}", current_indentation, text)
+                    .trim_end()
+                    .to_string()
+                    + "\n"
+            }
+            JavaComment::Block(text) => {
+                if !text.contains('\n') {
+                    return format!("{}/* {} */\n", current_indentation, text);
+                }
+                let mut result = format!("{}/*\n", current_indentation);
+                for line in text.lines() {
+                    result += format!("{} * {}", current_indentation, line).trim_end();
+                    result += "\n";
+                }
+                result += format!("{} */\n", current_indentation).as_str();
+                result
+            }
+            JavaComment::Javadoc(javadoc) => javadoc.to_file_string(indentation),
+        }
+    }
+}
+
+pub(crate) fn is_comment(node: &JavaNode) -> bool {
+    matches!(
+        node.get_node_type(),
+        Some(JavaNodeType::Comment)
+            | Some(JavaNodeType::LineComment)
+            | Some(JavaNodeType::BlockComment)
+    )
+}
+
+/// Comments right before the sibling at "index", skipping the ones written
+/// at the end of the line of the previous sibling (i.e. "int a; // a").
+pub(crate) fn get_leading_comments(
+    siblings: &[JavaNode],
+    index: usize,
+    file_cache: &FileCache,
+) -> Vec<JavaComment> {
+    let mut first_comment_index = index;
+    while first_comment_index > 0 && is_comment(&siblings[first_comment_index - 1]) {
+        first_comment_index -= 1;
+    }
+    if first_comment_index < index && is_trailing_comment(siblings, first_comment_index, file_cache)
+    {
+        first_comment_index += 1;
+    }
+
+    siblings[first_comment_index..index]
+        .iter()
+        .map(|comment| JavaComment::from_content(&comment.get_content_from_cache(file_cache)))
+        .collect()
+}
+
+/// Comment in the same line where the previous sibling ends
+fn is_trailing_comment(
+    siblings: &[JavaNode],
+    comment_index: usize,
+    file_cache: &FileCache,
+) -> bool {
+    if comment_index == 0 {
+        return false;
+    }
+    let previous_end_byte = siblings[comment_index - 1].get_end_byte();
+    let comment_start_byte = siblings[comment_index].get_start_byte();
+    !file_cache
+        .get_content(previous_end_byte, comment_start_byte)
+        .contains('\n')
+}
+
+pub(crate) fn write_comments(
+    result: &mut String,
+    comments: &[JavaComment],
+    indentation: &JavaIndentation,
+) {
+    for comment in comments {
+        *result += comment.to_file_string(indentation).as_str();
+    }
+}
+
+/// Replace the javadoc of the comments or add it as the closest comment to the member
+pub(crate) fn set_javadoc(comments: &mut Vec<JavaComment>, javadoc: JavaDoc) {
+    comments.retain(|comment| !matches!(comment, JavaComment::Javadoc(_)));
+    comments.push(JavaComment::Javadoc(javadoc));
+}
+
+pub(crate) fn get_javadoc(comments: &[JavaComment]) -> Option<&JavaDoc> {
+    comments.iter().find_map(|comment| match comment {
+        JavaComment::Javadoc(javadoc) => Some(javadoc),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::java::comment::JavaComment;
+    use crate::java::indentation_config::JavaIndentation;
+
+    #[test]
+    fn from_content_detects_comment_kind() {
+        assert_eq!(
+            JavaComment::Line("A line".to_string()),
+            JavaComment::from_content("// A line")
+        );
+        assert_eq!(
+            JavaComment::Block("First\nSecond".to_string()),
+            JavaComment::from_content("/*\n * First\n * Second\n */")
+        );
+        assert!(matches!(
+            JavaComment::from_content("/** Doc */"),
+            JavaComment::Javadoc(javadoc) if "Doc" == javadoc.get_description()
+        ));
+    }
+
+    #[test]
+    fn to_file_string_block() {
+        let comment = JavaComment::Block("First\n\nSecond".to_string());
+
+        let comment_str = comment.to_file_string(&JavaIndentation::default());
+
+        assert_eq!("/*\n * First\n *\n * Second\n */\n", comment_str);
+    }
+}
@@ -4,6 +4,8 @@ use std::path::Path;
 use crate::core::file_system::file_cache::FileCache;
 use crate::core::file_system::path_helper::try_to_absolute_path;
 use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::dependency::jdk::java_jdk_catalog;
+use crate::java::dependency::jdk::java_jdk_catalog::JavaJdkType;
 use crate::java::import::JavaImport;
 use crate::java::parser::java_node::JavaNode;
 use crate::java::parser::java_node_type::JavaNodeType;
@@ -14,6 +16,8 @@ use crate::java::scanner::file::java_file_imports::JavaFileImports;
 pub struct JavaDataType {
     primitive: Option<JavaPrimitiveDataType>,
     non_primitive: Option<JavaNonPrimitiveDataType>,
+    /// Generic type arguments, i.e. "String" and "Long" in "Map<String, Long>"
+    type_arguments: Vec<JavaDataType>,
 }
 
 // Public methods
@@ -26,6 +30,48 @@ impl JavaDataType {
         Ok(Self::from_non_primitive(result))
     }
 
+    /// # from_simple_name
+    /// Create a JavaDataType from its simple name (i.e. "int", "List" or "OffsetDateTime").
+    /// Types from the JDK standard library are imported automatically, "java.lang" ones
+    /// do not require any import.
+    ///
+    /// ```
+    /// use genco::java::data_type::JavaDataType;
+    ///
+    /// let list_type = JavaDataType::from_simple_name("List").expect("List is a JDK type");
+    /// assert_eq!("List", list_type.to_string());
+    /// ```
+    pub fn from_simple_name(simple_name: &str) -> Result<Self, String> {
+        Self::from_simple_name_for_java_version(simple_name, java_jdk_catalog::DEFAULT_JAVA_VERSION)
+    }
+
+    /// # from_simple_name_for_java_version
+    /// Same as from_simple_name but only considering the JDK types available in the given
+    /// Java release (i.e. 11, 17, 21). Releases older than Java 8 are not supported.
+    pub fn from_simple_name_for_java_version(
+        simple_name: &str,
+        java_version: u8,
+    ) -> Result<Self, String> {
+        if java_version < java_jdk_catalog::MIN_JAVA_VERSION {
+            return Err(format!(
+                "Java {} is not supported, the oldest supported release is Java {}",
+                java_version,
+                java_jdk_catalog::MIN_JAVA_VERSION
+            ));
+        }
+        if let Some(data_type) = Self::new_basic_data_type(simple_name) {
+            return Ok(data_type);
+        }
+
+        match java_jdk_catalog::find_type(simple_name, java_version) {
+            Some(jdk_type) => Ok(Self::from_jdk_type(jdk_type)),
+            None => Err(format!(
+                "Java type \"{}\" not found in the Java {} standard library, an explicit import is required.",
+                simple_name, java_version
+            )),
+        }
+    }
+
     pub fn int() -> Self {
         Self::from_primitive(JavaPrimitiveDataType::Int)
     }
@@ -64,6 +110,45 @@ impl JavaDataType {
         Self::from_primitive(JavaPrimitiveDataType::Short)
     }
 
+    /// # with_type_arguments
+    /// Same non primitive type with the given generic type arguments.
+    ///
+    /// ```
+    /// use genco::java::data_type::JavaDataType;
+    ///
+    /// let list_type = JavaDataType::from_simple_name("List").expect("List is a JDK type");
+    /// let list_of_strings = list_type.with_type_arguments(vec![JavaDataType::string()]);
+    /// assert_eq!("List<String>", list_of_strings.expect("List is generic").to_string());
+    /// ```
+    pub fn with_type_arguments(&self, type_arguments: Vec<JavaDataType>) -> Result<Self, String> {
+        if self.primitive.is_some() {
+            return Err(format!(
+                "Primitive java type \"{}\" can not be generic",
+                self
+            ));
+        }
+        if let Some(type_argument) = type_arguments.iter().find(|arg| arg.primitive.is_some()) {
+            return Err(format!(
+                "Primitive java type \"{}\" can not be a type argument",
+                type_argument
+            ));
+        }
+
+        let mut result = self.clone();
+        result.type_arguments = type_arguments;
+        Ok(result)
+    }
+
+    pub fn get_type_arguments(&self) -> &Vec<JavaDataType> {
+        &self.type_arguments
+    }
+
+    /// # is_primitive
+    /// Primitive types (i.e. "int", not "Integer") can not be null.
+    pub fn is_primitive(&self) -> bool {
+        self.primitive.is_some()
+    }
+
     fn get_primitive(&self) -> &Option<JavaPrimitiveDataType> {
         &self.primitive
     }
@@ -78,6 +163,51 @@ impl JavaDataType {
     pub(crate) fn from_import(import: JavaImport) -> Self {
         Self::from_non_primitive(JavaNonPrimitiveDataType::from_explicit_import(import))
     }
+    pub(crate) fn from_jdk_type(jdk_type: &JavaJdkType) -> Self {
+        if jdk_type.is_java_lang() {
+            let non_primitive = JavaNonPrimitiveDataType::without_import(
+                JavaNonPrimitiveDataTypeWithoutImport::JavaLangClass(
+                    jdk_type.get_name().to_string(),
+                ),
+            );
+            return Self::from_non_primitive(non_primitive);
+        }
+
+        let import = JavaImport::new_explicit_import_without_m2_repo_scan(&jdk_type.get_route())
+            .expect("JDK catalog types must have valid routes");
+        Self::from_import(import)
+    }
+
+    /// Import of the type and the ones of its type arguments
+    pub(crate) fn get_imports(&self) -> Vec<JavaImport> {
+        let mut imports: Vec<JavaImport> = self.get_import_opt().into_iter().collect();
+        for type_argument in &self.type_arguments {
+            imports.extend(type_argument.get_imports());
+        }
+
+        imports
+    }
+
+    /// Same type, the types of "package" are written without import
+    pub(crate) fn without_package_imports(&self, package: &str) -> Self {
+        let mut result = self.clone();
+        if let Some(import) = self.get_import_opt() {
+            let name = import.get_last_node();
+            if import.get_route() == format!("{}.{}", package, name) {
+                result.non_primitive = Some(JavaNonPrimitiveDataType::without_import(
+                    JavaNonPrimitiveDataTypeWithoutImport::SamePackageClass(name),
+                ));
+            }
+        }
+        result.type_arguments = self
+            .type_arguments
+            .iter()
+            .map(|type_argument| type_argument.without_package_imports(package))
+            .collect();
+
+        result
+    }
+
     pub(crate) fn get_import_opt(&self) -> Option<JavaImport> {
         if let Some(non_primitive) = self.non_primitive.to_owned() {
             return non_primitive.get_import();
@@ -100,6 +230,12 @@ impl JavaDataType {
                 file_imports,
                 java_file_cache,
             );
+        } else if JavaNodeType::GenericType == node_type {
+            return Self::get_data_type_from_generic_type(
+                data_type_node,
+                file_imports,
+                java_file_cache,
+            );
         } else if JavaNodeType::Boolean == node_type {
             return Ok(JavaDataType::boolean());
         } else if JavaNodeType::IntegralType == node_type {
@@ -169,6 +305,7 @@ impl JavaDataType {
         Self {
             primitive: None,
             non_primitive: Some(result),
+            type_arguments: Vec::new(),
         }
     }
 
@@ -176,6 +313,7 @@ impl JavaDataType {
         Self {
             primitive: Some(primitive),
             non_primitive: None,
+            type_arguments: Vec::new(),
         }
     }
 
@@ -190,7 +328,63 @@ impl JavaDataType {
             return Ok(data_type);
         }
 
-        Self::from_data_type_identifier_with_import(type_id_node, file_imports, java_file_cache)
+        let result = Self::from_data_type_identifier_with_import(
+            type_id_node,
+            file_imports,
+            java_file_cache,
+        );
+        if result.is_err() {
+            if let Some(jdk_type) =
+                java_jdk_catalog::find_type(&type_id, java_jdk_catalog::DEFAULT_JAVA_VERSION)
+            {
+                if jdk_type.is_java_lang() {
+                    return Ok(Self::from_jdk_type(jdk_type));
+                }
+            }
+        }
+
+        result
+    }
+
+    /// "List<String>" is a generic type node with the "List" type identifier and the
+    /// type arguments node. Wildcard type arguments ("List<? extends Number>") are not supported.
+    fn get_data_type_from_generic_type(
+        data_type_node: &JavaNode,
+        file_imports: &JavaFileImports,
+        java_file_cache: &FileCache,
+    ) -> Result<JavaDataType, String> {
+        let mut result_opt = None;
+        let mut type_arguments = Vec::new();
+        for child in data_type_node.get_children() {
+            if child.is_data_type_identifier() {
+                result_opt = Some(Self::get_data_type(child, file_imports, java_file_cache)?);
+            } else if Some(JavaNodeType::TypeArguments) == child.get_node_type() {
+                for type_argument in child.get_children() {
+                    if Some(JavaNodeType::Wildcard) == type_argument.get_node_type() {
+                        return Err(format!(
+                            "Unsupported wildcard type argument \"{}\" in file:\n{}\n",
+                            data_type_node.get_content_from_cache(java_file_cache),
+                            try_to_absolute_path(java_file_cache.get_path())
+                        ));
+                    }
+                    if type_argument.is_data_type_identifier() {
+                        type_arguments.push(Self::get_data_type(
+                            type_argument,
+                            file_imports,
+                            java_file_cache,
+                        )?);
+                    }
+                }
+            }
+        }
+
+        let mut result = result_opt.ok_or(format!(
+            "Unrecognized generic type \"{}\" in file:\n{}\n",
+            data_type_node.get_content_from_cache(java_file_cache),
+            try_to_absolute_path(java_file_cache.get_path())
+        ))?;
+        result.type_arguments = type_arguments;
+        Ok(result)
     }
 
     fn get_data_type_from_floating_point_type(
@@ -279,6 +473,10 @@ pub(crate) enum JavaNonPrimitiveDataTypeWithoutImport {
     DoubleClass,
     BooleanClass,
     String,
+    /// Any other "java.lang" type, implicitly imported (i.e. "Object", "Runnable")
+    JavaLangClass(String),
+    /// Type of the package of the file using it, not imported
+    SamePackageClass(String),
 }
 
 #[derive(Debug, Clone)]
@@ -320,6 +518,15 @@ impl fmt::Display for JavaDataType {
             write!(f, "{}", non_primitive)?;
         }
 
+        if !self.type_arguments.is_empty() {
+            let type_arguments: Vec<String> = self
+                .type_arguments
+                .iter()
+                .map(|type_argument| type_argument.to_string())
+                .collect();
+            write!(f, "<{}>", type_arguments.join(", "))?;
+        }
+
         Ok(())
     }
 }
@@ -366,6 +573,8 @@ impl fmt::Display for JavaNonPrimitiveDataTypeWithoutImport {
             JavaNonPrimitiveDataTypeWithoutImport::DoubleClass => "Double".to_string(),
             JavaNonPrimitiveDataTypeWithoutImport::BooleanClass => "Boolean".to_string(),
             JavaNonPrimitiveDataTypeWithoutImport::String => "String".to_string(),
+            JavaNonPrimitiveDataTypeWithoutImport::JavaLangClass(name) => name.to_string(),
+            JavaNonPrimitiveDataTypeWithoutImport::SamePackageClass(name) => name.to_string(),
         };
 
         write!(fmt, "{}", string)?;
@@ -380,6 +589,7 @@ mod tests {
     use crate::core::testing::test_assert::assert_fail;
     use crate::core::testing::test_path::get_java_test_file;
     use crate::java::data_type::JavaDataType;
+    use crate::java::scanner::file::java_file::JavaFile;
 
     #[test]
     fn new_class() {
@@ -447,6 +657,68 @@ mod tests {
         assert_eq!("String", JavaDataType::string().to_string());
     }
 
+    #[test]
+    fn from_simple_name_jdk_type() {
+        let java_type = JavaDataType::from_simple_name("Optional").expect("Optional is a JDK type");
+
+        let import = java_type
+            .get_import_opt()
+            .expect("Optional must be imported");
+        assert_eq!("java.util.Optional", import.get_route());
+        assert_eq!("Optional", java_type.to_string());
+    }
+
+    #[test]
+    fn from_simple_name_java_lang_type() {
+        let java_type = JavaDataType::from_simple_name("Object").expect("Object is a JDK type");
+
+        assert!(java_type.get_import_opt().is_none());
+        assert_eq!("Object", java_type.to_string());
+    }
+
+    #[test]
+    fn from_simple_name_unknown_type() {
+        assert!(JavaDataType::from_simple_name("NonExistingJdkType").is_err());
+        assert!(JavaDataType::from_simple_name_for_java_version("HttpClient", 8).is_err());
+        assert!(JavaDataType::from_simple_name_for_java_version("HttpClient", 11).is_ok());
+        assert!(JavaDataType::from_simple_name_for_java_version("int", 7).is_err());
+    }
+
+    #[test]
+    fn scan_java_lang_types_without_import() {
+        let file_path = get_java_test_file(
+            get_current_file_path(),
+            "data_type",
+            "JavaDataTypeJavaLang.java",
+        );
+
+        let java_file = JavaFile::from_user_input_path(&file_path).expect("Valid java file");
+
+        let methods = java_file.get_structure().get_methods();
+        let method = methods.first().expect("Method expected");
+        let return_type = method
+            .get_return_type()
+            .clone()
+            .expect("Return type expected");
+        assert_eq!("Object", return_type.to_string());
+        assert!(return_type.get_import_opt().is_none());
+        let parameter = method.get_parameters().first().expect("Parameter expected");
+        assert_eq!("Runnable task", parameter.to_string());
+    }
+
+    #[test]
+    fn scan_wildcard_type_argument_skips_method() {
+        let file_path = get_java_test_file(
+            get_current_file_path(),
+            "data_type",
+            "JavaDataTypeWildcard.java",
+        );
+
+        let java_file = JavaFile::from_user_input_path(&file_path).expect("Valid java file");
+
+        assert!(java_file.get_structure().get_methods().is_empty());
+    }
+
     pub fn get_current_file_path() -> PathBuf {
         PathBuf::from(file!())
     }
@@ -0,0 +1,14 @@
+use crate::java::annotation_usage::JavaAnnotationUsage;
+
+/// Name of the JSON property written for a field or parameter
+pub(crate) fn create_json_property_annotation_usage(name: &str) -> JavaAnnotationUsage {
+    let mut builder = JavaAnnotationUsage::builder();
+    builder
+        .import("com.fasterxml.jackson.annotation.JsonProperty")
+        .parameter("value", &to_string_literal(name));
+    builder.build().expect("Valid annotation")
+}
+
+fn to_string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1 @@
+pub mod java_jackson_annotations_factory;
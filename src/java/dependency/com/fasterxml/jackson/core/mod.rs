@@ -0,0 +1 @@
+pub mod jackson_annotations;
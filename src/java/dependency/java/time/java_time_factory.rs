@@ -1,8 +1,25 @@
 use crate::java::data_type::JavaDataType;
-use crate::java::import::JavaImport;
 
-pub(crate) fn _create_offset_date_time() -> JavaDataType {
-    let import = JavaImport::new_explicit_import_without_m2_repo_scan("java.time.OffsetDateTime")
-        .expect("OffsetDateTime java type is in the standard library");
-    JavaDataType::from_import(import)
+pub(crate) fn create_offset_date_time() -> JavaDataType {
+    JavaDataType::from_simple_name("OffsetDateTime")
+        .expect("OffsetDateTime java type is in the standard library")
+}
+
+pub(crate) fn create_instant() -> JavaDataType {
+    JavaDataType::from_simple_name("Instant").expect("Instant java type is in the standard library")
+}
+
+pub(crate) fn create_local_date() -> JavaDataType {
+    JavaDataType::from_simple_name("LocalDate")
+        .expect("LocalDate java type is in the standard library")
+}
+
+pub(crate) fn create_local_date_time() -> JavaDataType {
+    JavaDataType::from_simple_name("LocalDateTime")
+        .expect("LocalDateTime java type is in the standard library")
+}
+
+pub(crate) fn create_local_time() -> JavaDataType {
+    JavaDataType::from_simple_name("LocalTime")
+        .expect("LocalTime java type is in the standard library")
 }
@@ -0,0 +1,723 @@
+/// Java release used when the caller does not specify one.
+pub(crate) const DEFAULT_JAVA_VERSION: u8 = 17;
+
+/// Oldest Java release described by the catalog, types available since
+/// earlier versions are registered with this value.
+pub(crate) const MIN_JAVA_VERSION: u8 = 8;
+
+const JAVA_LANG_PACKAGE: &str = "java.lang";
+
+/// Public type shipped with the JDK (java.* and javax.* packages).
+/// Simple names are unique inside the catalog, so ambiguous types
+/// such as "java.awt.List" or "java.sql.Date" are intentionally left out.
+#[derive(Debug)]
+pub(crate) struct JavaJdkType {
+    package: &'static str,
+    name: &'static str,
+    since: u8,
+    members: &'static [&'static str],
+}
+
+// Public crate methods
+impl JavaJdkType {
+    pub(crate) fn get_name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Basic member signatures, i.e. "int size()"
+    pub(crate) fn get_members(&self) -> &'static [&'static str] {
+        self.members
+    }
+
+    pub(crate) fn get_route(&self) -> String {
+        format!("{}.{}", self.package, self.name)
+    }
+
+    pub(crate) fn is_java_lang(&self) -> bool {
+        JAVA_LANG_PACKAGE == self.package
+    }
+
+    pub(crate) fn is_available_in(&self, java_version: u8) -> bool {
+        self.since <= java_version
+    }
+}
+
+/// # find_type
+/// Find the JDK type with the given simple name (i.e. "List") available in the java version.
+pub(crate) fn find_type(simple_name: &str, java_version: u8) -> Option<&'static JavaJdkType> {
+    JDK_TYPES
+        .iter()
+        .find(|jdk_type| jdk_type.name == simple_name && jdk_type.is_available_in(java_version))
+}
+
+/// # find_type_by_route
+/// Find the JDK type with the given full route (i.e. "java.util.List") available in the java version.
+pub(crate) fn find_type_by_route(route: &str, java_version: u8) -> Option<&'static JavaJdkType> {
+    let (package, name) = route.rsplit_once('.')?;
+    JDK_TYPES.iter().find(|jdk_type| {
+        jdk_type.package == package
+            && jdk_type.name == name
+            && jdk_type.is_available_in(java_version)
+    })
+}
+
+/// # is_java_lang
+/// It returns true if the simple name refers to a type implicitly imported from "java.lang".
+pub(crate) fn is_java_lang(simple_name: &str, java_version: u8) -> bool {
+    find_type(simple_name, java_version)
+        .map(|jdk_type| jdk_type.is_java_lang())
+        .unwrap_or(false)
+}
+
+const fn new_type(
+    package: &'static str,
+    name: &'static str,
+    since: u8,
+    members: &'static [&'static str],
+) -> JavaJdkType {
+    JavaJdkType {
+        package,
+        name,
+        since,
+        members,
+    }
+}
+
+const OBJECT_MEMBERS: &[&str] = &[
+    "boolean equals(Object obj)",
+    "int hashCode()",
+    "String toString()",
+    "Class<?> getClass()",
+];
+const EXCEPTION_MEMBERS: &[&str] = &[
+    "String getMessage()",
+    "Throwable getCause()",
+    "void printStackTrace()",
+];
+const COLLECTION_MEMBERS: &[&str] = &[
+    "int size()",
+    "boolean isEmpty()",
+    "boolean contains(Object o)",
+    "boolean add(E e)",
+    "boolean remove(Object o)",
+    "Stream<E> stream()",
+];
+const LIST_MEMBERS: &[&str] = &[
+    "int size()",
+    "boolean isEmpty()",
+    "E get(int index)",
+    "boolean add(E e)",
+    "E set(int index, E element)",
+    "E remove(int index)",
+    "Stream<E> stream()",
+];
+const MAP_MEMBERS: &[&str] = &[
+    "int size()",
+    "boolean isEmpty()",
+    "V get(Object key)",
+    "V put(K key, V value)",
+    "V remove(Object key)",
+    "boolean containsKey(Object key)",
+    "Set<K> keySet()",
+    "Collection<V> values()",
+];
+const TEMPORAL_MEMBERS: &[&str] = &[
+    "String format(DateTimeFormatter formatter)",
+    "boolean isAfter(ChronoLocalDate other)",
+    "boolean isBefore(ChronoLocalDate other)",
+];
+
+static JDK_TYPES: &[JavaJdkType] = &[
+    // java.lang
+    new_type(JAVA_LANG_PACKAGE, "Object", 8, OBJECT_MEMBERS),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "String",
+        8,
+        &[
+            "int length()",
+            "boolean isEmpty()",
+            "char charAt(int index)",
+            "String substring(int beginIndex, int endIndex)",
+            "boolean isBlank()",
+        ],
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "Integer",
+        8,
+        &["int intValue()", "static Integer valueOf(int i)"],
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "Long",
+        8,
+        &["long longValue()", "static Long valueOf(long l)"],
+    ),
+    new_type(JAVA_LANG_PACKAGE, "Short", 8, &["short shortValue()"]),
+    new_type(JAVA_LANG_PACKAGE, "Byte", 8, &["byte byteValue()"]),
+    new_type(JAVA_LANG_PACKAGE, "Float", 8, &["float floatValue()"]),
+    new_type(JAVA_LANG_PACKAGE, "Double", 8, &["double doubleValue()"]),
+    new_type(JAVA_LANG_PACKAGE, "Boolean", 8, &["boolean booleanValue()"]),
+    new_type(JAVA_LANG_PACKAGE, "Character", 8, &["char charValue()"]),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "Number",
+        8,
+        &["int intValue()", "long longValue()"],
+    ),
+    new_type(JAVA_LANG_PACKAGE, "Void", 8, &[]),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "Math",
+        8,
+        &[
+            "static int max(int a, int b)",
+            "static int min(int a, int b)",
+        ],
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "System",
+        8,
+        &["static long currentTimeMillis()"],
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "Thread",
+        8,
+        &["void start()", "void join()"],
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "StringBuilder",
+        8,
+        &["StringBuilder append(String str)", "String toString()"],
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "Class",
+        8,
+        &["String getName()", "String getSimpleName()"],
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "Enum",
+        8,
+        &["String name()", "int ordinal()"],
+    ),
+    new_type(JAVA_LANG_PACKAGE, "Record", 16, &[]),
+    new_type(JAVA_LANG_PACKAGE, "Throwable", 8, EXCEPTION_MEMBERS),
+    new_type(JAVA_LANG_PACKAGE, "Exception", 8, EXCEPTION_MEMBERS),
+    new_type(JAVA_LANG_PACKAGE, "RuntimeException", 8, EXCEPTION_MEMBERS),
+    new_type(JAVA_LANG_PACKAGE, "Error", 8, EXCEPTION_MEMBERS),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "IllegalArgumentException",
+        8,
+        EXCEPTION_MEMBERS,
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "IllegalStateException",
+        8,
+        EXCEPTION_MEMBERS,
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "NullPointerException",
+        8,
+        EXCEPTION_MEMBERS,
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "UnsupportedOperationException",
+        8,
+        EXCEPTION_MEMBERS,
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "IndexOutOfBoundsException",
+        8,
+        EXCEPTION_MEMBERS,
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "ClassCastException",
+        8,
+        EXCEPTION_MEMBERS,
+    ),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "InterruptedException",
+        8,
+        EXCEPTION_MEMBERS,
+    ),
+    new_type(JAVA_LANG_PACKAGE, "Runnable", 8, &["void run()"]),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "Iterable",
+        8,
+        &["Iterator<T> iterator()"],
+    ),
+    new_type(JAVA_LANG_PACKAGE, "Comparable", 8, &["int compareTo(T o)"]),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "CharSequence",
+        8,
+        &["int length()", "char charAt(int index)"],
+    ),
+    new_type(JAVA_LANG_PACKAGE, "AutoCloseable", 8, &["void close()"]),
+    new_type(JAVA_LANG_PACKAGE, "Cloneable", 8, &[]),
+    new_type(JAVA_LANG_PACKAGE, "Override", 8, &[]),
+    new_type(JAVA_LANG_PACKAGE, "Deprecated", 8, &[]),
+    new_type(JAVA_LANG_PACKAGE, "FunctionalInterface", 8, &[]),
+    new_type(
+        JAVA_LANG_PACKAGE,
+        "SuppressWarnings",
+        8,
+        &["String[] value()"],
+    ),
+    new_type(JAVA_LANG_PACKAGE, "SafeVarargs", 8, &[]),
+    // java.util
+    new_type("java.util", "Collection", 8, COLLECTION_MEMBERS),
+    new_type("java.util", "List", 8, LIST_MEMBERS),
+    new_type("java.util", "ArrayList", 8, LIST_MEMBERS),
+    new_type("java.util", "LinkedList", 8, LIST_MEMBERS),
+    new_type("java.util", "Set", 8, COLLECTION_MEMBERS),
+    new_type("java.util", "HashSet", 8, COLLECTION_MEMBERS),
+    new_type("java.util", "LinkedHashSet", 8, COLLECTION_MEMBERS),
+    new_type("java.util", "TreeSet", 8, COLLECTION_MEMBERS),
+    new_type("java.util", "Map", 8, MAP_MEMBERS),
+    new_type("java.util", "HashMap", 8, MAP_MEMBERS),
+    new_type("java.util", "LinkedHashMap", 8, MAP_MEMBERS),
+    new_type("java.util", "TreeMap", 8, MAP_MEMBERS),
+    new_type(
+        "java.util",
+        "Queue",
+        8,
+        &["boolean offer(E e)", "E poll()", "E peek()"],
+    ),
+    new_type(
+        "java.util",
+        "Deque",
+        8,
+        &["void push(E e)", "E pop()", "E peek()"],
+    ),
+    new_type(
+        "java.util",
+        "ArrayDeque",
+        8,
+        &["void push(E e)", "E pop()", "E peek()"],
+    ),
+    new_type(
+        "java.util",
+        "Iterator",
+        8,
+        &["boolean hasNext()", "E next()"],
+    ),
+    new_type("java.util", "Comparator", 8, &["int compare(T o1, T o2)"]),
+    new_type(
+        "java.util",
+        "Optional",
+        8,
+        &[
+            "static <T> Optional<T> of(T value)",
+            "static <T> Optional<T> empty()",
+            "boolean isPresent()",
+            "T get()",
+            "T orElse(T other)",
+        ],
+    ),
+    new_type(
+        "java.util",
+        "OptionalInt",
+        8,
+        &["boolean isPresent()", "int getAsInt()"],
+    ),
+    new_type(
+        "java.util",
+        "Objects",
+        8,
+        &[
+            "static boolean equals(Object a, Object b)",
+            "static int hash(Object... values)",
+        ],
+    ),
+    new_type(
+        "java.util",
+        "Collections",
+        8,
+        &["static <T> List<T> emptyList()"],
+    ),
+    new_type(
+        "java.util",
+        "Arrays",
+        8,
+        &["static <T> List<T> asList(T... a)"],
+    ),
+    new_type(
+        "java.util",
+        "UUID",
+        8,
+        &[
+            "static UUID randomUUID()",
+            "static UUID fromString(String name)",
+        ],
+    ),
+    new_type("java.util", "Date", 8, &["long getTime()"]),
+    new_type("java.util", "Locale", 8, &["String getLanguage()"]),
+    new_type("java.util", "Random", 8, &["int nextInt(int bound)"]),
+    new_type(
+        "java.util",
+        "StringJoiner",
+        8,
+        &["StringJoiner add(CharSequence newElement)"],
+    ),
+    new_type(
+        "java.util",
+        "HexFormat",
+        17,
+        &["static HexFormat of()", "String formatHex(byte[] bytes)"],
+    ),
+    // java.util.function
+    new_type("java.util.function", "Function", 8, &["R apply(T t)"]),
+    new_type(
+        "java.util.function",
+        "BiFunction",
+        8,
+        &["R apply(T t, U u)"],
+    ),
+    new_type("java.util.function", "Supplier", 8, &["T get()"]),
+    new_type("java.util.function", "Consumer", 8, &["void accept(T t)"]),
+    new_type(
+        "java.util.function",
+        "BiConsumer",
+        8,
+        &["void accept(T t, U u)"],
+    ),
+    new_type("java.util.function", "Predicate", 8, &["boolean test(T t)"]),
+    new_type(
+        "java.util.function",
+        "BiPredicate",
+        8,
+        &["boolean test(T t, U u)"],
+    ),
+    new_type("java.util.function", "UnaryOperator", 8, &["T apply(T t)"]),
+    new_type(
+        "java.util.function",
+        "BinaryOperator",
+        8,
+        &["T apply(T t, T u)"],
+    ),
+    // java.util.stream
+    new_type(
+        "java.util.stream",
+        "Stream",
+        8,
+        &[
+            "<R> Stream<R> map(Function<? super T, ? extends R> mapper)",
+            "Stream<T> filter(Predicate<? super T> predicate)",
+            "List<T> toList()",
+        ],
+    ),
+    new_type("java.util.stream", "IntStream", 8, &["int sum()"]),
+    new_type(
+        "java.util.stream",
+        "Collectors",
+        8,
+        &["static <T> Collector<T, ?, List<T>> toList()"],
+    ),
+    // java.util.concurrent
+    new_type(
+        "java.util.concurrent",
+        "CompletableFuture",
+        8,
+        &["T join()", "T get()"],
+    ),
+    new_type("java.util.concurrent", "ConcurrentHashMap", 8, MAP_MEMBERS),
+    new_type(
+        "java.util.concurrent",
+        "Future",
+        8,
+        &["T get()", "boolean isDone()"],
+    ),
+    new_type("java.util.concurrent", "Callable", 8, &["V call()"]),
+    new_type(
+        "java.util.concurrent",
+        "ExecutorService",
+        8,
+        &["void shutdown()", "<T> Future<T> submit(Callable<T> task)"],
+    ),
+    new_type(
+        "java.util.concurrent",
+        "Executors",
+        8,
+        &["static ExecutorService newFixedThreadPool(int nThreads)"],
+    ),
+    new_type("java.util.concurrent", "TimeUnit", 8, &[]),
+    new_type("java.util.concurrent", "Flow", 9, &[]),
+    // java.time
+    new_type(
+        "java.time",
+        "Instant",
+        8,
+        &["static Instant now()", "long toEpochMilli()"],
+    ),
+    new_type("java.time", "LocalDate", 8, TEMPORAL_MEMBERS),
+    new_type("java.time", "LocalDateTime", 8, TEMPORAL_MEMBERS),
+    new_type(
+        "java.time",
+        "LocalTime",
+        8,
+        &["static LocalTime now()", "int getHour()"],
+    ),
+    new_type(
+        "java.time",
+        "OffsetDateTime",
+        8,
+        &["static OffsetDateTime now()", "ZoneOffset getOffset()"],
+    ),
+    new_type(
+        "java.time",
+        "ZonedDateTime",
+        8,
+        &["static ZonedDateTime now()", "ZoneId getZone()"],
+    ),
+    new_type(
+        "java.time",
+        "Duration",
+        8,
+        &["static Duration ofSeconds(long seconds)", "long toMillis()"],
+    ),
+    new_type(
+        "java.time",
+        "Period",
+        8,
+        &["static Period ofDays(int days)", "int getDays()"],
+    ),
+    new_type(
+        "java.time",
+        "ZoneId",
+        8,
+        &["static ZoneId of(String zoneId)"],
+    ),
+    new_type("java.time", "ZoneOffset", 8, &["int getTotalSeconds()"]),
+    new_type(
+        "java.time",
+        "Clock",
+        8,
+        &["static Clock systemUTC()", "Instant instant()"],
+    ),
+    new_type("java.time", "DayOfWeek", 8, &[]),
+    new_type("java.time", "Month", 8, &[]),
+    new_type(
+        "java.time.format",
+        "DateTimeFormatter",
+        8,
+        &["static DateTimeFormatter ofPattern(String pattern)"],
+    ),
+    // java.math
+    new_type(
+        "java.math",
+        "BigDecimal",
+        8,
+        &["BigDecimal add(BigDecimal augend)", "int scale()"],
+    ),
+    new_type(
+        "java.math",
+        "BigInteger",
+        8,
+        &["BigInteger add(BigInteger val)"],
+    ),
+    new_type("java.math", "RoundingMode", 8, &[]),
+    // java.io
+    new_type(
+        "java.io",
+        "File",
+        8,
+        &["boolean exists()", "String getName()"],
+    ),
+    new_type("java.io", "InputStream", 8, &["int read()", "void close()"]),
+    new_type(
+        "java.io",
+        "OutputStream",
+        8,
+        &["void write(int b)", "void close()"],
+    ),
+    new_type("java.io", "Reader", 8, &["int read()", "void close()"]),
+    new_type(
+        "java.io",
+        "Writer",
+        8,
+        &["void write(String str)", "void close()"],
+    ),
+    new_type("java.io", "IOException", 8, EXCEPTION_MEMBERS),
+    new_type("java.io", "UncheckedIOException", 8, EXCEPTION_MEMBERS),
+    new_type("java.io", "Serializable", 8, &[]),
+    new_type("java.io", "Closeable", 8, &["void close()"]),
+    // java.nio
+    new_type(
+        "java.nio.file",
+        "Path",
+        8,
+        &["Path getFileName()", "Path resolve(String other)"],
+    ),
+    new_type(
+        "java.nio.file",
+        "Paths",
+        8,
+        &["static Path get(String first, String... more)"],
+    ),
+    new_type(
+        "java.nio.file",
+        "Files",
+        8,
+        &[
+            "static boolean exists(Path path, LinkOption... options)",
+            "static String readString(Path path)",
+        ],
+    ),
+    new_type("java.nio.charset", "StandardCharsets", 8, &[]),
+    new_type("java.nio.charset", "Charset", 8, &["String name()"]),
+    new_type("java.nio", "ByteBuffer", 8, &["byte[] array()"]),
+    // java.net
+    new_type(
+        "java.net",
+        "URI",
+        8,
+        &["static URI create(String str)", "String getHost()"],
+    ),
+    new_type("java.net", "URL", 8, &["String getHost()"]),
+    new_type(
+        "java.net.http",
+        "HttpClient",
+        11,
+        &["static HttpClient newHttpClient()"],
+    ),
+    new_type("java.net.http", "HttpRequest", 11, &["URI uri()"]),
+    new_type(
+        "java.net.http",
+        "HttpResponse",
+        11,
+        &["int statusCode()", "T body()"],
+    ),
+    // java.util.regex
+    new_type(
+        "java.util.regex",
+        "Pattern",
+        8,
+        &[
+            "static Pattern compile(String regex)",
+            "Matcher matcher(CharSequence input)",
+        ],
+    ),
+    new_type(
+        "java.util.regex",
+        "Matcher",
+        8,
+        &["boolean matches()", "boolean find()"],
+    ),
+    // javax.*
+    new_type(
+        "javax.crypto",
+        "Cipher",
+        8,
+        &["static Cipher getInstance(String transformation)"],
+    ),
+    new_type(
+        "javax.net.ssl",
+        "SSLContext",
+        8,
+        &["static SSLContext getInstance(String protocol)"],
+    ),
+    new_type(
+        "javax.sql",
+        "DataSource",
+        8,
+        &["Connection getConnection()"],
+    ),
+    new_type(
+        "java.sql",
+        "Connection",
+        8,
+        &["void close()", "void commit()"],
+    ),
+    new_type("java.sql", "Timestamp", 8, &["long getTime()"]),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::core::testing::test_assert::assert_fail;
+    use crate::java::dependency::jdk::java_jdk_catalog;
+    use crate::java::dependency::jdk::java_jdk_catalog::{
+        DEFAULT_JAVA_VERSION, JDK_TYPES, MIN_JAVA_VERSION,
+    };
+
+    #[test]
+    fn find_type_java_util_list() {
+        match java_jdk_catalog::find_type("List", DEFAULT_JAVA_VERSION) {
+            Some(jdk_type) => {
+                assert_eq!("java.util.List", jdk_type.get_route());
+                assert!(!jdk_type.is_java_lang());
+                assert!(jdk_type.get_members().contains(&"int size()"));
+            }
+            None => assert_fail("java.util.List must be in the JDK catalog"),
+        }
+    }
+
+    #[test]
+    fn find_type_depends_on_java_version() {
+        assert!(java_jdk_catalog::find_type("Record", 11).is_none());
+        assert!(java_jdk_catalog::find_type("Record", 16).is_some());
+        assert!(java_jdk_catalog::find_type("HttpClient", MIN_JAVA_VERSION).is_none());
+    }
+
+    #[test]
+    fn find_type_by_route() {
+        let jdk_type =
+            java_jdk_catalog::find_type_by_route("java.time.OffsetDateTime", DEFAULT_JAVA_VERSION)
+                .expect("OffsetDateTime must be in the JDK catalog");
+
+        assert_eq!("OffsetDateTime", jdk_type.get_name());
+        assert!(
+            java_jdk_catalog::find_type_by_route("java.time.List", DEFAULT_JAVA_VERSION).is_none()
+        );
+        assert!(java_jdk_catalog::find_type_by_route("List", DEFAULT_JAVA_VERSION).is_none());
+    }
+
+    #[test]
+    fn is_java_lang() {
+        assert!(java_jdk_catalog::is_java_lang(
+            "Object",
+            DEFAULT_JAVA_VERSION
+        ));
+        assert!(java_jdk_catalog::is_java_lang(
+            "Override",
+            DEFAULT_JAVA_VERSION
+        ));
+        assert!(!java_jdk_catalog::is_java_lang(
+            "Optional",
+            DEFAULT_JAVA_VERSION
+        ));
+        assert!(!java_jdk_catalog::is_java_lang(
+            "NonJdkClass",
+            DEFAULT_JAVA_VERSION
+        ));
+    }
+
+    #[test]
+    fn simple_names_are_unique() {
+        let mut names = HashSet::new();
+        for jdk_type in JDK_TYPES {
+            assert!(
+                names.insert(jdk_type.get_name()),
+                "Duplicated simple name \"{}\" in JDK catalog",
+                jdk_type.get_name()
+            );
+            assert!(jdk_type.since >= MIN_JAVA_VERSION);
+        }
+    }
+}
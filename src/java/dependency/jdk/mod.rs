@@ -0,0 +1 @@
+pub(crate) mod java_jdk_catalog;
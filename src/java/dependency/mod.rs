@@ -1,2 +1,4 @@
+pub mod com;
 pub mod java;
+pub mod jdk;
 pub mod org;
@@ -1,2 +1,3 @@
 pub mod spring_beans;
 pub mod spring_context;
+pub mod spring_web;
@@ -0,0 +1,110 @@
+use crate::java::annotation_usage::JavaAnnotationUsage;
+use crate::java::data_type::JavaDataType;
+use crate::java::import::JavaImport;
+
+const ANNOTATION_PACKAGE: &str = "org.springframework.web.bind.annotation";
+
+pub(crate) fn create_rest_controller_annotation_usage() -> JavaAnnotationUsage {
+    create_annotation_usage("RestController", vec![])
+}
+
+pub(crate) fn create_get_mapping_annotation_usage(path: &str) -> JavaAnnotationUsage {
+    create_annotation_usage("GetMapping", vec![("value", to_string_literal(path))])
+}
+
+pub(crate) fn create_post_mapping_annotation_usage(path: &str) -> JavaAnnotationUsage {
+    create_annotation_usage("PostMapping", vec![("value", to_string_literal(path))])
+}
+
+pub(crate) fn create_put_mapping_annotation_usage(path: &str) -> JavaAnnotationUsage {
+    create_annotation_usage("PutMapping", vec![("value", to_string_literal(path))])
+}
+
+pub(crate) fn create_delete_mapping_annotation_usage(path: &str) -> JavaAnnotationUsage {
+    create_annotation_usage("DeleteMapping", vec![("value", to_string_literal(path))])
+}
+
+pub(crate) fn create_patch_mapping_annotation_usage(path: &str) -> JavaAnnotationUsage {
+    create_annotation_usage("PatchMapping", vec![("value", to_string_literal(path))])
+}
+
+/// Methods without shortcut annotation (i.e. "HEAD")
+pub(crate) fn create_request_mapping_annotation_usage(
+    path: &str,
+    request_method: &str,
+) -> JavaAnnotationUsage {
+    let mut builder = JavaAnnotationUsage::builder();
+    builder
+        .import(&format!("{}.RequestMapping", ANNOTATION_PACKAGE))
+        .parameter("value", &to_string_literal(path))
+        .parameter("method", &format!("RequestMethod.{}", request_method))
+        .parameter_import(&format!("{}.RequestMethod", ANNOTATION_PACKAGE));
+    builder.build().expect("Valid annotation")
+}
+
+pub(crate) fn create_path_variable_annotation_usage(name: &str) -> JavaAnnotationUsage {
+    create_annotation_usage("PathVariable", vec![("value", to_string_literal(name))])
+}
+
+pub(crate) fn create_request_param_annotation_usage(
+    name: &str,
+    required: bool,
+) -> JavaAnnotationUsage {
+    create_annotation_usage("RequestParam", get_named_parameters(name, required))
+}
+
+pub(crate) fn create_request_header_annotation_usage(
+    name: &str,
+    required: bool,
+) -> JavaAnnotationUsage {
+    create_annotation_usage("RequestHeader", get_named_parameters(name, required))
+}
+
+pub(crate) fn create_cookie_value_annotation_usage(
+    name: &str,
+    required: bool,
+) -> JavaAnnotationUsage {
+    create_annotation_usage("CookieValue", get_named_parameters(name, required))
+}
+
+pub(crate) fn create_request_body_annotation_usage(required: bool) -> JavaAnnotationUsage {
+    if required {
+        return create_annotation_usage("RequestBody", vec![]);
+    }
+    create_annotation_usage("RequestBody", vec![("required", "false".to_string())])
+}
+
+pub(crate) fn create_response_entity(body_type: JavaDataType) -> JavaDataType {
+    let import = JavaImport::new_explicit_import_without_m2_repo_scan(
+        "org.springframework.http.ResponseEntity",
+    )
+    .expect("ResponseEntity route is valid");
+    JavaDataType::from_import(import)
+        .with_type_arguments(vec![body_type])
+        .expect("ResponseEntity is generic")
+}
+
+/// Spring parameters are required by default
+fn get_named_parameters(name: &str, required: bool) -> Vec<(&'static str, String)> {
+    let mut parameters = vec![("value", to_string_literal(name))];
+    if !required {
+        parameters.push(("required", "false".to_string()));
+    }
+    parameters
+}
+
+fn create_annotation_usage(
+    simple_name: &str,
+    parameters: Vec<(&str, String)>,
+) -> JavaAnnotationUsage {
+    let mut builder = JavaAnnotationUsage::builder();
+    builder.import(&format!("{}.{}", ANNOTATION_PACKAGE, simple_name));
+    for (name, value) in parameters {
+        builder.parameter(name, &value);
+    }
+    builder.build().expect("Valid annotation")
+}
+
+fn to_string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1 @@
+pub mod java_spring_web_factory;
@@ -0,0 +1,322 @@
+use std::path::{Path, PathBuf};
+
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::java::annotation_usage::JavaAnnotationUsage;
+use crate::java::comment;
+use crate::java::comment::JavaComment;
+use crate::java::field::JavaField;
+use crate::java::import::JavaImport;
+use crate::java::interface::JavaInterface;
+use crate::java::javadoc::JavaDoc;
+use crate::java::method::JavaMethod;
+use crate::java::scanner::file::java_file::JavaFile;
+use crate::java::scanner::file::java_structure::JavaStructure;
+use crate::java::scanner::file::java_structure_type::JavaStructureType;
+use crate::java::visibility::JavaVisibility;
+
+/// # JavaEnum
+/// A Java Enum with its constants, written before the rest of the members.
+#[derive(Debug)]
+pub struct JavaEnum {
+    scanned_file: JavaFile,
+}
+
+impl JavaEnum {
+    // Public methods
+    /// # Builder pattern
+    /// This method allows to create a new Java Enum
+    /// and export it to a file. The "name" parameter is mandatory.
+    ///
+    /// ```
+    /// use std::env;
+    /// use genco::java::enumeration::JavaEnum;
+    ///
+    /// let dir = &env::current_dir().unwrap().join("doc/test/java/enumeration/src/main/java/org/test");
+    /// let java_enum = JavaEnum::builder().folder(dir).name("Status").constants(vec!["ACTIVE", "INACTIVE"]).build();
+    /// java_enum.expect("Java enum must be created");
+    /// ```
+    pub fn builder() -> JavaEnumBuilder {
+        JavaEnumBuilder::new_builder()
+    }
+
+    /// # from
+    /// Creates a reference to a java enum from a given "file_path".
+    /// If the provided file does not exist or it is not a valid java enum
+    /// an error is returned.
+    pub fn from(file_path: &Path) -> Result<Self, String> {
+        let java_file = JavaFile::from_user_input_path(file_path)?;
+        Self::from_java_file(java_file)
+    }
+
+    /// # get_comments
+    /// Get the comments written before the enum declaration, including its javadoc.
+    pub fn get_comments(&self) -> &Vec<JavaComment> {
+        self.get_structure().get_comments()
+    }
+
+    /// # get_javadoc
+    /// Get the javadoc of the JavaEnum if any.
+    pub fn get_javadoc(&self) -> Option<&JavaDoc> {
+        comment::get_javadoc(self.get_comments())
+    }
+
+    /// # get_annotations
+    /// Get the java annotations of the JavaEnum
+    pub fn get_annotations(&self) -> &Vec<JavaAnnotationUsage> {
+        self.get_structure().get_annotations()
+    }
+
+    /// # get_visibility
+    /// Get the java visibility of the JavaEnum
+    pub fn get_visibility(&self) -> JavaVisibility {
+        self.get_structure().get_visibility()
+    }
+
+    /// # get_name
+    /// It returns the current JavaEnum name.
+    pub fn get_name(&self) -> &str {
+        self.get_structure().get_name()
+    }
+
+    /// # get_constants
+    /// Get the names of the enum constants, in declaration order.
+    pub fn get_constants(&self) -> &Vec<String> {
+        self.get_structure().get_enum_constants()
+    }
+}
+
+impl JavaEnum {
+    // Crate or private methods
+    fn from_java_file(java_file: JavaFile) -> Result<Self, String> {
+        let structure_type = java_file.get_main_structure_type();
+        if structure_type != JavaStructureType::Enum {
+            return Err(format!(
+                "Expected java enum, found java {:?}",
+                structure_type
+            ));
+        }
+
+        Ok(Self {
+            scanned_file: java_file,
+        })
+    }
+
+    fn write(file: &Path, structure: JavaStructure) -> Result<Self, String> {
+        let java_file = JavaFile::write(file, structure)?;
+        Self::from_java_file(java_file)
+    }
+
+    pub(crate) fn get_structure(&self) -> &JavaStructure {
+        self.scanned_file.get_structure()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn get_file(&self) -> &PathBuf {
+        self.scanned_file.get_file_path()
+    }
+}
+
+pub struct JavaEnumBuilder {
+    folder: Option<PathBuf>,
+
+    comments: Vec<JavaComment>,
+    annotations: Vec<JavaAnnotationUsage>,
+    visibility: JavaVisibility,
+
+    implemented_interfaces: Vec<JavaImport>,
+
+    name: Option<String>,
+    constants: Vec<String>,
+    fields: Vec<JavaField>,
+    methods: Vec<JavaMethod>,
+}
+
+impl JavaEnumBuilder {
+    fn new_builder() -> Self {
+        Self {
+            folder: None,
+            comments: vec![],
+            annotations: vec![],
+            visibility: JavaVisibility::Package,
+            implemented_interfaces: vec![],
+            name: None,
+            constants: vec![],
+            fields: vec![],
+            methods: vec![],
+        }
+    }
+
+    pub fn folder(&mut self, input: &Path) -> &mut Self {
+        self.folder = Some(input.to_path_buf());
+        self
+    }
+
+    pub fn comments(&mut self, input: Vec<JavaComment>) -> &mut Self {
+        self.comments = input;
+        self
+    }
+
+    /// Javadoc written right before the enum annotations.
+    pub fn javadoc(&mut self, input: JavaDoc) -> &mut Self {
+        comment::set_javadoc(&mut self.comments, input);
+        self
+    }
+
+    pub fn annotations(&mut self, input: Vec<JavaAnnotationUsage>) -> &mut Self {
+        self.annotations = input;
+        self
+    }
+
+    pub fn visibility(&mut self, input: JavaVisibility) -> &mut Self {
+        self.visibility = input;
+        self
+    }
+
+    pub fn implemented_interfaces(&mut self, input: Vec<JavaInterface>) -> &mut Self {
+        self.implemented_interfaces = input
+            .iter()
+            .map(|interface| interface.get_self_import())
+            .collect();
+        self
+    }
+
+    pub fn name(&mut self, input: &str) -> &mut Self {
+        self.name = Some(input.to_string());
+        self
+    }
+
+    pub fn constants(&mut self, input: Vec<&str>) -> &mut Self {
+        self.constants = input.iter().map(|constant| constant.to_string()).collect();
+        self
+    }
+
+    pub fn fields(&mut self, input: Vec<JavaField>) -> &mut Self {
+        self.fields = input;
+        self
+    }
+
+    pub fn methods(&mut self, input: Vec<JavaMethod>) -> &mut Self {
+        self.methods = input;
+        self
+    }
+
+    /// # build
+    /// Create java enum writing it into a file.
+    /// This method does not create the folder structure.
+    pub fn build(&mut self) -> Result<JavaEnum, String> {
+        let minimal_build_usage =
+            "JavaEnum::builder()\n\t.folder(/* Mandatory folder */)\n\t.name(/* Enum name */)\n\t.build()";
+        let name = self.name.clone().ok_or(format!(
+            "Invalid java enum build, name is mandatory. Example:\n{}\n",
+            minimal_build_usage
+        ))?;
+        let folder = self.folder.as_ref().ok_or(format!(
+            "Invalid java enum build, folder is mandatory. Example:\n{}\n",
+            minimal_build_usage
+        ))?;
+        if !folder.is_dir() {
+            return Err(format!(
+                "Invalid java enum \"{}\" build, expected dir:\n{}\n",
+                name,
+                try_to_absolute_path(folder)
+            ));
+        }
+        if let Some(duplicate) = self
+            .constants
+            .iter()
+            .enumerate()
+            .find(|(index, constant)| self.constants[..*index].contains(constant))
+            .map(|(_, constant)| constant)
+        {
+            return Err(format!(
+                "Invalid java enum \"{}\" build, duplicated constant \"{}\"",
+                name, duplicate
+            ));
+        }
+
+        let file = folder.join(format!("{}.java", name));
+        match JavaStructure::builder()
+            .structure_type(JavaStructureType::Enum)
+            .comments(self.comments.clone())
+            .annotations(self.annotations.clone())
+            .visibility(self.visibility)
+            .implemented_interfaces(self.implemented_interfaces.clone())
+            .enum_constants(self.constants.clone())
+            .name(&name)
+            .fields(self.fields.clone())
+            .methods(self.methods.clone())
+            .build()
+        {
+            Ok(structure) => JavaEnum::write(&file, structure),
+            Err(err) => Err(format!("Invalid java enum \"{}\" build, {}", name, err)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_assert::assert_same_file;
+    use crate::core::testing::test_path;
+    use crate::java::data_type::JavaDataType;
+    use crate::java::enumeration::JavaEnum;
+    use crate::java::field::JavaField;
+    use crate::java::visibility::JavaVisibility::{Private, Public};
+
+    #[test]
+    fn build_enum_with_constants_and_fields() {
+        let folder = get_test_folder();
+        let file_path = folder.join("Currency.java");
+        let field = JavaField::builder()
+            .visibility(Private)
+            .data_type(JavaDataType::string())
+            .name("symbol")
+            .build()
+            .expect("Field must be valid");
+
+        let java_enum = JavaEnum::builder()
+            .folder(&folder)
+            .visibility(Public)
+            .name("Currency")
+            .constants(vec!["EUR", "USD"])
+            .fields(vec![field])
+            .build()
+            .expect("Java enum must be created");
+
+        assert_same_file(&folder.join("ExpectedCurrency.java"), &file_path);
+        assert_eq!(&vec!["EUR", "USD"], java_enum.get_constants());
+        assert_eq!(&file_path, java_enum.get_file());
+        fs::remove_file(&file_path).expect("Result file must be removed");
+    }
+
+    #[test]
+    fn build_enum_with_duplicated_constant_fails() {
+        let result = JavaEnum::builder()
+            .folder(&get_test_folder())
+            .name("DuplicatedConstant")
+            .constants(vec!["ONE", "TWO", "ONE"])
+            .build();
+
+        assert!(result.is_err_and(|err| err.contains("duplicated constant \"ONE\"")));
+    }
+
+    #[test]
+    fn new_enum_from_path() {
+        let file_path = get_test_folder().join("Status.java");
+
+        let java_enum = JavaEnum::from(&file_path).expect("Java enum must be scanned");
+
+        assert_eq!("Status", java_enum.get_name());
+        assert_eq!(&vec!["ACTIVE", "INACTIVE"], java_enum.get_constants());
+    }
+
+    fn get_test_folder() -> PathBuf {
+        test_path::get_java_project_test_folder(get_current_file_path(), "enumeration")
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -3,19 +3,23 @@ use crate::core::file_system::path_helper::try_to_absolute_path;
 use crate::core::observability::logger;
 use crate::core::parser::parser_node_trait::ParserNode;
 use crate::java::annotation_usage::JavaAnnotationUsage;
+use crate::java::code_style_config::JavaCodeStyle;
+use crate::java::comment::JavaComment;
 use crate::java::data_type::JavaDataType;
 use crate::java::expression::JavaExpression;
 use crate::java::import::JavaImport;
 use crate::java::indentation_config::JavaIndentation;
+use crate::java::javadoc::JavaDoc;
 use crate::java::parser::java_node::JavaNode;
 use crate::java::parser::java_node_type;
 use crate::java::parser::java_node_type::JavaNodeType;
 use crate::java::scanner::file::java_file_imports::JavaFileImports;
 use crate::java::visibility::JavaVisibility;
-use crate::java::{annotation_usage, visibility};
+use crate::java::{annotation_usage, comment, visibility};
 
 #[derive(Debug, Clone)]
 pub struct JavaField {
+    comments: Vec<JavaComment>,
     annotations: Vec<JavaAnnotationUsage>,
     visibility: JavaVisibility,
     is_static: bool,
@@ -29,6 +33,12 @@ impl JavaField {
     pub fn builder() -> JavaFieldBuilder {
         JavaFieldBuilder::new_builder()
     }
+    pub fn get_comments(&self) -> &Vec<JavaComment> {
+        &self.comments
+    }
+    pub fn get_javadoc(&self) -> Option<&JavaDoc> {
+        comment::get_javadoc(&self.comments)
+    }
     pub fn get_annotations(&self) -> &Vec<JavaAnnotationUsage> {
         &self.annotations
     }
@@ -59,6 +69,7 @@ impl JavaField {
 impl JavaField {
     pub(crate) fn new(
         root_node: &JavaNode,
+        comments: Vec<JavaComment>,
         file_imports: &JavaFileImports,
         java_file_cache: &FileCache,
     ) -> Result<JavaField, String> {
@@ -103,7 +114,7 @@ impl JavaField {
                 for var_decl_child in child.get_children() {
                     if let Some(var_node_type) = var_decl_child.get_node_type() {
                         if JavaNodeType::Id == var_node_type {
-                            name = child.get_content_from_cache(java_file_cache);
+                            name = var_decl_child.get_content_from_cache(java_file_cache);
                         } else if JavaNodeType::Equals == var_node_type {
                             next_child_is_expression = true
                         } else if next_child_is_expression {
@@ -129,6 +140,7 @@ impl JavaField {
         ))?;
 
         Ok(JavaField {
+            comments,
             annotations,
             visibility,
             is_static,
@@ -139,15 +151,20 @@ impl JavaField {
         })
     }
 
-    pub(crate) fn get_str(&self, initial_indentation: &JavaIndentation) -> String {
+    pub(crate) fn get_str(
+        &self,
+        initial_indentation: &JavaIndentation,
+        style: &JavaCodeStyle,
+    ) -> String {
         let mut result = "".to_string();
-        for annotation in self.get_annotations() {
-            result += initial_indentation.get_current_indentation().as_str();
-            result += annotation.to_string().as_str();
-            result += "\n"
-        }
+        comment::write_comments(&mut result, self.get_comments(), initial_indentation);
+        annotation_usage::write_annotations(
+            &mut result,
+            self.get_annotations(),
+            initial_indentation,
+            style.is_field_annotations_on_same_line(),
+        );
 
-        result += initial_indentation.get_current_indentation().as_str();
         result += self.get_visibility().as_file_string().as_str();
         if self.is_static {
             result += "static ";
@@ -167,9 +184,7 @@ impl JavaField {
             imports.push(import.clone());
         }
 
-        if let Some(type_import) = self.get_data_type().get_import_opt() {
-            imports.push(type_import.clone());
-        }
+        imports.extend(self.get_data_type().get_imports());
 
         imports
     }
@@ -183,6 +198,7 @@ impl JavaField {
 }
 
 pub struct JavaFieldBuilder {
+    comments: Vec<JavaComment>,
     annotations: Vec<JavaAnnotationUsage>,
     visibility: JavaVisibility,
     is_static: bool,
@@ -195,6 +211,7 @@ pub struct JavaFieldBuilder {
 impl JavaFieldBuilder {
     fn new_builder() -> Self {
         Self {
+            comments: vec![],
             annotations: vec![],
             visibility: JavaVisibility::Package,
             is_static: false,
@@ -204,6 +221,18 @@ impl JavaFieldBuilder {
             value: None,
         }
     }
+    /// # comments
+    /// Comments written before the field annotations, sorted from top to bottom.
+    pub fn comments(&mut self, input: Vec<JavaComment>) -> &mut Self {
+        self.comments = input;
+        self
+    }
+    /// # javadoc
+    /// Replace the javadoc of the field comments.
+    pub fn javadoc(&mut self, input: JavaDoc) -> &mut Self {
+        comment::set_javadoc(&mut self.comments, input);
+        self
+    }
     pub fn annotations(&mut self, input: Vec<JavaAnnotationUsage>) -> &mut Self {
         self.annotations = input;
         self
@@ -235,6 +264,7 @@ impl JavaFieldBuilder {
 
     pub fn build(&mut self) -> Result<JavaField, String> {
         Ok(JavaField {
+            comments: self.comments.clone(),
             annotations: self.annotations.clone(),
             visibility: self.visibility,
             is_static: self.is_static,
@@ -258,6 +288,7 @@ mod tests {
 
     use crate::core::testing::test_assert::{assert_fail, assert_same_as_file};
     use crate::core::testing::test_path::get_test_dir;
+    use crate::java::code_style_config::JavaCodeStyle;
     use crate::java::data_type::JavaDataType;
     use crate::java::dependency::org::springframework::spring_beans::java_spring_beans_factory;
     use crate::java::field::JavaField;
@@ -280,7 +311,8 @@ mod tests {
             .build()
         {
             Ok(field) => {
-                let field_str = field.get_str(&JavaIndentation::default());
+                let field_str =
+                    field.get_str(&JavaIndentation::default(), &JavaCodeStyle::default());
                 assert_same_as_file(&expected_str_file_path, &field_str);
             }
             Err(err) => assert_fail(&err),
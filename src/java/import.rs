@@ -27,6 +27,10 @@ pub(crate) struct JavaImport {
     // If the folder_path is None it represents a hardcoded import without dependency tree analysis
     folder_path: Option<PathBuf>,
     nodes: Vec<String>, // ["JavaClass", "JavaSubClass", "JavaSubClassMethod", ...]
+
+    /// Static imports (i.e. "org.junit.Assert.assertEquals") always use the route case,
+    /// the last node is the imported member or "*"
+    is_static: bool,
 }
 
 // Public crate methods
@@ -72,6 +76,37 @@ impl JavaImport {
         Ok(import)
     }
 
+    /// # new_static_import
+    /// Static import of a type member (i.e. "org.junit.Assert.assertEquals")
+    /// or of all the static members of a type (i.e. "org.junit.Assert.*").
+    pub(crate) fn new_static_import(route: &str) -> Result<JavaImport, String> {
+        let nodes = split_to_nodes(route);
+        let is_valid_node = |node: &String| {
+            !node.is_empty()
+                && node
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        };
+        let is_valid = nodes.len() > 1
+            && nodes[..nodes.len() - 1].iter().all(is_valid_node)
+            && nodes
+                .last()
+                .is_some_and(|last| last == "*" || is_valid_node(last));
+        if !is_valid {
+            return Err(format!(
+                "Invalid java static import \"{}\", expected a type member (i.e. \"org.junit.Assert.assertEquals\")",
+                route
+            ));
+        }
+
+        Ok(JavaImport {
+            fake_non_checked_route: route.to_string(),
+            folder_path: None,
+            nodes: vec![],
+            is_static: true,
+        })
+    }
+
     pub(crate) fn get_specific_file(&self) -> Result<PathBuf, String> {
         if !self.is_explicit_import() {
             return Err(format!(
@@ -100,6 +135,21 @@ impl JavaImport {
         false
     }
 
+    pub(crate) fn is_static_import(&self) -> bool {
+        self.is_static
+    }
+
+    /// Route of the type declaring the static members, i.e. "org.junit.Assert"
+    pub(crate) fn get_static_type_route(&self) -> Option<String> {
+        if !self.is_static {
+            return None;
+        }
+
+        self.fake_non_checked_route
+            .rsplit_once('.')
+            .map(|(type_route, _)| type_route.to_string())
+    }
+
     pub(crate) fn is_wildcard_import(&self) -> bool {
         if self.folder_path.is_some() {
             return self.nodes.is_empty();
@@ -130,6 +180,16 @@ impl JavaImport {
             .to_string()
     }
 
+    /// "java.lang" types are implicitly imported in every java file
+    pub(crate) fn is_java_lang(&self) -> bool {
+        if self.folder_path.is_some() {
+            return false;
+        }
+
+        let nodes = self.get_all_fake_nodes();
+        nodes.len() == 3 && nodes[0] == "java" && nodes[1] == "lang"
+    }
+
     pub(crate) fn get_package_route(&self) -> String {
         self.get_route_internal(true)
     }
@@ -154,6 +214,7 @@ impl JavaImport {
             fake_non_checked_route: "".to_string(),
             folder_path: Some(dir_path),
             nodes: vec![file_browser::remove_java_extension(last_node)],
+            is_static: false,
         })
     }
 
@@ -222,6 +283,7 @@ impl JavaImport {
             fake_non_checked_route: route.to_string(),
             folder_path: None,
             nodes: vec![],
+            is_static: false,
         }
     }
 
@@ -349,6 +411,9 @@ fn split_to_nodes(content: &str) -> Vec<String> {
 impl fmt::Display for JavaImport {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         fmt.write_str("import ")?;
+        if self.is_static {
+            fmt.write_str("static ")?;
+        }
 
         for (idx, children) in self.get_all_nodes().iter().enumerate() {
             if idx != 0 {
@@ -404,6 +469,17 @@ mod tests {
         assert_eq!("LastNodeClass", import.get_last_node());
     }
 
+    #[test]
+    fn is_java_lang() {
+        let java_lang_import = JavaImport::new_explicit_import("java.lang.Override")
+            .expect("Java explicit import is valid");
+        let java_util_import = JavaImport::new_explicit_import("java.util.List")
+            .expect("Java explicit import is valid");
+
+        assert!(java_lang_import.is_java_lang());
+        assert!(!java_util_import.is_java_lang());
+    }
+
     #[test]
     fn to_string_hardcoded_import() {
         let import = JavaImport::new_explicit_import("org.test.Class")
@@ -412,6 +488,33 @@ mod tests {
         assert_eq!("import org.test.Class;", import.to_string());
     }
 
+    #[test]
+    fn new_static_import() {
+        let member_import = JavaImport::new_static_import("org.junit.Assert.assertEquals")
+            .expect("Java static import is valid");
+        let wildcard_import = JavaImport::new_static_import("org.junit.Assert.*")
+            .expect("Java static import is valid");
+
+        assert!(member_import.is_static_import());
+        assert!(member_import.is_explicit_import());
+        assert_eq!("assertEquals", member_import.get_last_node());
+        assert_eq!(
+            Some("org.junit.Assert".to_string()),
+            member_import.get_static_type_route()
+        );
+        assert_eq!(
+            "import static org.junit.Assert.assertEquals;",
+            member_import.to_string()
+        );
+        assert!(wildcard_import.is_wildcard_import());
+        assert_eq!(
+            "import static org.junit.Assert.*;",
+            wildcard_import.to_string()
+        );
+        assert!(JavaImport::new_static_import("assertEquals").is_err());
+        assert!(JavaImport::new_static_import("org.junit..assertEquals").is_err());
+    }
+
     ///// Other tests:
 
     #[test]
@@ -468,7 +571,8 @@ mod tests {
                 assert_eq!(
                     "jakarta.persistence.Entity",
                     parameter
-                        .get_import()
+                        .get_data_type()
+                        .get_import_opt()
                         .expect("Parameter import")
                         .get_route()
                 )
@@ -500,7 +604,8 @@ mod tests {
         assert_eq!(1, parameters.len());
         let parameter = parameters.get(0).expect("First parameter expected");
         let route = parameter
-            .get_import()
+            .get_data_type()
+            .get_import_opt()
             .expect("Parameter import expected")
             .get_route();
         assert_eq!("jakarta.persistence.Entity", route);
@@ -0,0 +1,95 @@
+/// # JavaImportGroup
+/// Block of imports separated from the others by a blank line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JavaImportGroup {
+    /// "import static ...;"
+    Static,
+    /// "java.*" packages
+    Java,
+    /// "javax.*" packages
+    Javax,
+    /// Any package not belonging to the other groups
+    ThirdParty,
+    /// Packages declared in the same java project
+    Project,
+}
+
+/// # JavaImportLayout
+/// Order of the import groups and wildcard thresholds used to organize the imports of a java file.
+#[derive(Debug, Clone)]
+pub struct JavaImportLayout {
+    groups: Vec<JavaImportGroup>,
+    wildcard_threshold: usize,
+    static_wildcard_threshold: usize,
+}
+
+impl JavaImportLayout {
+    pub fn builder() -> JavaImportLayoutBuilder {
+        JavaImportLayoutBuilder::new_builder()
+    }
+
+    pub fn get_groups(&self) -> &Vec<JavaImportGroup> {
+        &self.groups
+    }
+
+    pub fn get_wildcard_threshold(&self) -> usize {
+        self.wildcard_threshold
+    }
+
+    pub fn get_static_wildcard_threshold(&self) -> usize {
+        self.static_wildcard_threshold
+    }
+}
+
+pub struct JavaImportLayoutBuilder {
+    groups: Vec<JavaImportGroup>,
+    wildcard_threshold: usize,
+    static_wildcard_threshold: usize,
+}
+
+impl JavaImportLayoutBuilder {
+    fn new_builder() -> JavaImportLayoutBuilder {
+        JavaImportLayoutBuilder {
+            groups: vec![
+                JavaImportGroup::Static,
+                JavaImportGroup::Java,
+                JavaImportGroup::Javax,
+                JavaImportGroup::ThirdParty,
+                JavaImportGroup::Project,
+            ],
+            wildcard_threshold: 5,
+            static_wildcard_threshold: 3,
+        }
+    }
+
+    /// # groups
+    /// Groups in the order they are written, imports of a missing group are written last.
+    pub fn groups(&mut self, input: Vec<JavaImportGroup>) -> &mut Self {
+        self.groups = input;
+        self
+    }
+
+    /// # wildcard_threshold
+    /// Imports of the same package are collapsed into "package.*" from this number of types,
+    /// on-demand imports covering fewer types are expanded. Zero disables both conversions.
+    pub fn wildcard_threshold(&mut self, input: usize) -> &mut Self {
+        self.wildcard_threshold = input;
+        self
+    }
+
+    /// # static_wildcard_threshold
+    /// Static imports of the same type are collapsed into "Type.*" from this number of members.
+    /// Zero disables it.
+    pub fn static_wildcard_threshold(&mut self, input: usize) -> &mut Self {
+        self.static_wildcard_threshold = input;
+        self
+    }
+
+    pub fn build(&mut self) -> JavaImportLayout {
+        JavaImportLayout {
+            groups: self.groups.clone(),
+            wildcard_threshold: self.wildcard_threshold,
+            static_wildcard_threshold: self.static_wildcard_threshold,
+        }
+    }
+}
@@ -1,13 +1,21 @@
 use std::path::{Path, PathBuf};
 
 use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::observability::logger;
 use crate::java::annotation_usage::JavaAnnotationUsage;
+use crate::java::class::JavaClass;
+use crate::java::comment;
+use crate::java::comment::JavaComment;
 use crate::java::field::JavaField;
 use crate::java::import::JavaImport;
+use crate::java::import_layout_config::JavaImportLayout;
+use crate::java::javadoc::JavaDoc;
 use crate::java::method::JavaMethod;
 use crate::java::scanner::file::java_file::JavaFile;
 use crate::java::scanner::file::java_structure::JavaStructure;
 use crate::java::scanner::file::java_structure_type::JavaStructureType;
+use crate::java::scanner::hierarchy::java_type_hierarchy;
+use crate::java::supertype::JavaSupertype;
 use crate::java::visibility::JavaVisibility;
 
 #[derive(Debug)]
@@ -51,6 +59,15 @@ impl JavaInterface {
         Self::from_java_file(java_file)
     }
 
+    /// # organize_imports
+    /// Remove the unused and duplicated imports of the interface file and sort them
+    /// following the given layout.
+    pub fn organize_imports(&mut self, layout: &JavaImportLayout) -> Result<(), String> {
+        self.scanned_file = self.scanned_file.organize_imports(layout)?;
+
+        Ok(())
+    }
+
     fn from_java_file(java_file: JavaFile) -> Result<Self, String> {
         let structure_type = java_file.get_main_structure_type();
         if structure_type != JavaStructureType::Interface {
@@ -67,6 +84,18 @@ impl JavaInterface {
         Ok(java_interface)
     }
 
+    /// # get_comments
+    /// Get the comments written before the interface declaration, including its javadoc.
+    pub fn get_comments(&self) -> &Vec<JavaComment> {
+        self.get_structure().get_comments()
+    }
+
+    /// # get_javadoc
+    /// Get the javadoc of the JavaInterface if any.
+    pub fn get_javadoc(&self) -> Option<&JavaDoc> {
+        comment::get_javadoc(self.get_comments())
+    }
+
     /// # get_annotations
     /// Get the java annotations of the JavaInterface
     pub fn get_annotations(&self) -> &Vec<JavaAnnotationUsage> {
@@ -79,12 +108,81 @@ impl JavaInterface {
         self.get_structure().get_visibility()
     }
 
+    /// # is_sealed
+    /// It returns if the current JavaInterface is sealed.
+    pub fn is_sealed(&self) -> bool {
+        self.get_structure().is_sealed()
+    }
+
+    /// # is_non_sealed
+    /// It returns if the current JavaInterface is non-sealed.
+    pub fn is_non_sealed(&self) -> bool {
+        self.get_structure().is_non_sealed()
+    }
+
+    /// # get_permitted_subtypes
+    /// Routes of the classes and interfaces allowed by the "permits" clause.
+    pub fn get_permitted_subtypes(&self) -> Vec<String> {
+        self.get_structure()
+            .get_permitted_subtypes()
+            .iter()
+            .map(|import| import.get_route())
+            .collect()
+    }
+
     /// # get_name
     /// It returns the current JavaInterface name.
     pub fn get_name(&self) -> &str {
         self.get_structure().get_name()
     }
 
+    /// # get_all_supertypes
+    /// Get the interfaces that the current JavaInterface extends, directly or transitively,
+    /// nearest first. External and unresolved interfaces are also returned.
+    pub fn get_all_supertypes(&self) -> Result<Vec<JavaSupertype>, String> {
+        java_type_hierarchy::get_supertypes(self.get_file_path(), &self.get_route())
+    }
+
+    /// # get_implementations
+    /// Get the classes of the project implementing the current JavaInterface, directly,
+    /// through a subinterface or through a superclass. Abstract classes are included.
+    pub fn get_implementations(&self) -> Result<Vec<JavaClass>, String> {
+        let subtypes = java_type_hierarchy::get_subtypes(self.get_file_path(), &self.get_route())?;
+
+        Ok(JavaClass::from_structure_entities(&subtypes))
+    }
+
+    /// # get_subinterfaces
+    /// Get the interfaces of the project extending the current JavaInterface, directly or transitively.
+    pub fn get_subinterfaces(&self) -> Result<Vec<JavaInterface>, String> {
+        let subtypes = java_type_hierarchy::get_subtypes(self.get_file_path(), &self.get_route())?;
+
+        Ok(subtypes
+            .iter()
+            .filter(|subtype| subtype.structure_type == "interface")
+            .filter_map(
+                |subtype| match Self::from(Path::new(&subtype.location.file_path)) {
+                    Ok(java_interface) => Some(java_interface),
+                    Err(err) => {
+                        logger::log_warning(&err);
+                        None
+                    }
+                },
+            )
+            .collect())
+    }
+
+    /// # get_inherited_methods
+    /// Get the methods declared in the project superinterfaces and not in the current JavaInterface.
+    pub fn get_inherited_methods(&self) -> Result<Vec<JavaMethod>, String> {
+        let supertypes = self.get_all_supertypes()?;
+
+        Ok(java_type_hierarchy::get_inherited_methods(
+            self.get_structure(),
+            &supertypes,
+        ))
+    }
+
     /// # get_methods
     /// Get the methods of the current JavaInterface.
     pub fn get_methods(&self) -> &Vec<JavaMethod> {
@@ -120,6 +218,14 @@ impl JavaInterface {
         &self.scanned_file
     }
 
+    fn get_file_path(&self) -> &Path {
+        self.get_scanned_file().get_file_path()
+    }
+
+    fn get_route(&self) -> String {
+        self.get_self_import().get_route()
+    }
+
     #[cfg(test)]
     pub(crate) fn get_file(&self) -> &PathBuf {
         self.get_scanned_file().get_file_path()
@@ -138,10 +244,14 @@ impl JavaInterface {
 pub struct JavaInterfaceBuilder {
     folder: Option<PathBuf>,
 
+    comments: Vec<JavaComment>,
     annotations: Vec<JavaAnnotationUsage>,
     visibility: JavaVisibility,
     //is_static: bool,
     //is_abstract: bool,
+    is_sealed: bool,
+    is_non_sealed: bool,
+    permitted_subtypes: Vec<String>,
 
     //extended_interfaces: Vec<JavaInterface>,
     name: Option<String>,
@@ -153,8 +263,12 @@ impl JavaInterfaceBuilder {
     fn new_builder() -> Self {
         Self {
             folder: None,
+            comments: vec![],
             annotations: vec![],
             visibility: JavaVisibility::Package,
+            is_sealed: false,
+            is_non_sealed: false,
+            permitted_subtypes: vec![],
             name: None,
             fields: vec![],
             methods: vec![],
@@ -166,6 +280,19 @@ impl JavaInterfaceBuilder {
         self
     }
 
+    /// # comments
+    /// Comments written before the interface annotations, sorted from top to bottom.
+    pub fn comments(&mut self, input: Vec<JavaComment>) -> &mut Self {
+        self.comments = input;
+        self
+    }
+
+    /// Javadoc written right before the interface annotations.
+    pub fn javadoc(&mut self, input: JavaDoc) -> &mut Self {
+        comment::set_javadoc(&mut self.comments, input);
+        self
+    }
+
     pub fn annotations(&mut self, input: Vec<JavaAnnotationUsage>) -> &mut Self {
         self.annotations = input;
         self
@@ -176,6 +303,24 @@ impl JavaInterfaceBuilder {
         self
     }
 
+    pub fn is_sealed(&mut self, input: bool) -> &mut Self {
+        self.is_sealed = input;
+        self
+    }
+
+    pub fn is_non_sealed(&mut self, input: bool) -> &mut Self {
+        self.is_non_sealed = input;
+        self
+    }
+
+    /// # permits
+    /// Routes of the classes or interfaces allowed to extend a sealed interface.
+    /// The ones found in the project must implement or extend the interface.
+    pub fn permits(&mut self, input: Vec<&str>) -> &mut Self {
+        self.permitted_subtypes = input.iter().map(|route| route.to_string()).collect();
+        self
+    }
+
     pub fn name(&mut self, input: &str) -> &mut Self {
         self.name = Some(input.to_string());
         self
@@ -222,13 +367,28 @@ impl JavaInterfaceBuilder {
             ));
         }
 
+        if self.is_sealed {
+            java_type_hierarchy::check_permitted_subtypes(folder, &name, &self.permitted_subtypes)
+                .map_err(|err| format!("Invalid java interface \"{}\" build, {}", name, err))?;
+        }
+        let permitted_subtypes = self
+            .permitted_subtypes
+            .iter()
+            .map(|route| JavaImport::new_explicit_import_without_m2_repo_scan(route))
+            .collect::<Result<Vec<JavaImport>, String>>()
+            .map_err(|err| format!("Invalid java interface \"{}\" build, {}", name, err))?;
+
         let file = folder.join(format!("{}.java", name));
         return match JavaStructure::builder()
             .structure_type(JavaStructureType::Interface)
+            .comments(self.comments.clone())
             .annotations(self.annotations.clone())
             .visibility(self.visibility)
             //.is_static(self.is_static)
             //.is_abstract(self.is_abstract)
+            .is_sealed(self.is_sealed)
+            .is_non_sealed(self.is_non_sealed)
+            .permitted_subtypes(permitted_subtypes)
             //.extended_interfaces(self.extended_interfaces.to_owned())
             .name(&name)
             .fields(self.fields.clone())
@@ -251,8 +411,10 @@ mod tests {
 
     use crate::core::testing::test_assert::{assert_fail, assert_same_file};
     use crate::core::testing::test_path;
+    use crate::java::data_type::JavaDataType;
     use crate::java::interface::JavaInterface;
-    use crate::java::visibility::JavaVisibility::Package;
+    use crate::java::method::JavaMethod;
+    use crate::java::visibility::JavaVisibility::{Package, Public};
 
     #[test]
     fn builder() {
@@ -278,6 +440,67 @@ mod tests {
         };
     }
 
+    #[test]
+    fn build_interface_with_default_and_static_methods() {
+        let folder = get_test_folder();
+        let file_path = folder.join("InterfaceWithMethods.java");
+        let methods = vec![
+            JavaMethod::builder()
+                .return_type(JavaDataType::string())
+                .name("getName")
+                .build()
+                .expect("getName is expected to be valid"),
+            JavaMethod::builder()
+                .is_default(true)
+                .return_type(JavaDataType::boolean())
+                .name("isEmpty")
+                .build()
+                .expect("isEmpty is expected to be valid"),
+            JavaMethod::builder()
+                .is_static(true)
+                .return_type(JavaDataType::int())
+                .name("count")
+                .build()
+                .expect("count is expected to be valid"),
+        ];
+
+        let interface = JavaInterface::builder()
+            .folder(&folder)
+            .visibility(Public)
+            .name("InterfaceWithMethods")
+            .methods(methods)
+            .build()
+            .expect("Java interface must be created");
+
+        assert_same_file(&get_test_file("ExpectedInterfaceWithMethods"), &file_path);
+        let is_default: Vec<bool> = interface
+            .get_methods()
+            .iter()
+            .map(|method| method.is_default())
+            .collect();
+        assert_eq!(vec![false, true, false], is_default);
+        assert!(interface.get_methods()[2].is_static());
+        fs::remove_file(&file_path).expect("Result file must be removed");
+    }
+
+    #[test]
+    fn build_interface_with_synchronized_method_fails() {
+        let method = JavaMethod::builder()
+            .is_default(true)
+            .is_synchronized(true)
+            .name("synchronizedMethod")
+            .build()
+            .expect("synchronizedMethod is expected to be valid");
+
+        let result = JavaInterface::builder()
+            .folder(&get_test_folder())
+            .name("InterfaceWithSynchronizedMethod")
+            .methods(vec![method])
+            .build();
+
+        assert!(result.is_err_and(|err| err.contains("can not be final, synchronized or native")));
+    }
+
     #[test]
     fn new_from_path_interface() {
         let file_path = get_test_file("JavaInterfaceFrom");
@@ -290,6 +513,66 @@ mod tests {
         }
     }
 
+    #[test]
+    fn interface_hierarchy() {
+        let folder = test_path::get_java_project_test_folder(get_current_file_path(), "hierarchy");
+        let base_port = JavaInterface::from(&folder.join("BasePort.java"))
+            .expect("Java interface must be scanned");
+        let port_repository = JavaInterface::from(&folder.join("PortRepository.java"))
+            .expect("Java interface must be scanned");
+
+        let implementations = base_port
+            .get_implementations()
+            .expect("Implementations expected");
+        let subinterfaces = base_port
+            .get_subinterfaces()
+            .expect("Subinterfaces expected");
+        let supertypes = port_repository
+            .get_all_supertypes()
+            .expect("Supertypes expected");
+        let inherited_methods = port_repository
+            .get_inherited_methods()
+            .expect("Inherited methods expected");
+
+        let implementations: Vec<&str> = implementations
+            .iter()
+            .map(|implementation| implementation.get_name())
+            .collect();
+        assert_eq!(
+            vec!["AbstractRepository", "DatabaseRepository"],
+            implementations
+        );
+        assert_eq!(1, subinterfaces.len());
+        assert_eq!("PortRepository", subinterfaces[0].get_name());
+        assert_eq!(1, supertypes.len());
+        assert_eq!(Some("org.test.BasePort"), supertypes[0].get_route());
+        assert_eq!(1, inherited_methods.len());
+        assert_eq!("close", inherited_methods[0].get_name());
+    }
+
+    #[test]
+    fn build_sealed_interface_with_permits() {
+        let folder = get_test_folder().join("sealed");
+        let file_path = folder.join("Vehicle.java");
+
+        let interface = JavaInterface::builder()
+            .folder(&folder)
+            .visibility(Public)
+            .is_sealed(true)
+            .permits(vec!["org.test.sealed.Car"])
+            .name("Vehicle")
+            .build()
+            .expect("Java interface must be created");
+
+        assert_same_file(&folder.join("ExpectedVehicle.java"), &file_path);
+        assert!(interface.is_sealed());
+        assert_eq!(
+            vec!["org.test.sealed.Car"],
+            interface.get_permitted_subtypes()
+        );
+        fs::remove_file(&file_path).expect("Result file must be removed");
+    }
+
     fn get_test_file(structure_name: &str) -> PathBuf {
         get_test_folder().join(format!("{}.java", structure_name).as_str())
     }
@@ -0,0 +1,300 @@
+use crate::java::indentation_config::JavaIndentation;
+
+/// # JavaDoc
+/// Javadoc comment ("/** ... */") split into its description and block tags.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JavaDoc {
+    description: String,
+    params: Vec<JavaDocTag>,
+    return_description: Option<String>,
+    throws: Vec<JavaDocTag>,
+    other_tags: Vec<String>,
+}
+
+/// # JavaDocTag
+/// "@param" or "@throws" tag, where the name is the parameter name or the exception type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JavaDocTag {
+    name: String,
+    description: String,
+}
+
+// Public methods
+impl JavaDoc {
+    /// # Builder pattern
+    /// This method allows to create a new Javadoc for a class, interface, field or method.
+    ///
+    /// ```
+    /// use genco::java::javadoc::JavaDoc;
+    ///
+    /// let javadoc = JavaDoc::builder()
+    ///     .description("Find a user by its identifier.")
+    ///     .param("id", "user identifier")
+    ///     .return_description("the user found")
+    ///     .throws("IllegalArgumentException", "if the id is negative")
+    ///     .build();
+    /// ```
+    pub fn builder() -> JavaDocBuilder {
+        JavaDocBuilder::new_builder()
+    }
+
+    /// # get_description
+    /// Text before the block tags, lines are separated by "\n".
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+
+    /// # get_params
+    /// "@param" tags sorted as written.
+    pub fn get_params(&self) -> &Vec<JavaDocTag> {
+        &self.params
+    }
+
+    /// # get_return_description
+    /// Text of the "@return" tag.
+    pub fn get_return_description(&self) -> &Option<String> {
+        &self.return_description
+    }
+
+    /// # get_throws
+    /// "@throws" (or "@exception") tags sorted as written.
+    pub fn get_throws(&self) -> &Vec<JavaDocTag> {
+        &self.throws
+    }
+
+    /// # get_other_tags
+    /// Remaining block tags as written, i.e. "@see java.util.List" or "@deprecated".
+    pub fn get_other_tags(&self) -> &Vec<String> {
+        &self.other_tags
+    }
+}
+
+impl JavaDocTag {
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get_description(&self) -> &str {
+        &self.description
+    }
+}
+
+// Crate or private methods
+impl JavaDoc {
+    /// Parse the content of a javadoc comment, including the "/**" and "*/" delimiters.
+    pub(crate) fn from_comment(comment: &str) -> JavaDoc {
+        let inner_content = comment
+            .trim()
+            .trim_start_matches("/**")
+            .trim_end_matches("*/");
+        let mut builder = JavaDoc::builder();
+        let mut description_lines: Vec<String> = Vec::new();
+        let mut tags: Vec<String> = Vec::new();
+        for line in inner_content.lines() {
+            let line = get_line_text(line);
+            if line.starts_with('@') {
+                tags.push(line.to_string());
+            } else if let Some(last_tag) = tags.last_mut() {
+                if !line.is_empty() {
+                    *last_tag += "\n";
+                    *last_tag += line.trim_start();
+                }
+            } else {
+                description_lines.push(line.to_string());
+            }
+        }
+
+        let description = description_lines.join("\n");
+        builder.description(description.trim_matches('\n'));
+        for tag in tags {
+            add_tag(&mut builder, &tag);
+        }
+        builder.build()
+    }
+
+    pub(crate) fn to_file_string(&self, indentation: &JavaIndentation) -> String {
+        let current_indentation = indentation.get_current_indentation();
+        let mut lines: Vec<String> = Vec::new();
+        if !self.description.is_empty() {
+            lines.extend(self.description.lines().map(|line| line.to_string()));
+        }
+
+        let mut tags = Vec::new();
+        for param in &self.params {
+            tags.push(format!("@param {} {}", param.name, param.description));
+        }
+        if let Some(return_description) = &self.return_description {
+            tags.push(format!("@return {}", return_description));
+        }
+        for throws in &self.throws {
+            tags.push(format!("@throws {} {}", throws.name, throws.description));
+        }
+        for tag in &self.other_tags {
+            tags.push(tag.to_string());
+        }
+        if !lines.is_empty() && !tags.is_empty() {
+            lines.push("".to_string());
+        }
+        for tag in tags {
+            lines.extend(tag.trim_end().lines().map(|line| line.to_string()));
+        }
+
+        let mut result = format!("{}/**\n", current_indentation);
+        for line in lines {
+            if line.is_empty() {
+                result += format!("{} *\n", current_indentation).as_str();
+            } else {
+                result += format!("{} * {}\n", current_indentation, line).as_str();
+            }
+        }
+        result += format!("{} */\n", current_indentation).as_str();
+        result
+    }
+}
+
+/// Line without the leading "*" and the first space after it
+fn get_line_text(line: &str) -> &str {
+    let line = line.trim();
+    let line = line.strip_prefix('*').unwrap_or(line);
+    line.strip_prefix(' ').unwrap_or(line).trim_end()
+}
+
+fn add_tag(builder: &mut JavaDocBuilder, tag: &str) {
+    let (tag_name, content) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+    let content = content.trim_start();
+    match tag_name {
+        "@param" | "@throws" | "@exception" => {
+            let (name, description) = content
+                .split_once(char::is_whitespace)
+                .unwrap_or((content, ""));
+            if "@param" == tag_name {
+                builder.param(name, description.trim_start());
+            } else {
+                builder.throws(name, description.trim_start());
+            }
+        }
+        "@return" => {
+            builder.return_description(content);
+        }
+        _ => {
+            builder.other_tag(tag);
+        }
+    }
+}
+
+pub struct JavaDocBuilder {
+    description: String,
+    params: Vec<JavaDocTag>,
+    return_description: Option<String>,
+    throws: Vec<JavaDocTag>,
+    other_tags: Vec<String>,
+}
+
+impl JavaDocBuilder {
+    fn new_builder() -> Self {
+        Self {
+            description: "".to_string(),
+            params: vec![],
+            return_description: None,
+            throws: vec![],
+            other_tags: vec![],
+        }
+    }
+
+    pub fn description(&mut self, input: &str) -> &mut Self {
+        self.description = input.to_string();
+        self
+    }
+
+    /// # param
+    /// Add a "@param" tag, it can be called once per parameter.
+    pub fn param(&mut self, name: &str, description: &str) -> &mut Self {
+        self.params.push(JavaDocTag {
+            name: name.to_string(),
+            description: description.to_string(),
+        });
+        self
+    }
+
+    pub fn return_description(&mut self, input: &str) -> &mut Self {
+        self.return_description = Some(input.to_string());
+        self
+    }
+
+    /// # throws
+    /// Add a "@throws" tag, it can be called once per exception type.
+    pub fn throws(&mut self, exception_type: &str, description: &str) -> &mut Self {
+        self.throws.push(JavaDocTag {
+            name: exception_type.to_string(),
+            description: description.to_string(),
+        });
+        self
+    }
+
+    /// # other_tag
+    /// Add any other block tag written as is (i.e. "@since 1.2").
+    pub fn other_tag(&mut self, input: &str) -> &mut Self {
+        self.other_tags.push(input.to_string());
+        self
+    }
+
+    pub fn build(&mut self) -> JavaDoc {
+        JavaDoc {
+            description: self.description.clone(),
+            params: self.params.clone(),
+            return_description: self.return_description.clone(),
+            throws: self.throws.clone(),
+            other_tags: self.other_tags.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::java::indentation_config::JavaIndentation;
+    use crate::java::javadoc::JavaDoc;
+
+    #[test]
+    fn from_comment_splits_description_and_tags() {
+        let comment = "/**\n     * Find a user.\n     *\n     * Second line with {@link String}.\n     *\n     * @param id user identifier\n     *        must be positive\n     * @return the user\n     * @exception IllegalStateException when closed\n     * @see java.util.List\n     */";
+
+        let javadoc = JavaDoc::from_comment(comment);
+
+        assert_eq!(
+            "Find a user.\n\nSecond line with {@link String}.",
+            javadoc.get_description()
+        );
+        assert_eq!(1, javadoc.get_params().len());
+        assert_eq!("id", javadoc.get_params()[0].get_name());
+        assert_eq!(
+            "user identifier\nmust be positive",
+            javadoc.get_params()[0].get_description()
+        );
+        assert_eq!(
+            &Some("the user".to_string()),
+            javadoc.get_return_description()
+        );
+        assert_eq!("IllegalStateException", javadoc.get_throws()[0].get_name());
+        assert_eq!(&vec!["@see java.util.List"], javadoc.get_other_tags());
+    }
+
+    #[test]
+    fn to_file_string_round_trip() {
+        let javadoc = JavaDoc::builder()
+            .description("Find a user.")
+            .param("id", "user identifier")
+            .return_description("the user")
+            .throws("IllegalArgumentException", "if the id is negative")
+            .build();
+        let mut indentation = JavaIndentation::default();
+        indentation.increase_level();
+
+        let javadoc_str = javadoc.to_file_string(&indentation);
+
+        assert_eq!(
+            "    /**\n     * Find a user.\n     *\n     * @param id user identifier\n     * @return the user\n     * @throws IllegalArgumentException if the id is negative\n     */\n",
+            javadoc_str
+        );
+        assert_eq!(javadoc, JavaDoc::from_comment(&javadoc_str));
+    }
+}
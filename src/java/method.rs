@@ -3,25 +3,38 @@ use crate::core::file_system::file_cache::FileCache;
 use crate::core::observability::logger;
 use crate::core::parser::parser_node_trait::ParserNode;
 use crate::java::annotation_usage::JavaAnnotationUsage;
+use crate::java::code_style_config::JavaCodeStyle;
+use crate::java::comment::JavaComment;
 use crate::java::data_type::JavaDataType;
 use crate::java::import::JavaImport;
 use crate::java::indentation_config::JavaIndentation;
+use crate::java::javadoc::JavaDoc;
 use crate::java::parser::java_node::JavaNode;
 use crate::java::parser::java_node_type::JavaNodeType;
 use crate::java::scanner::file::java_file_imports::JavaFileImports;
+use crate::java::scanner::file::java_structure_type::JavaStructureType;
 use crate::java::statement::JavaStatement;
 use crate::java::variable::JavaVariable;
 use crate::java::visibility::JavaVisibility;
-use crate::java::{annotation_usage, visibility};
+use crate::java::{annotation_usage, comment, visibility};
 
 #[derive(Debug, Clone)]
 pub struct JavaMethod {
+    comments: Vec<JavaComment>,
     annotations: Vec<JavaAnnotationUsage>,
     visibility: JavaVisibility,
     is_static: bool,
+    is_abstract: bool,
+    is_final: bool,
+    is_default: bool,
+    is_synchronized: bool,
+    is_native: bool,
+    is_strictfp: bool,
     return_type: Option<JavaDataType>,
     name: String,
     parameters: Vec<JavaVariable>,
+    throws: Vec<JavaDataType>,
+    static_imports: Vec<JavaImport>,
     _statements: Vec<JavaStatement>,
 }
 
@@ -41,6 +54,18 @@ impl JavaMethod {
         JavaMethodBuilder::new_builder()
     }
 
+    /// # get_comments
+    /// Get the comments written before the method, including its javadoc.
+    pub fn get_comments(&self) -> &Vec<JavaComment> {
+        &self.comments
+    }
+
+    /// # get_javadoc
+    /// Get the javadoc of the method if any.
+    pub fn get_javadoc(&self) -> Option<&JavaDoc> {
+        comment::get_javadoc(&self.comments)
+    }
+
     /// # get_annotations
     /// Get the java annotations of the method
     pub fn get_annotations(&self) -> &Vec<JavaAnnotationUsage> {
@@ -59,6 +84,42 @@ impl JavaMethod {
         self.is_static
     }
 
+    /// # is_abstract
+    /// It returns if the current JavaMethod is declared abstract.
+    pub fn is_abstract(&self) -> bool {
+        self.is_abstract
+    }
+
+    /// # is_final
+    /// It returns if the current JavaMethod is final.
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
+
+    /// # is_default
+    /// It returns if the current JavaMethod is an interface default method.
+    pub fn is_default(&self) -> bool {
+        self.is_default
+    }
+
+    /// # is_synchronized
+    /// It returns if the current JavaMethod is synchronized.
+    pub fn is_synchronized(&self) -> bool {
+        self.is_synchronized
+    }
+
+    /// # is_native
+    /// It returns if the current JavaMethod is native.
+    pub fn is_native(&self) -> bool {
+        self.is_native
+    }
+
+    /// # is_strictfp
+    /// It returns if the current JavaMethod is strictfp.
+    pub fn is_strictfp(&self) -> bool {
+        self.is_strictfp
+    }
+
     /// # get_return_type
     /// It returns the JavaMethod return JavaDataType.
     pub fn get_return_type(&self) -> &Option<JavaDataType> {
@@ -76,22 +137,36 @@ impl JavaMethod {
     pub fn get_parameters(&self) -> &Vec<JavaVariable> {
         &self.parameters
     }
+
+    /// # get_throws
+    /// Get the exception types declared in the "throws" clause.
+    pub fn get_throws(&self) -> &Vec<JavaDataType> {
+        &self.throws
+    }
 }
 
 // Crate related methods
 impl JavaMethod {
     pub(crate) fn new_from_node(
         root_node: &JavaNode,
+        comments: Vec<JavaComment>,
         file_imports: &JavaFileImports,
         java_file_cache: &FileCache,
     ) -> Result<JavaMethod, String> {
         let mut annotations = Vec::new();
         let mut visibility = JavaVisibility::Package;
         let mut is_static = false;
+        let mut is_abstract = false;
+        let mut is_final = false;
+        let mut is_default = false;
+        let mut is_synchronized = false;
+        let mut is_native = false;
+        let mut is_strictfp = false;
         let mut return_type_opt = None;
         let mut return_type_detected = false;
         let mut name_opt = None;
         let mut parameters = Vec::new();
+        let mut throws = Vec::new();
 
         for child_node in root_node.get_children() {
             if let Some(node_type) = child_node.get_node_type() {
@@ -111,6 +186,18 @@ impl JavaMethod {
                                 visibility = visibility::new(&node_type);
                             } else if JavaNodeType::Static == node_type {
                                 is_static = true;
+                            } else if JavaNodeType::Abstract == node_type {
+                                is_abstract = true;
+                            } else if JavaNodeType::Final == node_type {
+                                is_final = true;
+                            } else if JavaNodeType::Default == node_type {
+                                is_default = true;
+                            } else if JavaNodeType::Synchronized == node_type {
+                                is_synchronized = true;
+                            } else if JavaNodeType::Native == node_type {
+                                is_native = true;
+                            } else if JavaNodeType::Strictfp == node_type {
+                                is_strictfp = true;
                             }
                         }
                     }
@@ -135,6 +222,20 @@ impl JavaMethod {
                         Ok(result) => parameters = result,
                         Err(err) => return Err(format!("Invalid java method parameters, {}", err)),
                     }
+                } else if JavaNodeType::Throws == node_type {
+                    for exception_node in child_node.get_children() {
+                        if !exception_node.is_data_type_identifier() {
+                            continue;
+                        }
+                        match JavaDataType::get_data_type(
+                            exception_node,
+                            file_imports,
+                            java_file_cache,
+                        ) {
+                            Ok(data_type) => throws.push(data_type),
+                            Err(err) => logger::log_warning(&err),
+                        }
+                    }
                 } else if JavaNodeType::Block == node_type {
                     // TODO: inspect method body
                 }
@@ -146,45 +247,121 @@ impl JavaMethod {
         }
 
         Ok(JavaMethod {
+            comments,
             annotations,
             visibility,
             return_type: return_type_opt,
             is_static,
+            is_abstract,
+            is_final,
+            is_default,
+            is_synchronized,
+            is_native,
+            is_strictfp,
             name: name_opt.ok_or("Java method name not detected.")?,
             parameters,
+            throws,
+            static_imports: Vec::new(),
             _statements: Vec::new(),
         })
     }
 
-    pub(crate) fn write_to_string(&self, result: &mut String, indentation: &JavaIndentation) {
-        self.write_annotations(result, indentation);
-        self.write_visibility(result, indentation);
+    pub(crate) fn write_to_string(
+        &self,
+        result: &mut String,
+        indentation: &JavaIndentation,
+        style: &JavaCodeStyle,
+        structure_type: JavaStructureType,
+    ) {
+        comment::write_comments(result, self.get_comments(), indentation);
+        annotation_usage::write_annotations(
+            result,
+            self.get_annotations(),
+            indentation,
+            style.is_method_annotations_on_same_line(),
+        );
+        *result += &self.get_visibility().as_file_string();
+        self.write_modifiers(result);
         self.write_return_type(result);
         *result += self.get_name();
         self.write_parameters(result);
-        *result += " {\n";
+        self.write_throws(result);
+        if !self.has_body(structure_type) {
+            *result += ";\n";
+            return;
+        }
+        *result += style.get_opening_brace(indentation).as_str();
         // TODO: write method body here
         *result += format!("{}}}\n", indentation.get_current_indentation()).as_str();
     }
 
+    /// Abstract and native methods, and interface methods that are
+    /// neither default, static nor private, are declared without body.
+    pub(crate) fn has_body(&self, structure_type: JavaStructureType) -> bool {
+        if self.is_abstract || self.is_native {
+            return false;
+        }
+        JavaStructureType::Interface != structure_type
+            || self.is_default
+            || self.is_static
+            || JavaVisibility::Private == self.visibility
+    }
+
+    /// Check the method modifiers are allowed in a structure of the given type.
+    pub(crate) fn check_structure(
+        &self,
+        structure_type: JavaStructureType,
+        is_abstract_structure: bool,
+    ) -> Result<(), String> {
+        if self.is_default && JavaStructureType::Interface != structure_type {
+            return Err(
+                self.get_invalid_modifier_error("\"default\" is only allowed in interfaces")
+            );
+        }
+        if JavaStructureType::Interface == structure_type {
+            if self.is_final || self.is_synchronized || self.is_native {
+                return Err(self.get_invalid_modifier_error(
+                    "interface methods can not be final, synchronized or native",
+                ));
+            }
+            if JavaVisibility::Protected == self.visibility {
+                return Err(
+                    self.get_invalid_modifier_error("interface methods can not be protected")
+                );
+            }
+        }
+        if self.is_abstract && JavaStructureType::Class == structure_type && !is_abstract_structure
+        {
+            return Err(self.get_invalid_modifier_error(
+                "abstract methods are only allowed in abstract classes",
+            ));
+        }
+
+        Ok(())
+    }
+
     pub(crate) fn get_imports(&self) -> Vec<JavaImport> {
         let mut imports = Vec::new();
         for import in self.get_annotation_imports() {
             imports.push(import.clone())
         }
 
-        if let Some(import) = self
-            .get_return_type()
-            .as_ref()
-            .and_then(|rt| rt.get_import_opt())
-        {
-            imports.push(import.clone())
+        if let Some(return_type) = self.get_return_type() {
+            imports.extend(return_type.get_imports());
         }
 
         for import in self.get_param_imports() {
             imports.push(import.clone());
         }
 
+        for exception_type in self.get_throws() {
+            imports.extend(exception_type.get_imports());
+        }
+
+        for import in &self.static_imports {
+            imports.push(import.clone());
+        }
+
         imports
     }
 
@@ -198,7 +375,7 @@ impl JavaMethod {
     fn get_param_imports(&self) -> Vec<JavaImport> {
         self.get_parameters()
             .iter()
-            .filter_map(|param| param.get_import())
+            .flat_map(|param| param.get_imports())
             .collect()
     }
 
@@ -215,6 +392,18 @@ impl JavaMethod {
         *result += ")";
     }
 
+    fn write_throws(&self, result: &mut String) {
+        let throws = self.get_throws();
+        if throws.is_empty() {
+            return;
+        }
+        let exception_types: Vec<String> = throws
+            .iter()
+            .map(|exception_type| exception_type.to_string())
+            .collect();
+        *result += format!(" throws {}", exception_types.join(", ")).as_str();
+    }
+
     fn write_return_type(&self, result: &mut String) {
         if let Some(return_type) = self.get_return_type() {
             *result += format!("{} ", return_type).as_str();
@@ -223,38 +412,116 @@ impl JavaMethod {
         }
     }
 
-    fn write_annotations(&self, result: &mut String, indentation: &JavaIndentation) {
-        for annotation in self.get_annotations() {
-            *result += annotation.to_file_string(indentation).as_str();
+    fn write_modifiers(&self, result: &mut String) {
+        let modifiers = [
+            (self.is_abstract, "abstract "),
+            (self.is_default, "default "),
+            (self.is_static, "static "),
+            (self.is_final, "final "),
+            (self.is_synchronized, "synchronized "),
+            (self.is_native, "native "),
+            (self.is_strictfp, "strictfp "),
+        ];
+        for (is_present, modifier) in modifiers {
+            if is_present {
+                *result += modifier;
+            }
         }
     }
 
-    fn write_visibility(&self, result: &mut String, indentation: &JavaIndentation) {
-        *result += indentation.get_current_indentation().as_str();
-        *result += &self.get_visibility().as_file_string();
+    fn get_invalid_modifier_error(&self, reason: &str) -> String {
+        get_invalid_modifiers_error(&self.name, reason)
+    }
+}
+
+fn get_invalid_modifiers_error(method_name: &str, reason: &str) -> String {
+    format!(
+        "Invalid java method \"{}\" modifiers, {}",
+        method_name, reason
+    )
+}
+
+fn check_modifiers(builder: &JavaMethodBuilder, name: &str) -> Result<(), String> {
+    if builder.is_abstract
+        && (builder.is_static
+            || builder.is_final
+            || builder.is_default
+            || builder.is_synchronized
+            || builder.is_native
+            || builder.is_strictfp
+            || JavaVisibility::Private == builder.visibility)
+    {
+        return Err(get_invalid_modifiers_error(
+            name,
+            "abstract can not be combined with private, static, final, default, synchronized, native or strictfp",
+        ));
+    }
+    if builder.is_default && (builder.is_static || JavaVisibility::Private == builder.visibility) {
+        return Err(get_invalid_modifiers_error(
+            name,
+            "default can not be combined with private or static",
+        ));
     }
+    if builder.is_native && builder.is_strictfp {
+        return Err(get_invalid_modifiers_error(
+            name,
+            "native can not be combined with strictfp",
+        ));
+    }
+
+    Ok(())
 }
 
 pub struct JavaMethodBuilder {
+    comments: Vec<JavaComment>,
     annotations: Vec<JavaAnnotationUsage>,
     visibility: JavaVisibility,
     is_static: bool,
+    is_abstract: bool,
+    is_final: bool,
+    is_default: bool,
+    is_synchronized: bool,
+    is_native: bool,
+    is_strictfp: bool,
     return_type: Option<JavaDataType>,
     name: Option<String>,
     parameters: Vec<JavaVariable>,
+    throws: Vec<JavaDataType>,
+    static_imports: Vec<String>,
 }
 
 impl JavaMethodBuilder {
     fn new_builder() -> Self {
         Self {
+            comments: vec![],
             annotations: vec![],
             visibility: JavaVisibility::Package,
             is_static: false,
+            is_abstract: false,
+            is_final: false,
+            is_default: false,
+            is_synchronized: false,
+            is_native: false,
+            is_strictfp: false,
             return_type: None,
             name: None,
             parameters: vec![],
+            throws: vec![],
+            static_imports: vec![],
         }
     }
+    /// # comments
+    /// Comments written before the method annotations, sorted from top to bottom.
+    pub fn comments(&mut self, input: Vec<JavaComment>) -> &mut Self {
+        self.comments = input;
+        self
+    }
+    /// Javadoc written right before the method annotations, it replaces any javadoc
+    /// of the previous comments.
+    pub fn javadoc(&mut self, input: JavaDoc) -> &mut Self {
+        comment::set_javadoc(&mut self.comments, input);
+        self
+    }
     pub fn annotations(&mut self, input: Vec<JavaAnnotationUsage>) -> &mut Self {
         self.annotations = input.clone();
         self
@@ -267,6 +534,32 @@ impl JavaMethodBuilder {
         self.is_static = input;
         self
     }
+    /// Abstract methods are written without body
+    pub fn is_abstract(&mut self, input: bool) -> &mut Self {
+        self.is_abstract = input;
+        self
+    }
+    pub fn is_final(&mut self, input: bool) -> &mut Self {
+        self.is_final = input;
+        self
+    }
+    /// Interface method with body, the interface builder rejects it in other structures
+    pub fn is_default(&mut self, input: bool) -> &mut Self {
+        self.is_default = input;
+        self
+    }
+    pub fn is_synchronized(&mut self, input: bool) -> &mut Self {
+        self.is_synchronized = input;
+        self
+    }
+    pub fn is_native(&mut self, input: bool) -> &mut Self {
+        self.is_native = input;
+        self
+    }
+    pub fn is_strictfp(&mut self, input: bool) -> &mut Self {
+        self.is_strictfp = input;
+        self
+    }
     pub fn return_type(&mut self, input: JavaDataType) -> &mut Self {
         self.return_type = Some(input.clone());
         self
@@ -279,20 +572,60 @@ impl JavaMethodBuilder {
         self.parameters = input.clone();
         self
     }
+    /// Exception types written in the "throws" clause
+    pub fn throws(&mut self, input: Vec<JavaDataType>) -> &mut Self {
+        self.throws = input;
+        self
+    }
+    /// Static members used by the method (i.e. "org.junit.jupiter.api.Assertions.assertEquals"),
+    /// they are imported in the file where the method is written.
+    pub fn static_imports(&mut self, input: Vec<&str>) -> &mut Self {
+        self.static_imports = input.iter().map(|route| route.to_string()).collect();
+        self
+    }
 
     // TODO: possibility to add statements and expressions here
 
     pub fn build(&mut self) -> Result<JavaMethod, String> {
+        let static_imports = self
+            .static_imports
+            .iter()
+            .map(|route| JavaImport::new_static_import(route))
+            .collect::<Result<Vec<JavaImport>, String>>()?;
+        let name = self
+            .name
+            .clone()
+            .ok_or("Missing mandatory name to build JavaMethod")?;
+        check_modifiers(self, &name)?;
+        let parameters_count = self.parameters.len();
+        if let Some(varargs) = self
+            .parameters
+            .iter()
+            .take(parameters_count.saturating_sub(1))
+            .find(|parameter| parameter.is_varargs())
+        {
+            return Err(format!(
+                "Invalid java method \"{}\", only the last parameter can be varargs, found \"{}\"",
+                name, varargs
+            ));
+        }
+
         Ok(JavaMethod {
+            comments: self.comments.clone(),
             annotations: self.annotations.clone(),
             visibility: self.visibility,
             is_static: self.is_static,
+            is_abstract: self.is_abstract,
+            is_final: self.is_final,
+            is_default: self.is_default,
+            is_synchronized: self.is_synchronized,
+            is_native: self.is_native,
+            is_strictfp: self.is_strictfp,
             return_type: self.return_type.clone(),
-            name: self
-                .name
-                .clone()
-                .ok_or("Missing mandatory name to build JavaMethod")?,
+            name,
             parameters: self.parameters.clone(),
+            throws: self.throws.clone(),
+            static_imports,
             _statements: vec![],
         })
     }
@@ -304,9 +637,14 @@ mod tests {
 
     use crate::core::testing::test_assert::assert_same_as_file;
     use crate::core::testing::test_path;
+    use crate::java::annotation_usage::JavaAnnotationUsage;
+    use crate::java::code_style_config::JavaCodeStyle;
+    use crate::java::data_type::JavaDataType;
     use crate::java::dependency::org::junit::jupiter::junit_jupiter_api::java_junit_jupiter_api_factory;
+    use crate::java::dependency::org::springframework::spring_web::java_spring_web_factory;
     use crate::java::indentation_config::JavaIndentation;
     use crate::java::method::JavaMethod;
+    use crate::java::scanner::file::java_structure_type::JavaStructureType;
     use crate::java::variable::JavaVariable;
     use crate::java::visibility::JavaVisibility;
 
@@ -329,7 +667,12 @@ mod tests {
             .expect("newMethodToGenerate is expected to be valid");
 
         let mut result = "".to_string();
-        method.write_to_string(&mut result, &JavaIndentation::default());
+        method.write_to_string(
+            &mut result,
+            &JavaIndentation::default(),
+            &JavaCodeStyle::default(),
+            JavaStructureType::Class,
+        );
 
         assert_same_as_file(&expected_file_content, &result);
     }
@@ -346,6 +689,177 @@ mod tests {
         assert!(imports.is_empty());
     }
 
+    #[test]
+    fn get_method_imports_with_static_imports() {
+        let method = JavaMethod::builder()
+            .name("newMethodWithStaticImports")
+            .static_imports(vec!["org.junit.jupiter.api.Assertions.assertEquals"])
+            .build()
+            .expect("newMethodWithStaticImports is expected to be valid");
+
+        let imports: Vec<String> = method
+            .get_imports()
+            .iter()
+            .map(|import| import.to_string())
+            .collect();
+
+        assert_eq!(
+            vec!["import static org.junit.jupiter.api.Assertions.assertEquals;"],
+            imports
+        );
+    }
+
+    #[test]
+    fn build_method_with_invalid_static_import_fails() {
+        let result = JavaMethod::builder()
+            .name("newMethodWithInvalidStaticImport")
+            .static_imports(vec!["assertEquals"])
+            .build();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn generate_java_method_with_throws_and_varargs() {
+        let expected_file_content = get_test_file("ExpectedMethodWithThrowsAndVarargs");
+        let id = JavaVariable::builder()
+            .annotations(vec![JavaAnnotationUsage::builder()
+                .import("org.springframework.web.bind.annotation.PathVariable")
+                .build()
+                .expect("PathVariable is expected to be valid")])
+            .is_final(true)
+            .data_type(JavaDataType::int())
+            .name("id")
+            .build()
+            .expect("id is expected to be valid");
+        let names = JavaVariable::builder()
+            .data_type(JavaDataType::string())
+            .is_varargs(true)
+            .name("names")
+            .build()
+            .expect("names is expected to be valid");
+        let method = JavaMethod::builder()
+            .visibility(JavaVisibility::Public)
+            .return_type(JavaDataType::string())
+            .name("find")
+            .parameters(vec![id, names])
+            .throws(vec![
+                JavaDataType::from_simple_name("IOException").expect("JDK type"),
+                JavaDataType::from_simple_name("IllegalStateException").expect("JDK type"),
+            ])
+            .build()
+            .expect("find is expected to be valid");
+
+        let mut result = "".to_string();
+        method.write_to_string(
+            &mut result,
+            &JavaIndentation::builder().current_level(0).build(),
+            &JavaCodeStyle::default(),
+            JavaStructureType::Class,
+        );
+        let imports: Vec<String> = method
+            .get_imports()
+            .iter()
+            .map(|import| import.get_route())
+            .collect();
+
+        assert_same_as_file(&expected_file_content, &result);
+        assert_eq!(
+            vec![
+                "org.springframework.web.bind.annotation.PathVariable",
+                "java.io.IOException"
+            ],
+            imports
+        );
+    }
+
+    #[test]
+    fn generate_java_method_with_annotation_parameters() {
+        let expected_file_content = get_test_file("ExpectedMethodWithAnnotationParameters");
+        let id = JavaVariable::builder()
+            .annotations(vec![
+                java_spring_web_factory::create_path_variable_annotation_usage("id"),
+            ])
+            .is_final(true)
+            .data_type(JavaDataType::int())
+            .name("id")
+            .build()
+            .expect("id is expected to be valid");
+        let page = JavaVariable::builder()
+            .annotations(vec![
+                java_spring_web_factory::create_request_param_annotation_usage("page", false),
+            ])
+            .data_type(JavaDataType::from_simple_name("Integer").expect("JDK type"))
+            .name("page")
+            .build()
+            .expect("page is expected to be valid");
+        let method = JavaMethod::builder()
+            .visibility(JavaVisibility::Public)
+            .return_type(JavaDataType::string())
+            .name("find")
+            .parameters(vec![id, page])
+            .build()
+            .expect("find is expected to be valid");
+
+        let mut result = "".to_string();
+        method.write_to_string(
+            &mut result,
+            &JavaIndentation::builder().current_level(0).build(),
+            &JavaCodeStyle::default(),
+            JavaStructureType::Class,
+        );
+
+        assert_same_as_file(&expected_file_content, &result);
+    }
+
+    #[test]
+    fn build_method_with_varargs_before_last_parameter_fails() {
+        let names = JavaVariable::builder()
+            .data_type(JavaDataType::string())
+            .is_varargs(true)
+            .name("names")
+            .build()
+            .expect("names is expected to be valid");
+
+        let result = JavaMethod::builder()
+            .name("find")
+            .parameters(vec![names, JavaVariable::new_final_int("id")])
+            .build();
+
+        assert!(result.is_err_and(|err| err.contains("only the last parameter can be varargs")));
+    }
+
+    #[test]
+    fn build_abstract_final_method_fails() {
+        let result = JavaMethod::builder()
+            .is_abstract(true)
+            .is_final(true)
+            .name("abstractFinalMethod")
+            .build();
+
+        assert!(result.is_err_and(|err| err.contains("abstract can not be combined")));
+    }
+
+    #[test]
+    fn write_abstract_method_without_body() {
+        let method = JavaMethod::builder()
+            .visibility(JavaVisibility::Protected)
+            .is_abstract(true)
+            .name("abstractMethod")
+            .build()
+            .expect("abstractMethod is expected to be valid");
+
+        let mut result = "".to_string();
+        method.write_to_string(
+            &mut result,
+            &JavaIndentation::default(),
+            &JavaCodeStyle::default(),
+            JavaStructureType::Class,
+        );
+
+        assert_eq!("protected abstract void abstractMethod();\n", result);
+    }
+
     fn get_test_file(structure_name: &str) -> PathBuf {
         get_test_folder().join(format!("{}.java", structure_name).as_str())
     }
@@ -1,16 +1,25 @@
 pub mod annotation_usage;
 pub mod class;
+pub mod code_style_config;
+pub mod comment;
 pub mod data_type;
+pub mod enumeration;
 pub mod expression;
 pub mod field;
 pub mod import;
+pub mod import_layout_config;
 pub mod indentation_config;
 pub mod interface;
+pub mod javadoc;
 pub mod method;
+pub mod project_scan;
+pub mod supertype;
+pub mod translation;
+pub mod usage;
 pub mod variable;
 pub mod visibility;
 
-mod dependency;
+pub(crate) mod dependency;
 mod parser;
 pub(crate) mod recipe;
 pub(crate) mod scanner;
@@ -10,12 +10,15 @@ use crate::core::file_system::path_helper::try_to_absolute_path;
 use crate::core::observability::logger;
 use crate::core::parser::parser_node_trait::ParserNode;
 use crate::java::parser::java_node_type::JavaNodeType;
+use crate::java::parser::java_sealed_keyword;
 
 #[derive(Debug, Clone)]
 pub(crate) struct JavaNode {
     file_path: PathBuf,
     start_byte: usize,
     end_byte: usize,
+    start_line: usize,
+    start_column: usize,
     children: Vec<JavaNode>,
     node_type: Option<JavaNodeType>,
 }
@@ -25,6 +28,17 @@ impl JavaNode {
         &self.children
     }
 
+    /// Line where the node starts, the first line is 1
+    pub(crate) fn get_start_line(&self) -> usize {
+        self.start_line
+    }
+
+    /// Column (in bytes) where the node starts, the first column is 1
+    pub(crate) fn get_start_column(&self) -> usize {
+        self.start_column
+    }
+
+    /// Imported route, ending with ".*" for on-demand imports (i.e. "org.test.*")
     pub(crate) fn get_import_decl_content(
         import_decl_node: &JavaNode,
         java_file_cache: &FileCache,
@@ -33,18 +47,35 @@ impl JavaNode {
             return Err("Java import declaration node required".to_string());
         }
 
-        for children_level_one in import_decl_node.get_children() {
+        let children = import_decl_node.get_children();
+        let is_wildcard = children
+            .iter()
+            .any(|child| Some(JavaNodeType::Asterisk) == child.get_node_type());
+        for children_level_one in children {
             if Some(JavaNodeType::ScopedIdentifier) == children_level_one.get_node_type() {
-                return Ok(children_level_one.get_content_from_cache(java_file_cache));
+                let route = children_level_one.get_content_from_cache(java_file_cache);
+                if is_wildcard {
+                    return Ok(format!("{}.*", route));
+                }
+                return Ok(route);
             }
         }
 
         Err("Import scoped identifier not found".to_string())
     }
 
+    /// "import static org.junit.Assert.assertEquals;"
+    pub(crate) fn is_static_import_decl(import_decl_node: &JavaNode) -> bool {
+        import_decl_node
+            .get_children()
+            .iter()
+            .any(|child| Some(JavaNodeType::Static) == child.get_node_type())
+    }
+
     pub(crate) fn is_data_type_identifier(&self) -> bool {
         if let Some(node_type) = self.get_node_type() {
             return node_type.is_data_type_id_identifier()
+                || JavaNodeType::GenericType == node_type
                 || JavaNodeType::IntegralType == node_type
                 || JavaNodeType::FloatingPointType == node_type
                 || JavaNodeType::Boolean == node_type;
@@ -61,7 +92,10 @@ impl ParserNode<JavaNodeType> for JavaNode {
 
         if let Ok(file_content) = fs::read_to_string(file_path_str) {
             let mut parser = build_parser();
-            let parsed = parser.parse(file_content, None);
+            let parsed = parser.parse(
+                java_sealed_keyword::mask_sealed_keywords(&file_content),
+                None,
+            );
             let result: Result<Self, String> = if let Some(parsed_tree) = parsed {
                 let node = JavaNode::new_internal(parsed_tree.root_node(), file_path);
                 Ok(node)
@@ -117,6 +151,8 @@ impl JavaNode {
             file_path: PathBuf::from(file_path),
             start_byte: node.start_byte(),
             end_byte: node.end_byte(),
+            start_line: node.start_position().row + 1,
+            start_column: node.start_position().column + 1,
             children,
             node_type: match JavaNodeType::from_str(node.kind()) {
                 Ok(v) => Some(v),
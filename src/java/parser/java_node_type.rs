@@ -13,6 +13,8 @@ pub(crate) enum JavaNodeType {
     ImportDecl,
     // Ex: "import java.util.List;"
     Import,
+    Asterisk,
+    // Ex: "*" in "import org.test.*;"
     Id,
     Modifiers,
 
@@ -50,7 +52,8 @@ pub(crate) enum JavaNodeType {
     InstanceofExpression,
     // Ex: "null instanceof Integer"
     Instanceof,
-    FieldDeclaration, // Ex: "volatile int testClassIntVarName = 0;"
+    FieldDeclaration,    // Ex: "volatile int testClassIntVarName = 0;"
+    ConstantDeclaration, // Ex: "int MAX = 10;" in an interface
 
     // Exceptions
     Throws,
@@ -102,6 +105,7 @@ pub(crate) enum JavaNodeType {
     ConstructorDeclaration,
     // Ex: "private TestClass() { super(); }"
     ConstructorBody,
+    StaticInitializer,
     // Ex: "{ super(); }"
     Super,
     ExplicitConstructorInvocation,
@@ -119,10 +123,15 @@ pub(crate) enum JavaNodeType {
     EnumBody,
     // Ex: "{ SOMETHING, SIMILAR }"
     EnumConstant, // Ex: "SOMETHING"
+    EnumBodyDeclarations,
+    RecordDeclaration,
+    Record,
 
     // Interface
     InterfaceDeclaration,
     SuperInterfaces,
+    ExtendsInterfaces,
+    // Ex: "extends TestInterface" in an interface declaration
     // Ex: "implements TestInterface"
     InterfaceTypeList,
     TypeList,
@@ -143,7 +152,8 @@ pub(crate) enum JavaNodeType {
     AnnotationArgumentList,
     ElementValuePair,
     // Ex: "(RetentionPolicy.RUNTIME)"
-    AnnotationTypeBody, // Ex: "{}" in "public @interface Init {}"
+    AnnotationTypeBody,               // Ex: "{}" in "public @interface Init {}"
+    AnnotationTypeElementDeclaration, // Ex: "String value();"
 
     // Method
     MethodDecl,
@@ -165,6 +175,8 @@ pub(crate) enum JavaNodeType {
     ThreeDots,
     // ...
     Synchronized,
+    Native,
+    Strictfp,
     TypeParameters,
     // Ex: "<T>"
     TypeParameter, // Ex: "T"
@@ -367,6 +379,7 @@ impl FromStr for JavaNodeType {
             "scoped_identifier" => Ok(JavaNodeType::ScopedIdentifier),
             "import_declaration" => Ok(JavaNodeType::ImportDecl),
             "import" => Ok(JavaNodeType::Import),
+            "asterisk" => Ok(JavaNodeType::Asterisk),
             "identifier" => Ok(JavaNodeType::Id),
             "modifiers" => Ok(JavaNodeType::Modifiers),
 
@@ -390,6 +403,7 @@ impl FromStr for JavaNodeType {
             "instanceof_expression" => Ok(JavaNodeType::InstanceofExpression),
             "instanceof" => Ok(JavaNodeType::Instanceof),
             "field_declaration" => Ok(JavaNodeType::FieldDeclaration),
+            "constant_declaration" => Ok(JavaNodeType::ConstantDeclaration),
 
             // Exceptions
             "throws" => Ok(JavaNodeType::Throws),
@@ -426,6 +440,7 @@ impl FromStr for JavaNodeType {
             "implements" => Ok(JavaNodeType::Implements),
             "constructor_declaration" => Ok(JavaNodeType::ConstructorDeclaration),
             "constructor_body" => Ok(JavaNodeType::ConstructorBody),
+            "static_initializer" => Ok(JavaNodeType::StaticInitializer),
             "super" => Ok(JavaNodeType::Super),
             "explicit_constructor_invocation" => Ok(JavaNodeType::ExplicitConstructorInvocation),
             "class_literal" => Ok(JavaNodeType::ClassLiteral),
@@ -437,10 +452,14 @@ impl FromStr for JavaNodeType {
             "enum" => Ok(JavaNodeType::Enum),
             "enum_body" => Ok(JavaNodeType::EnumBody),
             "enum_constant" => Ok(JavaNodeType::EnumConstant),
+            "enum_body_declarations" => Ok(JavaNodeType::EnumBodyDeclarations),
+            "record_declaration" => Ok(JavaNodeType::RecordDeclaration),
+            "record" => Ok(JavaNodeType::Record),
 
             // Interface
             "interface_declaration" => Ok(JavaNodeType::InterfaceDeclaration),
             "super_interfaces" => Ok(JavaNodeType::SuperInterfaces),
+            "extends_interfaces" => Ok(JavaNodeType::ExtendsInterfaces),
             "interface_type_list" => Ok(JavaNodeType::InterfaceTypeList),
             "type_list" => Ok(JavaNodeType::TypeList),
             "interface" => Ok(JavaNodeType::Interface),
@@ -455,6 +474,9 @@ impl FromStr for JavaNodeType {
             "annotation_argument_list" => Ok(JavaNodeType::AnnotationArgumentList),
             "element_value_pair" => Ok(JavaNodeType::ElementValuePair),
             "annotation_type_body" => Ok(JavaNodeType::AnnotationTypeBody),
+            "annotation_type_element_declaration" => {
+                Ok(JavaNodeType::AnnotationTypeElementDeclaration)
+            }
 
             // Method
             "method_declaration" => Ok(JavaNodeType::MethodDecl),
@@ -469,6 +491,8 @@ impl FromStr for JavaNodeType {
             "spread_parameter" => Ok(JavaNodeType::SpreadParameter),
             "..." => Ok(JavaNodeType::ThreeDots),
             "synchronized" => Ok(JavaNodeType::Synchronized),
+            "native" => Ok(JavaNodeType::Native),
+            "strictfp" => Ok(JavaNodeType::Strictfp),
             "type_parameters" => Ok(JavaNodeType::TypeParameters),
             "type_parameter" => Ok(JavaNodeType::TypeParameter),
 
@@ -0,0 +1,260 @@
+/// # JavaSealedKeyword
+/// "sealed" or "non-sealed" modifier, or "permits" clause, of a class or interface declaration.
+/// The tree-sitter java grammar lexes these contextual keywords as identifiers, so they are
+/// found in the source text, and masked before parsing to get a valid declaration node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct JavaSealedKeyword {
+    kind: JavaSealedKeywordKind,
+    start_byte: usize,
+    end_byte: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum JavaSealedKeywordKind {
+    Sealed,
+    NonSealed,
+    /// Type names as written, simple (i.e. "Circle") or qualified (i.e. "org.test.Circle")
+    Permits(Vec<String>),
+}
+
+/// Masked modifiers written before the declaration node are searched in this many bytes,
+/// the whole file content before each declaration would be read otherwise
+pub(crate) const LEADING_KEYWORDS_MAX_BYTES: usize = 256;
+
+const DECLARATION_PREFIXES: [&str; 11] = [
+    "public",
+    "protected",
+    "private",
+    "abstract",
+    "static",
+    "final",
+    "strictfp",
+    "sealed",
+    "class",
+    "interface",
+    "@",
+];
+
+impl JavaSealedKeyword {
+    pub(crate) fn get_kind(&self) -> &JavaSealedKeywordKind {
+        &self.kind
+    }
+}
+
+/// Keywords found in the content, comments and literals are skipped
+pub(crate) fn find_sealed_keywords(content: &str) -> Vec<JavaSealedKeyword> {
+    if !content.contains("sealed") && !content.contains("permits") {
+        return Vec::new();
+    }
+
+    let tokens = tokenize(content);
+    let text = |index: usize| -> &str {
+        tokens
+            .get(index)
+            .map(|(start, end)| &content[*start..*end])
+            .unwrap_or("")
+    };
+    let is_non_sealed = |index: usize| -> bool {
+        "non" == text(index)
+            && "-" == text(index + 1)
+            && "sealed" == text(index + 2)
+            && tokens[index].1 == tokens[index + 1].0
+            && tokens[index + 1].1 == tokens[index + 2].0
+    };
+    let precedes_declaration =
+        |index: usize| DECLARATION_PREFIXES.contains(&text(index)) || is_non_sealed(index);
+
+    let mut result = Vec::new();
+    let mut in_declaration_header = false;
+    let mut index = 0;
+    while index < tokens.len() {
+        match text(index) {
+            "class" | "interface" if index == 0 || "." != text(index - 1) => {
+                in_declaration_header = true;
+            }
+            "{" | ";" => in_declaration_header = false,
+            "sealed" if precedes_declaration(index + 1) => {
+                result.push(JavaSealedKeyword {
+                    kind: JavaSealedKeywordKind::Sealed,
+                    start_byte: tokens[index].0,
+                    end_byte: tokens[index].1,
+                });
+            }
+            "non" if is_non_sealed(index) && precedes_declaration(index + 3) => {
+                result.push(JavaSealedKeyword {
+                    kind: JavaSealedKeywordKind::NonSealed,
+                    start_byte: tokens[index].0,
+                    end_byte: tokens[index + 2].1,
+                });
+                index += 2;
+            }
+            "permits" if in_declaration_header => {
+                let start_byte = tokens[index].0;
+                let mut names = vec![String::new()];
+                while index + 1 < tokens.len() && "{" != text(index + 1) {
+                    index += 1;
+                    match text(index) {
+                        "," => names.push(String::new()),
+                        name_part => {
+                            if let Some(name) = names.last_mut() {
+                                name.push_str(name_part);
+                            }
+                        }
+                    }
+                }
+                result.push(JavaSealedKeyword {
+                    kind: JavaSealedKeywordKind::Permits(names),
+                    start_byte,
+                    end_byte: tokens[index].1,
+                });
+            }
+            _ => {}
+        }
+        index += 1;
+    }
+
+    result
+}
+
+/// Keywords of the declaration with the header between "start_byte" and "end_byte" (the body
+/// start) of "content". Masked modifiers written first are not part of the declaration node,
+/// so they are also searched before "start_byte", "content" only needs to include a few of
+/// the bytes before the node (see [LEADING_KEYWORDS_MAX_BYTES]).
+pub(crate) fn find_declaration_keywords(
+    content: &str,
+    start_byte: usize,
+    end_byte: usize,
+) -> Vec<JavaSealedKeyword> {
+    let mut header_start_byte = start_byte;
+    loop {
+        let previous_content = content[..header_start_byte].trim_end();
+        let keyword_opt = ["non-sealed", "sealed"]
+            .into_iter()
+            .find(|keyword| previous_content.ends_with(keyword));
+        match keyword_opt {
+            Some(keyword)
+                if !previous_content[..previous_content.len() - keyword.len()]
+                    .ends_with(|c: char| c.is_alphanumeric() || matches!(c, '_' | '$' | '-')) =>
+            {
+                header_start_byte = previous_content.len() - keyword.len();
+            }
+            _ => break,
+        }
+    }
+
+    find_sealed_keywords(&content[header_start_byte..end_byte])
+}
+
+/// Same content replacing the sealed keywords with spaces, so node bytes and lines do not change
+pub(crate) fn mask_sealed_keywords(content: &str) -> String {
+    let keywords = find_sealed_keywords(content);
+    if keywords.is_empty() {
+        return content.to_string();
+    }
+
+    let mut bytes = content.as_bytes().to_vec();
+    for keyword in keywords {
+        for byte in &mut bytes[keyword.start_byte..keyword.end_byte] {
+            if b'\n' != *byte && b'\r' != *byte {
+                *byte = b' ';
+            }
+        }
+    }
+
+    String::from_utf8(bytes).expect("Masked java content must be valid UTF-8")
+}
+
+/// Byte ranges of identifiers and symbols, without whitespaces, comments and literals
+fn tokenize(content: &str) -> Vec<(usize, usize)> {
+    let bytes = content.as_bytes();
+    let mut tokens = Vec::new();
+    let mut index = 0;
+    while index < bytes.len() {
+        let byte = bytes[index];
+        if byte.is_ascii_whitespace() {
+            index += 1;
+        } else if content[index..].starts_with("//") {
+            index = find_from(content, index, "\n").unwrap_or(bytes.len());
+        } else if content[index..].starts_with("/*") {
+            index = find_from(content, index + 2, "*/").map_or(bytes.len(), |end| end + 2);
+        } else if content[index..].starts_with("\"\"\"") {
+            index = find_from(content, index + 3, "\"\"\"").map_or(bytes.len(), |end| end + 3);
+        } else if b'"' == byte || b'\'' == byte {
+            index = skip_literal(bytes, index);
+        } else if is_identifier_byte(byte) {
+            let start = index;
+            while index < bytes.len() && is_identifier_byte(bytes[index]) {
+                index += 1;
+            }
+            tokens.push((start, index));
+        } else {
+            let char_len = content[index..].chars().next().map_or(1, char::len_utf8);
+            tokens.push((index, index + char_len));
+            index += char_len;
+        }
+    }
+
+    tokens
+}
+
+fn find_from(content: &str, start: usize, pattern: &str) -> Option<usize> {
+    content[start..]
+        .find(pattern)
+        .map(|position| start + position)
+}
+
+/// Index after the string or char literal starting at "start"
+fn skip_literal(bytes: &[u8], start: usize) -> usize {
+    let delimiter = bytes[start];
+    let mut index = start + 1;
+    while index < bytes.len() && delimiter != bytes[index] && b'\n' != bytes[index] {
+        if b'\\' == bytes[index] {
+            index += 1;
+        }
+        index += 1;
+    }
+    (index + 1).min(bytes.len())
+}
+
+/// Non ASCII bytes are part of identifiers, java allows unicode letters
+fn is_identifier_byte(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b'_' == byte || b'$' == byte || !byte.is_ascii()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::java::parser::java_sealed_keyword::{
+        find_sealed_keywords, mask_sealed_keywords, JavaSealedKeywordKind,
+    };
+
+    #[test]
+    fn find_sealed_keywords_in_declarations() {
+        let content = "public abstract sealed class Shape permits Circle, org.test.Square {\n    String sealed = \"sealed class\";\n}\nnon-sealed class Square extends Shape {}\n";
+
+        let kinds: Vec<JavaSealedKeywordKind> = find_sealed_keywords(content)
+            .iter()
+            .map(|keyword| keyword.get_kind().clone())
+            .collect();
+
+        assert_eq!(
+            vec![
+                JavaSealedKeywordKind::Sealed,
+                JavaSealedKeywordKind::Permits(vec![
+                    "Circle".to_string(),
+                    "org.test.Square".to_string()
+                ]),
+                JavaSealedKeywordKind::NonSealed,
+            ],
+            kinds
+        );
+    }
+
+    #[test]
+    fn mask_sealed_keywords_keeps_bytes() {
+        let content = "sealed interface Polygon permits Square {\n}\n";
+
+        let masked = mask_sealed_keywords(content);
+
+        assert_eq!("       interface Polygon                {\n}\n", masked);
+    }
+}
@@ -1,2 +1,3 @@
 pub mod java_node;
 pub mod java_node_type;
+pub(crate) mod java_sealed_keyword;
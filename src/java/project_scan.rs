@@ -0,0 +1,91 @@
+use std::path::{Path, PathBuf};
+
+use crate::java::scanner::package::java_dependency_scanner;
+
+/// # JavaScanSummary
+/// Java files re-indexed by a project scan.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct JavaScanSummary {
+    added: Vec<PathBuf>,
+    changed: Vec<PathBuf>,
+    removed: Vec<PathBuf>,
+    unchanged: usize,
+}
+
+/// # JavaScanProgress
+/// Progress of a project scan, reported every time a java file has been processed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JavaScanProgress {
+    scanned_files: usize,
+    total_files: usize,
+}
+
+/// # scan_project
+/// Index the java files of a project. Only the files added, changed or removed since the
+/// previous scan are indexed again, "on_progress" is called after each file in path order.
+/// It fails when the directory does not exist or the scan can not be stored.
+pub fn scan_project(
+    project_dir: &Path,
+    on_progress: &dyn Fn(&JavaScanProgress),
+) -> Result<JavaScanSummary, String> {
+    java_dependency_scanner::recursive_scan_dir_with_progress(project_dir, on_progress)
+}
+
+impl JavaScanSummary {
+    // Public methods
+    pub fn get_added(&self) -> &Vec<PathBuf> {
+        &self.added
+    }
+
+    pub fn get_changed(&self) -> &Vec<PathBuf> {
+        &self.changed
+    }
+
+    pub fn get_removed(&self) -> &Vec<PathBuf> {
+        &self.removed
+    }
+
+    /// # get_unchanged
+    /// Number of java files found with the same content than in the previous scan.
+    pub fn get_unchanged(&self) -> usize {
+        self.unchanged
+    }
+}
+
+impl JavaScanSummary {
+    // Crate or private methods
+    pub(crate) fn new(
+        added: Vec<PathBuf>,
+        changed: Vec<PathBuf>,
+        removed: Vec<PathBuf>,
+        unchanged: usize,
+    ) -> Self {
+        Self {
+            added,
+            changed,
+            removed,
+            unchanged,
+        }
+    }
+}
+
+impl JavaScanProgress {
+    // Public methods
+    pub fn get_scanned_files(&self) -> usize {
+        self.scanned_files
+    }
+
+    pub fn get_total_files(&self) -> usize {
+        self.total_files
+    }
+}
+
+impl JavaScanProgress {
+    // Crate or private methods
+    pub(crate) fn new(scanned_files: usize, total_files: usize) -> Self {
+        Self {
+            scanned_files,
+            total_files,
+        }
+    }
+}
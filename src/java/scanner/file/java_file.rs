@@ -7,12 +7,15 @@ use crate::core::file_system::path_helper;
 use crate::core::file_system::path_helper::try_to_absolute_path;
 use crate::core::observability::logger;
 use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::code_style_config;
+use crate::java::comment;
 use crate::java::import::JavaImport;
-use crate::java::indentation_config::JavaIndentation;
+use crate::java::import_layout_config::JavaImportLayout;
 use crate::java::method::JavaMethod;
 use crate::java::parser::java_node::JavaNode;
 use crate::java::parser::java_node_type::JavaNodeType;
 use crate::java::scanner::file::java_file_imports::JavaFileImports;
+use crate::java::scanner::file::java_import_organizer;
 use crate::java::scanner::file::java_structure::JavaStructure;
 use crate::java::scanner::file::java_structure_type::JavaStructureType;
 use crate::java::scanner::package::{java_dependency_scanner, java_package_scanner};
@@ -44,6 +47,14 @@ impl JavaFile {
         self.insert_method_internal(method)
     }
 
+    /// # organize_imports
+    /// Remove unused and duplicated imports and sort the rest following the layout.
+    pub(crate) fn organize_imports(&self, layout: &JavaImportLayout) -> Result<JavaFile, String> {
+        java_import_organizer::organize_imports(self.get_file_path(), layout)?;
+
+        JavaFile::from_user_input_path(self.get_file_path())
+    }
+
     pub(crate) fn get_file_path(&self) -> &PathBuf {
         &self.file
     }
@@ -70,7 +81,14 @@ impl JavaFile {
     ) -> Result<Result<JavaFile, String>, String> {
         validate_output_file(file)?;
 
-        let imports = input_structure.get_imports_sorted_asc();
+        // Recursive types (i.e. a field of its own type) do not import themselves
+        let self_route = java_package_scanner::get_package_route_from_file(file)
+            .map(|package| format!("{}.{}", package, input_structure.get_name()));
+        let imports: Vec<JavaImport> = input_structure
+            .get_imports_sorted_asc()
+            .into_iter()
+            .filter(|import| Some(import.get_route()) != self_route)
+            .collect();
         Self::write_to_file_internal(file, &imports, input_structure)?;
 
         Ok(JavaFile::from_user_input_path(file))
@@ -87,24 +105,23 @@ impl JavaFile {
         let file_cache = FileCache::from(java_file_path);
         let root_java_node = JavaNode::from_path(java_file_path)?;
 
-        let mut imports = JavaFileImports::new();
+        let mut imports = JavaFileImports::from_file(java_file_path);
         let mut structure_opt: Option<JavaStructure> = None;
         let java_file_import = JavaImport::new_explicit_import_from_file(java_file_path)?;
 
-        for child in root_java_node.get_children() {
+        let children = root_java_node.get_children();
+        for (index, child) in children.iter().enumerate() {
             if let Some(node_type) = child.get_node_type() {
                 if JavaNodeType::ImportDecl == node_type {
-                    match JavaNode::get_import_decl_content(child, &file_cache) {
-                        Ok(import_route) => imports.insert(
-                            JavaImport::from_file_import_decl(import_route, &file_cache),
-                            child.get_end_byte(),
-                        ),
+                    match Self::get_import(child, &file_cache) {
+                        Ok(import) => imports.insert(import, child.get_end_byte()),
                         Err(err) => log_invalid_import(java_file_path, err),
                     };
                 } else if JavaNodeType::PackageDecl == node_type {
                     Self::check_package_def(&java_file_import, child, &file_cache);
                 } else if node_type.is_structure() {
-                    let structure = JavaStructure::new(child, &imports, &file_cache)?;
+                    let comments = comment::get_leading_comments(children, index, &file_cache);
+                    let structure = JavaStructure::new(child, comments, &imports, &file_cache)?;
                     structure_opt = Some(structure);
                 }
             }
@@ -125,6 +142,15 @@ impl JavaFile {
         })
     }
 
+    fn get_import(import_decl: &JavaNode, file_cache: &FileCache) -> Result<JavaImport, String> {
+        let import_route = JavaNode::get_import_decl_content(import_decl, file_cache)?;
+        if JavaNode::is_static_import_decl(import_decl) {
+            return JavaImport::new_static_import(&import_route);
+        }
+
+        Ok(JavaImport::from_file_import_decl(import_route, file_cache))
+    }
+
     fn log_java_file_package_mismatch_if_needed(
         java_file_path: &&Path,
         java_file_import: JavaImport,
@@ -213,6 +239,8 @@ impl JavaFile {
 
     fn insert_method_internal(&mut self, method: &JavaMethod) -> Result<JavaFile, String> {
         // TODO: validate if java_file changed before inserting method (still exist?)
+        let structure = self.get_structure();
+        method.check_structure(structure.get_type(), structure.is_abstract())?;
         let mut to_overwrite = FileOverwriting::from_path(self.get_file_path())?;
         let mut byte_to_insert_first_import_opt = None;
         if self.get_file_imports().is_empty() {
@@ -223,19 +251,25 @@ impl JavaFile {
                 }
             };
         }
+        let style = code_style_config::get_code_style(self.get_file_path());
         self.imports.add_missing_imports(
             &mut to_overwrite,
             method.get_imports(),
             byte_to_insert_first_import_opt,
+            style.get_line_ending(),
         )?;
 
-        let mut method_str = "\n".to_string();
-        let mut initial_method_indentation = JavaIndentation::default();
-        initial_method_indentation.increase_level();
-        method.write_to_string(&mut method_str, &initial_method_indentation);
-        to_overwrite.insert_content_with_previous_newline_at(
+        let mut method_str = "\n\n".to_string();
+        let initial_method_indentation = style.get_indentation_at(1);
+        method.write_to_string(
+            &mut method_str,
+            &initial_method_indentation,
+            &style,
+            self.get_structure().get_type(),
+        );
+        to_overwrite.insert_content_at(
             self.get_structure().get_start_byte(),
-            &method_str,
+            &style.apply_line_ending(&method_str),
         )?;
 
         to_overwrite.write_all()?;
@@ -250,16 +284,32 @@ impl JavaFile {
     /// the name of the structure.
     fn write_to_file_internal(
         file_path: &Path,
-        java_file_imports: &Vec<JavaImport>,
+        java_file_imports: &[JavaImport],
         structure: &JavaStructure,
     ) -> Result<(), String> {
+        let style = code_style_config::get_code_style(file_path);
         let mut result = write_package(file_path);
-        result +=
-            JavaFileImports::get_file_string_with_trailing_newline(java_file_imports).as_str();
-        result += structure.get_skeleton_without_imports().as_str();
-        structure.write_body(&mut result);
+        match style.get_import_layout() {
+            Some(layout) => {
+                let imports_block =
+                    java_import_organizer::get_imports_block(file_path, java_file_imports, layout);
+                if !imports_block.is_empty() {
+                    result += imports_block.as_str();
+                    result += "\n\n";
+                }
+            }
+            None => {
+                result += JavaFileImports::get_file_string_with_trailing_newline(java_file_imports)
+                    .as_str()
+            }
+        }
+        result += structure.get_skeleton_without_imports(&style).as_str();
+        structure.write_body(&mut result, &style);
 
-        file_editor::create_or_replace_file_with_bytes(file_path, result.as_bytes())
+        file_editor::create_or_replace_file_with_bytes(
+            file_path,
+            style.apply_line_ending(&result).as_bytes(),
+        )
     }
 
     fn get_file_imports(&self) -> &JavaFileImports {
@@ -333,8 +383,10 @@ fn write_package(file_path: &Path) -> String {
 mod tests {
     use std::path::PathBuf;
 
+    use crate::core::file_system::file_reader::read_to_string;
     use crate::core::testing::test_assert::{assert_fail, assert_same_file};
     use crate::core::testing::test_path::get_java_project_test_folder;
+    use crate::java::import_layout_config::JavaImportLayout;
     use crate::java::scanner::file::java_file::JavaFile;
     use crate::java::scanner::file::java_structure_type::JavaStructureType;
 
@@ -383,6 +435,43 @@ mod tests {
         assert_eq!(JavaStructureType::Enum, structure.get_type());
     }
 
+    #[test]
+    fn scan_static_wildcard_and_same_package_imports() {
+        let file_path = get_test_folder().join("JavaFileImportResolution.java");
+
+        let java_file = JavaFile::from_user_input_path(&file_path).expect("Valid file scan");
+
+        let imports = java_file.get_file_imports();
+        assert_eq!(4, imports.count());
+        let field_routes: Vec<String> = java_file
+            .get_structure()
+            .get_fields()
+            .iter()
+            .flat_map(|field| field.get_imports())
+            .map(|import| import.get_route())
+            .collect();
+        assert_eq!(
+            vec![
+                "java.util.UUID",
+                "org.test.SuperClass",
+                "org.test.other.OtherPackageType"
+            ],
+            field_routes
+        );
+        let assert_equals = imports
+            .get_static_import("assertEquals")
+            .expect("Static member import expected");
+        assert_eq!(
+            "import static org.junit.jupiter.api.Assertions.assertEquals;",
+            assert_equals.to_string()
+        );
+        let equals = imports
+            .get_static_import("equals")
+            .expect("On-demand static import expected");
+        assert_eq!("import static java.util.Objects.*;", equals.to_string());
+        assert!(imports.get_static_import("assertTrue").is_err());
+    }
+
     #[test]
     fn scan_invalid() {
         let invalid_java_file = get_test_folder().join("Invalid.java");
@@ -430,6 +519,60 @@ mod tests {
         }
     }
 
+    #[test]
+    fn organize_imports_with_default_layout() {
+        let initial_file_path = get_test_folder().join("OrganizeImports.java");
+        let output_folder = get_test_folder().join("organize_imports_test");
+        let expected_file_content = get_expected_test_folder().join("ExpectedOrganizeImports.java");
+        let java_file = JavaFile::from_user_input_path(&initial_file_path)
+            .expect("Java file scan must succeed")
+            .copy_to_output_folder(&output_folder)
+            .expect("Java file copy must succeed");
+
+        let organized_file = java_file
+            .organize_imports(&JavaImportLayout::builder().build())
+            .expect("Imports must be organized");
+
+        assert_same_file(&expected_file_content, organized_file.get_file_path());
+    }
+
+    #[test]
+    fn organize_imports_collapses_and_keeps_line_endings() {
+        let initial_file_path = get_test_folder().join("OrganizeImportsCrlf.java");
+        let output_folder = get_test_folder().join("organize_imports_test");
+        let java_file = JavaFile::from_user_input_path(&initial_file_path)
+            .expect("Java file scan must succeed")
+            .copy_to_output_folder(&output_folder)
+            .expect("Java file copy must succeed");
+        let layout = JavaImportLayout::builder().wildcard_threshold(3).build();
+
+        let organized_file = java_file
+            .organize_imports(&layout)
+            .expect("Imports must be organized");
+
+        let content = read_to_string(organized_file.get_file_path());
+        assert!(content.contains(";\r\n\r\nimport java.util.*;\r\n\r\npublic class"));
+        assert!(!content.replace("\r\n", "").contains('\n'));
+    }
+
+    #[test]
+    fn organize_imports_keeps_types_hidden_by_package_types() {
+        let initial_file_path = get_test_folder().join("OrganizeImportsPackageType.java");
+        let output_folder = get_test_folder().join("organize_imports_test");
+        let java_file = JavaFile::from_user_input_path(&initial_file_path)
+            .expect("Java file scan must succeed")
+            .copy_to_output_folder(&output_folder)
+            .expect("Java file copy must succeed");
+        let layout = JavaImportLayout::builder().wildcard_threshold(3).build();
+
+        let organized_file = java_file
+            .organize_imports(&layout)
+            .expect("Imports must be organized");
+
+        let content = read_to_string(organized_file.get_file_path());
+        assert!(content.contains("\nimport java.util.*;\nimport java.util.Set;\n"));
+    }
+
     fn get_expected_test_folder() -> PathBuf {
         get_test_folder().join("expected")
     }
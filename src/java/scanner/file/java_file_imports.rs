@@ -1,4 +1,5 @@
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 use crate::core::file_system::file_cache::FileCache;
 
@@ -6,14 +7,24 @@ use crate::core::file_system::file_overwriting::file_overwriter::FileOverwriting
 use crate::core::file_system::path_helper::try_to_absolute_path;
 use crate::core::observability::logger;
 use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::dependency::jdk::java_jdk_catalog;
+use crate::java::dependency::jdk::java_jdk_catalog::DEFAULT_JAVA_VERSION;
 use crate::java::import::JavaImport;
 use crate::java::parser::java_node::JavaNode;
 use crate::java::parser::java_node_type::JavaNodeType;
+use crate::java::scanner::package::java_dependency_scanner;
 
+/// Imports declared in a java file. Simple type names are resolved following the java
+/// shadowing rules: single type imports, same package types and then on-demand imports.
 #[derive(Debug)]
 pub(crate) struct JavaFileImports {
+    /// Java file declaring the imports, required to resolve same package types
+    /// and on-demand imports of project packages
+    file: Option<PathBuf>,
     wildcard_imports: Vec<JavaFileImport>,
     last_node_to_import: HashMap<String, JavaFileImport>,
+    static_member_imports: HashMap<String, JavaFileImport>,
+    static_wildcard_imports: Vec<JavaFileImport>,
 }
 
 #[derive(Debug)]
@@ -35,13 +46,26 @@ impl JavaFileImport {
 impl JavaFileImports {
     pub(crate) fn new() -> Self {
         JavaFileImports {
+            file: None,
             wildcard_imports: Vec::new(),
             last_node_to_import: HashMap::new(),
+            static_member_imports: HashMap::new(),
+            static_wildcard_imports: Vec::new(),
+        }
+    }
+
+    pub(crate) fn from_file(java_file: &Path) -> Self {
+        JavaFileImports {
+            file: Some(java_file.to_path_buf()),
+            ..Self::new()
         }
     }
 
     pub(crate) fn is_empty(&self) -> bool {
-        self.last_node_to_import.is_empty() && self.wildcard_imports.is_empty()
+        self.last_node_to_import.is_empty()
+            && self.wildcard_imports.is_empty()
+            && self.static_member_imports.is_empty()
+            && self.static_wildcard_imports.is_empty()
     }
 
     pub(crate) fn get_explicit_import_from_identifier(
@@ -54,7 +78,7 @@ impl JavaFileImports {
             .ok_or("Unexpected java node without node type".to_string())?;
         if node_type == JavaNodeType::Id || node_type == JavaNodeType::TypeIdentifier {
             let content = type_id_node.get_content_from_cache(java_file_cache);
-            return self.get_explicit_import(&content).cloned();
+            return self.get_explicit_import(&content);
         } else if node_type == JavaNodeType::ScopedIdentifier
             || node_type == JavaNodeType::ScopedTypeIdentifier
         {
@@ -67,22 +91,49 @@ impl JavaFileImports {
         Err("Unexpected identifier getting explicit import from identifier".to_string())
     }
 
-    pub(crate) fn get_explicit_import(&self, type_id: &str) -> Result<&JavaImport, String> {
-        match self.last_node_to_import.get(type_id) {
-            Some(explicit_import) => Ok(&explicit_import.import),
-            None => Err(format!("Import for \"{}\" not found.", type_id)),
+    /// # get_explicit_import
+    /// Import of the type with the simple name "type_id" (i.e. "List"). Types of the same
+    /// package or from on-demand imports (i.e. "java.util.*") return an explicit import too.
+    pub(crate) fn get_explicit_import(&self, type_id: &str) -> Result<JavaImport, String> {
+        if let Some(explicit_import) = self.last_node_to_import.get(type_id) {
+            return Ok(explicit_import.import.clone());
+        }
+
+        self.get_same_package_import(type_id)
+            .or_else(|| self.get_on_demand_import(type_id))
+            .ok_or(format!("Import for \"{}\" not found.", type_id))
+    }
+
+    /// # get_static_import
+    /// Static import of the member "member_name" (i.e. "assertEquals"). On-demand static
+    /// imports are only checked for JDK types, project and library members are unknown.
+    pub(crate) fn get_static_import(&self, member_name: &str) -> Result<JavaImport, String> {
+        if let Some(static_import) = self.static_member_imports.get(member_name) {
+            return Ok(static_import.import.clone());
         }
+
+        self.static_wildcard_imports
+            .iter()
+            .find(|static_import| declares_jdk_static_member(&static_import.import, member_name))
+            .map(|static_import| static_import.import.clone())
+            .ok_or(format!("Static import for \"{}\" not found.", member_name))
     }
 
     pub(crate) fn insert(&mut self, import: JavaImport, import_end_byte: usize) {
-        if import.is_explicit_import() {
+        if import.is_static_import() {
+            let java_file_import = JavaFileImport::new(import, import_end_byte);
+            if java_file_import.import.is_wildcard_import() {
+                self.static_wildcard_imports.push(java_file_import);
+            } else {
+                let member_name = java_file_import.import.get_last_node();
+                self.static_member_imports
+                    .insert(member_name, java_file_import);
+            }
+        } else if import.is_explicit_import() {
             let last_node = import.get_last_node().clone();
             let java_file_import = JavaFileImport::new(import, import_end_byte);
             self.last_node_to_import.insert(last_node, java_file_import);
         } else if import.is_wildcard_import() {
-            logger::log_unrecoverable_error(
-                format!("Wildcard imports are not supported yet\n\"{}\"", import).as_str(),
-            );
             self.wildcard_imports
                 .push(JavaFileImport::new(import, import_end_byte));
         } else {
@@ -98,23 +149,22 @@ impl JavaFileImports {
         to_overwrite: &mut FileOverwriting,
         imports_to_add: Vec<JavaImport>,
         byte_to_insert_first_import_opt: Option<usize>,
+        line_ending: &str,
     ) -> Result<(), String> {
-        let sorted_imports_to_add = get_sorted_asc(imports_to_add);
+        let missing_imports = imports_to_add
+            .into_iter()
+            .filter(|import| !self.contains(import))
+            .collect();
+        let sorted_imports_to_add = get_sorted_asc(missing_imports);
         let last_import_end_byte_opt = self.get_last_import_end_byte();
 
         for import_to_add in sorted_imports_to_add {
+            let import_line = format!("{}{}", line_ending, import_to_add);
             if let Some(last_import_end_byte) = last_import_end_byte_opt {
-                to_overwrite.insert_content_with_previous_newline_at(
-                    last_import_end_byte,
-                    &import_to_add.to_string(),
-                )?;
+                to_overwrite.insert_content_at(last_import_end_byte, &import_line)?;
             } else if let Some(byte_to_insert_first_import) = byte_to_insert_first_import_opt {
-                to_overwrite
-                    .insert_content_with_previous_newline_at(byte_to_insert_first_import, "")?;
-                to_overwrite.insert_content_with_previous_newline_at(
-                    byte_to_insert_first_import,
-                    &import_to_add.to_string(),
-                )?;
+                to_overwrite.insert_content_at(byte_to_insert_first_import, line_ending)?;
+                to_overwrite.insert_content_at(byte_to_insert_first_import, &import_line)?;
             } else {
                 return Err(format!(
                     "It was not possible to add import to file:\n{}\n",
@@ -125,14 +175,20 @@ impl JavaFileImports {
         Ok(())
     }
 
-    pub(crate) fn get_file_string_with_trailing_newline(imports: &Vec<JavaImport>) -> String {
+    /// Static imports are written in their own group after the type imports
+    pub(crate) fn get_file_string_with_trailing_newline(imports: &[JavaImport]) -> String {
         let mut result = "".to_string();
 
-        for import in imports {
-            result += import.to_string().as_str();
-            result += "\n";
-        }
-        if !imports.is_empty() {
+        let (static_imports, type_imports): (Vec<&JavaImport>, Vec<&JavaImport>) =
+            imports.iter().partition(|import| import.is_static_import());
+        for import_group in [type_imports, static_imports] {
+            if import_group.is_empty() {
+                continue;
+            }
+            for import in import_group {
+                result += import.to_string().as_str();
+                result += "\n";
+            }
             result += "\n";
         }
 
@@ -142,15 +198,62 @@ impl JavaFileImports {
 
 // Private methods
 impl JavaFileImports {
+    fn get_all_file_imports(&self) -> impl Iterator<Item = &JavaFileImport> {
+        self.last_node_to_import
+            .values()
+            .chain(self.wildcard_imports.iter())
+            .chain(self.static_member_imports.values())
+            .chain(self.static_wildcard_imports.iter())
+    }
+
+    /// Static members are also present when an on-demand import of their type covers them
+    fn contains(&self, import: &JavaImport) -> bool {
+        if import.is_static_import() && !import.is_wildcard_import() {
+            if let Ok(static_import) = self.get_static_import(&import.get_last_node()) {
+                return static_import.get_static_type_route() == import.get_static_type_route();
+            }
+        }
+
+        let import_str = import.to_string();
+        self.get_all_file_imports()
+            .any(|file_import| file_import.import.to_string() == import_str)
+    }
+
+    fn get_same_package_import(&self, type_id: &str) -> Option<JavaImport> {
+        let same_package_file = self
+            .file
+            .as_ref()?
+            .with_file_name(format!("{}.java", type_id));
+        if !same_package_file.is_file() {
+            return None;
+        }
+
+        JavaImport::new_explicit_import_from_file(&same_package_file).ok()
+    }
+
+    /// The first on-demand import declaring the type is used, the java compiler would
+    /// reject the file if several of them did
+    fn get_on_demand_import(&self, type_id: &str) -> Option<JavaImport> {
+        self.wildcard_imports.iter().find_map(|wildcard_import| {
+            let route = wildcard_import.import.get_route();
+            let type_route = format!("{}.{}", route.strip_suffix(".*")?, type_id);
+
+            if java_jdk_catalog::find_type_by_route(&type_route, DEFAULT_JAVA_VERSION).is_some() {
+                return JavaImport::new_explicit_import_without_m2_repo_scan(&type_route).ok();
+            }
+            let file = self.file.as_ref()?;
+            java_dependency_scanner::search_imports(&type_route, file)
+                .first()
+                .and_then(|import_route| {
+                    JavaImport::new_explicit_import_from_file(&import_route.to_file_path()).ok()
+                })
+        })
+    }
+
     fn get_last_import_end_byte(&self) -> Option<usize> {
         let mut result = None;
 
-        for import in self
-            .last_node_to_import
-            .iter()
-            .map(|ik| ik.1)
-            .chain(self.wildcard_imports.iter())
-        {
+        for import in self.get_all_file_imports() {
             if let Some(current_result) = result {
                 if current_result < import.file_end_byte {
                     result = Some(import.file_end_byte);
@@ -164,16 +267,38 @@ impl JavaFileImports {
     }
 }
 
-/// TODO: sort in alphabetically ascending order
-pub(crate) fn get_sorted_asc(result: Vec<JavaImport>) -> Vec<JavaImport> {
-    result.to_vec()
+fn declares_jdk_static_member(static_import: &JavaImport, member_name: &str) -> bool {
+    let member_prefix = format!("{}(", member_name);
+    static_import
+        .get_static_type_route()
+        .and_then(|type_route| {
+            java_jdk_catalog::find_type_by_route(&type_route, DEFAULT_JAVA_VERSION)
+        })
+        .is_some_and(|jdk_type| {
+            jdk_type.get_members().iter().any(|member| {
+                member.starts_with("static ")
+                    && member
+                        .split_whitespace()
+                        .any(|word| word.starts_with(&member_prefix))
+            })
+        })
+}
+
+/// Imports sorted in alphabetically ascending order by route, static imports last
+pub(crate) fn get_sorted_asc(mut result: Vec<JavaImport>) -> Vec<JavaImport> {
+    result.sort_by_key(|import| (import.is_static_import(), import.get_route()));
+    result.dedup_by_key(|import| import.to_string());
+    result
 }
 
 impl JavaFileImports {
     // Test specific methods
     #[cfg(test)]
     pub(crate) fn count(&self) -> usize {
-        self.last_node_to_import.len() + self.wildcard_imports.len()
+        self.last_node_to_import.len()
+            + self.wildcard_imports.len()
+            + self.static_member_imports.len()
+            + self.static_wildcard_imports.len()
     }
 }
 
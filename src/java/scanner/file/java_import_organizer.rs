@@ -0,0 +1,487 @@
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use regex::Regex;
+
+use crate::core::file_system::file_cache::FileCache;
+use crate::core::file_system::file_overwriting::file_overwriter::FileOverwriting;
+use crate::core::file_system::file_reader;
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::comment;
+use crate::java::dependency::jdk::java_jdk_catalog;
+use crate::java::dependency::jdk::java_jdk_catalog::DEFAULT_JAVA_VERSION;
+use crate::java::import::JavaImport;
+use crate::java::import_layout_config::{JavaImportGroup, JavaImportLayout};
+use crate::java::parser::java_node::JavaNode;
+use crate::java::parser::java_node_type::JavaNodeType;
+use crate::java::scanner::package::{java_dependency_scanner, java_package_scanner};
+
+const JAVADOC_REFERENCE_PATTERN: &str =
+    r"(?:\{@link(?:plain)?|@see|@throws|@exception)\s+([A-Za-z_$][\w$]*)";
+
+static JAVADOC_REFERENCE_REGEX: OnceLock<Regex> = OnceLock::new();
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct OrganizedImport {
+    route: String,
+    is_static: bool,
+}
+
+/// Names found outside the import declarations of a java file
+#[derive(Debug, Default)]
+struct ReferencedNames {
+    /// Every identifier, including the ones of javadoc references
+    identifiers: HashSet<String>,
+    /// Simple type names that could be resolved through an on-demand import,
+    /// javadoc references are included to keep them resolvable
+    type_names: HashSet<String>,
+    /// Types, type parameters and variables declared in the file itself
+    declared_names: HashSet<String>,
+}
+
+/// # organize_imports
+/// Rewrite the import declarations of the java file removing unused and duplicated imports,
+/// collapsing or expanding on-demand imports and sorting them in the layout groups.
+/// On-demand imports are only expanded or removed when every type of the file is resolved.
+pub(crate) fn organize_imports(file: &Path, layout: &JavaImportLayout) -> Result<(), String> {
+    let file_cache = FileCache::from(file);
+    let root_java_node = JavaNode::from_path(file)?;
+    let import_decls: Vec<&JavaNode> = root_java_node
+        .get_children()
+        .iter()
+        .filter(|child| Some(JavaNodeType::ImportDecl) == child.get_node_type())
+        .collect();
+    let (first_import, last_import) = match (import_decls.first(), import_decls.last()) {
+        (Some(first_import), Some(last_import)) => (first_import, last_import),
+        _ => return Ok(()),
+    };
+    check_no_comments_between_imports(
+        &root_java_node,
+        first_import.get_start_byte(),
+        last_import.get_end_byte(),
+        file,
+    )?;
+
+    let mut imports = Vec::new();
+    for import_decl in &import_decls {
+        imports.push(OrganizedImport {
+            route: JavaNode::get_import_decl_content(import_decl, &file_cache)?,
+            is_static: JavaNode::is_static_import_decl(import_decl),
+        });
+    }
+    let mut names = ReferencedNames::default();
+    collect_names(&root_java_node, None, &file_cache, &mut names);
+
+    let organized_imports = get_organized_imports(file, imports, &names, layout);
+    let line_ending = get_line_ending(file)?;
+    let imports_content = get_imports_content(file, organized_imports, layout, line_ending);
+    let end_byte = if imports_content.is_empty() {
+        get_next_content_byte(file, last_import.get_end_byte())?
+    } else {
+        last_import.get_end_byte()
+    };
+
+    let mut to_overwrite = FileOverwriting::from_path(file)?;
+    to_overwrite.replace(first_import.get_start_byte(), end_byte, &imports_content)?;
+    to_overwrite.write_all()
+}
+
+/// # get_imports_block
+/// Import declarations grouped and sorted following the layout, used to write new files.
+pub(crate) fn get_imports_block(
+    file: &Path,
+    imports: &[JavaImport],
+    layout: &JavaImportLayout,
+) -> String {
+    let mut organized_imports: Vec<OrganizedImport> = Vec::new();
+    for import in imports {
+        let organized_import = OrganizedImport {
+            route: import.get_route(),
+            is_static: import.is_static_import(),
+        };
+        if !organized_imports.contains(&organized_import) {
+            organized_imports.push(organized_import);
+        }
+    }
+
+    get_imports_content(file, organized_imports, layout, "\n")
+}
+
+/// Comments between the import declarations would be lost after sorting them
+fn check_no_comments_between_imports(
+    root_java_node: &JavaNode,
+    start_byte: usize,
+    end_byte: usize,
+    file: &Path,
+) -> Result<(), String> {
+    let has_comments = root_java_node.get_children().iter().any(|child| {
+        comment::is_comment(child)
+            && start_byte < child.get_start_byte()
+            && child.get_end_byte() < end_byte
+    });
+    if has_comments {
+        return Err(format!(
+            "Imports with comments between them can not be organized in file:\n\"{}\"\n",
+            try_to_absolute_path(file)
+        ));
+    }
+
+    Ok(())
+}
+
+fn collect_names(
+    node: &JavaNode,
+    parent: Option<(&JavaNode, usize)>,
+    file_cache: &FileCache,
+    names: &mut ReferencedNames,
+) {
+    let node_type = match node.get_node_type() {
+        Some(node_type) => node_type,
+        None => return,
+    };
+    match node_type {
+        JavaNodeType::ImportDecl | JavaNodeType::PackageDecl => return,
+        JavaNodeType::Comment | JavaNodeType::LineComment | JavaNodeType::BlockComment => {
+            let regex = JAVADOC_REFERENCE_REGEX.get_or_init(|| {
+                Regex::new(JAVADOC_REFERENCE_PATTERN).expect("Valid javadoc regex")
+            });
+            let content = node.get_content_from_cache(file_cache);
+            for captures in regex.captures_iter(&content) {
+                names.identifiers.insert(captures[1].to_string());
+                names.type_names.insert(captures[1].to_string());
+            }
+        }
+        JavaNodeType::Id | JavaNodeType::TypeIdentifier => {
+            let name = node.get_content_from_cache(file_cache);
+            if is_declaration(parent) {
+                names.declared_names.insert(name.clone());
+            } else if is_type_reference(node_type, &name, parent) {
+                names.type_names.insert(name.clone());
+            }
+            names.identifiers.insert(name);
+        }
+        _ => {}
+    }
+
+    for (index, child) in node.get_children().iter().enumerate() {
+        collect_names(child, Some((node, index)), file_cache, names);
+    }
+}
+
+fn is_declaration(parent: Option<(&JavaNode, usize)>) -> bool {
+    match parent.and_then(|(parent, index)| Some((parent.get_node_type()?, index))) {
+        Some((JavaNodeType::VariableDeclarator, 0))
+        | Some((JavaNodeType::EnumConstant, 0))
+        | Some((JavaNodeType::TypeParameter, _)) => true,
+        Some((parent_type, _)) => matches!(
+            parent_type,
+            JavaNodeType::ClassDecl
+                | JavaNodeType::InterfaceDeclaration
+                | JavaNodeType::EnumDeclaration
+                | JavaNodeType::RecordDeclaration
+                | JavaNodeType::AnnotationTypeDeclaration
+        ),
+        None => false,
+    }
+}
+
+/// Type identifiers not qualified by another type and capitalized identifiers used
+/// as annotation names or as the object of a member access (i.e. "List.of()")
+fn is_type_reference(
+    node_type: JavaNodeType,
+    name: &str,
+    parent: Option<(&JavaNode, usize)>,
+) -> bool {
+    let (parent, index) = match parent {
+        Some(parent) => parent,
+        None => return false,
+    };
+    let parent_type = parent.get_node_type();
+    if JavaNodeType::TypeIdentifier == node_type {
+        return Some(JavaNodeType::ScopedTypeIdentifier) != parent_type || 0 == index;
+    }
+    if !name.starts_with(|c: char| c.is_uppercase()) {
+        return false;
+    }
+
+    match parent_type {
+        Some(JavaNodeType::MarkerAnnotation) | Some(JavaNodeType::Annotation) => true,
+        Some(JavaNodeType::MethodInvocation)
+        | Some(JavaNodeType::FieldAccess)
+        | Some(JavaNodeType::MethodReference)
+        | Some(JavaNodeType::ScopedIdentifier) => {
+            0 == index
+                && parent.get_children().get(1).is_some_and(|next| {
+                    matches!(
+                        next.get_node_type(),
+                        Some(JavaNodeType::Dot) | Some(JavaNodeType::MethodReferenceOperator)
+                    )
+                })
+        }
+        _ => false,
+    }
+}
+
+fn get_organized_imports(
+    file: &Path,
+    imports: Vec<OrganizedImport>,
+    names: &ReferencedNames,
+    layout: &JavaImportLayout,
+) -> Vec<OrganizedImport> {
+    let mut unique_imports = Vec::new();
+    for import in imports {
+        if !unique_imports.contains(&import) {
+            unique_imports.push(import);
+        }
+    }
+
+    let explicit_type_names: HashSet<&str> = unique_imports
+        .iter()
+        .filter(|import| !import.is_static && !is_wildcard(import))
+        .map(|import| get_last_node(&import.route))
+        .collect();
+    let unresolved_names: Vec<&String> = names
+        .type_names
+        .iter()
+        .filter(|name| {
+            !names.declared_names.contains(*name)
+                && !explicit_type_names.contains(name.as_str())
+                && !java_jdk_catalog::is_java_lang(name, DEFAULT_JAVA_VERSION)
+                && !file.with_file_name(format!("{}.java", name)).is_file()
+        })
+        .collect();
+    let on_demand_names: BTreeMap<String, Vec<&String>> = unique_imports
+        .iter()
+        .filter(|import| !import.is_static && is_wildcard(import))
+        .map(|import| {
+            let package = get_wildcard_package(&import.route);
+            let package_names = unresolved_names
+                .iter()
+                .filter(|name| declares_type(package, name, file))
+                .copied()
+                .collect();
+            (import.route.clone(), package_names)
+        })
+        .collect();
+    let are_all_names_resolved = unresolved_names
+        .iter()
+        .all(|name| on_demand_names.values().any(|names| names.contains(name)));
+
+    let mut result = Vec::new();
+    for import in unique_imports {
+        if import.is_static && is_wildcard(&import) {
+            result.push(import);
+        } else if is_wildcard(&import) {
+            let package_names = &on_demand_names[&import.route];
+            if !are_all_names_resolved {
+                result.push(import);
+            } else if 0 < layout.get_wildcard_threshold()
+                && !package_names.is_empty()
+                && package_names.len() < layout.get_wildcard_threshold()
+            {
+                let package = get_wildcard_package(&import.route);
+                result.extend(package_names.iter().map(|name| OrganizedImport {
+                    route: format!("{}.{}", package, name),
+                    is_static: false,
+                }));
+            } else if !package_names.is_empty() {
+                result.push(import);
+            }
+        } else if names.identifiers.contains(get_last_node(&import.route)) {
+            result.push(import);
+        }
+    }
+
+    collapse_imports(file, result, layout)
+}
+
+/// Explicit imports of the same package or type are replaced by an on-demand import
+/// when they reach the layout threshold, unless another on-demand import declares
+/// one of their types too. Types named like a type of the file package are kept explicit,
+/// the package type would hide them once collapsed.
+fn collapse_imports(
+    file: &Path,
+    imports: Vec<OrganizedImport>,
+    layout: &JavaImportLayout,
+) -> Vec<OrganizedImport> {
+    let mut explicit_imports: BTreeMap<(bool, &str), Vec<&OrganizedImport>> = BTreeMap::new();
+    for import in imports
+        .iter()
+        .filter(|import| !is_wildcard(import) && !is_hidden_by_package_type(import, file))
+    {
+        if let Some((parent_route, _)) = import.route.rsplit_once('.') {
+            explicit_imports
+                .entry((import.is_static, parent_route))
+                .or_default()
+                .push(import);
+        }
+    }
+    let type_wildcard_packages: Vec<&str> = imports
+        .iter()
+        .filter(|import| !import.is_static && is_wildcard(import))
+        .map(|import| get_wildcard_package(&import.route))
+        .collect();
+
+    let mut collapsed_parents = HashSet::new();
+    for ((is_static, parent_route), group) in explicit_imports {
+        let threshold = if is_static {
+            layout.get_static_wildcard_threshold()
+        } else {
+            layout.get_wildcard_threshold()
+        };
+        let has_conflicts = !is_static
+            && group.iter().any(|import| {
+                type_wildcard_packages.iter().any(|package| {
+                    *package != parent_route
+                        && declares_type(package, get_last_node(&import.route), file)
+                })
+            });
+        if 0 < threshold && threshold <= group.len() && !has_conflicts {
+            collapsed_parents.insert((is_static, parent_route.to_string()));
+        }
+    }
+
+    let mut result: Vec<OrganizedImport> = Vec::new();
+    for import in &imports {
+        let collapsed_import = match import.route.rsplit_once('.') {
+            Some((parent_route, _))
+                if !is_wildcard(import)
+                    && !is_hidden_by_package_type(import, file)
+                    && collapsed_parents
+                        .contains(&(import.is_static, parent_route.to_string())) =>
+            {
+                OrganizedImport {
+                    route: format!("{}.*", parent_route),
+                    is_static: import.is_static,
+                }
+            }
+            _ => import.clone(),
+        };
+        if !result.contains(&collapsed_import) {
+            result.push(collapsed_import);
+        }
+    }
+
+    result
+}
+
+fn get_imports_content(
+    file: &Path,
+    imports: Vec<OrganizedImport>,
+    layout: &JavaImportLayout,
+    line_ending: &str,
+) -> String {
+    let groups = layout.get_groups();
+    let mut grouped_imports: BTreeMap<usize, Vec<OrganizedImport>> = BTreeMap::new();
+    for import in imports {
+        let group_index = get_group_candidates(&import, file)
+            .into_iter()
+            .find_map(|group| {
+                groups
+                    .iter()
+                    .position(|layout_group| group == *layout_group)
+            })
+            .unwrap_or(groups.len());
+        grouped_imports.entry(group_index).or_default().push(import);
+    }
+
+    grouped_imports
+        .into_values()
+        .map(|mut group| {
+            group.sort_by(|first, second| first.route.cmp(&second.route));
+            group
+                .iter()
+                .map(|import| match import.is_static {
+                    true => format!("import static {};", import.route),
+                    false => format!("import {};", import.route),
+                })
+                .collect::<Vec<String>>()
+                .join(line_ending)
+        })
+        .collect::<Vec<String>>()
+        .join(&format!("{}{}", line_ending, line_ending))
+}
+
+/// Groups the import could belong to, the first one present in the layout is used
+fn get_group_candidates(import: &OrganizedImport, file: &Path) -> Vec<JavaImportGroup> {
+    let mut candidates = Vec::new();
+    if import.is_static {
+        candidates.push(JavaImportGroup::Static);
+    }
+    if is_project_route(&import.route, file) {
+        candidates.push(JavaImportGroup::Project);
+    }
+    if import.route.starts_with("java.") {
+        candidates.push(JavaImportGroup::Java);
+    } else if import.route.starts_with("javax.") {
+        candidates.push(JavaImportGroup::Javax);
+    }
+    candidates.push(JavaImportGroup::ThirdParty);
+
+    candidates
+}
+
+fn is_project_route(route: &str, file: &Path) -> bool {
+    let java_dir = match java_package_scanner::get_base_package(file) {
+        Some(base_package) => base_package.join("src").join("main").join("java"),
+        None => return false,
+    };
+    let nodes: Vec<&str> = route.split('.').filter(|node| "*" != *node).collect();
+    if java_dir.join(nodes.join("/")).is_dir() {
+        return true;
+    }
+
+    (1..=nodes.len()).any(|len| {
+        let mut type_file: PathBuf = java_dir.join(nodes[..len].join("/"));
+        type_file.set_extension("java");
+        type_file.is_file()
+    })
+}
+
+fn declares_type(package: &str, type_name: &str, file: &Path) -> bool {
+    let type_route = format!("{}.{}", package, type_name);
+    java_jdk_catalog::find_type_by_route(&type_route, DEFAULT_JAVA_VERSION).is_some()
+        || !java_dependency_scanner::search_imports(&type_route, file).is_empty()
+}
+
+fn is_hidden_by_package_type(import: &OrganizedImport, file: &Path) -> bool {
+    !import.is_static
+        && file
+            .with_file_name(format!("{}.java", get_last_node(&import.route)))
+            .is_file()
+}
+
+fn is_wildcard(import: &OrganizedImport) -> bool {
+    import.route.ends_with(".*")
+}
+
+fn get_wildcard_package(route: &str) -> &str {
+    route.strip_suffix(".*").unwrap_or(route)
+}
+
+fn get_last_node(route: &str) -> &str {
+    route.rsplit('.').next().unwrap_or(route)
+}
+
+fn get_line_ending(file: &Path) -> Result<&'static str, String> {
+    let bytes = file_reader::read_all_bytes(file)?;
+    if bytes.windows(2).any(|window| b"\r\n" == window) {
+        return Ok("\r\n");
+    }
+
+    Ok("\n")
+}
+
+/// First byte after "byte" that is not a whitespace, used to remove the blank
+/// lines left when all the imports are removed
+fn get_next_content_byte(file: &Path, byte: usize) -> Result<usize, String> {
+    let bytes = file_reader::read_all_bytes(file)?;
+    let whitespaces = bytes[byte..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_whitespace())
+        .count();
+
+    Ok(byte + whitespaces)
+}
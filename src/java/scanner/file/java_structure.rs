@@ -6,6 +6,8 @@ use crate::core::observability::logger;
 use crate::core::parser::parser_node_trait::ParserNode;
 use crate::java::annotation_usage::JavaAnnotationUsage;
 use crate::java::class::JavaClass;
+use crate::java::code_style_config::JavaCodeStyle;
+use crate::java::comment::JavaComment;
 use crate::java::data_type::JavaDataType;
 use crate::java::field::JavaField;
 use crate::java::import::JavaImport;
@@ -14,11 +16,13 @@ use crate::java::interface::JavaInterface;
 use crate::java::method::JavaMethod;
 use crate::java::parser::java_node::JavaNode;
 use crate::java::parser::java_node_type::JavaNodeType;
+use crate::java::parser::java_sealed_keyword;
+use crate::java::parser::java_sealed_keyword::JavaSealedKeywordKind;
 use crate::java::scanner::file::java_file_imports;
 use crate::java::scanner::file::java_file_imports::JavaFileImports;
 use crate::java::scanner::file::java_structure_type::JavaStructureType;
 use crate::java::visibility::JavaVisibility;
-use crate::java::{annotation_usage, visibility};
+use crate::java::{annotation_usage, comment, visibility};
 
 #[allow(unused)]
 #[derive(Debug)]
@@ -28,16 +32,29 @@ pub(crate) struct JavaStructure {
     struct_body_start_byte: usize,
     struct_body_end_byte: usize,
 
+    /// Comments written before the annotations, including the javadoc
+    comments: Vec<JavaComment>,
+
     // Modifiers
     annotations: Vec<JavaAnnotationUsage>,
     visibility: JavaVisibility,
     is_static: bool,
     is_final: bool,
     is_abstract: bool,
+    is_sealed: bool,
+    is_non_sealed: bool,
 
     // Class specific
     extended_class: Vec<JavaImport>,
     implemented_interfaces: Vec<JavaImport>,
+    /// Types listed in the "permits" clause of a sealed structure
+    permitted_subtypes: Vec<JavaImport>,
+
+    /// Static imports required by the structure, only used by generated structures
+    static_imports: Vec<JavaImport>,
+
+    // Enum specific
+    enum_constants: Vec<String>,
 
     // Rest of the fields
     name: String,
@@ -53,10 +70,11 @@ impl JavaStructure {
 
     pub(crate) fn new(
         root_struct_decl_node: &JavaNode,
+        comments: Vec<JavaComment>,
         file_imports: &JavaFileImports,
         file_cache: &FileCache,
     ) -> Result<Self, String> {
-        new_structure_internal(root_struct_decl_node, file_imports, file_cache)
+        new_structure_internal(root_struct_decl_node, comments, file_imports, file_cache)
     }
 
     pub(crate) fn get_type(&self) -> JavaStructureType {
@@ -67,6 +85,10 @@ impl JavaStructure {
         self.struct_body_start_byte
     }
 
+    pub(crate) fn get_comments(&self) -> &Vec<JavaComment> {
+        &self.comments
+    }
+
     pub(crate) fn get_annotations(&self) -> &Vec<JavaAnnotationUsage> {
         &self.annotations
     }
@@ -85,6 +107,18 @@ impl JavaStructure {
         self.is_abstract
     }
 
+    pub(crate) fn is_sealed(&self) -> bool {
+        self.is_sealed
+    }
+
+    pub(crate) fn is_non_sealed(&self) -> bool {
+        self.is_non_sealed
+    }
+
+    pub(crate) fn get_permitted_subtypes(&self) -> &Vec<JavaImport> {
+        &self.permitted_subtypes
+    }
+
     pub(crate) fn get_name(&self) -> &str {
         &self.name
     }
@@ -132,6 +166,10 @@ impl JavaStructure {
         ));
     }
 
+    pub(crate) fn get_enum_constants(&self) -> &Vec<String> {
+        &self.enum_constants
+    }
+
     pub(crate) fn get_fields(&self) -> &Vec<JavaField> {
         &self.fields
     }
@@ -161,9 +199,37 @@ impl JavaStructure {
             imports.push(import);
         }
 
+        for import in &self.static_imports {
+            imports.push(import.clone());
+        }
+
+        for import in self.get_permitted_subtypes() {
+            if !self.is_clashing_permitted_subtype(import, &imports) {
+                imports.push(import.clone());
+            }
+        }
+
+        imports.retain(|import| !import.is_java_lang());
         imports
     }
 
+    /// Permitted subtypes named like the structure, another permitted subtype or another
+    /// imported type are written with their full name instead of being imported
+    fn is_clashing_permitted_subtype(&self, subtype: &JavaImport, imports: &[JavaImport]) -> bool {
+        let simple_name = subtype.get_last_node();
+        let route = subtype.get_route();
+
+        simple_name == self.name
+            || imports
+                .iter()
+                .chain(self.get_permitted_subtypes())
+                .any(|import| {
+                    !import.is_static_import()
+                        && simple_name == import.get_last_node()
+                        && route != import.get_route()
+                })
+    }
+
     pub(crate) fn get_imports_sorted_asc(&self) -> Vec<JavaImport> {
         java_file_imports::get_sorted_asc(self.get_imports())
     }
@@ -196,35 +262,57 @@ impl JavaStructure {
             .collect()
     }
 
-    pub(crate) fn get_skeleton_without_imports(&self) -> String {
+    pub(crate) fn get_skeleton_without_imports(&self, style: &JavaCodeStyle) -> String {
         let mut result = "".to_string();
-        self.write_annotations(&mut result);
+        let indentation = JavaIndentation::default();
+        comment::write_comments(&mut result, self.get_comments(), &indentation);
+        annotation_usage::write_annotations(
+            &mut result,
+            self.get_annotations(),
+            &indentation,
+            style.is_type_annotations_on_same_line(),
+        );
         self.write_visibility(&mut result);
         result += self.get_name();
         self.write_extensions_and_implementations(&mut result);
         result
     }
 
-    pub(crate) fn write_body(&self, result: &mut String) {
-        *result += " {\n";
-        let mut java_indentation = JavaIndentation::default();
+    pub(crate) fn write_body(&self, result: &mut String, style: &JavaCodeStyle) {
+        let mut java_indentation = style.get_indentation_at(0);
+        *result += style.get_opening_brace(&java_indentation).as_str();
         java_indentation.increase_level();
+        let members_separator = style.get_members_separator();
+
+        let enum_constants = self.get_enum_constants();
+        for (index, constant) in enum_constants.iter().enumerate() {
+            *result += java_indentation.get_current_indentation().as_str();
+            *result += constant;
+            if index + 1 < enum_constants.len() {
+                *result += ",\n";
+            } else if self.get_fields().is_empty() && self.get_methods().is_empty() {
+                *result += "\n";
+            } else {
+                *result += ";\n";
+                *result += members_separator.as_str();
+            }
+        }
 
         for (index, field) in self.get_fields().iter().enumerate() {
             if index > 0 {
-                *result += "\n";
+                *result += members_separator.as_str();
             }
-            *result += field.get_str(&java_indentation).as_str();
+            *result += field.get_str(&java_indentation, style).as_str();
         }
 
-        if !self.get_methods().is_empty() {
-            *result += "\n";
+        if !self.get_fields().is_empty() && !self.get_methods().is_empty() {
+            *result += members_separator.as_str();
         }
         for (index, method) in self.get_methods().iter().enumerate() {
             if index > 0 {
-                *result += "\n";
+                *result += members_separator.as_str();
             }
-            method.write_to_string(result, &java_indentation);
+            method.write_to_string(result, &java_indentation, style, self.get_type());
         }
 
         java_indentation.decrease_level();
@@ -242,6 +330,12 @@ impl JavaStructure {
         if self.is_abstract() {
             *result += "abstract ";
         }
+        if self.is_sealed() {
+            *result += "sealed ";
+        }
+        if self.is_non_sealed() {
+            *result += "non-sealed ";
+        }
         match self.get_type() {
             JavaStructureType::Class => *result += "class ",
             JavaStructureType::Interface => *result += "interface ",
@@ -265,18 +359,31 @@ impl JavaStructure {
                 *result += interface.get_name();
             }
         }
-    }
 
-    fn write_annotations(&self, result: &mut String) {
-        let indentation = JavaIndentation::default();
-        for annotation in self.get_annotations() {
-            *result += annotation.to_file_string(&indentation).as_str();
+        let permitted_subtypes = self.get_permitted_subtypes();
+        if !permitted_subtypes.is_empty() {
+            let imported_routes: Vec<String> = self
+                .get_imports()
+                .iter()
+                .map(JavaImport::get_route)
+                .collect();
+            let names: Vec<String> = permitted_subtypes
+                .iter()
+                .map(
+                    |subtype| match imported_routes.contains(&subtype.get_route()) {
+                        true => subtype.get_last_node(),
+                        false => subtype.get_route(),
+                    },
+                )
+                .collect();
+            *result += format!(" permits {}", names.join(", ")).as_str();
         }
     }
 }
 
 fn new_structure_internal(
     root_node: &JavaNode,
+    comments: Vec<JavaComment>,
     file_imports: &JavaFileImports,
     java_file_cache: &FileCache,
 ) -> Result<JavaStructure, String> {
@@ -286,10 +393,14 @@ fn new_structure_internal(
     let mut is_static = false;
     let mut is_final = false;
     let mut is_abstract = false;
+    let mut is_sealed = false;
+    let mut is_non_sealed = false;
     let mut name_opt = None;
     let mut annotations = Vec::new();
     let mut extended_class = Vec::new();
     let mut implemented_interfaces = Vec::new();
+    let mut permitted_subtypes = Vec::new();
+    let mut enum_constants = Vec::new();
     let mut fields = Vec::new();
     let mut methods = Vec::new();
     let mut substructures = Vec::new();
@@ -297,7 +408,8 @@ fn new_structure_internal(
     let mut struct_body_end_byte_opt: Option<usize> = None;
     let input_java_file = java_file_cache.get_path();
 
-    for child_node in root_node.get_children() {
+    let children = root_node.get_children();
+    for (child_index, child_node) in children.iter().enumerate() {
         if let Some(structure_node_type) = child_node.get_node_type() {
             if JavaNodeType::Modifiers == structure_node_type {
                 for modifier in child_node.get_children() {
@@ -335,16 +447,61 @@ fn new_structure_internal(
                     Err(err) => logger::log_warning(&err),
                 }
             } else if is_structure_body(&structure_node_type) {
-                for body_child in child_node.get_children() {
+                let content_start_byte = java_file_cache.get_char_start_byte(
+                    root_node
+                        .get_start_byte()
+                        .saturating_sub(java_sealed_keyword::LEADING_KEYWORDS_MAX_BYTES),
+                );
+                let header_content =
+                    java_file_cache.get_content(content_start_byte, child_node.get_start_byte());
+                for keyword in java_sealed_keyword::find_declaration_keywords(
+                    &header_content,
+                    root_node.get_start_byte() - content_start_byte,
+                    child_node.get_start_byte() - content_start_byte,
+                ) {
+                    match keyword.get_kind() {
+                        JavaSealedKeywordKind::Sealed => is_sealed = true,
+                        JavaSealedKeywordKind::NonSealed => is_non_sealed = true,
+                        JavaSealedKeywordKind::Permits(names) => {
+                            permitted_subtypes = get_permitted_subtypes(names, file_imports)
+                        }
+                    }
+                }
+                let body_children = child_node.get_children();
+                for (index, body_child) in body_children.iter().enumerate() {
                     if let Some(body_node_type) = body_child.get_node_type() {
-                        if JavaNodeType::FieldDeclaration == body_node_type {
-                            match JavaField::new(body_child, file_imports, java_file_cache) {
+                        if JavaNodeType::EnumConstant == body_node_type {
+                            if let Some(id_node) = body_child
+                                .get_children()
+                                .iter()
+                                .find(|child| Some(JavaNodeType::Id) == child.get_node_type())
+                            {
+                                enum_constants
+                                    .push(id_node.get_content_from_cache(java_file_cache));
+                            }
+                        } else if JavaNodeType::FieldDeclaration == body_node_type {
+                            let comments = comment::get_leading_comments(
+                                body_children,
+                                index,
+                                java_file_cache,
+                            );
+                            match JavaField::new(
+                                body_child,
+                                comments,
+                                file_imports,
+                                java_file_cache,
+                            ) {
                                 Ok(field) => fields.push(field),
                                 Err(err) => logger::log_warning(&err),
                             };
                         } else if JavaNodeType::MethodDecl == body_node_type {
                             match JavaMethod::new_from_node(
                                 body_child,
+                                comment::get_leading_comments(
+                                    body_children,
+                                    index,
+                                    java_file_cache,
+                                ),
                                 file_imports,
                                 java_file_cache,
                             ) {
@@ -352,15 +509,24 @@ fn new_structure_internal(
                                 Err(err) => log_invalid_method_decl(input_java_file, err),
                             }
                         } else if JavaNodeType::LBrace == body_node_type {
-                            // This does not take into account comments in that line
-                            struct_body_start_byte_opt = Some(body_child.get_end_byte());
+                            // Inserted members go after the comment in the opening brace line
+                            let brace_line_end = body_children
+                                .get(index + 1)
+                                .filter(|next| {
+                                    comment::is_comment(next)
+                                        && next.get_start_line() == body_child.get_start_line()
+                                })
+                                .unwrap_or(body_child);
+                            struct_body_start_byte_opt = Some(brace_line_end.get_end_byte());
                         } else if JavaNodeType::RBrace == body_node_type {
                             struct_body_end_byte_opt = Some(body_child.get_start_byte());
                         }
                     }
                 }
             } else if is_java_structure_type(Some(structure_node_type)) {
-                match new_structure_internal(child_node, file_imports, java_file_cache) {
+                let comments =
+                    comment::get_leading_comments(children, child_index, java_file_cache);
+                match new_structure_internal(child_node, comments, file_imports, java_file_cache) {
                     Ok(new_substructure) => substructures.push(new_substructure),
                     Err(err) => logger::log_warning(&err),
                 }
@@ -377,13 +543,19 @@ fn new_structure_internal(
         structure_type,
         struct_body_start_byte,
         struct_body_end_byte,
+        comments,
         annotations,
         visibility,
         is_static,
         is_final,
         is_abstract,
+        is_sealed,
+        is_non_sealed,
         extended_class,
         implemented_interfaces,
+        permitted_subtypes,
+        static_imports: vec![],
+        enum_constants,
         name,
         fields,
         methods,
@@ -425,6 +597,24 @@ fn extract_super_class(
     }
 }
 
+/// Imports of the "permits" clause types, i.e. "permits Circle, org.test.Square"
+fn get_permitted_subtypes(names: &[String], file_imports: &JavaFileImports) -> Vec<JavaImport> {
+    let mut result = Vec::new();
+    for name in names {
+        let import_result = if name.contains('.') {
+            JavaImport::new_explicit_import_without_m2_repo_scan(name)
+        } else {
+            file_imports.get_explicit_import(name)
+        };
+        match import_result {
+            Ok(import) => result.push(import),
+            Err(err) => logger::log_warning(&err),
+        }
+    }
+
+    result
+}
+
 fn log_invalid_method_decl(input_java_file: &Path, err: String) {
     logger::log_warning(
         format!(
@@ -462,13 +652,19 @@ fn get_java_structure_type(node_type_opt: Option<JavaNodeType>) -> Option<JavaSt
 
 pub(crate) struct JavaStructureBuilder {
     structure_type: Option<JavaStructureType>,
+    comments: Vec<JavaComment>,
     annotations: Vec<JavaAnnotationUsage>,
     visibility: JavaVisibility,
     is_static: bool,
     is_final: bool,
     is_abstract: bool,
+    is_sealed: bool,
+    is_non_sealed: bool,
     extended_class: Vec<JavaImport>,
     implemented_interfaces: Vec<JavaImport>,
+    permitted_subtypes: Vec<JavaImport>,
+    static_imports: Vec<JavaImport>,
+    enum_constants: Vec<String>,
     name: Option<String>,
     fields: Vec<JavaField>,
     methods: Vec<JavaMethod>,
@@ -478,13 +674,19 @@ impl JavaStructureBuilder {
     fn new_builder() -> Self {
         Self {
             structure_type: None,
+            comments: vec![],
             annotations: vec![],
             visibility: JavaVisibility::Public,
             is_static: false,
             is_final: false,
             is_abstract: false,
+            is_sealed: false,
+            is_non_sealed: false,
             extended_class: Vec::new(),
             implemented_interfaces: vec![],
+            permitted_subtypes: vec![],
+            static_imports: vec![],
+            enum_constants: vec![],
             name: None,
             fields: vec![],
             methods: vec![],
@@ -494,6 +696,10 @@ impl JavaStructureBuilder {
         self.structure_type = Some(input);
         self
     }
+    pub fn comments(&mut self, input: Vec<JavaComment>) -> &mut Self {
+        self.comments = input;
+        self
+    }
     pub fn annotations(&mut self, input: Vec<JavaAnnotationUsage>) -> &mut Self {
         self.annotations = input.clone();
         self
@@ -516,6 +722,15 @@ impl JavaStructureBuilder {
         self.is_abstract = input;
         self
     }
+    pub fn is_sealed(&mut self, input: bool) -> &mut Self {
+        self.is_sealed = input;
+        self
+    }
+
+    pub fn is_non_sealed(&mut self, input: bool) -> &mut Self {
+        self.is_non_sealed = input;
+        self
+    }
     pub fn extended_classes(&mut self, input: Vec<JavaImport>) -> &mut Self {
         self.extended_class = input;
         self
@@ -524,6 +739,18 @@ impl JavaStructureBuilder {
         self.implemented_interfaces = input;
         self
     }
+    pub fn permitted_subtypes(&mut self, input: Vec<JavaImport>) -> &mut Self {
+        self.permitted_subtypes = input;
+        self
+    }
+    pub fn static_imports(&mut self, input: Vec<JavaImport>) -> &mut Self {
+        self.static_imports = input;
+        self
+    }
+    pub fn enum_constants(&mut self, input: Vec<String>) -> &mut Self {
+        self.enum_constants = input;
+        self
+    }
     pub fn name(&mut self, input: &str) -> &mut Self {
         self.name = Some(input.to_string());
         self
@@ -557,18 +784,30 @@ impl JavaStructureBuilder {
         // though, because methods like "JavaClass::from(&existing_file)" are much more convenient.
         let classes = self.get_extended_class_imports();
         let implemented_interfaces = self.get_interfaces_imports();
+        let structure_type = self.structure_type.ok_or("Structure type is mandatory")?;
+        for method in &self.methods {
+            method.check_structure(structure_type, self.is_abstract)?;
+        }
+        self.check_sealing()?;
+        self.check_enum_constants(structure_type)?;
 
         let structure = JavaStructure {
-            structure_type: self.structure_type.ok_or("Structure type is mandatory")?,
+            structure_type,
             struct_body_start_byte: 0,
             struct_body_end_byte: 0,
+            comments: self.comments.clone(),
             annotations: self.annotations.clone(),
             visibility: self.visibility,
             is_static: self.is_static,
             is_final: self.is_final,
             is_abstract: self.is_abstract,
+            is_sealed: self.is_sealed,
+            is_non_sealed: self.is_non_sealed,
             extended_class: classes.clone(),
             implemented_interfaces: implemented_interfaces.clone(),
+            permitted_subtypes: self.permitted_subtypes.clone(),
+            static_imports: self.static_imports.clone(),
+            enum_constants: self.enum_constants.clone(),
             name,
             fields: self.fields.clone(),
             methods: self.methods.clone(),
@@ -578,6 +817,28 @@ impl JavaStructureBuilder {
         Ok(structure)
     }
 
+    fn check_sealing(&self) -> Result<(), String> {
+        if self.is_sealed && self.is_non_sealed {
+            return Err("\"sealed\" and \"non-sealed\" can not be combined".to_string());
+        }
+        if self.is_sealed && self.is_final {
+            return Err("\"sealed\" and \"final\" can not be combined".to_string());
+        }
+        if !self.is_sealed && !self.permitted_subtypes.is_empty() {
+            return Err("only sealed structures can declare permitted subtypes".to_string());
+        }
+
+        Ok(())
+    }
+
+    fn check_enum_constants(&self, structure_type: JavaStructureType) -> Result<(), String> {
+        if JavaStructureType::Enum != structure_type && !self.enum_constants.is_empty() {
+            return Err("only enums can declare enum constants".to_string());
+        }
+
+        Ok(())
+    }
+
     fn get_name(&self) -> Result<String, String> {
         Ok(self
             .name
@@ -638,12 +899,17 @@ fn extract_interfaces(
             .expect("Interface already checked")
             .get_children()
         {
-            let data_type = JavaDataType::from_data_type_identifier_with_import(
+            let data_type = match JavaDataType::from_data_type_identifier_with_import(
                 interface_type,
                 file_imports,
                 input_java_file_cache,
-            )
-            .expect("Interface definition must be ok");
+            ) {
+                Ok(data_type) => data_type,
+                Err(err) => {
+                    logger::log_warning(&err);
+                    continue;
+                }
+            };
             if let Some(import) = data_type.get_import_opt() {
                 result.push(import);
             } else {
@@ -1,4 +1,5 @@
 pub mod java_file;
 pub mod java_file_imports;
+pub mod java_import_organizer;
 pub mod java_structure;
 pub mod java_structure_type;
@@ -0,0 +1,13 @@
+package org.test;
+
+import static java.util.Objects.*;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+import java.util.*;
+import org.test.other.*;
+
+public class JavaFileImportResolution {
+    private UUID id;
+    private SuperClass superClass;
+    private OtherPackageType other;
+}
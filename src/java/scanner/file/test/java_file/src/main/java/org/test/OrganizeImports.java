@@ -0,0 +1,28 @@
+package org.test;
+
+import org.test.other.OtherPackageType;
+import java.util.List;
+import java.util.Map;
+import java.util.List;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import javax.annotation.Nullable;
+import org.springframework.stereotype.Service;
+import java.util.*;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+import java.util.Optional;
+
+/**
+ * Uses {@link Objects} only in the javadoc
+ */
+@Service
+public class OrganizeImports {
+    private List<String> names;
+    private OtherPackageType other;
+    private UUID id;
+    @Nullable
+    private String description;
+
+    void check() {
+        assertEquals(1, names.size());
+    }
+}
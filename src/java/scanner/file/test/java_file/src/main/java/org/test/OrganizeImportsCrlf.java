@@ -0,0 +1,11 @@
+package org.test;
+
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.HashMap;
+
+public class OrganizeImportsCrlf {
+    private List<String> names = new ArrayList<>();
+    private Map<String, String> values = new HashMap<>();
+}
@@ -0,0 +1,13 @@
+package org.test;
+
+import java.util.ArrayList;
+import java.util.List;
+import java.util.Map;
+import java.util.HashMap;
+import java.util.Set;
+
+public class OrganizeImportsPackageType {
+    private List<String> names = new ArrayList<>();
+    private Map<String, String> values = new HashMap<>();
+    private Set<String> keys;
+}
@@ -0,0 +1,29 @@
+package org.test.organize_imports_test;
+
+import static org.junit.jupiter.api.Assertions.assertEquals;
+
+import java.util.List;
+import java.util.Objects;
+import java.util.UUID;
+
+import javax.annotation.Nullable;
+
+import org.springframework.stereotype.Service;
+
+import org.test.other.OtherPackageType;
+
+/**
+ * Uses {@link Objects} only in the javadoc
+ */
+@Service
+public class OrganizeImports {
+    private List<String> names;
+    private OtherPackageType other;
+    private UUID id;
+    @Nullable
+    private String description;
+
+    void check() {
+        assertEquals(1, names.size());
+    }
+}
@@ -0,0 +1,8 @@
+package org.test.organize_imports_test;
+
+import java.util.*;
+
+public class OrganizeImportsCrlf {
+    private List<String> names = new ArrayList<>();
+    private Map<String, String> values = new HashMap<>();
+}
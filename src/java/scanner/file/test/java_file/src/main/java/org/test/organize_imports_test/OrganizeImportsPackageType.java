@@ -0,0 +1,10 @@
+package org.test.organize_imports_test;
+
+import java.util.*;
+import java.util.Set;
+
+public class OrganizeImportsPackageType {
+    private List<String> names = new ArrayList<>();
+    private Map<String, String> values = new HashMap<>();
+    private Set<String> keys;
+}
@@ -0,0 +1,4 @@
+package org.test.organize_imports_test;
+
+public class Set {
+}
@@ -0,0 +1,4 @@
+package org.test.other;
+
+public class OtherPackageType {
+}
@@ -0,0 +1,357 @@
+use std::collections::{HashSet, VecDeque};
+use std::path::{Path, PathBuf};
+
+use crate::core::database::model::java_symbol::db_java_symbol_search;
+use crate::core::database::model::java_symbol::java_symbol_entity::JavaStructureEntity;
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::observability::logger;
+use crate::java::data_type::JavaDataType;
+use crate::java::method::JavaMethod;
+use crate::java::scanner::file::java_file::JavaFile;
+use crate::java::scanner::file::java_structure::JavaStructure;
+use crate::java::scanner::package::{java_dependency_scanner, java_package_scanner};
+use crate::java::supertype::JavaSupertype;
+use crate::java::visibility::JavaVisibility;
+
+const OVERRIDE_ANNOTATION_ROUTE: &str = "java.lang.Override";
+
+/// # get_supertypes
+/// All the supertypes of the structure with the given route declared in "file", nearest first.
+/// Each supertype is only returned once even if the hierarchy contains cycles, and the
+/// hierarchy of external or unresolved types is not explored.
+pub(crate) fn get_supertypes(file: &Path, route: &str) -> Result<Vec<JavaSupertype>, String> {
+    let base_package = index_project(file)?;
+    let mut visited = HashSet::from([route.to_string()]);
+    let mut pending = VecDeque::from([(route.to_string(), 0)]);
+    let mut supertypes = Vec::new();
+
+    while let Some((current_route, depth)) = pending.pop_front() {
+        for supertype in
+            db_java_symbol_search::supertypes_by_structure_route(&base_package, &current_route)
+        {
+            let key = supertype.route.clone().unwrap_or(supertype.name.clone());
+            if !visited.insert(key) {
+                continue;
+            }
+
+            let file_opt = supertype
+                .route
+                .as_ref()
+                .and_then(|route| db_java_symbol_search::structure_by_route(&base_package, route))
+                .map(|structure| PathBuf::from(structure.location.file_path));
+            if let (Some(route), Some(_)) = (&supertype.route, &file_opt) {
+                pending.push_back((route.clone(), depth + 1));
+            }
+            supertypes.push(JavaSupertype::new(
+                supertype.name,
+                supertype.route,
+                file_opt,
+                depth + 1,
+            ));
+        }
+    }
+
+    Ok(supertypes)
+}
+
+/// # get_subtypes
+/// Project structures extending or implementing, directly or transitively, the structure
+/// with the given route. Nested structures are skipped: only the main structure
+/// of each file is returned.
+pub(crate) fn get_subtypes(file: &Path, route: &str) -> Result<Vec<JavaStructureEntity>, String> {
+    let base_package = index_project(file)?;
+
+    Ok(get_indexed_subtypes(&base_package, route)
+        .into_iter()
+        .filter(is_main_structure)
+        .collect())
+}
+
+/// # get_indexed_subtypes
+/// Same as "get_subtypes" including nested structures, the project must be already indexed.
+pub(crate) fn get_indexed_subtypes(base_package: &Path, route: &str) -> Vec<JavaStructureEntity> {
+    let mut visited = HashSet::from([route.to_string()]);
+    let mut pending = VecDeque::from([route.to_string()]);
+    let mut subtypes = Vec::new();
+
+    while let Some(current_route) = pending.pop_front() {
+        for subtype in db_java_symbol_search::implementors_of(base_package, &current_route) {
+            if visited.insert(subtype.route.clone()) {
+                pending.push_back(subtype.route.clone());
+                subtypes.push(subtype);
+            }
+        }
+    }
+
+    subtypes
+}
+
+/// # get_inherited_methods
+/// Non private methods of the project supertypes that the structure does not declare itself.
+/// When several supertypes declare the same method, the nearest one is returned.
+pub(crate) fn get_inherited_methods(
+    structure: &JavaStructure,
+    supertypes: &[JavaSupertype],
+) -> Vec<JavaMethod> {
+    let mut known_signatures: HashSet<String> =
+        structure.get_methods().iter().map(get_signature).collect();
+
+    supertypes
+        .iter()
+        .flat_map(get_supertype_methods)
+        .filter(|method| known_signatures.insert(get_signature(method)))
+        .collect()
+}
+
+/// # get_overridden_methods
+/// Methods of the structure declared in any project supertype with the same parameter types.
+/// Methods annotated with "@Override" are also included because their supertype may be
+/// external (i.e. "toString").
+pub(crate) fn get_overridden_methods(
+    file: &Path,
+    route: &str,
+    structure: &JavaStructure,
+    supertypes: &[JavaSupertype],
+) -> Result<Vec<JavaMethod>, String> {
+    let base_package = index_project(file)?;
+    let annotated_signatures = get_annotated_override_signatures(&base_package, route);
+    let supertype_signatures: HashSet<String> = supertypes
+        .iter()
+        .flat_map(get_supertype_methods)
+        .map(|method| get_signature(&method))
+        .collect();
+
+    Ok(structure
+        .get_methods()
+        .iter()
+        .filter(|method| {
+            supertype_signatures.contains(&get_signature(method))
+                || annotated_signatures.contains(&get_source_signature(method))
+        })
+        .cloned()
+        .collect())
+}
+
+/// # check_permitted_subtypes
+/// Permitted subtypes of the sealed structure "sealed_name" written in "folder" must
+/// extend or implement it. Subtypes that are not part of the project are not checked.
+pub(crate) fn check_permitted_subtypes(
+    folder: &Path,
+    sealed_name: &str,
+    permitted_routes: &[String],
+) -> Result<(), String> {
+    if permitted_routes.is_empty() {
+        return Ok(());
+    }
+    let base_package = index_project(folder)?;
+    let sealed_route = match java_package_scanner::get_package_route_opt_from_dir_no_check(folder) {
+        Some(package_route) => format!("{}.{}", package_route, sealed_name),
+        None => sealed_name.to_string(),
+    };
+
+    for permitted_route in permitted_routes {
+        if db_java_symbol_search::structure_by_route(&base_package, permitted_route).is_none() {
+            logger::log_warning(&format!(
+                "Permitted subtype \"{}\" of \"{}\" is not part of the project, it can not be checked",
+                permitted_route, sealed_route
+            ));
+            continue;
+        }
+        let extends_sealed =
+            db_java_symbol_search::supertypes_by_structure_route(&base_package, permitted_route)
+                .iter()
+                .any(|supertype| match &supertype.route {
+                    Some(route) => &sealed_route == route,
+                    None => sealed_name == supertype.name,
+                });
+        if !extends_sealed {
+            return Err(format!(
+                "permitted subtype \"{}\" does not extend or implement \"{}\"",
+                permitted_route, sealed_route
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// The project index is updated before any query, only changed files are scanned again
+fn index_project(file: &Path) -> Result<PathBuf, String> {
+    let base_package = java_package_scanner::get_base_package(file).ok_or(format!(
+        "Java file does not belong to a java project:\n\"{}\"\n",
+        try_to_absolute_path(file)
+    ))?;
+    java_dependency_scanner::recursive_scan_dir_unchecked(&base_package);
+
+    Ok(base_package)
+}
+
+fn is_main_structure(structure: &JavaStructureEntity) -> bool {
+    Path::new(&structure.location.file_path)
+        .file_stem()
+        .is_some_and(|file_stem| file_stem.eq(structure.name.as_str()))
+}
+
+fn get_supertype_methods(supertype: &JavaSupertype) -> Vec<JavaMethod> {
+    let file = match supertype.get_file() {
+        Some(file) => file,
+        None => return vec![],
+    };
+    let simple_name = supertype
+        .get_route()
+        .and_then(|route| route.rsplit('.').next())
+        .unwrap_or_default();
+
+    match JavaFile::from_user_input_path(file) {
+        Ok(java_file) if java_file.get_structure().get_name() == simple_name => java_file
+            .get_structure()
+            .get_methods()
+            .iter()
+            .filter(|method| JavaVisibility::Private != method.get_visibility())
+            .cloned()
+            .collect(),
+        Ok(_) => vec![],
+        Err(err) => {
+            logger::log_warning(&format!(
+                "Methods of supertype \"{}\" could not be scanned:\n{}",
+                supertype.get_name(),
+                err
+            ));
+            vec![]
+        }
+    }
+}
+
+/// Source signatures of the indexed methods annotated with "java.lang.Override", the
+/// annotation belongs to the method declaration containing it so overloads are told apart
+fn get_annotated_override_signatures(base_package: &Path, route: &str) -> HashSet<String> {
+    let methods = db_java_symbol_search::methods_by_structure_route(base_package, route);
+
+    db_java_symbol_search::annotations_by_structure_route(base_package, route)
+        .into_iter()
+        .filter(|annotation| Some(OVERRIDE_ANNOTATION_ROUTE) == annotation.route.as_deref())
+        .filter_map(|annotation| {
+            methods.iter().find(|method| {
+                Some(&method.name) == annotation.member_name.as_ref()
+                    && method.location.file_path == annotation.location.file_path
+                    && method.location.start_byte <= annotation.location.start_byte
+                    && annotation.location.end_byte <= method.location.end_byte
+            })
+        })
+        .map(|method| {
+            let parameter_types: Vec<&str> = method
+                .parameter_types
+                .iter()
+                .map(|parameter_type| get_simple_erasure(parameter_type))
+                .collect();
+            format!("{}({})", method.name, parameter_types.join(","))
+        })
+        .collect()
+}
+
+/// Method name and parameter types as written in java without type arguments,
+/// i.e. "find(List,int)"
+fn get_source_signature(method: &JavaMethod) -> String {
+    let parameter_types: Vec<String> = method
+        .get_parameters()
+        .iter()
+        .map(|parameter| get_simple_erasure(&parameter.get_data_type().to_string()).to_string())
+        .collect();
+
+    format!("{}({})", method.get_name(), parameter_types.join(","))
+}
+
+/// Simple name of a type written in java, i.e. "List" for "java.util.List<String>"
+fn get_simple_erasure(data_type: &str) -> &str {
+    let erasure = data_type.split('<').next().unwrap_or_default().trim();
+    erasure.rsplit('.').next().unwrap_or(erasure)
+}
+
+/// Method name and erasure of its parameter types, i.e. "find(java.util.List,int)"
+fn get_signature(method: &JavaMethod) -> String {
+    let parameter_types: Vec<String> = method
+        .get_parameters()
+        .iter()
+        .map(|parameter| get_erasure(parameter.get_data_type()))
+        .collect();
+
+    format!("{}({})", method.get_name(), parameter_types.join(","))
+}
+
+/// Type without its type arguments, imported types are compared by route
+fn get_erasure(data_type: &JavaDataType) -> String {
+    match data_type.get_import_opt() {
+        Some(import) => import.get_route(),
+        None => data_type
+            .to_string()
+            .split('<')
+            .next()
+            .unwrap_or_default()
+            .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_path;
+    use crate::java::scanner::hierarchy::java_type_hierarchy;
+
+    #[test]
+    fn get_supertypes_with_cycle() {
+        let file = get_test_file("CycleFirst");
+
+        let supertypes = java_type_hierarchy::get_supertypes(&file, "org.test.CycleFirst")
+            .expect("Supertypes expected");
+
+        assert_eq!(1, supertypes.len());
+        assert_eq!(Some("org.test.CycleSecond"), supertypes[0].get_route());
+        assert_eq!(1, supertypes[0].get_depth());
+    }
+
+    #[test]
+    fn get_subtypes_transitively() {
+        let file = get_test_file("BasePort");
+
+        let subtypes = java_type_hierarchy::get_subtypes(&file, "org.test.BasePort")
+            .expect("Subtypes expected");
+
+        let routes: Vec<&str> = subtypes
+            .iter()
+            .map(|subtype| subtype.route.as_str())
+            .collect();
+        assert_eq!(
+            vec![
+                "org.test.PortRepository",
+                "org.test.AbstractRepository",
+                "org.test.DatabaseRepository"
+            ],
+            routes
+        );
+    }
+
+    #[test]
+    fn get_supertypes_outside_project_fails() {
+        let result = java_type_hierarchy::get_supertypes(&get_current_file_path(), "any");
+
+        assert!(result.is_err());
+    }
+
+    /// The hierarchy test project is shared with the JavaClass and JavaInterface tests
+    fn get_test_file(structure_name: &str) -> PathBuf {
+        let mut java_dir = get_current_file_path();
+        java_dir.pop();
+        java_dir.pop();
+        java_dir.pop();
+        test_path::get_java_test_file(
+            java_dir.join("java.rs"),
+            "hierarchy",
+            &format!("{}.java", structure_name),
+        )
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1 @@
+pub mod java_type_hierarchy;
@@ -0,0 +1,988 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::core::database::model::java_symbol::java_symbol_entity::{
+    JavaAnnotationEntity, JavaFieldEntity, JavaFileSymbolsCreate, JavaMethodEntity,
+    JavaReferenceEntity, JavaReferenceKind, JavaStructureEntity, JavaSupertypeEntity,
+    JavaSupertypeRelation, JavaSymbolLocation,
+};
+use crate::core::file_system::file_cache::FileCache;
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::parser::java_node::JavaNode;
+use crate::java::parser::java_node_type::JavaNodeType;
+use crate::java::scanner::index::java_type_resolver::JavaTypeResolver;
+
+/// Variables visible from a member by name
+type JavaVariableScope = HashMap<String, JavaScopeVariable>;
+
+#[derive(Debug, Clone)]
+struct JavaScopeVariable {
+    type_route: Option<String>,
+    /// Route of the structure declaring the variable when it is a field
+    field_of: Option<String>,
+}
+
+struct JavaSymbolIndexer {
+    file_cache: FileCache,
+    resolver: JavaTypeResolver,
+    symbols: JavaFileSymbolsCreate,
+}
+
+struct JavaStructureContext {
+    route: String,
+    superclass_route: Option<String>,
+}
+
+/// # index_file
+/// Structures, members, annotations, supertypes and references declared in a java file.
+/// Variables are tracked per method without block scoping, which is enough to resolve
+/// the target of most method calls.
+pub(crate) fn index_file(file: &Path, base_package: &str) -> Result<JavaFileSymbolsCreate, String> {
+    let program = JavaNode::from_path(file)?;
+    let file_cache = FileCache::from(file);
+    let resolver = JavaTypeResolver::from_program(&program, &file_cache, file);
+    let mut indexer = JavaSymbolIndexer {
+        file_cache,
+        resolver,
+        symbols: JavaFileSymbolsCreate {
+            base_package: base_package.to_string(),
+            file_path: try_to_absolute_path(file),
+            ..Default::default()
+        },
+    };
+
+    let package = indexer.resolver.get_package().map(str::to_string);
+    indexer.index_imports(&program, file, package.as_deref());
+    for structure in program.get_children() {
+        indexer.declare_structure(structure, package.as_deref());
+    }
+    for structure in program.get_children() {
+        indexer.index_structure(structure, package.as_deref(), &JavaVariableScope::new());
+    }
+
+    Ok(indexer.symbols)
+}
+
+// Private methods
+impl JavaSymbolIndexer {
+    fn declare_structure(&mut self, node: &JavaNode, prefix: Option<&str>) {
+        if get_structure_type(node).is_none() {
+            return;
+        }
+        let name = match self.get_child_content(node, JavaNodeType::Id) {
+            Some(name) => name,
+            None => return,
+        };
+        let route = get_route(prefix, &name);
+        self.resolver.add_declared_structure(&name, &route);
+
+        if let Some(body) = get_body(node) {
+            for member in get_members(body) {
+                self.declare_structure(member, Some(&route));
+            }
+        }
+    }
+
+    fn index_structure(
+        &mut self,
+        node: &JavaNode,
+        prefix: Option<&str>,
+        scope: &JavaVariableScope,
+    ) {
+        let structure_type = match get_structure_type(node) {
+            Some(structure_type) => structure_type,
+            None => return,
+        };
+        let name = match self.get_child_content(node, JavaNodeType::Id) {
+            Some(name) => name,
+            None => return,
+        };
+        let route = get_route(prefix, &name);
+        self.symbols.structures.push(JavaStructureEntity {
+            route: route.clone(),
+            name,
+            structure_type: structure_type.to_string(),
+            location: self.get_location(node),
+        });
+
+        let mut superclass_route = None;
+        let mut structure_scope = scope.clone();
+        for child in node.get_children() {
+            match child.get_node_type() {
+                Some(JavaNodeType::Modifiers) => self.index_annotations(child, &route, None),
+                Some(JavaNodeType::Superclass) => {
+                    for type_node in get_type_nodes(child) {
+                        superclass_route = self.resolve_type_node(type_node);
+                        self.index_supertype(type_node, &route, JavaSupertypeRelation::Extends);
+                    }
+                }
+                Some(JavaNodeType::SuperInterfaces) => {
+                    self.index_supertypes(child, &route, JavaSupertypeRelation::Implements)
+                }
+                Some(JavaNodeType::ExtendsInterfaces) => {
+                    self.index_supertypes(child, &route, JavaSupertypeRelation::Extends)
+                }
+                Some(JavaNodeType::FormalParams) => {
+                    self.index_record_components(child, &route, &mut structure_scope)
+                }
+                _ => {}
+            }
+        }
+
+        let context = JavaStructureContext {
+            route,
+            superclass_route,
+        };
+        for child in node.get_children() {
+            if matches!(
+                child.get_node_type(),
+                Some(JavaNodeType::Superclass)
+                    | Some(JavaNodeType::SuperInterfaces)
+                    | Some(JavaNodeType::ExtendsInterfaces)
+            ) {
+                self.index_references(child, &context, &mut JavaVariableScope::new());
+            }
+        }
+
+        if let Some(body) = get_body(node) {
+            let members = get_members(body);
+            for member in &members {
+                if matches!(
+                    member.get_node_type(),
+                    Some(JavaNodeType::FieldDeclaration) | Some(JavaNodeType::ConstantDeclaration)
+                ) {
+                    self.declare_variables(member, &mut structure_scope, Some(&context.route));
+                }
+            }
+            for member in members {
+                self.index_member(member, &context, &structure_scope);
+            }
+        }
+    }
+
+    fn index_member(
+        &mut self,
+        member: &JavaNode,
+        context: &JavaStructureContext,
+        scope: &JavaVariableScope,
+    ) {
+        match member.get_node_type() {
+            Some(JavaNodeType::FieldDeclaration) | Some(JavaNodeType::ConstantDeclaration) => {
+                self.index_field(member, context)
+            }
+            Some(JavaNodeType::MethodDecl)
+            | Some(JavaNodeType::ConstructorDeclaration)
+            | Some(JavaNodeType::AnnotationTypeElementDeclaration) => {
+                self.index_method(member, context)
+            }
+            Some(JavaNodeType::ClassDecl)
+            | Some(JavaNodeType::InterfaceDeclaration)
+            | Some(JavaNodeType::EnumDeclaration)
+            | Some(JavaNodeType::AnnotationTypeDeclaration)
+            | Some(JavaNodeType::RecordDeclaration) => {
+                self.index_structure(member, Some(&context.route), scope);
+                return;
+            }
+            Some(JavaNodeType::StaticInitializer)
+            | Some(JavaNodeType::Block)
+            | Some(JavaNodeType::EnumConstant) => {}
+            _ => return,
+        }
+
+        self.index_references(member, context, &mut scope.clone());
+    }
+
+    fn index_field(&mut self, field: &JavaNode, context: &JavaStructureContext) {
+        let type_node = match get_type_nodes(field).into_iter().next() {
+            Some(type_node) => type_node,
+            None => return,
+        };
+        let data_type = type_node.get_content_from_cache(&self.file_cache);
+        let data_type_route = self.resolver.resolve(&data_type);
+
+        let mut field_names = Vec::new();
+        for declarator in get_children_of_type(field, JavaNodeType::VariableDeclarator) {
+            if let Some(name) = self.get_child_content(declarator, JavaNodeType::Id) {
+                self.symbols.fields.push(JavaFieldEntity {
+                    structure_route: context.route.clone(),
+                    name: name.clone(),
+                    data_type: data_type.clone(),
+                    data_type_route: data_type_route.clone(),
+                    location: self.get_location(declarator),
+                });
+                field_names.push(name);
+            }
+        }
+
+        for modifiers in get_children_of_type(field, JavaNodeType::Modifiers) {
+            for name in &field_names {
+                self.index_annotations(modifiers, &context.route, Some(name));
+            }
+        }
+    }
+
+    fn index_method(&mut self, method: &JavaNode, context: &JavaStructureContext) {
+        let name = match self.get_child_content(method, JavaNodeType::Id) {
+            Some(name) => name,
+            None => return,
+        };
+        let (return_type, return_type_route) = match get_type_nodes(method).into_iter().next() {
+            Some(type_node) => {
+                let return_type = type_node.get_content_from_cache(&self.file_cache);
+                let return_type_route = self.resolver.resolve(&return_type);
+                (return_type, return_type_route)
+            }
+            None => (String::new(), None),
+        };
+        let parameter_types = get_children_of_type(method, JavaNodeType::FormalParams)
+            .into_iter()
+            .flat_map(|params| params.get_children())
+            .filter_map(|param| self.get_parameter_type(param))
+            .collect();
+
+        self.symbols.methods.push(JavaMethodEntity {
+            structure_route: context.route.clone(),
+            name: name.clone(),
+            return_type,
+            return_type_route,
+            parameter_types,
+            location: self.get_location(method),
+        });
+
+        for modifiers in get_children_of_type(method, JavaNodeType::Modifiers) {
+            self.index_annotations(modifiers, &context.route, Some(&name));
+        }
+    }
+
+    fn index_record_components(
+        &mut self,
+        params: &JavaNode,
+        route: &str,
+        scope: &mut JavaVariableScope,
+    ) {
+        for param in get_children_of_type(params, JavaNodeType::FormalParam) {
+            let name = self.get_child_content(param, JavaNodeType::Id);
+            let type_node = get_type_nodes(param).into_iter().next();
+            if let (Some(name), Some(type_node)) = (name, type_node) {
+                let data_type = type_node.get_content_from_cache(&self.file_cache);
+                let data_type_route = self.resolver.resolve(&data_type);
+                scope.insert(
+                    name.clone(),
+                    JavaScopeVariable {
+                        type_route: data_type_route.clone(),
+                        field_of: Some(route.to_string()),
+                    },
+                );
+                self.symbols.fields.push(JavaFieldEntity {
+                    structure_route: route.to_string(),
+                    name,
+                    data_type,
+                    data_type_route,
+                    location: self.get_location(param),
+                });
+            }
+        }
+    }
+
+    fn index_annotations(&mut self, modifiers: &JavaNode, route: &str, member_name: Option<&str>) {
+        for annotation in modifiers.get_children() {
+            if !matches!(
+                annotation.get_node_type(),
+                Some(JavaNodeType::MarkerAnnotation) | Some(JavaNodeType::Annotation)
+            ) {
+                continue;
+            }
+            let name_opt = annotation
+                .get_children()
+                .iter()
+                .find(|child| {
+                    matches!(
+                        child.get_node_type(),
+                        Some(JavaNodeType::Id) | Some(JavaNodeType::ScopedIdentifier)
+                    )
+                })
+                .map(|child| child.get_content_from_cache(&self.file_cache));
+
+            if let Some(name) = name_opt {
+                self.symbols.annotations.push(JavaAnnotationEntity {
+                    structure_route: route.to_string(),
+                    member_name: member_name.map(str::to_string),
+                    route: self.resolver.resolve(&name),
+                    name,
+                    location: self.get_location(annotation),
+                });
+            }
+        }
+    }
+
+    fn index_supertypes(&mut self, node: &JavaNode, route: &str, relation: JavaSupertypeRelation) {
+        for type_list in node.get_children().iter().filter(|child| {
+            matches!(
+                child.get_node_type(),
+                Some(JavaNodeType::TypeList) | Some(JavaNodeType::InterfaceTypeList)
+            )
+        }) {
+            for type_node in get_type_nodes(type_list) {
+                self.index_supertype(type_node, route, relation);
+            }
+        }
+    }
+
+    fn index_supertype(
+        &mut self,
+        type_node: &JavaNode,
+        route: &str,
+        relation: JavaSupertypeRelation,
+    ) {
+        let name = type_node.get_content_from_cache(&self.file_cache);
+        self.symbols.supertypes.push(JavaSupertypeEntity {
+            structure_route: route.to_string(),
+            relation,
+            route: self.resolver.resolve(&name),
+            name,
+            location: self.get_location(type_node),
+        });
+    }
+
+    fn index_references(
+        &mut self,
+        node: &JavaNode,
+        context: &JavaStructureContext,
+        scope: &mut JavaVariableScope,
+    ) {
+        match node.get_node_type() {
+            Some(JavaNodeType::TypeIdentifier) | Some(JavaNodeType::ScopedTypeIdentifier) => {
+                self.add_type_reference(node, context, JavaReferenceKind::Type);
+                return;
+            }
+            Some(JavaNodeType::ObjectCreationExpression) => {
+                if let Some(type_node) = get_type_nodes(node).into_iter().next() {
+                    self.add_type_reference(type_node, context, JavaReferenceKind::ObjectCreation);
+                }
+            }
+            Some(JavaNodeType::MethodInvocation) => self.add_method_call(node, context, scope),
+            Some(JavaNodeType::MethodReference) => {
+                self.add_qualified_reference(node, context, scope, JavaReferenceKind::MethodCall)
+            }
+            Some(JavaNodeType::FieldAccess) => {
+                self.add_qualified_reference(node, context, scope, JavaReferenceKind::FieldAccess)
+            }
+            Some(JavaNodeType::LocalVarDecl)
+            | Some(JavaNodeType::FormalParam)
+            | Some(JavaNodeType::SpreadParameter)
+            | Some(JavaNodeType::EnhancedForStatement)
+            | Some(JavaNodeType::CatchFormalParameter) => self.declare_variables(node, scope, None),
+            _ => {}
+        }
+
+        for (index, child) in node.get_children().iter().enumerate() {
+            if Some(JavaNodeType::Id) == child.get_node_type()
+                && is_expression_identifier(node, index)
+            {
+                self.add_unqualified_field_access(child, context, scope);
+            }
+            self.index_references(child, context, scope);
+        }
+    }
+
+    /// Single type and static imports, wildcard type imports do not reference a type
+    fn index_imports(&mut self, program: &JavaNode, file: &Path, package: Option<&str>) {
+        let file_stem = file
+            .file_stem()
+            .map(|file_stem| file_stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let structure_route = get_route(package, &file_stem);
+
+        for import_decl in get_children_of_type(program, JavaNodeType::ImportDecl) {
+            let children = import_decl.get_children();
+            let is_static = children
+                .iter()
+                .any(|child| Some(JavaNodeType::Static) == child.get_node_type());
+            let is_wildcard = children
+                .iter()
+                .any(|child| Some(JavaNodeType::Asterisk) == child.get_node_type());
+            let route_node = match children.iter().find(|child| {
+                matches!(
+                    child.get_node_type(),
+                    Some(JavaNodeType::ScopedIdentifier) | Some(JavaNodeType::Id)
+                )
+            }) {
+                Some(route_node) => route_node,
+                None => continue,
+            };
+            let route = route_node.get_content_from_cache(&self.file_cache);
+
+            let (name, target_route) = match (is_static, is_wildcard) {
+                (false, true) => continue,
+                (true, true) => ("*".to_string(), route),
+                (_, false) => match route.rsplit_once('.') {
+                    Some((type_route, member_name)) if is_static => {
+                        (member_name.to_string(), type_route.to_string())
+                    }
+                    Some((_, simple_name)) => (simple_name.to_string(), route.clone()),
+                    None => continue,
+                },
+            };
+            self.symbols.references.push(JavaReferenceEntity {
+                structure_route: structure_route.clone(),
+                kind: JavaReferenceKind::Import,
+                name,
+                target_route: Some(target_route),
+                location: self.get_location(route_node),
+            });
+        }
+    }
+
+    fn add_type_reference(
+        &mut self,
+        type_node: &JavaNode,
+        context: &JavaStructureContext,
+        kind: JavaReferenceKind,
+    ) {
+        let type_name = type_node.get_content_from_cache(&self.file_cache);
+        let name = type_name
+            .split('<')
+            .next()
+            .unwrap_or_default()
+            .trim()
+            .to_string();
+        self.symbols.references.push(JavaReferenceEntity {
+            structure_route: context.route.clone(),
+            kind,
+            target_route: self.resolver.resolve(&name),
+            name,
+            location: self.get_location(type_node),
+        });
+    }
+
+    fn add_method_call(
+        &mut self,
+        invocation: &JavaNode,
+        context: &JavaStructureContext,
+        scope: &JavaVariableScope,
+    ) {
+        let children = invocation.get_children();
+        let name_node = match children
+            .iter()
+            .rev()
+            .find(|child| Some(JavaNodeType::Id) == child.get_node_type())
+        {
+            Some(name_node) => name_node,
+            None => return,
+        };
+        let name = name_node.get_content_from_cache(&self.file_cache);
+        let has_object = children
+            .iter()
+            .any(|child| Some(JavaNodeType::Dot) == child.get_node_type());
+
+        let target_route = match children.first() {
+            Some(_) if !has_object => self
+                .resolver
+                .resolve_static_member(&name)
+                .or_else(|| Some(context.route.clone())),
+            Some(object) => {
+                self.add_type_qualifier(object, context, scope);
+                self.get_object_type_route(object, context, scope)
+            }
+            None => None,
+        };
+
+        self.symbols.references.push(JavaReferenceEntity {
+            structure_route: context.route.clone(),
+            kind: JavaReferenceKind::MethodCall,
+            name,
+            target_route,
+            location: self.get_location(name_node),
+        });
+    }
+
+    /// "object.field" and "object::method", the member name is the last identifier
+    fn add_qualified_reference(
+        &mut self,
+        node: &JavaNode,
+        context: &JavaStructureContext,
+        scope: &JavaVariableScope,
+        kind: JavaReferenceKind,
+    ) {
+        let children = node.get_children();
+        let (object, name_node) = match (children.first(), children.last()) {
+            (Some(object), Some(name_node))
+                if children.len() > 2 && Some(JavaNodeType::Id) == name_node.get_node_type() =>
+            {
+                (object, name_node)
+            }
+            _ => return,
+        };
+
+        self.add_type_qualifier(object, context, scope);
+        self.symbols.references.push(JavaReferenceEntity {
+            structure_route: context.route.clone(),
+            kind,
+            name: name_node.get_content_from_cache(&self.file_cache),
+            target_route: self.get_object_type_route(object, context, scope),
+            location: self.get_location(name_node),
+        });
+    }
+
+    /// Fields used without "this", local variables and parameters are not references
+    fn add_unqualified_field_access(
+        &mut self,
+        identifier: &JavaNode,
+        context: &JavaStructureContext,
+        scope: &JavaVariableScope,
+    ) {
+        let name = identifier.get_content_from_cache(&self.file_cache);
+        if let Some(field_of) = scope
+            .get(&name)
+            .and_then(|variable| variable.field_of.clone())
+        {
+            self.symbols.references.push(JavaReferenceEntity {
+                structure_route: context.route.clone(),
+                kind: JavaReferenceKind::FieldAccess,
+                name,
+                target_route: Some(field_of),
+                location: self.get_location(identifier),
+            });
+        }
+    }
+
+    /// Type names used to access static members (i.e. "Objects" in "Objects.equals(a, b)")
+    fn add_type_qualifier(
+        &mut self,
+        object: &JavaNode,
+        context: &JavaStructureContext,
+        scope: &JavaVariableScope,
+    ) {
+        if Some(JavaNodeType::Id) != object.get_node_type() {
+            return;
+        }
+        let name = object.get_content_from_cache(&self.file_cache);
+        if scope.contains_key(&name) || !name.starts_with(char::is_uppercase) {
+            return;
+        }
+        if let Some(target_route) = self.resolver.resolve(&name) {
+            self.symbols.references.push(JavaReferenceEntity {
+                structure_route: context.route.clone(),
+                kind: JavaReferenceKind::Type,
+                name,
+                target_route: Some(target_route),
+                location: self.get_location(object),
+            });
+        }
+    }
+
+    /// Only variables, "this", "super", "this.field" and type names are resolved,
+    /// chained calls and other expressions return None
+    fn get_object_type_route(
+        &self,
+        object: &JavaNode,
+        context: &JavaStructureContext,
+        scope: &JavaVariableScope,
+    ) -> Option<String> {
+        match object.get_node_type() {
+            Some(JavaNodeType::This) => Some(context.route.clone()),
+            Some(JavaNodeType::Super) => context.superclass_route.clone(),
+            Some(JavaNodeType::Id) => {
+                let name = object.get_content_from_cache(&self.file_cache);
+                match scope.get(&name) {
+                    Some(variable) => variable.type_route.clone(),
+                    None if name.starts_with(char::is_uppercase) => self.resolver.resolve(&name),
+                    None => None,
+                }
+            }
+            Some(JavaNodeType::FieldAccess) => {
+                let children = object.get_children();
+                let is_this_field = children
+                    .first()
+                    .is_some_and(|first| Some(JavaNodeType::This) == first.get_node_type());
+                if !is_this_field {
+                    return None;
+                }
+                children
+                    .iter()
+                    .rev()
+                    .find(|child| Some(JavaNodeType::Id) == child.get_node_type())
+                    .and_then(|field| scope.get(&field.get_content_from_cache(&self.file_cache)))
+                    .and_then(|variable| variable.type_route.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Variable names are the first identifier after the type, either directly
+    /// (parameters, for-each loops, catch clauses) or in variable declarators
+    fn declare_variables(
+        &self,
+        node: &JavaNode,
+        scope: &mut JavaVariableScope,
+        field_of: Option<&str>,
+    ) {
+        let type_node_opt = match node.get_node_type() {
+            Some(JavaNodeType::CatchFormalParameter) => {
+                get_children_of_type(node, JavaNodeType::CatchType)
+                    .into_iter()
+                    .flat_map(get_type_nodes)
+                    .next()
+            }
+            _ => get_type_nodes(node).into_iter().next(),
+        };
+        let variable = JavaScopeVariable {
+            type_route: type_node_opt.and_then(|type_node| self.resolve_type_node(type_node)),
+            field_of: field_of.map(str::to_string),
+        };
+
+        let mut after_type = type_node_opt.is_none();
+        for child in node.get_children() {
+            if !after_type {
+                after_type = Some(JavaNodeType::CatchType) == child.get_node_type()
+                    || type_node_opt.is_some_and(|type_node| std::ptr::eq(type_node, child));
+                continue;
+            }
+            match child.get_node_type() {
+                Some(JavaNodeType::Id) => {
+                    scope.insert(child.get_content_from_cache(&self.file_cache), variable);
+                    return;
+                }
+                Some(JavaNodeType::VariableDeclarator) => {
+                    if let Some(name) = self.get_child_content(child, JavaNodeType::Id) {
+                        scope.insert(name, variable.clone());
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn get_parameter_type(&self, param: &JavaNode) -> Option<String> {
+        let type_content = get_type_nodes(param)
+            .into_iter()
+            .next()?
+            .get_content_from_cache(&self.file_cache);
+        match param.get_node_type() {
+            Some(JavaNodeType::FormalParam) => Some(type_content),
+            Some(JavaNodeType::SpreadParameter) => Some(format!("{}...", type_content)),
+            _ => None,
+        }
+    }
+
+    fn resolve_type_node(&self, type_node: &JavaNode) -> Option<String> {
+        self.resolver
+            .resolve(&type_node.get_content_from_cache(&self.file_cache))
+    }
+
+    fn get_child_content(&self, node: &JavaNode, node_type: JavaNodeType) -> Option<String> {
+        node.get_children()
+            .iter()
+            .find(|child| Some(node_type) == child.get_node_type())
+            .map(|child| child.get_content_from_cache(&self.file_cache))
+    }
+
+    fn get_location(&self, node: &JavaNode) -> JavaSymbolLocation {
+        JavaSymbolLocation {
+            file_path: self.symbols.file_path.clone(),
+            start_byte: node.get_start_byte(),
+            end_byte: node.get_end_byte(),
+            line: node.get_start_line(),
+            column: node.get_start_column(),
+        }
+    }
+}
+
+/// Identifiers used as values: variable names in declarations, method names and
+/// lambda parameters are excluded
+fn is_expression_identifier(parent: &JavaNode, index: usize) -> bool {
+    let children = parent.get_children();
+    let is_followed_by_dot = || {
+        index == 0
+            && children
+                .get(1)
+                .is_some_and(|next| Some(JavaNodeType::Dot) == next.get_node_type())
+    };
+    match parent.get_node_type() {
+        Some(JavaNodeType::ArgumentList)
+        | Some(JavaNodeType::BinaryExpression)
+        | Some(JavaNodeType::AssignmentExpression)
+        | Some(JavaNodeType::ReturnStatement)
+        | Some(JavaNodeType::ParenthesizedExpr)
+        | Some(JavaNodeType::UnaryExpression)
+        | Some(JavaNodeType::UpdateExpression)
+        | Some(JavaNodeType::TernaryExpression)
+        | Some(JavaNodeType::CastExpression)
+        | Some(JavaNodeType::ArrayInitializer) => true,
+        Some(JavaNodeType::InstanceofExpression) => index == 0,
+        Some(JavaNodeType::VariableDeclarator) => index > 0,
+        Some(JavaNodeType::EnhancedForStatement) => {
+            index > 0
+                && children
+                    .get(index - 1)
+                    .is_some_and(|previous| Some(JavaNodeType::Colon) == previous.get_node_type())
+        }
+        Some(JavaNodeType::MethodInvocation) | Some(JavaNodeType::FieldAccess) => {
+            is_followed_by_dot()
+        }
+        _ => false,
+    }
+}
+
+fn get_route(prefix: Option<&str>, name: &str) -> String {
+    match prefix {
+        Some(prefix) => format!("{}.{}", prefix, name),
+        None => name.to_string(),
+    }
+}
+
+fn get_structure_type(node: &JavaNode) -> Option<&'static str> {
+    match node.get_node_type() {
+        Some(JavaNodeType::ClassDecl) => Some("class"),
+        Some(JavaNodeType::InterfaceDeclaration) => Some("interface"),
+        Some(JavaNodeType::EnumDeclaration) => Some("enum"),
+        Some(JavaNodeType::RecordDeclaration) => Some("record"),
+        Some(JavaNodeType::AnnotationTypeDeclaration) => Some("annotation"),
+        _ => None,
+    }
+}
+
+fn get_body(structure: &JavaNode) -> Option<&JavaNode> {
+    structure.get_children().iter().find(|child| {
+        matches!(
+            child.get_node_type(),
+            Some(JavaNodeType::ClassBody)
+                | Some(JavaNodeType::InterfaceBody)
+                | Some(JavaNodeType::EnumBody)
+                | Some(JavaNodeType::AnnotationTypeBody)
+        )
+    })
+}
+
+/// Enum members after the constants are wrapped in an enum body declarations node
+fn get_members(body: &JavaNode) -> Vec<&JavaNode> {
+    body.get_children()
+        .iter()
+        .flat_map(|child| match child.get_node_type() {
+            Some(JavaNodeType::EnumBodyDeclarations) => child.get_children().iter().collect(),
+            _ => vec![child],
+        })
+        .collect()
+}
+
+fn get_type_nodes(node: &JavaNode) -> Vec<&JavaNode> {
+    node.get_children()
+        .iter()
+        .filter(|child| {
+            child.is_data_type_identifier()
+                || matches!(
+                    child.get_node_type(),
+                    Some(JavaNodeType::GenericType)
+                        | Some(JavaNodeType::ArrayType)
+                        | Some(JavaNodeType::VoidType)
+                )
+        })
+        .collect()
+}
+
+fn get_children_of_type(node: &JavaNode, node_type: JavaNodeType) -> Vec<&JavaNode> {
+    node.get_children()
+        .iter()
+        .filter(|child| Some(node_type) == child.get_node_type())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::database::model::java_symbol::java_symbol_entity::{
+        JavaReferenceKind, JavaSupertypeRelation,
+    };
+    use crate::java::scanner::index::java_symbol_indexer;
+
+    #[test]
+    fn index_file_test() {
+        let file = get_test_project_file("JavaGreetingService.java");
+
+        let symbols = java_symbol_indexer::index_file(&file, "base_package")
+            .expect("Java file must be indexed");
+
+        let structure_routes: Vec<&str> = symbols
+            .structures
+            .iter()
+            .map(|structure| structure.route.as_str())
+            .collect();
+        assert_eq!(
+            vec![
+                "org.test.JavaGreetingService",
+                "org.test.JavaGreetingService.Tone"
+            ],
+            structure_routes
+        );
+
+        let fields: Vec<(&str, Option<&str>)> = symbols
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.data_type_route.as_deref()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("names", Some("java.util.List")),
+                ("lastEntry", Some("java.util.Map.Entry")),
+                ("lastName", Some("java.util.Optional")),
+            ],
+            fields
+        );
+
+        assert_eq!(1, symbols.methods.len());
+        let method = &symbols.methods[0];
+        assert_eq!("greet", method.name);
+        assert_eq!(
+            Some("java.lang.String"),
+            method.return_type_route.as_deref()
+        );
+        assert_eq!(vec!["String".to_string()], method.parameter_types);
+        assert_eq!(15, method.location.line);
+        assert_eq!(5, method.location.column);
+
+        let annotations: Vec<(Option<&str>, Option<&str>)> = symbols
+            .annotations
+            .iter()
+            .map(|annotation| {
+                (
+                    annotation.member_name.as_deref(),
+                    annotation.route.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (None, Some("org.test.JavaMarker")),
+                (Some("greet"), Some("java.lang.Override")),
+            ],
+            annotations
+        );
+
+        assert_eq!(1, symbols.supertypes.len());
+        assert_eq!(
+            JavaSupertypeRelation::Implements,
+            symbols.supertypes[0].relation
+        );
+        assert_eq!(
+            Some("org.test.JavaGreeting"),
+            symbols.supertypes[0].route.as_deref()
+        );
+
+        let method_calls: Vec<(&str, Option<&str>)> = symbols
+            .references
+            .iter()
+            .filter(|reference| JavaReferenceKind::MethodCall == reference.kind)
+            .map(|reference| (reference.name.as_str(), reference.target_route.as_deref()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("empty", Some("java.util.Optional")),
+                ("requireNonNull", Some("java.util.Objects")),
+                ("add", Some("java.util.List")),
+            ],
+            method_calls
+        );
+
+        let other_references: Vec<(JavaReferenceKind, &str, Option<&str>)> = symbols
+            .references
+            .iter()
+            .filter(|reference| {
+                matches!(
+                    reference.kind,
+                    JavaReferenceKind::Import | JavaReferenceKind::FieldAccess
+                )
+            })
+            .map(|reference| {
+                (
+                    reference.kind,
+                    reference.name.as_str(),
+                    reference.target_route.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    JavaReferenceKind::Import,
+                    "requireNonNull",
+                    Some("java.util.Objects")
+                ),
+                (JavaReferenceKind::Import, "List", Some("java.util.List")),
+                (JavaReferenceKind::Import, "Map", Some("java.util.Map")),
+                (
+                    JavaReferenceKind::FieldAccess,
+                    "names",
+                    Some("org.test.JavaGreetingService")
+                ),
+            ],
+            other_references
+        );
+    }
+
+    #[test]
+    fn index_file_method_call_targets_test() {
+        let file = get_test_project_file("JavaGreetingClient.java");
+
+        let symbols = java_symbol_indexer::index_file(&file, "base_package")
+            .expect("Java file must be indexed");
+
+        let references: Vec<(JavaReferenceKind, &str, Option<&str>)> = symbols
+            .references
+            .iter()
+            .filter(|reference| JavaReferenceKind::Type != reference.kind)
+            .map(|reference| {
+                (
+                    reference.kind,
+                    reference.name.as_str(),
+                    reference.target_route.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            vec![
+                (
+                    JavaReferenceKind::ObjectCreation,
+                    "JavaGreetingService",
+                    Some("org.test.JavaGreetingService")
+                ),
+                (
+                    JavaReferenceKind::ObjectCreation,
+                    "JavaGreetingService",
+                    Some("org.test.JavaGreetingService")
+                ),
+                (
+                    JavaReferenceKind::MethodCall,
+                    "greet",
+                    Some("org.test.JavaGreetingService")
+                ),
+                (
+                    JavaReferenceKind::MethodCall,
+                    "greet",
+                    Some("org.test.JavaGreeting")
+                ),
+                (
+                    JavaReferenceKind::FieldAccess,
+                    "greeting",
+                    Some("org.test.JavaGreetingClient")
+                ),
+            ],
+            references
+        );
+    }
+
+    fn get_test_project_file(file_name: &str) -> PathBuf {
+        let mut file = get_current_file_path();
+        file.pop();
+        file.push("test");
+        file.push("symbol_project");
+        file.push("src/main/java/org/test");
+        file.push(file_name);
+        file
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1,256 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::core::file_system::file_cache::FileCache;
+use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::dependency::jdk::java_jdk_catalog;
+use crate::java::dependency::jdk::java_jdk_catalog::DEFAULT_JAVA_VERSION;
+use crate::java::parser::java_node::JavaNode;
+use crate::java::parser::java_node_type::JavaNodeType;
+
+const PRIMITIVE_TYPES: [&str; 10] = [
+    "boolean", "byte", "char", "short", "int", "long", "float", "double", "void", "var",
+];
+
+/// Resolves the type names written in a java file to their routes (i.e. "List" -> "java.util.List")
+/// following the java shadowing rules: single type imports, types declared in the file,
+/// same package types, wildcard imports and finally java.lang types.
+///
+/// Types that can not be determined return None instead of guessing a route.
+#[derive(Debug)]
+pub(crate) struct JavaTypeResolver {
+    file_dir: PathBuf,
+    source_root: PathBuf,
+    package: Option<String>,
+    single_type_imports: HashMap<String, String>,
+    wildcard_imports: Vec<String>,
+    static_member_imports: HashMap<String, String>,
+    declared_structures: HashMap<String, String>,
+}
+
+// Public crate methods
+impl JavaTypeResolver {
+    pub(crate) fn from_program(program: &JavaNode, file_cache: &FileCache, file: &Path) -> Self {
+        let file_dir = file.parent().map(Path::to_path_buf).unwrap_or_default();
+        let mut resolver = Self {
+            source_root: file_dir.clone(),
+            file_dir,
+            package: None,
+            single_type_imports: HashMap::new(),
+            wildcard_imports: Vec::new(),
+            static_member_imports: HashMap::new(),
+            declared_structures: HashMap::new(),
+        };
+
+        for child in program.get_children() {
+            match child.get_node_type() {
+                Some(JavaNodeType::PackageDecl) => resolver.set_package(child, file_cache),
+                Some(JavaNodeType::ImportDecl) => resolver.add_import(child, file_cache),
+                _ => {}
+            }
+        }
+
+        resolver
+    }
+
+    pub(crate) fn get_package(&self) -> Option<&str> {
+        self.package.as_deref()
+    }
+
+    /// Structures declared in the file shadow any import with the same simple name
+    pub(crate) fn add_declared_structure(&mut self, simple_name: &str, route: &str) {
+        self.declared_structures
+            .entry(simple_name.to_string())
+            .or_insert_with(|| route.to_string());
+    }
+
+    /// Input examples: "List<String>", "Map.Entry", "org.test.JavaClass[]"
+    pub(crate) fn resolve(&self, type_name: &str) -> Option<String> {
+        let base_type_name = get_base_type_name(type_name);
+        if base_type_name.is_empty() || PRIMITIVE_TYPES.contains(&base_type_name) {
+            return None;
+        }
+
+        match base_type_name.split_once('.') {
+            None => self.resolve_simple_name(base_type_name),
+            Some((first, rest)) => match self.resolve_simple_name(first) {
+                Some(first_route) => Some(format!("{}.{}", first_route, rest)),
+                None if first.starts_with(char::is_lowercase) => Some(base_type_name.to_string()),
+                None => None,
+            },
+        }
+    }
+
+    /// Route of the type declaring a statically imported member
+    pub(crate) fn resolve_static_member(&self, member_name: &str) -> Option<String> {
+        self.static_member_imports.get(member_name).cloned()
+    }
+}
+
+// Private methods
+impl JavaTypeResolver {
+    fn set_package(&mut self, package_decl: &JavaNode, file_cache: &FileCache) {
+        let package_opt = package_decl
+            .get_children()
+            .iter()
+            .find(|child| {
+                matches!(
+                    child.get_node_type(),
+                    Some(JavaNodeType::ScopedIdentifier) | Some(JavaNodeType::Id)
+                )
+            })
+            .map(|child| child.get_content_from_cache(file_cache));
+
+        if let Some(package) = package_opt {
+            let mut source_root = self.file_dir.clone();
+            for _ in package.split('.') {
+                source_root.pop();
+            }
+            self.source_root = source_root;
+            self.package = Some(package);
+        }
+    }
+
+    fn add_import(&mut self, import_decl: &JavaNode, file_cache: &FileCache) {
+        let mut is_static = false;
+        let mut is_wildcard = false;
+        let mut route_opt = None;
+        for child in import_decl.get_children() {
+            match child.get_node_type() {
+                Some(JavaNodeType::Static) => is_static = true,
+                Some(JavaNodeType::Asterisk) => is_wildcard = true,
+                Some(JavaNodeType::ScopedIdentifier) | Some(JavaNodeType::Id) => {
+                    route_opt = Some(child.get_content_from_cache(file_cache))
+                }
+                _ => {}
+            }
+        }
+
+        let route = match route_opt {
+            Some(route) => route,
+            None => return,
+        };
+        match (is_static, is_wildcard) {
+            (false, true) => self.wildcard_imports.push(route),
+            (false, false) => {
+                let simple_name = route.rsplit('.').next().unwrap_or(&route).to_string();
+                self.single_type_imports.insert(simple_name, route);
+            }
+            (true, false) => {
+                if let Some((type_route, member_name)) = route.rsplit_once('.') {
+                    self.static_member_imports
+                        .insert(member_name.to_string(), type_route.to_string());
+                }
+            }
+            (true, true) => {}
+        }
+    }
+
+    fn resolve_simple_name(&self, simple_name: &str) -> Option<String> {
+        if let Some(route) = self.single_type_imports.get(simple_name) {
+            return Some(route.clone());
+        }
+        if let Some(route) = self.declared_structures.get(simple_name) {
+            return Some(route.clone());
+        }
+        if self
+            .file_dir
+            .join(format!("{}.java", simple_name))
+            .is_file()
+        {
+            return Some(self.get_same_package_route(simple_name));
+        }
+        for wildcard_import in &self.wildcard_imports {
+            let route = format!("{}.{}", wildcard_import, simple_name);
+            if java_jdk_catalog::find_type_by_route(&route, DEFAULT_JAVA_VERSION).is_some()
+                || self.is_project_type(&route)
+            {
+                return Some(route);
+            }
+        }
+        if java_jdk_catalog::is_java_lang(simple_name, DEFAULT_JAVA_VERSION) {
+            return Some(format!("java.lang.{}", simple_name));
+        }
+
+        None
+    }
+
+    fn get_same_package_route(&self, simple_name: &str) -> String {
+        match &self.package {
+            Some(package) => format!("{}.{}", package, simple_name),
+            None => simple_name.to_string(),
+        }
+    }
+
+    fn is_project_type(&self, route: &str) -> bool {
+        let relative_path = format!("{}.java", route.replace('.', "/"));
+        self.source_root.join(relative_path).is_file()
+    }
+}
+
+/// Type name without generics, array dimensions or varargs
+fn get_base_type_name(type_name: &str) -> &str {
+    type_name
+        .split(['<', '[', ' '])
+        .next()
+        .unwrap_or_default()
+        .trim_end_matches("...")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::core::file_system::file_cache::FileCache;
+    use crate::core::parser::parser_node_trait::ParserNode;
+    use crate::java::parser::java_node::JavaNode;
+    use crate::java::scanner::index::java_type_resolver::JavaTypeResolver;
+
+    #[test]
+    fn resolve_test() {
+        let file = get_test_file();
+        let program = JavaNode::from_path(&file).expect("Java file must be parsed");
+        let resolver = JavaTypeResolver::from_program(&program, &FileCache::from(&file), &file);
+
+        assert_eq!(Some("org.test"), resolver.get_package());
+        assert_eq!(
+            Some("java.util.List".to_string()),
+            resolver.resolve("List<String>")
+        );
+        assert_eq!(
+            Some("java.util.Map.Entry".to_string()),
+            resolver.resolve("Map.Entry<String, Integer>")
+        );
+        assert_eq!(
+            Some("java.util.Optional".to_string()),
+            resolver.resolve("Optional<String>")
+        );
+        assert_eq!(
+            Some("java.lang.String".to_string()),
+            resolver.resolve("String[]")
+        );
+        assert_eq!(
+            Some("org.test.JavaGreeting".to_string()),
+            resolver.resolve("JavaGreeting")
+        );
+        assert_eq!(None, resolver.resolve("int"));
+        assert_eq!(None, resolver.resolve("UnknownType"));
+        assert_eq!(
+            Some("java.util.Objects".to_string()),
+            resolver.resolve_static_member("requireNonNull")
+        );
+    }
+
+    fn get_test_file() -> PathBuf {
+        let mut file = get_current_file_path();
+        file.pop();
+        file.push("test");
+        file.push("symbol_project");
+        file.push("src/main/java/org/test/JavaGreetingService.java");
+        file
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1,2 @@
+pub mod java_symbol_indexer;
+pub mod java_type_resolver;
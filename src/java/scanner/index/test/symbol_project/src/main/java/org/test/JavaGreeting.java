@@ -0,0 +1,5 @@
+package org.test;
+
+public interface JavaGreeting {
+    String greet(String name);
+}
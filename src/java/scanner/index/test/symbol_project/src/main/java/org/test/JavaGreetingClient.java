@@ -0,0 +1,11 @@
+package org.test;
+
+public class JavaGreetingClient {
+    private final JavaGreeting greeting = new JavaGreetingService();
+
+    public String welcome(String name) {
+        JavaGreetingService service = new JavaGreetingService();
+        service.greet(name);
+        return this.greeting.greet(name);
+    }
+}
@@ -0,0 +1,26 @@
+package org.test;
+
+import static java.util.Objects.requireNonNull;
+
+import java.util.List;
+import java.util.Map;
+import java.util.*;
+
+@JavaMarker
+public class JavaGreetingService implements JavaGreeting {
+    private final List<String> names = new ArrayList<>();
+    private Map.Entry<String, Integer> lastEntry;
+    private Optional<String> lastName = Optional.empty();
+
+    @Override
+    public String greet(String name) {
+        requireNonNull(name);
+        names.add(name);
+        return "Hello " + name;
+    }
+
+    enum Tone {
+        FORMAL,
+        CASUAL
+    }
+}
@@ -0,0 +1,4 @@
+package org.test;
+
+public @interface JavaMarker {
+}
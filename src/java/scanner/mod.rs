@@ -1,2 +1,5 @@
 pub mod file;
+pub(crate) mod hierarchy;
+pub(crate) mod index;
 pub mod package;
+pub(crate) mod usage;
@@ -1,21 +1,96 @@
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
 
+use rusqlite::{Error, Transaction};
+
+use crate::core::asynchronous::future_handler;
+use crate::core::database::db;
 use crate::core::database::model::java_import_route::java_import_route_entity::{
     JavaImportRouteCreate, JavaImportRouteEntity,
 };
 use crate::core::database::model::java_import_route::{
-    db_java_import_route_save, db_java_import_route_search,
+    db_java_import_route_delete, db_java_import_route_save, db_java_import_route_search,
+    java_import_route_entity,
 };
-
+use crate::core::database::model::java_scanned_file::java_scanned_file_entity::{
+    JavaScannedFileCreate, JavaScannedFileEntity,
+};
+use crate::core::database::model::java_scanned_file::{
+    db_java_scanned_file_delete, db_java_scanned_file_save, db_java_scanned_file_search,
+};
+use crate::core::database::model::java_symbol::java_symbol_entity::JavaFileSymbolsCreate;
+use crate::core::database::model::java_symbol::{db_java_symbol_delete, db_java_symbol_save};
+use crate::core::file_system::file_hashing::file_hasher;
 use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::core::observability::logger;
+use crate::java::project_scan::{JavaScanProgress, JavaScanSummary};
+use crate::java::scanner::index::java_symbol_indexer;
 use crate::java::scanner::package::java_package_scanner;
 
-/// TODO: optimize this, do not scan previously scanned dirs
-/// - Current approach: rescan always
-/// - Is it possible to detect any change within a directory to avoid rescan?
-pub(crate) fn recursive_scan_dir_unchecked(base_java_project_dir: &Path) {
-    recursive_scan(base_java_project_dir);
+enum JavaFileScanStatus {
+    Added,
+    Changed,
+    Unchanged,
+}
+
+struct JavaFileScan {
+    file: PathBuf,
+    status: JavaFileScanStatus,
+    scanned_file_to_save: Option<JavaScannedFileCreate>,
+    symbols: Option<JavaFileSymbolsCreate>,
+}
+
+/// Only files added, changed or removed since the previous scan of the project are re-indexed.
+/// A file is considered unchanged if its modification time and size are the same ones stored
+/// in the database, otherwise its content hash is compared. Scan errors are only logged.
+pub(crate) fn recursive_scan_dir_unchecked(base_java_project_dir: &Path) -> JavaScanSummary {
+    recursive_scan_dir_with_progress(base_java_project_dir, &|_| {}).unwrap_or_else(|err| {
+        logger::log_warning(&err);
+        JavaScanSummary::default()
+    })
+}
+
+/// Java files are processed concurrently in the shared runtime and all database changes
+/// are stored in a single transaction. The result and the progress reports do not depend
+/// on the task scheduling: files are always handled in path order.
+pub(crate) fn recursive_scan_dir_with_progress(
+    base_java_project_dir: &Path,
+    on_progress: &dyn Fn(&JavaScanProgress),
+) -> Result<JavaScanSummary, String> {
+    if !base_java_project_dir.is_dir() {
+        return Err(format!(
+            "Java project directory expected:\n\"{}\"\n",
+            try_to_absolute_path(base_java_project_dir)
+        ));
+    }
+    let base_package = try_to_absolute_path(base_java_project_dir);
+    let mut previous_scan: HashMap<String, JavaScannedFileEntity> =
+        db_java_scanned_file_search::by_base_package(&base_package)
+            .into_iter()
+            .map(|scanned_file| (scanned_file.get_file_path(), scanned_file))
+            .collect();
+
+    let mut java_files = Vec::new();
+    recursive_get_java_files(base_java_project_dir, &mut java_files)?;
+    java_files.sort();
+
+    let file_scans = scan_files(java_files, &base_package, &mut previous_scan, on_progress);
+    let mut deleted_files: Vec<JavaScannedFileEntity> = previous_scan.into_values().collect();
+    deleted_files.sort_by_key(|deleted_file| deleted_file.get_file_path());
+
+    db::execute_in_transaction(|transaction| {
+        save_scan_in_transaction(transaction, &file_scans, &deleted_files)
+    })
+    .map_err(|err| {
+        format!(
+            "Java project scan could not be stored:\n\"{}\"\n{}",
+            base_package, err
+        )
+    })?;
+
+    Ok(get_summary(file_scans, deleted_files))
 }
 
 ///
@@ -37,31 +112,208 @@ pub(crate) fn search_imports(import_route: &str, java_file: &Path) -> Vec<JavaIm
     vec![]
 }
 
-fn recursive_scan(path: &Path) {
-    let files_and_dirs = get_files_and_dirs_to_scan(path);
-    insert_java_import_routes_in_db(files_and_dirs.0);
+fn recursive_get_java_files(path: &Path, java_files: &mut Vec<PathBuf>) -> Result<(), String> {
+    let (files, dirs) = get_files_and_dirs_to_scan(path)?;
+    java_files.extend(files);
+
+    for dir in dirs {
+        recursive_get_java_files(&dir, java_files)?;
+    }
+    Ok(())
+}
+
+fn scan_files(
+    java_files: Vec<PathBuf>,
+    base_package: &str,
+    previous_scan: &mut HashMap<String, JavaScannedFileEntity>,
+    on_progress: &dyn Fn(&JavaScanProgress),
+) -> Vec<JavaFileScan> {
+    let total_files = java_files.len();
+    let runtime = future_handler::get_runtime();
+    let handles: Vec<_> = java_files
+        .into_iter()
+        .map(|file| {
+            let previous_scan_opt = previous_scan.remove(&try_to_absolute_path(&file));
+            let base_package = base_package.to_string();
+            runtime.spawn_blocking(move || scan_file(file, &base_package, previous_scan_opt))
+        })
+        .collect();
+
+    future_handler::wait(async {
+        let mut file_scans = Vec::with_capacity(total_files);
+        for (index, handle) in handles.into_iter().enumerate() {
+            match handle.await {
+                Ok(Ok(file_scan)) => file_scans.push(file_scan),
+                Ok(Err(err)) => logger::log_warning(&err),
+                Err(err) => logger::log_warning(&format!("Java file scan task failed: {}", err)),
+            }
+            on_progress(&JavaScanProgress::new(index + 1, total_files));
+        }
+        file_scans
+    })
+}
+
+fn scan_file(
+    file: PathBuf,
+    base_package: &str,
+    previous_scan_opt: Option<JavaScannedFileEntity>,
+) -> Result<JavaFileScan, String> {
+    let metadata = fs::metadata(&file).map_err(|err| {
+        format!(
+            "Error reading java file metadata ({}):\n\"{}\"\n",
+            err,
+            try_to_absolute_path(&file)
+        )
+    })?;
+    let size = metadata.len() as i64;
+    let modified_time = get_modified_time(&metadata);
+    if let Some(previous) = &previous_scan_opt {
+        if previous.get_modified_time() == modified_time && previous.get_size() == size {
+            return Ok(JavaFileScan {
+                file,
+                status: JavaFileScanStatus::Unchanged,
+                scanned_file_to_save: None,
+                symbols: None,
+            });
+        }
+    }
+
+    let file_path = try_to_absolute_path(&file);
+    let scanned_file = JavaScannedFileCreate {
+        base_package: base_package.to_string(),
+        route: java_import_route_entity::get_import_route(base_package, &file_path)
+            .ok_or(format!("Invalid java file route:\n\"{}\"\n", file_path))?,
+        file_path,
+        modified_time,
+        size,
+        content_hash: file_hasher::get_content_hash(&file)?,
+    };
+
+    let status = match previous_scan_opt {
+        None => JavaFileScanStatus::Added,
+        Some(previous) if previous.get_content_hash() == scanned_file.content_hash => {
+            JavaFileScanStatus::Unchanged
+        }
+        Some(_) => JavaFileScanStatus::Changed,
+    };
+    let symbols = match status {
+        JavaFileScanStatus::Unchanged => None,
+        _ => index_symbols(&file, base_package),
+    };
+
+    Ok(JavaFileScan {
+        file,
+        status,
+        scanned_file_to_save: Some(scanned_file),
+        symbols,
+    })
+}
+
+/// A file whose symbols can not be indexed keeps its import route and no symbols
+fn index_symbols(file: &Path, base_package: &str) -> Option<JavaFileSymbolsCreate> {
+    match java_symbol_indexer::index_file(file, base_package) {
+        Ok(symbols) => Some(symbols),
+        Err(err) => {
+            logger::log_warning(&format!("Java file symbols could not be indexed: {}", err));
+            None
+        }
+    }
+}
+
+fn get_modified_time(metadata: &fs::Metadata) -> i64 {
+    metadata
+        .modified()
+        .ok()
+        .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_nanos() as i64)
+        .unwrap_or(0)
+}
 
-    for dir in files_and_dirs.1 {
-        recursive_scan(&dir);
+fn save_scan_in_transaction(
+    transaction: &Transaction,
+    file_scans: &[JavaFileScan],
+    deleted_files: &[JavaScannedFileEntity],
+) -> Result<(), Error> {
+    for scanned_file in file_scans
+        .iter()
+        .filter_map(|file_scan| file_scan.scanned_file_to_save.as_ref())
+    {
+        db_java_scanned_file_save::save_in_transaction(transaction, scanned_file)?;
+    }
+
+    let files_to_index: Vec<PathBuf> = file_scans
+        .iter()
+        .filter(|file_scan| !matches!(file_scan.status, JavaFileScanStatus::Unchanged))
+        .map(|file_scan| file_scan.file.clone())
+        .collect();
+    let routes_to_save: Vec<JavaImportRouteCreate> = JavaImportRouteCreate::from(files_to_index);
+    db_java_import_route_save::save_in_transaction(transaction, &routes_to_save)?;
+
+    for file_scan in file_scans
+        .iter()
+        .filter(|file_scan| !matches!(file_scan.status, JavaFileScanStatus::Unchanged))
+    {
+        db_java_symbol_delete::by_file_path_in_transaction(
+            transaction,
+            &try_to_absolute_path(&file_scan.file),
+        )?;
+        if let Some(symbols) = &file_scan.symbols {
+            db_java_symbol_save::save_in_transaction(transaction, symbols)?;
+        }
     }
+
+    for deleted_file in deleted_files {
+        db_java_import_route_delete::by_base_package_and_route_in_transaction(
+            transaction,
+            &deleted_file.get_base_package(),
+            &deleted_file.get_route(),
+        )?;
+        db_java_scanned_file_delete::by_file_path_in_transaction(
+            transaction,
+            &deleted_file.get_file_path(),
+        )?;
+        db_java_symbol_delete::by_file_path_in_transaction(
+            transaction,
+            &deleted_file.get_file_path(),
+        )?;
+    }
+
+    Ok(())
 }
 
-fn insert_java_import_routes_in_db(java_files: Vec<PathBuf>) {
-    let routes_to_save: Vec<JavaImportRouteCreate> = JavaImportRouteCreate::from(java_files);
+fn get_summary(
+    file_scans: Vec<JavaFileScan>,
+    deleted_files: Vec<JavaScannedFileEntity>,
+) -> JavaScanSummary {
+    let mut added = Vec::new();
+    let mut changed = Vec::new();
+    let mut unchanged = 0;
+    for file_scan in file_scans {
+        match file_scan.status {
+            JavaFileScanStatus::Added => added.push(file_scan.file),
+            JavaFileScanStatus::Changed => changed.push(file_scan.file),
+            JavaFileScanStatus::Unchanged => unchanged += 1,
+        }
+    }
+    let removed = deleted_files
+        .iter()
+        .map(|deleted_file| PathBuf::from(deleted_file.get_file_path()))
+        .collect();
 
-    db_java_import_route_save::save(routes_to_save)
-        .expect("JavaImportRoute batch save must succeed")
+    JavaScanSummary::new(added, changed, removed, unchanged)
 }
 
-fn get_files_and_dirs_to_scan(path: &Path) -> (Vec<PathBuf>, Vec<PathBuf>) {
+fn get_files_and_dirs_to_scan(path: &Path) -> Result<(Vec<PathBuf>, Vec<PathBuf>), String> {
     let mut files = Vec::new();
     let mut dirs = Vec::new();
-    let paths_result = fs::read_dir(path).unwrap_or_else(|_| {
-        panic!(
-            "Error scanning directory:\n\"{}\"\n",
+    let scan_error = |err: std::io::Error| {
+        format!(
+            "Error scanning directory ({}):\n\"{}\"\n",
+            err,
             try_to_absolute_path(path)
         )
-    });
+    };
+    let paths_result = fs::read_dir(path).map_err(scan_error)?;
 
     for dir_entry_result in paths_result {
         match dir_entry_result {
@@ -75,11 +327,11 @@ fn get_files_and_dirs_to_scan(path: &Path) -> (Vec<PathBuf>, Vec<PathBuf>) {
                     dirs.push(path);
                 }
             }
-            Err(_e) => panic!("Error get_files_and_dirs_to_scan"),
+            Err(err) => return Err(scan_error(err)),
         }
     }
 
-    (files, dirs)
+    Ok((files, dirs))
 }
 
 fn is_java_file(file_name: &Path) -> bool {
@@ -91,9 +343,12 @@ fn is_java_file(file_name: &Path) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use std::cell::RefCell;
+    use std::fs;
     use std::path::PathBuf;
 
     use crate::core::database::model::java_import_route::db_java_import_route_search;
+    use crate::core::file_system::file_edition::file_editor;
     use crate::core::testing::test_path::get_test_dir;
     use crate::java::scanner::package::java_dependency_scanner;
 
@@ -114,16 +369,82 @@ mod tests {
         }
     }
 
+    #[test]
+    fn incremental_scan_test() {
+        let dir_path = get_local_test_dir().join("incremental_project");
+        let file_path = dir_path.join("src/main/java/org/test/IncrementalScanAdded.java");
+        file_editor::remove_file_if_exists(&file_path).expect("Previous file must be removed");
+        java_dependency_scanner::recursive_scan_dir_unchecked(&dir_path);
+
+        fs::write(
+            &file_path,
+            "package org.test;\n\npublic class IncrementalScanAdded {}\n",
+        )
+        .expect("Java file must be created");
+        let added_summary = java_dependency_scanner::recursive_scan_dir_unchecked(&dir_path);
+        let unchanged_summary = java_dependency_scanner::recursive_scan_dir_unchecked(&dir_path);
+        fs::write(
+            &file_path,
+            "package org.test;\n\npublic class IncrementalScanAdded {\n}\n",
+        )
+        .expect("Java file must be modified");
+        let changed_summary = java_dependency_scanner::recursive_scan_dir_unchecked(&dir_path);
+        fs::remove_file(&file_path).expect("Java file must be removed");
+        let removed_summary = java_dependency_scanner::recursive_scan_dir_unchecked(&dir_path);
+
+        assert_eq!(1, added_summary.get_added().len());
+        assert!(added_summary.get_changed().is_empty());
+        assert!(unchanged_summary.get_added().is_empty());
+        assert!(unchanged_summary.get_changed().is_empty());
+        assert_eq!(2, unchanged_summary.get_unchanged());
+        assert_eq!(1, changed_summary.get_changed().len());
+        assert_eq!(1, removed_summary.get_removed().len());
+        assert!(removed_summary.get_removed()[0].ends_with("IncrementalScanAdded.java"));
+        assert!(db_java_import_route_search::by_last_type_id("IncrementalScanAdded").is_empty());
+    }
+
+    #[test]
+    fn scan_progress_test() {
+        let dir_path = get_local_test_dir().join("basic_project");
+        let reported_progress = RefCell::new(Vec::new());
+
+        java_dependency_scanner::recursive_scan_dir_with_progress(&dir_path, &|progress| {
+            reported_progress
+                .borrow_mut()
+                .push((progress.get_scanned_files(), progress.get_total_files()))
+        })
+        .expect("Java project must be scanned");
+
+        let reported_progress = reported_progress.into_inner();
+        assert!(!reported_progress.is_empty());
+        for (index, (scanned_files, total_files)) in reported_progress.iter().enumerate() {
+            assert_eq!(index + 1, *scanned_files);
+            assert_eq!(reported_progress.len(), *total_files);
+        }
+    }
+
     #[test]
     fn get_files_and_dirs_to_scan_test() {
         let dir_path = get_local_test_dir().join("basic_project");
 
-        let (files, dirs) = java_dependency_scanner::get_files_and_dirs_to_scan(&dir_path);
+        let (files, dirs) = java_dependency_scanner::get_files_and_dirs_to_scan(&dir_path)
+            .expect("Directory must be readable");
 
         assert_eq!(0, files.len());
         assert_eq!(1, dirs.len());
     }
 
+    #[test]
+    fn scan_missing_dir_fails() {
+        let dir_path = get_local_test_dir().join("missing_project");
+
+        let result = java_dependency_scanner::recursive_scan_dir_with_progress(&dir_path, &|_| {});
+
+        let err = result.expect_err("Missing directory must not be scanned");
+        assert!(err.contains("Java project directory expected"));
+        assert!(err.contains("missing_project"));
+    }
+
     fn get_local_test_dir() -> PathBuf {
         get_test_dir(get_current_file_path(), "java_dependency_scanner")
     }
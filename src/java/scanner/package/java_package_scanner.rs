@@ -93,7 +93,7 @@ pub(crate) fn find_package_start_end_bytes(
     Ok(package_search_bytes)
 }
 
-fn contains_base_java_project_build_file(path: &Path) -> bool {
+pub(crate) fn contains_base_java_project_build_file(path: &Path) -> bool {
     let files = vec!["build.gradle", "pom.xml"];
 
     file_browser::get_first_file_from_dir_if_exists(path, files).is_some()
@@ -0,0 +1,116 @@
+use std::path::{Path, PathBuf};
+
+use crate::core::database::model::java_symbol::db_java_symbol_search;
+use crate::core::database::model::java_symbol::java_symbol_entity::{
+    JavaReferenceKind, JavaSymbolLocation,
+};
+use crate::core::file_system::path_helper::try_to_absolute_path;
+use crate::java::scanner::hierarchy::java_type_hierarchy;
+use crate::java::scanner::package::{java_dependency_scanner, java_package_scanner};
+use crate::java::usage::{JavaUsage, JavaUsageKind};
+
+const MEMBER_SEPARATOR: char = '#';
+
+/// # find_usages
+/// Symbols are a type route, optionally followed by "#" and a member name.
+/// Results are sorted by file and position.
+pub(crate) fn find_usages(project_dir: &Path, symbol: &str) -> Result<Vec<JavaUsage>, String> {
+    if !project_dir.is_dir()
+        || !java_package_scanner::contains_base_java_project_build_file(project_dir)
+    {
+        return Err(format!(
+            "Java project with pom.xml or build.gradle expected:\n\"{}\"\n",
+            try_to_absolute_path(project_dir)
+        ));
+    }
+    let (type_route, member_opt) = parse_symbol(symbol)?;
+    java_dependency_scanner::recursive_scan_dir_with_progress(project_dir, &|_| {})?;
+
+    let mut usages = match member_opt {
+        Some(member) => find_member_usages(project_dir, type_route, member),
+        None => find_type_usages(project_dir, type_route),
+    };
+    usages.sort_by(|first, second| {
+        (first.get_file(), first.get_start_byte())
+            .cmp(&(second.get_file(), second.get_start_byte()))
+    });
+    usages.dedup();
+
+    Ok(usages)
+}
+
+/// Object creations are skipped because the type name is already a type reference
+fn find_type_usages(project_dir: &Path, type_route: &str) -> Vec<JavaUsage> {
+    let references = db_java_symbol_search::references_to(project_dir, type_route, None)
+        .into_iter()
+        .filter_map(|reference| match reference.kind {
+            JavaReferenceKind::Type => Some((reference.location, JavaUsageKind::TypeReference)),
+            JavaReferenceKind::Import => Some((reference.location, JavaUsageKind::Import)),
+            _ => None,
+        });
+    let annotations = db_java_symbol_search::annotations_by_route(project_dir, type_route)
+        .into_iter()
+        .map(|annotation| (annotation.location, JavaUsageKind::Annotation));
+
+    references
+        .chain(annotations)
+        .map(|(location, kind)| to_usage(location, kind))
+        .collect()
+}
+
+/// Members accessed through a subtype (i.e. "service.greet()" with "greet" declared in an
+/// implemented interface) are usages of the member too
+fn find_member_usages(project_dir: &Path, type_route: &str, member: &str) -> Vec<JavaUsage> {
+    let mut routes = vec![type_route.to_string()];
+    routes.extend(
+        java_type_hierarchy::get_indexed_subtypes(project_dir, type_route)
+            .into_iter()
+            .map(|subtype| subtype.route),
+    );
+
+    routes
+        .iter()
+        .flat_map(|route| db_java_symbol_search::references_to(project_dir, route, Some(member)))
+        .filter_map(|reference| {
+            let kind = match reference.kind {
+                JavaReferenceKind::MethodCall => JavaUsageKind::Invocation,
+                JavaReferenceKind::FieldAccess => JavaUsageKind::FieldAccess,
+                JavaReferenceKind::Import => JavaUsageKind::Import,
+                JavaReferenceKind::Type | JavaReferenceKind::ObjectCreation => return None,
+            };
+            Some(to_usage(reference.location, kind))
+        })
+        .collect()
+}
+
+fn parse_symbol(symbol: &str) -> Result<(&str, Option<&str>), String> {
+    let (type_route, member_opt) = match symbol.trim().split_once(MEMBER_SEPARATOR) {
+        Some((type_route, member)) => (type_route, Some(member)),
+        None => (symbol.trim(), None),
+    };
+    let is_valid_route = !type_route.is_empty()
+        && type_route
+            .split('.')
+            .all(|part| !part.is_empty() && !part.contains(char::is_whitespace));
+    let is_valid_member =
+        member_opt.is_none_or(|member| !member.is_empty() && !member.contains(char::is_whitespace));
+    if !is_valid_route || !is_valid_member {
+        return Err(format!(
+            "Invalid java symbol \"{}\", expected \"org.test.JavaClass\" or \"org.test.JavaClass#member\"",
+            symbol
+        ));
+    }
+
+    Ok((type_route, member_opt))
+}
+
+fn to_usage(location: JavaSymbolLocation, kind: JavaUsageKind) -> JavaUsage {
+    JavaUsage::new(
+        PathBuf::from(location.file_path),
+        location.start_byte,
+        location.end_byte,
+        location.line,
+        location.column,
+        kind,
+    )
+}
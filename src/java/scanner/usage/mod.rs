@@ -0,0 +1 @@
+pub mod java_usage_finder;
@@ -0,0 +1,57 @@
+use std::path::{Path, PathBuf};
+
+/// # JavaSupertype
+/// Class or interface extended or implemented, directly or transitively, by a java structure.
+/// External types (i.e. JDK or library types) have no file in the project and types
+/// that could not be resolved from the imports have no route.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JavaSupertype {
+    name: String,
+    route: Option<String>,
+    file: Option<PathBuf>,
+    depth: usize,
+}
+
+impl JavaSupertype {
+    // Public methods
+    /// # get_name
+    /// Name as written in the source code (i.e. "Comparable<String>").
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    /// # get_route
+    /// Fully qualified name (i.e. "java.lang.Comparable") if it could be resolved.
+    pub fn get_route(&self) -> Option<&str> {
+        self.route.as_deref()
+    }
+
+    /// # get_file
+    /// Java file declaring the supertype if it belongs to the same project.
+    pub fn get_file(&self) -> Option<&Path> {
+        self.file.as_deref()
+    }
+
+    /// # get_depth
+    /// Distance to the structure, direct supertypes have depth 1.
+    pub fn get_depth(&self) -> usize {
+        self.depth
+    }
+}
+
+impl JavaSupertype {
+    // Crate or private methods
+    pub(crate) fn new(
+        name: String,
+        route: Option<String>,
+        file: Option<PathBuf>,
+        depth: usize,
+    ) -> Self {
+        Self {
+            name,
+            route,
+            file,
+            depth,
+        }
+    }
+}
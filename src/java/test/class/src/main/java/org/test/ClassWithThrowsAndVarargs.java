@@ -0,0 +1,12 @@
+package org.test;
+
+import java.io.IOException;
+import java.util.UUID;
+import org.springframework.web.bind.annotation.PathVariable;
+
+public class ClassWithThrowsAndVarargs {
+
+    public String find(@PathVariable final UUID id, String... names) throws IOException, IllegalStateException {
+        return id.toString();
+    }
+}
@@ -0,0 +1,22 @@
+package org.test;
+
+/**
+ * Service generated with javadoc.
+ */
+public class DocumentedService {
+    // Flag used by the service
+    /**
+     * Whether the service is enabled.
+     */
+    private boolean field;
+
+    /**
+     * New method.
+     *
+     * @param value input value
+     * @return the result
+     * @throws IllegalArgumentException if the value is negative
+     */
+    int newMethod(final int value) {
+    }
+}
@@ -0,0 +1,35 @@
+/*
+ * License header
+ */
+package org.test.comments;
+
+import java.util.UUID;
+
+// Generated from the user schema
+/**
+ * Service with comments.
+ *
+ * @see java.util.UUID
+ */
+public class CommentedService { // Do not edit
+
+    int newMethod() {
+    }
+
+
+    // Identifier of the service
+    private UUID id; // Never null
+
+    private String name;
+
+    /**
+     * Get the identifier.
+     *
+     * @param prefix prefix of the id
+     * @return the identifier
+     * @throws IllegalStateException when the id is missing
+     */
+    public String getId(String prefix) {
+        return prefix + id;
+    }
+}
@@ -0,0 +1,4 @@
+package org.test.sealed;
+
+public abstract sealed class Figure permits org.test.sealed.flat.Circle, org.test.sealed.solid.Circle {
+}
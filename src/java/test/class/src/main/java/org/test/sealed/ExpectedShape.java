@@ -0,0 +1,7 @@
+package org.test.sealed;
+
+import org.test.sealed.Circle;
+import org.test.sealed.Square;
+
+public abstract sealed class Shape permits Circle, Square {
+}
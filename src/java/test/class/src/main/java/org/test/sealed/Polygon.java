@@ -0,0 +1,4 @@
+package org.test.sealed;
+
+public sealed interface Polygon permits Square {
+}
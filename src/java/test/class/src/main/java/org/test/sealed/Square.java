@@ -0,0 +1,4 @@
+package org.test.sealed;
+
+public non-sealed class Square extends Shape implements Polygon {
+}
@@ -0,0 +1,6 @@
+package org.test.sealed.flat;
+
+import org.test.sealed.Figure;
+
+public final class Circle extends Figure {
+}
@@ -0,0 +1,16 @@
+package org.test;
+
+import org.junit.jupiter.api.Test;
+import org.springframework.beans.factory.annotation.Autowired;
+import org.springframework.stereotype.Service;
+
+@Service
+public class StyledService
+{
+  @Autowired private String field;
+
+
+  @Test int newMethod()
+  {
+  }
+}
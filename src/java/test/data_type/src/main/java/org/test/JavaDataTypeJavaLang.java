@@ -0,0 +1,8 @@
+package org.test;
+
+public class JavaDataTypeJavaLang {
+
+    public Object getValue(Runnable task) {
+    }
+
+}
@@ -0,0 +1,10 @@
+package org.test;
+
+import java.util.List;
+
+public class JavaDataTypeWildcard {
+
+    public void sum(List<? extends Number> numbers) {
+    }
+
+}
@@ -0,0 +1,8 @@
+package org.test;
+
+public enum Currency {
+    EUR,
+    USD;
+
+    private String symbol;
+}
@@ -0,0 +1,6 @@
+package org.test;
+
+public enum Status {
+    ACTIVE,
+    INACTIVE
+}
@@ -0,0 +1,12 @@
+package org.test;
+
+import com.external.*;
+
+public abstract class AbstractRepository implements PortRepository, AutoCloseable, ExternalPort {
+    public void close() {
+    }
+
+    protected String describe() {
+        return "repository";
+    }
+}
@@ -0,0 +1,4 @@
+package org.test;
+
+public class CycleFirst extends CycleSecond {
+}
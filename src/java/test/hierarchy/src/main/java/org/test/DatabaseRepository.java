@@ -0,0 +1,23 @@
+package org.test;
+
+public class DatabaseRepository extends AbstractRepository {
+    @Override
+    public String find(String id) {
+        return id;
+    }
+
+    @Override
+    public String toString() {
+        return "database";
+    }
+
+    public String find(Long id) {
+        return id.toString();
+    }
+
+    public void close(boolean force) {
+    }
+
+    public void migrate() {
+    }
+}
@@ -0,0 +1,5 @@
+package org.test;
+
+public interface PortRepository extends BasePort {
+    String find(String id);
+}
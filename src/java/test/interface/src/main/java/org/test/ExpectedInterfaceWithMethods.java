@@ -0,0 +1,11 @@
+package org.test;
+
+public interface InterfaceWithMethods {
+    String getName();
+
+    default boolean isEmpty() {
+    }
+
+    static int count() {
+    }
+}
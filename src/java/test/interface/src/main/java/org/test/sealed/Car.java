@@ -0,0 +1,4 @@
+package org.test.sealed;
+
+public final class Car implements Vehicle {
+}
@@ -0,0 +1,6 @@
+package org.test.sealed;
+
+import org.test.sealed.Car;
+
+public sealed interface Vehicle permits Car {
+}
@@ -0,0 +1,2 @@
+public String find(@PathVariable("id") final int id, @RequestParam(value = "page", required = false) Integer page) {
+}
@@ -0,0 +1,2 @@
+public String find(@PathVariable final int id, String... names) throws IOException, IllegalStateException {
+}
@@ -0,0 +1,88 @@
+use std::path::{Path, PathBuf};
+
+use crate::domain::usecase::avro::registry::avro_registry::AvroRegistry;
+use crate::domain::usecase::java::translator::from_avro::java_from_avro_translator;
+pub use crate::domain::usecase::java::translator::from_avro::java_from_avro_translator::JavaNullableStyle;
+use crate::domain::usecase::java::translator::from_openapi::java_from_openapi_translator;
+use crate::domain::usecase::openapi::parser::openapi_parser;
+
+/// # avro_to_java
+/// Write the java classes and enums of the Avro named types found in "avro_dir" and its
+/// subdirectories. Every type is written in "source_dir" (i.e. "src/main/java") inside the
+/// package of its namespace. It returns the written files, dependencies first.
+pub fn avro_to_java(
+    avro_dir: &Path,
+    source_dir: &Path,
+    nullable_style: JavaNullableStyle,
+) -> Result<Vec<PathBuf>, String> {
+    let registry = AvroRegistry::from_dir(avro_dir)?;
+    java_from_avro_translator::avro_to_java(&registry, source_dir, nullable_style)
+}
+
+/// # openapi_to_spring
+/// Write a spring "@RestController" interface in "controller_folder" for every tag of the
+/// operations of an OpenAPI document, and the classes and enums of the schemas they use in
+/// "dto_folder". Only the files of a previous generation are overwritten.
+/// It returns the written files, the DTOs first.
+pub fn openapi_to_spring(
+    openapi_file: &Path,
+    controller_folder: &Path,
+    dto_folder: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let document = openapi_parser::parse(openapi_file)?;
+    java_from_openapi_translator::openapi_to_spring(&document, controller_folder, dto_folder)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::java::translation::{self, JavaNullableStyle};
+
+    #[test]
+    fn avro_to_java_invalid_input() {
+        let avro_dir = get_translator_test_dir("from_avro").join("avro");
+
+        assert!(translation::avro_to_java(
+            &get_current_file_path(),
+            &avro_dir,
+            JavaNullableStyle::Nullable
+        )
+        .is_err());
+        assert!(translation::avro_to_java(
+            &avro_dir,
+            &avro_dir.join("order.avsc"),
+            JavaNullableStyle::Nullable
+        )
+        .is_err_and(|err| err.contains("Expected java source directory")));
+    }
+
+    #[test]
+    fn openapi_to_spring_invalid_input() {
+        let openapi_file = get_translator_test_dir("from_openapi").join("openapi/shop.yaml");
+        let mut folder = get_current_file_path();
+        folder.pop();
+
+        assert!(
+            translation::openapi_to_spring(&get_current_file_path(), &folder, &folder).is_err()
+        );
+        assert!(
+            translation::openapi_to_spring(&openapi_file, &folder, &folder)
+                .is_err_and(|err| err.contains("Invalid output folder outside of java project"))
+        );
+    }
+
+    fn get_translator_test_dir(name: &str) -> PathBuf {
+        let mut dir = get_current_file_path();
+        dir.pop();
+        dir.pop();
+        dir.push("domain/usecase/java/translator");
+        dir.push(name);
+        dir.push("test");
+        dir
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
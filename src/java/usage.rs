@@ -0,0 +1,204 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use crate::java::scanner::usage::java_usage_finder;
+
+/// # JavaUsageKind
+/// How a type or member is used in the source code.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum JavaUsageKind {
+    /// "import org.test.Type;" or "import static org.test.Type.member;"
+    Import,
+    /// Type names in declarations, casts, generics, object creations or static accesses
+    TypeReference,
+    /// Method calls and method references
+    Invocation,
+    /// Field reads and writes, with or without "this"
+    FieldAccess,
+    /// Annotation usages on structures and members
+    Annotation,
+}
+
+impl fmt::Display for JavaUsageKind {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        let kind = match self {
+            JavaUsageKind::Import => "import",
+            JavaUsageKind::TypeReference => "type_reference",
+            JavaUsageKind::Invocation => "invocation",
+            JavaUsageKind::FieldAccess => "field_access",
+            JavaUsageKind::Annotation => "annotation",
+        };
+        write!(fmt, "{}", kind)
+    }
+}
+
+/// # JavaUsage
+/// Location of a type or member usage in a java file of the project.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JavaUsage {
+    file: PathBuf,
+    start_byte: usize,
+    end_byte: usize,
+    line: usize,
+    column: usize,
+    kind: JavaUsageKind,
+}
+
+/// # find_usages
+/// Usages in the java project of a type ("org.test.JavaClass") or of one of its members
+/// ("org.test.JavaClass#method"). Member usages through subtypes are included, and usages
+/// whose type could not be resolved while indexing are not.
+///
+/// Only the files changed since the previous scan are indexed again.
+pub fn find_usages(project_dir: &Path, symbol: &str) -> Result<Vec<JavaUsage>, String> {
+    java_usage_finder::find_usages(project_dir, symbol)
+}
+
+impl JavaUsage {
+    // Public methods
+    pub fn get_file(&self) -> &Path {
+        &self.file
+    }
+
+    pub fn get_start_byte(&self) -> usize {
+        self.start_byte
+    }
+
+    pub fn get_end_byte(&self) -> usize {
+        self.end_byte
+    }
+
+    /// # get_line
+    /// Line of the usage, starting at 1.
+    pub fn get_line(&self) -> usize {
+        self.line
+    }
+
+    /// # get_column
+    /// Column of the usage, starting at 1.
+    pub fn get_column(&self) -> usize {
+        self.column
+    }
+
+    pub fn get_kind(&self) -> JavaUsageKind {
+        self.kind
+    }
+}
+
+impl JavaUsage {
+    // Crate or private methods
+    pub(crate) fn new(
+        file: PathBuf,
+        start_byte: usize,
+        end_byte: usize,
+        line: usize,
+        column: usize,
+        kind: JavaUsageKind,
+    ) -> Self {
+        Self {
+            file,
+            start_byte,
+            end_byte,
+            line,
+            column,
+            kind,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::java::usage::{self, JavaUsageKind};
+
+    #[test]
+    fn find_usages_of_type() {
+        let usages = usage::find_usages(&get_symbol_project_dir(), "org.test.JavaGreetingService")
+            .expect("Usages expected");
+
+        let found: Vec<(String, usize, JavaUsageKind)> = usages.iter().map(describe).collect();
+        assert_eq!(
+            vec![
+                (
+                    "JavaGreetingClient.java".to_string(),
+                    4,
+                    JavaUsageKind::TypeReference
+                ),
+                (
+                    "JavaGreetingClient.java".to_string(),
+                    7,
+                    JavaUsageKind::TypeReference
+                ),
+                (
+                    "JavaGreetingClient.java".to_string(),
+                    7,
+                    JavaUsageKind::TypeReference
+                ),
+            ],
+            found
+        );
+    }
+
+    #[test]
+    fn find_usages_of_member() {
+        let invocations =
+            usage::find_usages(&get_symbol_project_dir(), "org.test.JavaGreeting#greet")
+                .expect("Usages expected");
+        let field_accesses = usage::find_usages(
+            &get_symbol_project_dir(),
+            "org.test.JavaGreetingClient#greeting",
+        )
+        .expect("Usages expected");
+
+        let invocations: Vec<(String, usize, JavaUsageKind)> =
+            invocations.iter().map(describe).collect();
+        assert_eq!(
+            vec![
+                (
+                    "JavaGreetingClient.java".to_string(),
+                    8,
+                    JavaUsageKind::Invocation
+                ),
+                (
+                    "JavaGreetingClient.java".to_string(),
+                    9,
+                    JavaUsageKind::Invocation
+                ),
+            ],
+            invocations
+        );
+        assert_eq!(1, field_accesses.len());
+        assert_eq!(JavaUsageKind::FieldAccess, field_accesses[0].get_kind());
+        assert_eq!(
+            (9, 21),
+            (field_accesses[0].get_line(), field_accesses[0].get_column())
+        );
+    }
+
+    #[test]
+    fn find_usages_invalid_input() {
+        assert!(usage::find_usages(&get_current_file_path(), "org.test.JavaGreeting").is_err());
+        assert!(usage::find_usages(&get_symbol_project_dir(), "org.test.JavaGreeting#").is_err());
+    }
+
+    fn describe(usage: &usage::JavaUsage) -> (String, usize, JavaUsageKind) {
+        let file_name = usage
+            .get_file()
+            .file_name()
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or_default();
+        (file_name, usage.get_line(), usage.get_kind())
+    }
+
+    fn get_symbol_project_dir() -> PathBuf {
+        let mut dir = get_current_file_path();
+        dir.pop();
+        dir.push("scanner/index/test/symbol_project");
+        dir
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
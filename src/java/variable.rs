@@ -3,6 +3,8 @@ use std::fmt;
 use crate::core::file_system::file_cache::FileCache;
 use crate::core::observability::logger;
 use crate::core::parser::parser_node_trait::ParserNode;
+use crate::java::annotation_usage;
+use crate::java::annotation_usage::JavaAnnotationUsage;
 use crate::java::data_type::JavaDataType;
 use crate::java::import::JavaImport;
 use crate::java::parser::java_node::JavaNode;
@@ -11,8 +13,11 @@ use crate::java::scanner::file::java_file_imports::JavaFileImports;
 
 #[derive(Debug, Clone)]
 pub struct JavaVariable {
+    annotations: Vec<JavaAnnotationUsage>,
     is_final: bool,
     data_type: JavaDataType,
+    /// Last method parameter declared as "Type... name"
+    is_varargs: bool,
     name: String,
 }
 
@@ -38,6 +43,22 @@ impl JavaVariable {
             .build()
             .expect("Final String variable always succeed")
     }
+
+    pub fn get_annotations(&self) -> &Vec<JavaAnnotationUsage> {
+        &self.annotations
+    }
+
+    pub fn is_final(&self) -> bool {
+        self.is_final
+    }
+
+    pub fn is_varargs(&self) -> bool {
+        self.is_varargs
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
 }
 
 impl JavaVariable {
@@ -49,7 +70,9 @@ impl JavaVariable {
     ) -> Result<Vec<Self>, String> {
         let mut params = Vec::new();
         for param_node in root_node.get_children() {
-            if Some(JavaNodeType::FormalParam) == param_node.get_node_type() {
+            if Some(JavaNodeType::FormalParam) == param_node.get_node_type()
+                || Some(JavaNodeType::SpreadParameter) == param_node.get_node_type()
+            {
                 params.push(Self::from_formal_param_node(
                     param_node,
                     file_imports,
@@ -65,17 +88,41 @@ impl JavaVariable {
         file_imports: &JavaFileImports,
         input_java_file_cache: &FileCache,
     ) -> Result<Self, String> {
+        let mut annotations = Vec::new();
         let mut is_final = false;
+        let mut is_varargs = false;
         let mut data_type_opt = None;
         let mut name_opt = None;
         for child_node in root_node.get_children() {
             if Some(JavaNodeType::Modifiers) == child_node.get_node_type() {
-                for modifier_node in root_node.get_children() {
-                    if Some(JavaNodeType::Final) == modifier_node.get_node_type() {
+                for modifier_node in child_node.get_children() {
+                    let modifier_type_opt = modifier_node.get_node_type();
+                    if annotation_usage::is_java_node_annotation_opt(&modifier_type_opt) {
+                        match JavaAnnotationUsage::new_from_java_node_unchecked(
+                            modifier_node,
+                            file_imports,
+                            input_java_file_cache,
+                        ) {
+                            Ok(annotation) => annotations.push(annotation),
+                            Err(err) => logger::log_warning(&err),
+                        };
+                    } else if Some(JavaNodeType::Final) == modifier_type_opt {
                         is_final = true;
                     }
                 }
             }
+            if Some(JavaNodeType::ThreeDots) == child_node.get_node_type() {
+                is_varargs = true;
+            }
+            if Some(JavaNodeType::VariableDeclarator) == child_node.get_node_type() {
+                name_opt = child_node
+                    .get_children()
+                    .iter()
+                    .find(|declarator_child| {
+                        Some(JavaNodeType::Id) == declarator_child.get_node_type()
+                    })
+                    .map(|id_node| id_node.get_content_from_cache(input_java_file_cache));
+            }
             if child_node.is_data_type_identifier() {
                 match JavaDataType::get_data_type(child_node, file_imports, input_java_file_cache) {
                     Ok(data_type) => data_type_opt = Some(data_type),
@@ -91,42 +138,72 @@ impl JavaVariable {
         }
 
         Self::builder()
+            .annotations(annotations)
             .is_final(is_final)
+            .is_varargs(is_varargs)
             .data_type(data_type_opt.ok_or("Java data type is mandatory to build variable")?)
             .name(&name_opt.ok_or("Java variable name is mandatory")?)
             .build()
     }
 
-    pub(crate) fn get_import(&self) -> Option<JavaImport> {
-        self.data_type.get_import_opt()
+    pub(crate) fn get_data_type(&self) -> &JavaDataType {
+        &self.data_type
+    }
+
+    /// Imports of the annotations and the data type
+    pub(crate) fn get_imports(&self) -> Vec<JavaImport> {
+        let mut imports: Vec<JavaImport> = self
+            .annotations
+            .iter()
+            .flat_map(|annotation| annotation.get_imports())
+            .cloned()
+            .collect();
+        imports.extend(self.data_type.get_imports());
+
+        imports
     }
 }
 
 impl fmt::Display for JavaVariable {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut string = String::new();
+        for annotation in &self.annotations {
+            string += format!("{} ", annotation).as_str();
+        }
         if self.is_final {
             string += "final ";
         }
-        string += format!("{} {}", self.data_type, self.name).as_str();
+        string += self.data_type.to_string().as_str();
+        if self.is_varargs {
+            string += "...";
+        }
+        string += format!(" {}", self.name).as_str();
         write!(f, "{}", string)
     }
 }
 
 pub struct JavaVariableBuilder {
+    annotations: Vec<JavaAnnotationUsage>,
     is_final: bool,
     data_type: Option<JavaDataType>,
+    is_varargs: bool,
     name: Option<String>,
 }
 
 impl JavaVariableBuilder {
     fn new_builder() -> Self {
         Self {
+            annotations: vec![],
             is_final: false,
             data_type: None,
+            is_varargs: false,
             name: None,
         }
     }
+    pub fn annotations(&mut self, input: Vec<JavaAnnotationUsage>) -> &mut Self {
+        self.annotations = input;
+        self
+    }
     pub fn is_final(&mut self, input: bool) -> &mut Self {
         self.is_final = input;
         self
@@ -135,17 +212,25 @@ impl JavaVariableBuilder {
         self.data_type = Some(input);
         self
     }
+    /// # is_varargs
+    /// Write the variable as "Type... name", only valid for the last method parameter.
+    pub fn is_varargs(&mut self, input: bool) -> &mut Self {
+        self.is_varargs = input;
+        self
+    }
     pub fn name(&mut self, input: &str) -> &mut Self {
         self.name = Some(input.to_string());
         self
     }
     pub fn build(&mut self) -> Result<JavaVariable, String> {
         Ok(JavaVariable {
+            annotations: self.annotations.clone(),
             is_final: self.is_final,
             data_type: self
                 .data_type
                 .clone()
                 .ok_or("Java data type is mandatory to create a JavaVariable")?,
+            is_varargs: self.is_varargs,
             name: self
                 .name
                 .clone()
@@ -183,9 +268,9 @@ mod tests {
     }
 
     #[test]
-    fn get_import_basic_type() {
+    fn get_imports_basic_type() {
         let int = JavaVariable::new_final_int("id");
 
-        assert!(int.get_import().is_none());
+        assert!(int.get_imports().is_empty());
     }
 }
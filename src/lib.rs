@@ -1,8 +1,12 @@
+pub mod avro;
 pub(crate) mod core;
 mod domain;
 pub mod java;
+pub mod openapi;
 pub mod yaml;
 
+pub use crate::core::database::db_setup::{set_database_location, DatabaseLocation};
+
 pub fn test() {
     println!("test ok");
 }
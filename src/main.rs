@@ -0,0 +1,411 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use genco::avro;
+use genco::avro::{named_type, protocol};
+use genco::java::translation::JavaNullableStyle;
+use genco::java::{project_scan, translation, usage};
+use genco::openapi;
+
+const USAGE: &str = "Usage: genco usages <org.test.JavaClass[#member]> [--project <dir>]
+       genco scan [--project <dir>]
+       genco avro-types [--dir <dir>]
+       genco avro-protocol <protocol file>
+       genco avro-to-java <avro dir> <java source dir> [--optional]
+       genco avro-to-openapi <avro file> <openapi file> [--title <title>] [--version <version>]
+       genco openapi-check <openapi file>
+       genco openapi-convert <openapi file> <output file>
+       genco openapi-to-spring <openapi file> <controller dir> <dto dir>
+       genco java-to-openapi <openapi file> <java file>...
+       genco openapi-to-avro <openapi file> <namespace> <output dir>";
+
+/// "--option value" pairs of a command
+type CommandOptions<'a> = Vec<(&'a str, &'a str)>;
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(String::as_str) {
+        Some("usages") => print_usages(&args[1..]),
+        Some("scan") => scan_project(&args[1..]),
+        Some("avro-types") => print_avro_types(&args[1..]),
+        Some("avro-protocol") => print_avro_protocol(&args[1..]),
+        Some("avro-to-java") => avro_to_java(&args[1..]),
+        Some("avro-to-openapi") => avro_to_openapi(&args[1..]),
+        Some("openapi-check") => check_openapi(&args[1..]),
+        Some("openapi-convert") => convert_openapi(&args[1..]),
+        Some("openapi-to-spring") => openapi_to_spring(&args[1..]),
+        Some("java-to-openapi") => java_to_openapi(&args[1..]),
+        Some("openapi-to-avro") => openapi_to_avro(&args[1..]),
+        _ => {
+            eprintln!("{}", USAGE);
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Reports the progress in the same line and prints the re-indexed files
+fn scan_project(args: &[String]) -> ExitCode {
+    let project_dir = match args {
+        [] => PathBuf::from("."),
+        [option, dir] if "--project" == option => PathBuf::from(dir),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let scan_result = project_scan::scan_project(&project_dir, &|progress| {
+        eprint!(
+            "\rScanned {}/{} java files",
+            progress.get_scanned_files(),
+            progress.get_total_files()
+        );
+    });
+    eprintln!();
+    let summary = match scan_result {
+        Ok(summary) => summary,
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+    for (status, files) in [
+        ("added", summary.get_added()),
+        ("changed", summary.get_changed()),
+        ("removed", summary.get_removed()),
+    ] {
+        for file in files {
+            println!("{} {}", status, file.display());
+        }
+    }
+    println!("{} unchanged", summary.get_unchanged());
+    ExitCode::SUCCESS
+}
+
+/// Prints one "full.Name file" line per named type, dependencies first and recursive ones marked
+fn print_avro_types(args: &[String]) -> ExitCode {
+    let avro_dir = match args {
+        [] => PathBuf::from("."),
+        [option, dir] if "--dir" == option => PathBuf::from(dir),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match named_type::find_named_types(&avro_dir) {
+        Ok(named_types) => {
+            for named_type in named_types {
+                let recursive = if named_type.is_recursive() {
+                    " recursive"
+                } else {
+                    ""
+                };
+                println!(
+                    "{} {}{}",
+                    named_type.get_full_name(),
+                    named_type.get_file().display(),
+                    recursive
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints one line per message, "oneway" messages without response nor errors
+fn print_avro_protocol(args: &[String]) -> ExitCode {
+    let protocol_file = match args {
+        [protocol_file] => Path::new(protocol_file),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match protocol::read_protocol(protocol_file) {
+        Ok(protocol) => {
+            match protocol.get_namespace() {
+                Some(namespace) => println!("protocol {}.{}", namespace, protocol.get_name()),
+                None => println!("protocol {}", protocol.get_name()),
+            }
+            for message in protocol.get_messages() {
+                let parameters: Vec<String> = message
+                    .get_parameters()
+                    .iter()
+                    .map(|parameter| {
+                        format!("{} {}", parameter.get_type_name(), parameter.get_name())
+                    })
+                    .collect();
+                let signature = format!("{}({})", message.get_name(), parameters.join(", "));
+                if message.is_one_way() {
+                    println!("  oneway {}", signature);
+                } else if message.get_errors().is_empty() {
+                    println!("  {} {}", message.get_response(), signature);
+                } else {
+                    println!(
+                        "  {} {} throws {}",
+                        message.get_response(),
+                        signature,
+                        message.get_errors().join(", ")
+                    );
+                }
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Nullable avro fields are boxed java types, or "Optional"s with "--optional"
+fn avro_to_java(args: &[String]) -> ExitCode {
+    let (avro_dir, source_dir, nullable_style) = match args {
+        [avro_dir, source_dir] => (avro_dir, source_dir, JavaNullableStyle::Nullable),
+        [avro_dir, source_dir, option] if "--optional" == option => {
+            (avro_dir, source_dir, JavaNullableStyle::Optional)
+        }
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    print_written_files(translation::avro_to_java(
+        Path::new(avro_dir),
+        Path::new(source_dir),
+        nullable_style,
+    ))
+}
+
+/// The document is titled after the avro file name by default, in version "1.0.0"
+fn avro_to_openapi(args: &[String]) -> ExitCode {
+    let (files, options) = match split_options(args, &["--title", "--version"]) {
+        Some(result) => result,
+        None => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let (avro_file, openapi_file) = match files.as_slice() {
+        [avro_file, openapi_file] => (Path::new(avro_file), Path::new(openapi_file)),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+    let default_title = avro_file
+        .file_stem()
+        .map(|file_stem| file_stem.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let title = get_option(&options, "--title").unwrap_or(&default_title);
+    let version = get_option(&options, "--version").unwrap_or("1.0.0");
+
+    match openapi::translation::avro_to_openapi(avro_file, title, version, openapi_file) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Errors are printed with the file, line and column where they are found
+fn check_openapi(args: &[String]) -> ExitCode {
+    let openapi_file = match args {
+        [openapi_file] => Path::new(openapi_file),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match openapi::document::check_document(openapi_file) {
+        Ok(()) => {
+            println!("{} is valid", openapi_file.display());
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Output files with "json" extension are written as JSON, any other one as YAML
+fn convert_openapi(args: &[String]) -> ExitCode {
+    let (openapi_file, output_file) = match args {
+        [openapi_file, output_file] => (Path::new(openapi_file), Path::new(output_file)),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match openapi::document::convert_document(openapi_file, output_file) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Both folders must be inside the source folder of a java project
+fn openapi_to_spring(args: &[String]) -> ExitCode {
+    let (openapi_file, controller_dir, dto_dir) = match args {
+        [openapi_file, controller_dir, dto_dir] => (openapi_file, controller_dir, dto_dir),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    print_written_files(translation::openapi_to_spring(
+        Path::new(openapi_file),
+        Path::new(controller_dir),
+        Path::new(dto_dir),
+    ))
+}
+
+/// The schemas of the java DTOs are added to the OpenAPI document
+fn java_to_openapi(args: &[String]) -> ExitCode {
+    let (openapi_file, java_files) = match args {
+        [openapi_file, java_files @ ..] if !java_files.is_empty() => (
+            Path::new(openapi_file),
+            java_files
+                .iter()
+                .map(PathBuf::from)
+                .collect::<Vec<PathBuf>>(),
+        ),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match openapi::translation::java_to_openapi(&java_files, openapi_file) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Schema names without dots are in the given namespace (i.e. "com.shop")
+fn openapi_to_avro(args: &[String]) -> ExitCode {
+    let (openapi_file, namespace, output_dir) = match args {
+        [openapi_file, namespace, output_dir] => (openapi_file, namespace, output_dir),
+        _ => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    print_written_files(avro::translation::openapi_to_avro(
+        Path::new(openapi_file),
+        namespace,
+        Path::new(output_dir),
+    ))
+}
+
+/// Arguments without the given "--option value" pairs, and those pairs.
+/// None when an option has no value or any other option is found.
+fn split_options<'a>(
+    args: &'a [String],
+    option_names: &[&str],
+) -> Option<(Vec<&'a str>, CommandOptions<'a>)> {
+    let mut values = Vec::new();
+    let mut options = Vec::new();
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        if option_names.contains(&arg.as_str()) {
+            options.push((arg.as_str(), args_iter.next()?.as_str()));
+        } else if arg.starts_with("--") {
+            return None;
+        } else {
+            values.push(arg.as_str());
+        }
+    }
+    Some((values, options))
+}
+
+fn get_option<'a>(options: &[(&str, &'a str)], name: &str) -> Option<&'a str> {
+    options
+        .iter()
+        .find(|(option, _)| name == *option)
+        .map(|(_, value)| *value)
+}
+
+/// Prints one line per written file
+fn print_written_files(result: Result<Vec<PathBuf>, String>) -> ExitCode {
+    match result {
+        Ok(files) => {
+            for file in files {
+                println!("{}", file.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Prints one "file:line:column kind" line per usage
+fn print_usages(args: &[String]) -> ExitCode {
+    let mut symbol_opt = None;
+    let mut project_dir = PathBuf::from(".");
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match (arg.as_str(), symbol_opt.is_some()) {
+            ("--project", _) => match args_iter.next() {
+                Some(dir) => project_dir = PathBuf::from(dir),
+                None => {
+                    eprintln!("{}", USAGE);
+                    return ExitCode::from(2);
+                }
+            },
+            (_, false) => symbol_opt = Some(arg.as_str()),
+            (_, true) => {
+                eprintln!("{}", USAGE);
+                return ExitCode::from(2);
+            }
+        }
+    }
+    let symbol = match symbol_opt {
+        Some(symbol) => symbol,
+        None => {
+            eprintln!("{}", USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match usage::find_usages(&project_dir, symbol) {
+        Ok(usages) => {
+            for usage in usages {
+                println!(
+                    "{}:{}:{} {}",
+                    usage.get_file().display(),
+                    usage.get_line(),
+                    usage.get_column(),
+                    usage.get_kind()
+                );
+            }
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::path::Path;
+
+use crate::domain::usecase::openapi::parser::openapi_parser;
+use crate::domain::usecase::openapi::writer::openapi_writer;
+
+/// # check_document
+/// Parse an OpenAPI YAML or JSON document and the files its "$ref"s point to.
+/// It returns the first error found, with the file, line and column where it is.
+pub fn check_document(openapi_file: &Path) -> Result<(), String> {
+    openapi_parser::parse(openapi_file)?;
+    Ok(())
+}
+
+/// # convert_document
+/// Write an OpenAPI document again in "output_file", as JSON when its extension is "json"
+/// and as YAML otherwise. The schemas read from other files through "$ref"s are written
+/// in its components.
+pub fn convert_document(openapi_file: &Path, output_file: &Path) -> Result<(), String> {
+    let document = openapi_parser::parse(openapi_file)?;
+    openapi_writer::write(&document, output_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_assert::assert_same_file;
+    use crate::openapi::document;
+
+    #[test]
+    fn check_document_with_references() {
+        let test_dir = get_test_dir("parser");
+
+        assert!(document::check_document(&test_dir.join("petstore.yaml")).is_ok());
+        assert!(document::check_document(&test_dir.join("unresolved_ref.yaml")).is_err());
+    }
+
+    #[test]
+    fn convert_document_to_json() {
+        let test_dir = get_test_dir("writer");
+        let output_file = std::env::temp_dir().join(format!(
+            "genco_convert_document_test_{}.json",
+            std::process::id()
+        ));
+
+        document::convert_document(&test_dir.join("shop_3_1.json"), &output_file)
+            .expect("OpenAPI document must be converted");
+
+        assert_same_file(&test_dir.join("shop_3_1_expected.json"), &output_file);
+        fs::remove_file(&output_file).expect("Converted document must be removed");
+    }
+
+    fn get_test_dir(name: &str) -> PathBuf {
+        let mut dir = get_current_file_path();
+        dir.pop();
+        dir.pop();
+        dir.push("domain/usecase/openapi");
+        dir.push(name);
+        dir.push("test");
+        dir
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
@@ -0,0 +1,81 @@
+use std::path::{Path, PathBuf};
+
+use crate::core::file_system::file_edition::file_editor;
+use crate::domain::usecase::avro::parser::avro_parser;
+use crate::domain::usecase::openapi::parser::dto::openapi_document::OpenapiInfo;
+use crate::domain::usecase::openapi::translator::from_avro::openapi_from_avro_translator;
+use crate::domain::usecase::openapi::translator::from_java::openapi_from_java_translator;
+use crate::java::class::JavaClass;
+
+/// # avro_to_openapi
+/// Write an OpenAPI YAML document, with the "title" and "version" given, whose component
+/// schemas are the Avro schemas of "avro_file" (".avsc", ".avpr" or ".avdl").
+pub fn avro_to_openapi(
+    avro_file: &Path,
+    title: &str,
+    version: &str,
+    openapi_file: &Path,
+) -> Result<(), String> {
+    let avro_items = avro_parser::parse(avro_file)?;
+    let info = OpenapiInfo::new(title.to_string(), None, version.to_string());
+    let content = openapi_from_avro_translator::avro_to_openapi_str(&avro_items, info)?;
+
+    file_editor::create_or_replace_file_with_bytes(openapi_file, content.as_bytes())
+}
+
+/// # java_to_openapi
+/// Add the component schemas of the DTO classes of "java_files", and of the project classes
+/// and enums used by their fields, to an existing OpenAPI document. The schemas with the
+/// same name are replaced.
+pub fn java_to_openapi(java_files: &[PathBuf], openapi_file: &Path) -> Result<(), String> {
+    let classes = java_files
+        .iter()
+        .map(|java_file| JavaClass::from(java_file))
+        .collect::<Result<Vec<JavaClass>, String>>()?;
+    openapi_from_java_translator::java_to_openapi(&classes, openapi_file)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::core::testing::test_assert::assert_same_file;
+    use crate::openapi::translation;
+
+    #[test]
+    fn avro_to_openapi_writes_the_document() {
+        let test_dir = get_translator_test_dir("from_avro");
+        let openapi_file = std::env::temp_dir().join(format!(
+            "genco_avro_to_openapi_test_{}.yaml",
+            std::process::id()
+        ));
+
+        translation::avro_to_openapi(&test_dir.join("enum.avsc"), "Shop", "1.0.0", &openapi_file)
+            .expect("OpenAPI document must be written");
+
+        assert_same_file(&test_dir.join("enum_translated.yaml"), &openapi_file);
+        fs::remove_file(&openapi_file).expect("OpenAPI document must be removed");
+    }
+
+    #[test]
+    fn java_to_openapi_invalid_input() {
+        let openapi_file = get_translator_test_dir("from_java").join("openapi/shop.yaml");
+
+        assert!(translation::java_to_openapi(&[get_current_file_path()], &openapi_file).is_err());
+    }
+
+    fn get_translator_test_dir(name: &str) -> PathBuf {
+        let mut dir = get_current_file_path();
+        dir.pop();
+        dir.pop();
+        dir.push("domain/usecase/openapi/translator");
+        dir.push(name);
+        dir.push("test");
+        dir
+    }
+
+    fn get_current_file_path() -> PathBuf {
+        PathBuf::from(file!())
+    }
+}
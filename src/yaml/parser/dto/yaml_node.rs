@@ -17,6 +17,8 @@ pub(crate) struct YamlNode {
     file_path: PathBuf,
     start_byte: usize,
     end_byte: usize,
+    start_line: usize,
+    start_column: usize,
     children: Vec<YamlNode>,
     node_type: Option<YamlNodeType>,
 }
@@ -51,6 +53,16 @@ impl YamlNode {
 
         None
     }
+
+    /// Line where the node starts, the first line is 1
+    pub(crate) fn get_start_line(&self) -> usize {
+        self.start_line
+    }
+
+    /// Column (in bytes) where the node starts, the first column is 1
+    pub(crate) fn get_start_column(&self) -> usize {
+        self.start_column
+    }
 }
 
 impl YamlNode {
@@ -64,20 +76,26 @@ impl YamlNode {
             file_path: PathBuf::from(file_path),
             start_byte: node.start_byte(),
             end_byte: node.end_byte(),
+            start_line: node.start_position().row + 1,
+            start_column: node.start_position().column + 1,
             children,
-            node_type: match YamlNodeType::from_str(node.kind()) {
-                Ok(v) => Some(v),
-                Err(e) => None, /*{
-                                    let _file_content = file_reader::read_string(
-                                        &file_path.to_path_buf(),
-                                        node.start_byte(),
-                                        node.end_byte(),
-                                    );
-                                    panic!(
-                                        "Not possible to parse YamlNode \"{}\"",
-                                        node.kind()
-                                    );
-                                }*/
+            node_type: if node.is_missing() {
+                Some(YamlNodeType::Error)
+            } else {
+                match YamlNodeType::from_str(node.kind()) {
+                    Ok(v) => Some(v),
+                    Err(e) => None, /*{
+                                        let _file_content = file_reader::read_string(
+                                            &file_path.to_path_buf(),
+                                            node.start_byte(),
+                                            node.end_byte(),
+                                        );
+                                        panic!(
+                                            "Not possible to parse YamlNode \"{}\"",
+                                            node.kind()
+                                        );
+                                    }*/
+                }
             },
         }
     }
@@ -30,6 +30,18 @@ pub enum YamlNodeType {
     QuotationMark,
     // "
     DoubleQuoteScalar,
+    FloatScalar,
+    NullScalar,
+    FlowMapping,
+    FlowSequence,
+    FlowPair,
+    Anchor,
+    AnchorName,
+    Alias,
+    AliasName,
+    Tag,
+    // Syntax errors and missing nodes
+    Error,
 }
 
 impl FromStr for YamlNodeType {
@@ -59,6 +71,17 @@ impl FromStr for YamlNodeType {
             "single_quote_scalar" => Ok(YamlNodeType::SingleQuoteScalar),
             "\"" => Ok(YamlNodeType::QuotationMark),
             "double_quote_scalar" => Ok(YamlNodeType::DoubleQuoteScalar),
+            "float_scalar" => Ok(YamlNodeType::FloatScalar),
+            "null_scalar" => Ok(YamlNodeType::NullScalar),
+            "flow_mapping" => Ok(YamlNodeType::FlowMapping),
+            "flow_sequence" => Ok(YamlNodeType::FlowSequence),
+            "flow_pair" => Ok(YamlNodeType::FlowPair),
+            "anchor" => Ok(YamlNodeType::Anchor),
+            "anchor_name" => Ok(YamlNodeType::AnchorName),
+            "alias" => Ok(YamlNodeType::Alias),
+            "alias_name" => Ok(YamlNodeType::AliasName),
+            "tag" => Ok(YamlNodeType::Tag),
+            "ERROR" => Ok(YamlNodeType::Error),
 
             _ => Err(()),
         }
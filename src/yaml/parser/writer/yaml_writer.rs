@@ -5,6 +5,7 @@ use std::path::Path;
 
 use crate::core::file_system::file_edition::file_editor;
 use crate::core::file_system::file_overwriting::file_overwriter::FileOverwriting;
+use crate::core::file_system::file_reader;
 use crate::core::parser::parser_node_trait::ParserNode;
 use crate::yaml::parser::dto::yaml_node::YamlNode;
 use crate::yaml::parser::dto::yaml_node_type::YamlNodeType;
@@ -35,6 +36,36 @@ pub fn overwrite(original_yaml_file: &Path, to_add_yaml_file: &Path) {
     overwriting.write_all().expect("Yaml must be written");
 }
 
+/// # remove
+/// Remove the entries with the given keys, and their lines, from the mapping found following
+/// [parent_keys] from the root of a YAML resource. The rest of the file is kept as it is.
+pub fn remove(yaml_file: &Path, parent_keys: &[&str], keys: &[String]) -> Result<(), String> {
+    let mut mapping = YamlNode::from_path(yaml_file)?;
+    for parent_key in parent_keys {
+        let mapped_value = filter_block_mapping_pairs_without_sequence_items(&mapping)
+            .iter()
+            .filter_map(|mapping_pair| mapping_pair.get_block_mapping_pair_string_to_block())
+            .find(|(key, _)| parent_key == key)
+            .map(|(_, mapped_value)| mapped_value.clone());
+        match mapped_value {
+            Some(mapped_value) => mapping = mapped_value,
+            None => return Ok(()),
+        }
+    }
+
+    let content = file_reader::read_all_bytes(yaml_file)?;
+    let mut overwriting = FileOverwriting::from_path(yaml_file)?;
+    for mapping_pair in filter_block_mapping_pairs_without_sequence_items(&mapping) {
+        if keys.contains(&get_key_from_block_mapping_pair(&mapping_pair)) {
+            let line_start_byte =
+                mapping_pair.get_start_byte() + 1 - mapping_pair.get_start_column();
+            let line_end_byte = get_next_line_start_byte(&content, mapping_pair.get_end_byte());
+            overwriting.replace(line_start_byte, line_end_byte, "")?;
+        }
+    }
+    overwriting.write_all()
+}
+
 fn get_yaml_overwriting(
     original_yaml_file: &Path,
     original: &YamlNode,
@@ -266,6 +297,14 @@ fn split_into_new_and_modifications(
     (new_mapping_pairs, present_mapping_pairs)
 }
 
+/// First byte after the line break following the given byte
+fn get_next_line_start_byte(content: &[u8], byte: usize) -> usize {
+    match content[byte..].iter().position(|&current| b'\n' == current) {
+        Some(position) => byte + position + 1,
+        None => content.len(),
+    }
+}
+
 fn get_mapping_pairs_from_key(mapping_pairs_to_add: &Vec<YamlNode>) -> HashMap<String, &YamlNode> {
     let mut mapping_pairs_from_key = HashMap::new();
     for mapping_pair_to_add in mapping_pairs_to_add {
@@ -348,7 +387,7 @@ mod tests {
     use crate::core::file_system::file_edition::file_editor::copy;
     use crate::core::testing::test_assert::assert_same_as_file;
     use crate::core::testing::test_path::get_non_existing_test_file;
-    use crate::yaml::parser::writer::yaml_writer::overwrite;
+    use crate::yaml::parser::writer::yaml_writer::{overwrite, remove};
 
     #[test]
     fn overwrite_test() {
@@ -387,6 +426,28 @@ mod tests {
         fs::remove_file(&copy_file_path).expect("Result file must be removed");
     }
 
+    #[test]
+    fn remove_test() {
+        let original_file_path = get_yaml_test_file("remove_base.yaml");
+        let copy_file_path = get_yaml_test_file("remove_base_copy.yaml");
+        copy(&original_file_path, &copy_file_path).expect("File should be created");
+
+        remove(
+            &original_file_path,
+            &["components", "schemas"],
+            &["Order".to_string(), "Missing".to_string()],
+        )
+        .expect("Yaml must be valid");
+
+        let result_data = fs::read_to_string(&original_file_path)
+            .expect("Unable to read expected result resource");
+        copy(&copy_file_path, &original_file_path).expect("File should be created");
+
+        let expect_result_file_path = get_yaml_test_file("remove_base_expected_result.yaml");
+        assert_same_as_file(&expect_result_file_path, &result_data);
+        fs::remove_file(&copy_file_path).expect("Result file must be removed");
+    }
+
     fn get_current_file_path() -> PathBuf {
         PathBuf::from(file!())
     }